@@ -1 +1,79 @@
+use std::fs;
+use std::path::PathBuf;
 
+use super::*;
+use crate::core::AnyEmptyResult;
+
+#[test]
+fn can_build_from_a_build_script() -> AnyEmptyResult {
+	let directory = tempfile::tempdir()?;
+	let manifest_dir = directory.path().join("app");
+	let out_dir = directory.path().join("out");
+	fs::create_dir_all(manifest_dir.join("src"))?;
+	fs::create_dir_all(&out_dir)?;
+	fs::write(
+		directory.path().join(CONFIG_FILE_NAME),
+		r#"{ "options": { "output": "app.css" } }"#,
+	)?;
+	fs::write(manifest_dir.join("src/lib.rs"), LIB)?;
+	fs::write(manifest_dir.join("readme.md"), "# app")?;
+
+	let mut writer = Vec::<u8>::new();
+	build_in(
+		BuildOptions::default(),
+		&manifest_dir,
+		&out_dir,
+		&mut writer,
+	)?;
+
+	let instructions = String::from_utf8(writer)?
+		.replace(directory.path().to_string_lossy().as_ref(), "$DIRECTORY");
+	let module = fs::read_to_string(out_dir.join("skribble.rs"))?;
+	let css = fs::read_to_string(manifest_dir.join("app.css"))?;
+
+	insta::assert_display_snapshot!(instructions);
+	assert!(
+		module.starts_with("#[allow(clippy::all)]\n#[allow(unused)]\nmod skribble_generated {")
+	);
+	assert!(module.ends_with("}\npub use skribble_generated::*;\n"));
+	assert!(!manifest_dir.join("src/skribble.rs").exists());
+	assert!(out_dir.join("skribble_config.json").exists());
+	assert!(!manifest_dir.join("cache").exists());
+	assert!(css.contains("padding"));
+
+	Ok(())
+}
+
+#[test]
+fn can_build_without_the_preset() -> AnyEmptyResult {
+	let directory = tempfile::tempdir()?;
+	let manifest_dir = directory.path().join("app");
+	let out_dir = directory.path().join("out");
+	fs::create_dir_all(manifest_dir.join("src"))?;
+	fs::create_dir_all(&out_dir)?;
+	fs::write(manifest_dir.join("src/lib.rs"), LIB)?;
+
+	build_in(
+		BuildOptions::builder()
+			.preset(false)
+			.css_path(PathBuf::from("app.css"))
+			.build(),
+		&manifest_dir,
+		&out_dir,
+		&mut Vec::<u8>::new(),
+	)?;
+
+	let css = fs::read_to_string(manifest_dir.join("app.css"))?;
+	assert!(!css.contains("padding"));
+
+	Ok(())
+}
+
+const LIB: &str = r#"
+include!(concat!(env!("OUT_DIR"), "/skribble.rs"));
+
+pub fn classes() -> String {
+  let base = other();
+  [sk().md().p().n2(), base.p().n4()].join(" ")
+}
+"#;
@@ -0,0 +1,279 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use indexmap::IndexSet;
+use typed_builder::TypedBuilder;
+
+use crate::core::Error;
+use crate::core::Priority;
+use crate::core::Result;
+use crate::core::SkribbleRunner;
+use crate::core::StyleConfig;
+use crate::preset::PresetPlugin;
+use crate::rust::RustPlugin;
+
+/// The name of the configuration file which is discovered by [`build`].
+pub const CONFIG_FILE_NAME: &str = "skribble.json";
+
+/// The options of the [`build_with_options`] helper.
+#[derive(Debug, TypedBuilder)]
+pub struct BuildOptions {
+	/// The path of the json configuration, relative to the crate. When not
+	/// provided the closest `skribble.json` in the crate directory or its
+	/// ancestors is used, falling back to the default configuration.
+	#[builder(default, setter(into, strip_option))]
+	pub config_path: Option<PathBuf>,
+	/// The path of the generated css, relative to the crate. Defaults to the
+	/// `output` option of the configuration.
+	#[builder(default, setter(into, strip_option))]
+	pub css_path: Option<PathBuf>,
+	/// The globs of the scanned files, relative to the crate. Defaults to the
+	/// `files` option of the configuration when it has been changed and
+	/// otherwise to the rust files of the `src` directory.
+	#[builder(default, setter(into, strip_option))]
+	pub files: Option<Vec<String>>,
+	/// The name of the rust module which is written to the `OUT_DIR`.
+	#[builder(default = default_module_name(), setter(into))]
+	pub module_name: String,
+	/// Add the [`PresetPlugin`] unless the configuration already provides it.
+	#[builder(default = true, setter(into))]
+	pub preset: bool,
+	/// The plugin which generates the rust module.
+	#[builder(default)]
+	pub rust_plugin: RustPlugin,
+}
+
+impl Default for BuildOptions {
+	fn default() -> Self {
+		Self::builder().build()
+	}
+}
+
+fn default_module_name() -> String {
+	"skribble.rs".into()
+}
+
+/// Generate the rust module and the css from a `build.rs` file with the
+/// default [`BuildOptions`].
+///
+/// The module is written to `$OUT_DIR/skribble.rs` and should be included in
+/// the crate.
+///
+/// ```rust,ignore
+/// include!(concat!(env!("OUT_DIR"), "/skribble.rs"));
+/// ```
+pub fn build() -> Result<()> {
+	build_with_options(BuildOptions::default())
+}
+
+/// Generate the rust module and the css from a `build.rs` file.
+///
+/// Cargo is told to rerun the build script when the configuration or one of
+/// the scanned files changes, and the diagnostics of the scanned classes are
+/// shown as cargo warnings.
+pub fn build_with_options(options: BuildOptions) -> Result<()> {
+	let manifest_dir = get_env_path("CARGO_MANIFEST_DIR")?;
+	let out_dir = get_env_path("OUT_DIR")?;
+	let stdout = std::io::stdout();
+	let mut writer = stdout.lock();
+
+	build_in(options, manifest_dir, out_dir, &mut writer)
+}
+
+fn get_env_path(name: &str) -> Result<PathBuf> {
+	env::var_os(name)
+		.map(PathBuf::from)
+		.ok_or_else(|| Error::MissingEnvironmentVariable(name.into()))
+}
+
+/// Run the build in the crate directory and write the cargo instructions to
+/// the `writer`.
+pub(crate) fn build_in(
+	options: BuildOptions,
+	manifest_dir: impl AsRef<Path>,
+	out_dir: impl AsRef<Path>,
+	writer: &mut dyn Write,
+) -> Result<()> {
+	let manifest_dir = manifest_dir.as_ref();
+	let BuildOptions {
+		config_path,
+		css_path,
+		files,
+		module_name,
+		preset,
+		mut rust_plugin,
+	} = options;
+	let out_dir = out_dir.as_ref();
+
+	let config_candidates = match config_path {
+		Some(path) => vec![manifest_dir.join(path)],
+		None => find_config_candidates(manifest_dir),
+	};
+	let config_path = config_candidates
+		.last()
+		.filter(|path| path.is_file())
+		.cloned();
+	let rust_path = rust_plugin.output_path.clone();
+	let cache_paths = [
+		rust_plugin.cache_path.clone(),
+		rust_plugin.config_cache_path.clone(),
+	];
+	let config_cache_name = rust_plugin.config_cache_path.file_name().map(PathBuf::from);
+
+	let mut config = match config_path {
+		Some(ref path) => {
+			let json = fs::read_to_string(path)
+				.map_err(|_| Error::FileReadError(path.display().to_string()))?;
+			StyleConfig::from_json(json)?
+		}
+		None => StyleConfig::default(),
+	};
+
+	let has_preset = config
+		.plugins
+		.iter()
+		.any(|plugin| plugin.get_id() == "skribble_preset");
+
+	if preset && !has_preset {
+		config.add_plugin(PresetPlugin::builder().build(), Priority::DEFAULT);
+	}
+
+	// The module is embedded in the crate with `include!`.
+	rust_plugin.include_module = true;
+	config.remove_plugin("skribble_rust");
+	config.add_plugin(rust_plugin, Priority::DEFAULT);
+
+	if let Some(css_path) = css_path {
+		config.options.output = css_path;
+	}
+
+	if let Some(files) = files {
+		config.options.files = files;
+	} else if config.options.files == vec!["**".to_string()] {
+		config.options.files = default_files();
+	}
+
+	let mut runner = SkribbleRunner::new(config, manifest_dir, None);
+	let _ = runner.initialize()?;
+	// The code is generated after the scan since the pruned atoms depend on the
+	// scanned classes.
+	let classes = runner.scan_classes()?;
+	let mut generated_files = runner.generate()?;
+	let css = runner.generate_css(&classes)?;
+
+	// The rust module and the cache files are written to the `OUT_DIR` so the
+	// crate directory only receives the css.
+	for file in generated_files.iter() {
+		let (path, content) = if file.path == rust_path {
+			(out_dir.join(&module_name), file.content.clone())
+		} else if let Some(name) = file
+			.path
+			.file_name()
+			.filter(|_| cache_paths.contains(&file.path))
+		{
+			(out_dir.join(name), file.content.clone())
+		} else {
+			continue;
+		};
+
+		fs::write(&path, content).map_err(|_| Error::FileWriteError(path))?;
+	}
+
+	generated_files.retain(|file| file.path != rust_path && !cache_paths.contains(&file.path));
+	runner.write_files(&mut generated_files)?;
+	runner.write_css(&css)?;
+
+	// The candidates which don't exist yet are included so that creating a
+	// closer configuration reruns the build.
+	for path in config_candidates.iter() {
+		write_instruction(writer, "rerun-if-changed", path.display())?;
+	}
+
+	// The `sk!` macro reads the resolved configuration from this path.
+	if let Some(name) = config_cache_name {
+		let path = out_dir.join(name);
+		write_instruction(
+			writer,
+			"rustc-env",
+			format!("SKRIBBLE_CONFIG={}", path.display()),
+		)?;
+	}
+
+	// The root directories of the globs are watched so that new files are
+	// scanned.
+	for root in get_glob_roots(&runner.get_options().files) {
+		write_instruction(
+			writer,
+			"rerun-if-changed",
+			manifest_dir.join(root).display(),
+		)?;
+	}
+
+	for entry in runner.get_files()? {
+		let path = manifest_dir.join(entry.as_str().trim_start_matches('/'));
+		write_instruction(writer, "rerun-if-changed", path.display())?;
+	}
+
+	for diagnostic in classes.get_diagnostics() {
+		// Cargo only reads the first line of each instruction.
+		let message = diagnostic.to_string().replace('\n', " ");
+		write_instruction(writer, "warning", message)?;
+	}
+
+	Ok(())
+}
+
+/// The configuration files in the directory and its ancestors, up to and
+/// including the closest one which exists.
+fn find_config_candidates(directory: &Path) -> Vec<PathBuf> {
+	let mut candidates = vec![];
+
+	for path in directory
+		.ancestors()
+		.map(|directory| directory.join(CONFIG_FILE_NAME))
+	{
+		let is_file = path.is_file();
+		candidates.push(path);
+
+		if is_file {
+			break;
+		}
+	}
+
+	candidates
+}
+
+/// The directories before the first wildcard of each included glob, e.g. `src`
+/// for `src/**/*.rs`. Globs which start with a wildcard are skipped since
+/// watching the whole crate would include the build output.
+fn get_glob_roots(globs: &[String]) -> IndexSet<PathBuf> {
+	globs
+		.iter()
+		.filter(|glob| !glob.starts_with('!'))
+		.filter_map(|glob| {
+			let root = glob
+				.split('/')
+				.take_while(|segment| !segment.contains(['*', '?', '[', '{']))
+				.collect::<Vec<_>>()
+				.join("/");
+			let root = root.trim_start_matches("./").trim_matches('/');
+
+			(!root.is_empty()).then(|| PathBuf::from(root))
+		})
+		.collect()
+}
+
+fn default_files() -> Vec<String> {
+	vec!["src/**/*.rs".into()]
+}
+
+fn write_instruction(
+	writer: &mut dyn Write,
+	name: &str,
+	value: impl std::fmt::Display,
+) -> Result<()> {
+	writeln!(writer, "cargo:{name}={value}").map_err(Error::BuildInstructionError)
+}
@@ -5,6 +5,7 @@ doc_comment::doctest!("../readme.md");
 
 use std::path::Path;
 
+pub use build_script::*;
 pub use skribble_core as core;
 pub use skribble_core::vfs;
 pub use skribble_preset as preset;
@@ -69,5 +70,7 @@ pub fn run(
 	Ok(runner)
 }
 
+mod build_script;
+
 #[cfg(test)]
 mod __tests;
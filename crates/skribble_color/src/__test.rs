@@ -3,7 +3,10 @@ use similar_asserts::assert_eq;
 
 use crate::Color;
 use crate::ColorError;
+use crate::ColorScale;
+use crate::ColorSpace;
 use crate::ExtractedParams;
+use crate::Gamut;
 
 #[rstest]
 #[case("20  , 20, 10, 10 ", ("20,20,10,10", true, false, true))]
@@ -65,6 +68,15 @@ fn invalid_extracted_params(#[case] input: &str) {
 #[case("lab(61 -9.8 -52.2)", "lab(61% -9.800003 -52.199997)")]
 #[case("lab(61 -9.8 -52.2 / 0.79)", "lab(61% -9.800003 -52.199997 / 0.79)")]
 #[case("lab(61 -9.8 -52.2 / 79%)", "lab(61% -9.800003 -52.199997 / 0.79)")]
+#[case("rgb(none 2 41)", "rgb(0 2 41)")]
+#[case("rgb(100% none 0%)", "rgb(255 0 0)")]
+#[case("hsl(none 50% none)", "hsl(0 50% 0%)")]
+#[case("hsl(100 50% 50% / none)", "hsl(100 50% 50% / 0)")]
+#[case("oklch(0.67 0.16 none)", "oklch(67% 39.999996% 0)")]
+#[case("RebeccaPurple", "#663399")]
+#[case("red", "#ff0000")]
+#[case("lightgoldenrodyellow", "#fafad2")]
+#[case("transparent", "#00000000")]
 fn valid_colors(#[case] input: &str, #[case] expected: &str) {
 	let color: Color = input.parse().unwrap();
 	assert_eq!(color.to_string(), expected);
@@ -83,7 +95,311 @@ fn valid_colors(#[case] input: &str, #[case] expected: &str) {
 #[case("hsl(100px 50% 50% / 0.5)", ColorError::InvalidHsl)]
 #[case("hwb(100, 50%, 50%, 0.5)", ColorError::InvalidHwb)]
 #[case("hwb(100px 50% 50% / 0.5)", ColorError::InvalidHwb)]
+#[case::missing_channel("rgb(from red r g)", ColorError::InvalidRelative)]
+#[case::extra_channel("rgb(from red r g b b)", ColorError::InvalidRelative)]
+#[case::unknown_keyword("rgb(from red l g b)", ColorError::InvalidRelative)]
+#[case::unclosed_calc("rgb(from red calc(r g b)", ColorError::InvalidRelative)]
+#[case::invalid_origin("rgb(from nope r g b)", ColorError::InvalidName)]
+#[case::variable("oklch(from var(--x) l c h)", ColorError::UnresolvedVariable)]
+#[case::unknown_name("nope", ColorError::InvalidName)]
+#[case::current_color("currentColor", ColorError::CurrentColor)]
+#[case::unresolved_variable("var(--x)", ColorError::UnresolvedVariable)]
 fn invalid_colors(#[case] input: &str, #[case] expected: ColorError) {
 	let color = input.parse::<Color>();
 	assert_eq!(color.unwrap_err(), expected);
 }
+
+#[test]
+fn color_scale_palette() {
+	let scale = ColorScale::builder()
+		.name("brand")
+		.seed("#3b82f6".parse::<Color>().unwrap())
+		.build();
+
+	insta::assert_debug_snapshot!(scale.palette());
+}
+
+#[rstest]
+#[case::blue("#3b82f6", 0.6)]
+#[case::saturated_green("#00ff00", 0.0)]
+#[case::yellow("oklch(0.9 0.2 100)", 0.6)]
+#[case::gray("#808080", 1.0)]
+fn color_scale_is_perceptually_even(#[case] seed: &str, #[case] chroma_falloff: f32) {
+	let scale = ColorScale::builder()
+		.name("seed")
+		.seed(seed.parse::<Color>().unwrap())
+		.chroma_falloff(chroma_falloff)
+		.build();
+
+	let lightness = scale
+		.shades()
+		.into_iter()
+		.map(|(_, color)| {
+			let Color::Oklch(oklch) = color.into_oklch() else {
+				unreachable!();
+			};
+
+			oklch.l
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(lightness.len(), 11);
+
+	for pair in lightness.windows(2) {
+		let [lighter, darker] = pair else {
+			unreachable!();
+		};
+
+		assert!(
+			lighter > darker,
+			"{lighter} should be lighter than {darker}"
+		);
+	}
+
+	for (_, color) in scale.shades() {
+		let rgba = *color.get_hex().unwrap();
+
+		for value in [rgba.red, rgba.green, rgba.blue] {
+			assert!(
+				(0.0..=1.0).contains(&value),
+				"{color} is outside of the srgb gamut"
+			);
+		}
+	}
+}
+
+#[rstest]
+#[case::black_on_white("#000", "#fff", 21.0)]
+#[case::white_on_black("#fff", "#000", 21.0)]
+#[case::same("#777", "#777", 1.0)]
+#[case::gray_on_white("#767676", "#fff", 4.54)]
+#[case::blue_on_white("#0000ff", "#ffffff", 8.59)]
+#[case::transparent_black_on_white("rgb(0 0 0 / 0.5)", "#fff", 3.98)]
+fn wcag_contrast_ratio(#[case] foreground: &str, #[case] background: &str, #[case] expected: f32) {
+	let foreground: Color = foreground.parse().unwrap();
+	let background: Color = background.parse().unwrap();
+	let ratio = foreground.contrast_ratio(&background);
+
+	assert!(
+		(ratio - expected).abs() < 0.01,
+		"expected {expected} but received {ratio}"
+	);
+}
+
+#[rstest]
+#[case::black_on_white("#000", "#fff", 106.04)]
+#[case::white_on_black("#fff", "#000", -107.88)]
+#[case::same("#777", "#777", 0.0)]
+#[case::gray_on_white("#888", "#fff", 63.06)]
+#[case::white_on_gray("#fff", "#888", -68.54)]
+fn apca_contrast(#[case] text: &str, #[case] background: &str, #[case] expected: f32) {
+	let text: Color = text.parse().unwrap();
+	let background: Color = background.parse().unwrap();
+	let contrast = text.apca_contrast(&background);
+
+	assert!(
+		(contrast - expected).abs() < 0.05,
+		"expected {expected} but received {contrast}"
+	);
+}
+
+#[rstest]
+#[case::black_white_srgb("#000", "#fff", 0.5, ColorSpace::Srgb, "#7f7f7f")]
+#[case::red_blue_srgb("#f00", "#00f", 0.5, ColorSpace::Srgb, "#7f007f")]
+#[case::premultiplied_alpha("rgb(255 0 0 / 0)", "#00f", 0.5, ColorSpace::Srgb, "#0000ff7f")]
+#[case::no_amount("#3b82f6", "#fff", 0.0, ColorSpace::Srgb, "#3b82f6")]
+#[case::full_amount("#3b82f6", "#000", 1.0, ColorSpace::Oklch, "#000000")]
+fn mix_colors(
+	#[case] color: &str,
+	#[case] other: &str,
+	#[case] amount: f32,
+	#[case] space: ColorSpace,
+	#[case] expected: &str,
+) {
+	let color: Color = color.parse().unwrap();
+	let other: Color = other.parse().unwrap();
+	let mixed = color.mix(&other, amount, space).into_hex();
+
+	assert_eq!(mixed.to_string(), expected);
+}
+
+#[test]
+fn mix_colors_with_powerless_hue() {
+	let blue: Color = "#3b82f6".parse().unwrap();
+	let white: Color = "#fff".parse().unwrap();
+	let Color::Oklch(mixed) = blue.mix(&white, 0.5, ColorSpace::Oklch) else {
+		panic!("expected an oklch color");
+	};
+	let Color::Oklch(seed) = blue.into_oklch() else {
+		panic!("expected an oklch color");
+	};
+
+	assert!((mixed.hue.into_positive_degrees() - seed.hue.into_positive_degrees()).abs() < 0.01);
+	assert!((mixed.chroma - seed.chroma / 2.0).abs() < 0.001);
+}
+
+#[test]
+fn oklch_adjustments() {
+	let blue: Color = "#3b82f6".parse().unwrap();
+	let lightness = |color: Color| color.into_oklch().get_oklch().unwrap().l;
+
+	// Gamut mapping may shift the lightness by up to the just noticeable
+	// difference.
+	assert!((lightness(blue.lighten(0.1)) - lightness(blue) - 0.1).abs() < 0.02);
+	assert!((lightness(blue) - lightness(blue.darken(0.1)) - 0.1).abs() < 0.02);
+	assert!((lightness(blue.invert()) - (1.0 - lightness(blue))).abs() < 0.02);
+	assert_eq!(
+		blue.desaturate(1.0).into_hex(),
+		blue.desaturate(2.0).into_hex()
+	);
+}
+
+#[rstest]
+#[case("color(display-p3 1 0 0)", "color(display-p3 1 0 0)")]
+#[case(
+	"color(display-p3 100% 50% 0% / 50%)",
+	"color(display-p3 1 0.5 0 / 0.5)"
+)]
+#[case(
+	"color(rec2020 0.5 0.5 0.5 / 0.25)",
+	"color(rec2020 0.5 0.5 0.5 / 0.25)"
+)]
+#[case("color(srgb 1 0 0)", "rgb(255 0 0)")]
+#[case("color(srgb none 0 0)", "rgb(0 0 0)")]
+#[case("color(xyz 0.4124 0.2126 0.0193)", "#ff0000")]
+#[case("color(xyz-d65 0.4124 0.2126 0.0193)", "#ff0000")]
+#[case("color(xyz-d50 0.4361 0.2225 0.0139)", "#ff0000")]
+#[case("color(a98-rgb 0.8587 0 0)", "#ff0000")]
+#[case("color(prophoto-rgb 0.7023 0.2757 0.1036)", "#ff0000")]
+fn color_function(#[case] input: &str, #[case] expected: &str) {
+	let color: Color = input.parse().unwrap();
+	let color = if expected.starts_with('#') {
+		color.into_hex()
+	} else {
+		color
+	};
+
+	assert_eq!(color.to_string(), expected);
+}
+
+#[test]
+fn color_function_srgb_linear() {
+	let color: Color = "color(srgb-linear 0.5 0 1)".parse().unwrap();
+	let rgba = color.get_rgb().unwrap();
+
+	assert!((rgba.red - 0.7354).abs() < 0.001);
+	assert!(rgba.green.abs() < 0.001);
+	assert!((rgba.blue - 1.0).abs() < 0.001);
+}
+
+#[rstest]
+#[case::unknown_space("color(cmyk 1 1 1)")]
+#[case::missing_channel("color(display-p3 1 0)")]
+#[case::extra_channel("color(display-p3 1 0 0 0)")]
+#[case::invalid_alpha("color(display-p3 1 0 0 / a)")]
+fn invalid_color_function(#[case] input: &str) {
+	assert_eq!(input.parse::<Color>(), Err(ColorError::InvalidFunction));
+}
+
+#[rstest]
+#[case::srgb_red_in_p3("#f00", Gamut::DisplayP3, [0.9175, 0.2003, 0.1387])]
+#[case::srgb_red_in_rec2020("#f00", Gamut::Rec2020, [0.7919, 0.2308, 0.0739])]
+#[case::p3_red_in_p3("color(display-p3 1 0 0)", Gamut::DisplayP3, [1.0, 0.0, 0.0])]
+fn convert_into_gamut(#[case] input: &str, #[case] gamut: Gamut, #[case] expected: [f32; 3]) {
+	let color: Color = input.parse().unwrap();
+	let converted = color.into_gamut(gamut);
+	let rgba = converted.get_gamut().unwrap();
+
+	for (value, expected) in [rgba.red, rgba.green, rgba.blue].iter().zip(expected) {
+		assert!(
+			(value - expected).abs() < 0.002,
+			"expected {expected:?} but received {converted}"
+		);
+	}
+}
+
+#[test]
+fn gamut_mapping_preserves_hue() {
+	let color: Color = "color(display-p3 0 1 0)".parse().unwrap();
+	assert!(!color.is_in_gamut(Gamut::Srgb));
+	assert!(color.is_in_gamut(Gamut::DisplayP3));
+	assert!(color.is_in_gamut(Gamut::Rec2020));
+
+	let srgb = color.into_rgb();
+	let hue = |color: &Color| color.into_oklch().get_oklch().unwrap().hue.into_degrees();
+	let lightness = |color: &Color| color.into_oklch().get_oklch().unwrap().l;
+
+	// Clipping each channel would shift the hue, gamut mapping reduces the chroma
+	// instead.
+	assert!((hue(&srgb) - hue(&color)).abs() < 3.0);
+	assert!((lightness(&srgb) - lightness(&color)).abs() < 0.02);
+	assert!(srgb.is_in_gamut(Gamut::Srgb));
+}
+
+#[rstest]
+#[case("rgb(from red r g b)", "#ff0000")]
+#[case("rgb(from #ff0000 calc(r / 2) g b)", "#7f0000")]
+#[case("rgb(from red r g b / 50%)", "#ff00007f")]
+#[case("rgb(from red b r g / calc(alpha - 0.5))", "#00ff007f")]
+#[case("hsl(from red calc(h + 120) s l)", "#00ff00")]
+#[case("hsl(from red calc(h + 0.5turn) s l)", "#00ffff")]
+#[case("hsl(from red h s 25%)", "#7f0000")]
+#[case("hwb(from red h 100 b)", "#ffffff")]
+#[case("oklch(from #3b82f6 l c h)", "#3b82f6")]
+#[case("oklch(from #3b82f6 l 0 h)", "#868686")]
+#[case("oklch(from #3b82f6 clamp(0, calc(l + 0.5), 1) c h)", "#ffffff")]
+#[case("oklch(from rgb(from red r g b) 1 0 none)", "#ffffff")]
+#[case("oklab(from #3b82f6 l 0 0)", "#868686")]
+#[case("lab(from black 100 0 0)", "#ffffff")]
+#[case("lch(from white 0 c h)", "#000000")]
+#[case("color(from red srgb r g b)", "#ff0000")]
+#[case("color(from red xyz x y z)", "#ff0000")]
+#[case("color(from red srgb max(r, g) max(g, 1) min(b, 1))", "#ffff00")]
+fn relative_colors(#[case] input: &str, #[case] expected: &str) {
+	let color: Color = input.parse().unwrap();
+	assert_same_color(color, expected);
+}
+
+#[rstest]
+#[case("var(--primary)", "#ff0000")]
+#[case("var(--missing, blue)", "#0000ff")]
+#[case("var(--nested)", "#ff0000")]
+#[case("oklch(from var(--primary) l c h)", "#ff0000")]
+#[case("hsl(from var(--primary) calc(h + 240) s l)", "#0000ff")]
+fn parse_with_variables(#[case] input: &str, #[case] expected: &str) {
+	let resolve = |name: &str| {
+		match name {
+			"--primary" => Some("red".to_string()),
+			"--nested" => Some("var(--primary)".to_string()),
+			"--cycle" => Some("var(--cycle)".to_string()),
+			_ => None,
+		}
+	};
+	let color = Color::parse_with(input, resolve).unwrap();
+
+	assert_same_color(color, expected);
+	assert_eq!(
+		Color::parse_with("var(--cycle)", resolve),
+		Err(ColorError::UnresolvedVariable)
+	);
+}
+
+/// Check that the colors are the same when rendered as hex, allowing for
+/// rounding errors.
+fn assert_same_color(color: Color, expected: &str) {
+	let expected: Color = expected.parse().unwrap();
+	let (actual, expected) = (color.to_srgb(), expected.to_srgb());
+	let channels = [
+		(actual.red, expected.red),
+		(actual.green, expected.green),
+		(actual.blue, expected.blue),
+		(actual.alpha, expected.alpha),
+	];
+
+	for (actual, expected) in channels {
+		assert!(
+			(actual - expected).abs() < 2.0 / 255.0,
+			"{color} is not {expected}"
+		);
+	}
+}
@@ -0,0 +1,145 @@
+use palette::rgb::Rgba;
+
+use crate::Color;
+
+/// The exponent used to estimate the screen luminance in APCA.
+const APCA_MAIN_TRC: f32 = 2.4;
+const APCA_RED_COEFFICIENT: f32 = 0.212_672_9;
+const APCA_GREEN_COEFFICIENT: f32 = 0.715_152_2;
+const APCA_BLUE_COEFFICIENT: f32 = 0.072_175;
+const APCA_NORMAL_BACKGROUND: f32 = 0.56;
+const APCA_NORMAL_TEXT: f32 = 0.57;
+const APCA_REVERSE_TEXT: f32 = 0.62;
+const APCA_REVERSE_BACKGROUND: f32 = 0.65;
+const APCA_BLACK_THRESHOLD: f32 = 0.022;
+const APCA_BLACK_CLAMP: f32 = 1.414;
+const APCA_SCALE: f32 = 1.14;
+const APCA_LOW_OFFSET: f32 = 0.027;
+const APCA_LOW_CLIP: f32 = 0.1;
+const APCA_DELTA_Y_MIN: f32 = 0.0005;
+
+impl Color {
+	/// The [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+	/// of the color as defined by WCAG 2.x. The alpha channel is ignored.
+	pub fn relative_luminance(&self) -> f32 {
+		let rgba = self.to_rgba();
+		let linearize = |value: f32| {
+			let value = value.clamp(0.0, 1.0);
+
+			if value <= 0.040_45 {
+				value / 12.92
+			} else {
+				((value + 0.055) / 1.055).powf(2.4)
+			}
+		};
+
+		0.2126 * linearize(rgba.red)
+			+ 0.7152 * linearize(rgba.green)
+			+ 0.0722 * linearize(rgba.blue)
+	}
+
+	/// The [WCAG 2.x contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)
+	/// between this color and the provided color. The result is between `1.0`
+	/// and `21.0` and the order of the colors does not matter.
+	///
+	/// When this color is transparent it is composited over the `other` color
+	/// before the contrast is calculated.
+	///
+	/// ```rust
+	/// use skribble_color::Color;
+	///
+	/// let black: Color = "#000".parse().unwrap();
+	/// let white: Color = "#fff".parse().unwrap();
+	/// assert_eq!(black.contrast_ratio(&white).round(), 21.0);
+	/// ```
+	pub fn contrast_ratio(&self, other: &Color) -> f32 {
+		let foreground = self.composite_over(other).relative_luminance();
+		let background = other.relative_luminance();
+		let (lighter, darker) = if foreground > background {
+			(foreground, background)
+		} else {
+			(background, foreground)
+		};
+
+		(lighter + 0.05) / (darker + 0.05)
+	}
+
+	/// The [APCA](https://github.com/Myndex/apca-w3) lightness contrast (`Lc`)
+	/// of this color used as text on the provided `background`.
+	///
+	/// The value is positive for dark text on a light background and negative
+	/// for light text on a dark background. Values range from roughly `-108`
+	/// to `106`.
+	pub fn apca_contrast(&self, background: &Color) -> f32 {
+		let text = apca_luminance(&self.composite_over(background).to_rgba());
+		let background = apca_luminance(&background.to_rgba());
+
+		if (background - text).abs() < APCA_DELTA_Y_MIN {
+			return 0.0;
+		}
+
+		let contrast = if background > text {
+			let contrast = (background.powf(APCA_NORMAL_BACKGROUND) - text.powf(APCA_NORMAL_TEXT))
+				* APCA_SCALE;
+
+			if contrast < APCA_LOW_CLIP {
+				0.0
+			} else {
+				contrast - APCA_LOW_OFFSET
+			}
+		} else {
+			let contrast = (background.powf(APCA_REVERSE_BACKGROUND)
+				- text.powf(APCA_REVERSE_TEXT))
+				* APCA_SCALE;
+
+			if contrast > -APCA_LOW_CLIP {
+				0.0
+			} else {
+				contrast + APCA_LOW_OFFSET
+			}
+		};
+
+		contrast * 100.0
+	}
+
+	/// Composite this color over the provided background using the alpha
+	/// channel of this color. The alpha of the background is ignored.
+	fn composite_over(&self, background: &Color) -> Color {
+		let foreground = self.to_rgba();
+		let alpha = foreground.alpha.clamp(0.0, 1.0);
+
+		if alpha >= 1.0 {
+			return Color::Rgb(foreground);
+		}
+
+		let background = background.to_rgba();
+		let blend = |top: f32, bottom: f32| top * alpha + bottom * (1.0 - alpha);
+
+		Color::Rgb(Rgba::new(
+			blend(foreground.red, background.red),
+			blend(foreground.green, background.green),
+			blend(foreground.blue, background.blue),
+			1.0,
+		))
+	}
+
+	fn to_rgba(self) -> Rgba {
+		match self.into_rgb() {
+			Self::Rgb(rgba) => rgba,
+			_ => unreachable!("the color is always converted to rgb"),
+		}
+	}
+}
+
+/// The estimated screen luminance used by APCA.
+fn apca_luminance(rgba: &Rgba) -> f32 {
+	let luminance = APCA_RED_COEFFICIENT * rgba.red.clamp(0.0, 1.0).powf(APCA_MAIN_TRC)
+		+ APCA_GREEN_COEFFICIENT * rgba.green.clamp(0.0, 1.0).powf(APCA_MAIN_TRC)
+		+ APCA_BLUE_COEFFICIENT * rgba.blue.clamp(0.0, 1.0).powf(APCA_MAIN_TRC);
+
+	if luminance > APCA_BLACK_THRESHOLD {
+		luminance
+	} else {
+		luminance + (APCA_BLACK_THRESHOLD - luminance).powf(APCA_BLACK_CLAMP)
+	}
+}
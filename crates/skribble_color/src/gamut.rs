@@ -0,0 +1,599 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use palette::convert::FromColorUnclamped;
+use palette::rgb::Rgba;
+use palette::FromColor;
+use palette::Oklaba;
+use palette::Oklcha;
+use palette::Xyza;
+
+use crate::Color;
+use crate::ColorError;
+
+/// The just noticeable difference used when mapping colors into a gamut.
+const JND: f32 = 0.02;
+const EPSILON: f32 = 0.0001;
+
+type Matrix = [[f32; 3]; 3];
+
+const SRGB_TO_XYZ: Matrix = [
+	[0.412_390_8, 0.357_584_33, 0.180_480_8],
+	[0.212_639, 0.715_168_65, 0.072_192_32],
+	[0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+const XYZ_TO_SRGB: Matrix = [
+	[3.240_97, -1.537_383_2, -0.498_610_76],
+	[-0.969_243_65, 1.875_967_5, 0.041_555_06],
+	[0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+const DISPLAY_P3_TO_XYZ: Matrix = [
+	[0.486_570_95, 0.265_667_7, 0.198_217_29],
+	[0.228_974_56, 0.691_738_5, 0.079_286_91],
+	[0.0, 0.045_113_38, 1.043_944_4],
+];
+const XYZ_TO_DISPLAY_P3: Matrix = [
+	[2.493_497, -0.931_383_6, -0.402_710_8],
+	[-0.829_489, 1.762_664_1, 0.023_624_686],
+	[0.035_845_83, -0.076_172_39, 0.956_884_5],
+];
+const REC2020_TO_XYZ: Matrix = [
+	[0.636_958, 0.144_616_9, 0.168_880_98],
+	[0.262_700_2, 0.677_998_1, 0.059_301_716],
+	[0.0, 0.028_072_692, 1.060_985_1],
+];
+const XYZ_TO_REC2020: Matrix = [
+	[1.716_651_2, -0.355_670_8, -0.253_366_3],
+	[-0.666_684_3, 1.616_481_2, 0.015_768_546],
+	[0.017_639_857, -0.042_770_613, 0.942_103_1],
+];
+const A98_RGB_TO_XYZ: Matrix = [
+	[0.576_669, 0.185_558_24, 0.188_228_65],
+	[0.297_344_97, 0.627_363_6, 0.075_291_46],
+	[0.027_031_36, 0.070_688_85, 0.991_337_54],
+];
+const XYZ_TO_A98_RGB: Matrix = [
+	[2.041_588, -0.565_007, -0.344_731_35],
+	[-0.969_243_65, 1.875_967_5, 0.041_555_06],
+	[0.013_444_28, -0.118_362_39, 1.015_175],
+];
+const PROPHOTO_RGB_TO_XYZ_D50: Matrix = [
+	[0.797_766_6, 0.135_181_3, 0.031_347_734],
+	[0.288_074_83, 0.711_835_2, 0.000_089_936_94],
+	[0.0, 0.0, 0.825_104_6],
+];
+const XYZ_D50_TO_PROPHOTO_RGB: Matrix = [
+	[1.345_786_9, -0.255_572_08, -0.051_101_865],
+	[-0.544_630_7, 1.508_247_8, 0.020_527_447],
+	[0.0, 0.0, 1.211_967_6],
+];
+const D50_TO_D65: Matrix = [
+	[0.955_473_4, -0.023_098_537, 0.063_259_31],
+	[-0.028_369_707, 1.009_995_4, 0.021_041_4],
+	[0.012_314_002, -0.020_507_697, 1.330_365_9],
+];
+const D65_TO_D50: Matrix = [
+	[1.047_929_8, 0.022_946_95, -0.050_192_316],
+	[0.029_627_803, 0.990_434_4, -0.017_073_768],
+	[-0.009_243_029, 0.015_055_225, 0.751_874_3],
+];
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_968;
+
+/// The rgb color gamuts which are supported by the css `color()` function.
+#[cfg_attr(
+	feature = "serde",
+	derive(::serde::Serialize, ::serde::Deserialize),
+	serde(rename_all = "kebab-case")
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Gamut {
+	#[default]
+	Srgb,
+	DisplayP3,
+	Rec2020,
+}
+
+impl Gamut {
+	/// The name of the color space as used in the css `color()` function.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Srgb => "srgb",
+			Self::DisplayP3 => "display-p3",
+			Self::Rec2020 => "rec2020",
+		}
+	}
+
+	/// The media query which matches screens that can display this gamut.
+	/// e.g. `(color-gamut: p3)`.
+	pub fn media_query(&self) -> &'static str {
+		match self {
+			Self::Srgb => "(color-gamut: srgb)",
+			Self::DisplayP3 => "(color-gamut: p3)",
+			Self::Rec2020 => "(color-gamut: rec2020)",
+		}
+	}
+
+	fn linear_to_xyz_matrix(self) -> &'static Matrix {
+		match self {
+			Self::Srgb => &SRGB_TO_XYZ,
+			Self::DisplayP3 => &DISPLAY_P3_TO_XYZ,
+			Self::Rec2020 => &REC2020_TO_XYZ,
+		}
+	}
+
+	fn xyz_to_linear_matrix(self) -> &'static Matrix {
+		match self {
+			Self::Srgb => &XYZ_TO_SRGB,
+			Self::DisplayP3 => &XYZ_TO_DISPLAY_P3,
+			Self::Rec2020 => &XYZ_TO_REC2020,
+		}
+	}
+
+	/// Convert a gamma encoded channel into a linear channel.
+	fn linearize(self, value: f32) -> f32 {
+		let sign = value.signum();
+		let value = value.abs();
+
+		let linear = match self {
+			Self::Srgb | Self::DisplayP3 => {
+				if value <= 0.040_45 {
+					value / 12.92
+				} else {
+					((value + 0.055) / 1.055).powf(2.4)
+				}
+			}
+			Self::Rec2020 => {
+				if value < REC2020_BETA * 4.5 {
+					value / 4.5
+				} else {
+					((value + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+				}
+			}
+		};
+
+		sign * linear
+	}
+
+	/// Convert a linear channel into a gamma encoded channel.
+	fn encode(self, value: f32) -> f32 {
+		let sign = value.signum();
+		let value = value.abs();
+
+		let encoded = match self {
+			Self::Srgb | Self::DisplayP3 => {
+				if value <= 0.003_130_8 {
+					value * 12.92
+				} else {
+					1.055 * value.powf(1.0 / 2.4) - 0.055
+				}
+			}
+			Self::Rec2020 => {
+				if value < REC2020_BETA {
+					value * 4.5
+				} else {
+					REC2020_ALPHA * value.powf(0.45) - (REC2020_ALPHA - 1.0)
+				}
+			}
+		};
+
+		sign * encoded
+	}
+}
+
+impl Display for Gamut {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+impl FromStr for Gamut {
+	type Err = ColorError;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		match input.trim() {
+			"srgb" => Ok(Self::Srgb),
+			"display-p3" | "p3" => Ok(Self::DisplayP3),
+			"rec2020" => Ok(Self::Rec2020),
+			_ => Err(ColorError::InvalidFunction),
+		}
+	}
+}
+
+/// A gamma encoded rgb color within the provided [`Gamut`]. This is rendered
+/// with the css `color()` function. e.g. `color(display-p3 1 0 0)`.
+///
+/// The channels are between `0.0` and `1.0` when the color is within the
+/// gamut.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GamutRgba {
+	pub gamut: Gamut,
+	pub red: f32,
+	pub green: f32,
+	pub blue: f32,
+	pub alpha: f32,
+}
+
+impl GamutRgba {
+	pub fn new(gamut: Gamut, red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+		Self {
+			gamut,
+			red,
+			green,
+			blue,
+			alpha,
+		}
+	}
+
+	/// Convert the color into the CIE XYZ color space.
+	pub fn to_xyz(&self) -> Xyza {
+		let linear = [self.red, self.green, self.blue].map(|value| self.gamut.linearize(value));
+		let [x, y, z] = multiply(self.gamut.linear_to_xyz_matrix(), linear);
+
+		Xyza::new(x, y, z, self.alpha)
+	}
+
+	/// Convert the CIE XYZ color into the provided gamut without clamping the
+	/// channels.
+	pub fn from_xyz(xyz: Xyza, gamut: Gamut) -> Self {
+		let linear = multiply(gamut.xyz_to_linear_matrix(), [xyz.x, xyz.y, xyz.z]);
+		let [red, green, blue] = linear.map(|value| gamut.encode(value));
+
+		Self::new(gamut, red, green, blue, xyz.alpha)
+	}
+
+	/// Whether the color can be displayed within the gamut.
+	pub fn is_in_gamut(&self) -> bool {
+		[self.red, self.green, self.blue]
+			.iter()
+			.all(|value| (-EPSILON..=1.0 + EPSILON).contains(value))
+	}
+
+	/// Clip the channels to be within the gamut.
+	pub fn clip(&self) -> Self {
+		Self::new(
+			self.gamut,
+			self.red.clamp(0.0, 1.0),
+			self.green.clamp(0.0, 1.0),
+			self.blue.clamp(0.0, 1.0),
+			self.alpha.clamp(0.0, 1.0),
+		)
+	}
+
+	fn to_oklab(self) -> Oklaba {
+		Oklaba::from_color_unclamped(self.to_xyz())
+	}
+}
+
+impl Display for GamutRgba {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let Self {
+			gamut,
+			red,
+			green,
+			blue,
+			alpha,
+		} = self;
+		let [red, green, blue] = [red, green, blue].map(|channel| round_channel(*channel));
+
+		if *alpha == 1.0 {
+			write!(f, "color({gamut} {red} {green} {blue})")
+		} else {
+			write!(f, "color({gamut} {red} {green} {blue} / {alpha})")
+		}
+	}
+}
+
+/// Round a channel to remove the noise introduced by converting between
+/// gamuts.
+fn round_channel(channel: f32) -> f32 {
+	(channel * 100_000.0).round() / 100_000.0
+}
+
+impl Color {
+	/// Create a color in the `display-p3` gamut.
+	pub fn display_p3(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+		Self::Gamut(GamutRgba::new(Gamut::DisplayP3, red, green, blue, alpha))
+	}
+
+	/// Create a color in the `rec2020` gamut.
+	pub fn rec2020(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+		Self::Gamut(GamutRgba::new(Gamut::Rec2020, red, green, blue, alpha))
+	}
+
+	/// Whether the color can be displayed within the provided gamut.
+	pub fn is_in_gamut(&self, gamut: Gamut) -> bool {
+		match self {
+			Self::Hex(_) | Self::Rgb(_) | Self::Hsl(_) | Self::Hwb(_) | Self::Hsv(_) => true,
+			_ => GamutRgba::from_xyz(self.to_xyz(), gamut).is_in_gamut(),
+		}
+	}
+
+	/// Returns the color rendered with the css `color()` function in the
+	/// provided gamut. Colors outside of the gamut are mapped into it using the
+	/// [CSS Color 4](https://www.w3.org/TR/css-color-4/#gamut-mapping) gamut
+	/// mapping algorithm.
+	///
+	/// ```rust
+	/// use skribble_color::Color;
+	/// use skribble_color::Gamut;
+	///
+	/// let color: Color = "oklch(0.7 0.4 150)".parse().unwrap();
+	/// assert!(!color.is_in_gamut(Gamut::DisplayP3));
+	/// let mapped = color.into_gamut(Gamut::DisplayP3);
+	/// assert!(mapped.is_in_gamut(Gamut::DisplayP3));
+	/// ```
+	pub fn into_gamut(self, gamut: Gamut) -> Self {
+		Self::Gamut(map_into_gamut(self.to_oklch_unclamped(), gamut))
+	}
+
+	/// Get a reference to the inner rgb value if the current color uses the
+	/// `color()` function.
+	pub fn get_gamut(&self) -> Option<&GamutRgba> {
+		match self {
+			Self::Gamut(ref rgba) => Some(rgba),
+			_ => None,
+		}
+	}
+
+	/// Get a mutable reference to the inner rgb value if the current color
+	/// uses the `color()` function.
+	pub fn get_gamut_mut(&mut self) -> Option<&mut GamutRgba> {
+		match self {
+			Self::Gamut(ref mut rgba) => Some(rgba),
+			_ => None,
+		}
+	}
+
+	/// Convert the color into sRGB. Colors which are not already in an sRGB
+	/// based format are mapped into the sRGB gamut.
+	pub(crate) fn to_srgb(self) -> Rgba {
+		match self {
+			Self::Hex(rgba) | Self::Rgb(rgba) => rgba,
+			Self::Hsl(hsla) => Rgba::from_color(hsla),
+			Self::Hwb(hwba) => Rgba::from_color(hwba),
+			Self::Hsv(hsva) => Rgba::from_color(hsva),
+			_ => {
+				let GamutRgba {
+					red,
+					green,
+					blue,
+					alpha,
+					..
+				} = map_into_gamut(self.to_oklch_unclamped(), Gamut::Srgb);
+
+				Rgba::new(red, green, blue, alpha)
+			}
+		}
+	}
+
+	/// Convert the color into OKLCH without clamping so that wide gamut colors
+	/// are preserved.
+	pub(crate) fn to_oklch_unclamped(self) -> Oklcha {
+		match self {
+			Self::Hex(rgba) | Self::Rgb(rgba) => Oklcha::from_color_unclamped(rgba),
+			Self::Hsl(hsla) => Oklcha::from_color_unclamped(hsla),
+			Self::Hwb(hwba) => Oklcha::from_color_unclamped(hwba),
+			Self::Hsv(hsva) => Oklcha::from_color_unclamped(hsva),
+			Self::Lch(lcha) => Oklcha::from_color_unclamped(lcha),
+			Self::Oklch(oklcha) => oklcha,
+			Self::Lab(laba) => Oklcha::from_color_unclamped(laba),
+			Self::Oklab(oklaba) => Oklcha::from_color_unclamped(oklaba),
+			Self::Gamut(rgba) => Oklcha::from_color_unclamped(rgba.to_xyz()),
+		}
+	}
+
+	/// Convert the color into the CIE XYZ color space without clamping.
+	pub(crate) fn to_xyz(self) -> Xyza {
+		match self {
+			Self::Gamut(rgba) => rgba.to_xyz(),
+			_ => Xyza::from_color_unclamped(self.to_oklch_unclamped()),
+		}
+	}
+}
+
+/// Map an OKLCH color into the provided gamut by reducing its chroma until
+/// the clipped color is no longer noticeably different. This follows the
+/// gamut mapping algorithm from the CSS Color Module Level 4.
+pub(crate) fn map_into_gamut(color: Oklcha, gamut: Gamut) -> GamutRgba {
+	if color.l >= 1.0 {
+		return GamutRgba::new(gamut, 1.0, 1.0, 1.0, color.alpha);
+	}
+
+	if color.l <= 0.0 {
+		return GamutRgba::new(gamut, 0.0, 0.0, 0.0, color.alpha);
+	}
+
+	let to_gamut = |oklch: Oklcha| GamutRgba::from_xyz(Xyza::from_color_unclamped(oklch), gamut);
+	let rgba = to_gamut(color);
+
+	if rgba.is_in_gamut() {
+		return rgba.clip();
+	}
+
+	let mut current = color;
+	let mut clipped = rgba.clip();
+
+	if delta_eok(current, clipped) < JND {
+		return clipped;
+	}
+
+	let mut min = 0.0;
+	let mut max = color.chroma;
+	let mut min_in_gamut = true;
+
+	while max - min > EPSILON {
+		current.chroma = (min + max) / 2.0;
+		let rgba = to_gamut(current);
+
+		if min_in_gamut && rgba.is_in_gamut() {
+			min = current.chroma;
+			continue;
+		}
+
+		clipped = rgba.clip();
+		let delta = delta_eok(current, clipped);
+
+		if delta < JND {
+			if JND - delta < EPSILON {
+				return clipped;
+			}
+
+			min_in_gamut = false;
+			min = current.chroma;
+		} else {
+			max = current.chroma;
+		}
+	}
+
+	clipped
+}
+
+/// Parse the content of the css `color()` function. e.g. `display-p3 1 0 0 /
+/// 0.5`.
+pub(crate) fn parse_color_function(content: &str) -> Result<Color, ColorError> {
+	let error = ColorError::InvalidFunction;
+	let (channels, alpha) = match content.split_once('/') {
+		Some((channels, alpha)) => (channels, Some(alpha.trim())),
+		None => (content, None),
+	};
+
+	let mut params = channels.split_whitespace();
+	let space = params.next().ok_or(error)?;
+	let mut channel =
+		|| -> Result<f32, ColorError> { parse_channel(params.next().ok_or(error)?).ok_or(error) };
+
+	let channels = [channel()?, channel()?, channel()?];
+
+	if params.next().is_some() {
+		return Err(error);
+	}
+
+	let alpha = match alpha {
+		Some(alpha) => parse_channel(alpha).ok_or(error)?,
+		None => 1.0,
+	};
+
+	color_from_predefined(space, channels, alpha)
+}
+
+/// Parse a number, percentage or `none` channel of the `color()` function.
+fn parse_channel(value: &str) -> Option<f32> {
+	if value == "none" {
+		return Some(0.0);
+	}
+
+	match value.strip_suffix('%') {
+		Some(percent) => percent.parse::<f32>().ok().map(|v| v / 100.0),
+		None => value.parse::<f32>().ok(),
+	}
+}
+
+/// Create a color from the channels of one of the predefined color spaces
+/// supported by the css `color()` function. Colors in the `xyz`, `a98-rgb` and
+/// `prophoto-rgb` spaces are stored as OKLCH since they can't be used as an
+/// output format.
+pub(crate) fn color_from_predefined(
+	space: &str,
+	channels: [f32; 3],
+	alpha: f32,
+) -> Result<Color, ColorError> {
+	let [red, green, blue] = channels;
+	let color = match space {
+		"srgb" => Color::rgb(red, green, blue, alpha),
+		"srgb-linear" => {
+			let [red, green, blue] = channels.map(|value| Gamut::Srgb.encode(value));
+			Color::rgb(red, green, blue, alpha)
+		}
+		"display-p3" | "p3" | "rec2020" => {
+			let gamut = space.parse::<Gamut>()?;
+			Color::Gamut(GamutRgba::new(gamut, red, green, blue, alpha))
+		}
+		_ => {
+			let [x, y, z] = predefined_to_xyz(space, channels)?;
+			Color::Oklch(Oklcha::from_color_unclamped(Xyza::new(x, y, z, alpha)))
+		}
+	};
+
+	Ok(color)
+}
+
+/// Convert the channels of a predefined css color space into CIE XYZ with the
+/// D65 white point.
+pub(crate) fn predefined_to_xyz(space: &str, channels: [f32; 3]) -> Result<[f32; 3], ColorError> {
+	let xyz = match space {
+		"srgb-linear" => multiply(&SRGB_TO_XYZ, channels),
+		"xyz" | "xyz-d65" => channels,
+		"xyz-d50" => multiply(&D50_TO_D65, channels),
+		"a98-rgb" => {
+			let linear = channels.map(|value| value.signum() * value.abs().powf(563.0 / 256.0));
+			multiply(&A98_RGB_TO_XYZ, linear)
+		}
+		"prophoto-rgb" => {
+			let linear = channels.map(|value| {
+				let sign = value.signum();
+				let value = value.abs();
+
+				if value <= 16.0 / 512.0 {
+					sign * value / 16.0
+				} else {
+					sign * value.powf(1.8)
+				}
+			});
+			multiply(&D50_TO_D65, multiply(&PROPHOTO_RGB_TO_XYZ_D50, linear))
+		}
+		space => {
+			let gamut = space.parse::<Gamut>()?;
+			let linear = channels.map(|value| gamut.linearize(value));
+			multiply(gamut.linear_to_xyz_matrix(), linear)
+		}
+	};
+
+	Ok(xyz)
+}
+
+/// Convert CIE XYZ with the D65 white point into the channels of a predefined
+/// css color space.
+pub(crate) fn xyz_to_predefined(space: &str, xyz: [f32; 3]) -> Result<[f32; 3], ColorError> {
+	let channels = match space {
+		"srgb-linear" => multiply(&XYZ_TO_SRGB, xyz),
+		"xyz" | "xyz-d65" => xyz,
+		"xyz-d50" => multiply(&D65_TO_D50, xyz),
+		"a98-rgb" => {
+			multiply(&XYZ_TO_A98_RGB, xyz)
+				.map(|value| value.signum() * value.abs().powf(256.0 / 563.0))
+		}
+		"prophoto-rgb" => {
+			multiply(&XYZ_D50_TO_PROPHOTO_RGB, multiply(&D65_TO_D50, xyz)).map(|value| {
+				let sign = value.signum();
+				let value = value.abs();
+
+				if value < 1.0 / 512.0 {
+					sign * value * 16.0
+				} else {
+					sign * value.powf(1.0 / 1.8)
+				}
+			})
+		}
+		space => {
+			let gamut = space.parse::<Gamut>()?;
+			multiply(gamut.xyz_to_linear_matrix(), xyz).map(|value| gamut.encode(value))
+		}
+	};
+
+	Ok(channels)
+}
+
+fn multiply(matrix: &Matrix, [a, b, c]: [f32; 3]) -> [f32; 3] {
+	matrix.map(|[x, y, z]| x * a + y * b + z * c)
+}
+
+/// The euclidean distance between an OKLCH color and a gamut color in the
+/// OKLAB color space.
+fn delta_eok(color: Oklcha, rgba: GamutRgba) -> f32 {
+	let a = Oklaba::from_color_unclamped(color);
+	let b = rgba.to_oklab();
+
+	((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
@@ -8,6 +8,9 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
+pub use gamut::*;
+pub use mix::*;
+pub use named::*;
 pub use palette; // Re-export palette
 pub use palette::rgb::Rgba;
 pub use palette::FromColor;
@@ -21,8 +24,16 @@ pub use palette::OklabHue;
 pub use palette::Oklaba;
 pub use palette::Oklcha;
 pub use palette::RgbHue;
+pub use scale::*;
 use typed_builder::TypedBuilder;
 
+mod contrast;
+mod gamut;
+mod mix;
+mod named;
+mod relative;
+mod scale;
+
 /// This enum represents a color in any of the supported css color formats.
 ///
 /// The currently supported formats are:
@@ -35,6 +46,11 @@ use typed_builder::TypedBuilder;
 /// - [`oklch`](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/oklch)
 /// - [`lab`](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/lab)
 /// - [`oklab`](https://bottosson.github.io/posts/oklab/)
+/// - [`color`](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/color)
+///
+/// Named colors, the `none` keyword and the
+/// [relative color syntax](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_colors/Relative_colors)
+/// are also supported when parsing.
 #[cfg_attr(
 	feature = "serde",
 	derive(::serde::Serialize, ::serde::Deserialize),
@@ -51,6 +67,7 @@ pub enum Color {
 	Oklch(Oklcha),
 	Lab(Laba),
 	Oklab(Oklaba),
+	Gamut(GamutRgba),
 }
 
 impl Color {
@@ -205,34 +222,22 @@ impl Color {
 	}
 
 	/// Returns the color as a HEX value. Will remain unchanged if the color is
-	/// already in the HEX format.
+	/// already in the HEX format. Colors outside of the sRGB gamut are gamut
+	/// mapped.
 	pub fn into_hex(self) -> Self {
 		match self {
 			Self::Hex(_) => self,
-			Self::Rgb(rgba) => Self::Hex(rgba),
-			Self::Hsl(hsla) => Self::Hex(Rgba::from_color(hsla)),
-			Self::Hwb(hwba) => Self::Hex(Rgba::from_color(hwba)),
-			Self::Hsv(hsva) => Self::Hex(Rgba::from_color(hsva)),
-			Self::Lch(lcha) => Self::Hex(Rgba::from_color(lcha)),
-			Self::Oklch(oklcha) => Self::Hex(Rgba::from_color(oklcha)),
-			Self::Lab(laba) => Self::Hex(Rgba::from_color(laba)),
-			Self::Oklab(oklaba) => Self::Hex(Rgba::from_color(oklaba)),
+			_ => Self::Hex(self.to_srgb()),
 		}
 	}
 
 	/// Returns the color as an RGB value. Will remain unchanged if the color is
-	/// already in the RGB format.
+	/// already in the RGB format. Colors outside of the sRGB gamut are gamut
+	/// mapped.
 	pub fn into_rgb(self) -> Self {
 		match self {
-			Self::Hex(rgba) => Self::Rgb(rgba),
 			Self::Rgb(_) => self,
-			Self::Hsl(hsla) => Self::Rgb(Rgba::from_color(hsla)),
-			Self::Hwb(hwba) => Self::Rgb(Rgba::from_color(hwba)),
-			Self::Hsv(hsva) => Self::Rgb(Rgba::from_color(hsva)),
-			Self::Lch(lcha) => Self::Rgb(Rgba::from_color(lcha)),
-			Self::Oklch(oklcha) => Self::Rgb(Rgba::from_color(oklcha)),
-			Self::Lab(laba) => Self::Rgb(Rgba::from_color(laba)),
-			Self::Oklab(oklaba) => Self::Rgb(Rgba::from_color(oklaba)),
+			_ => Self::Rgb(self.to_srgb()),
 		}
 	}
 
@@ -245,10 +250,7 @@ impl Color {
 			Self::Hsl(_) => self,
 			Self::Hwb(hwba) => Self::Hsl(Hsla::from_color(hwba)),
 			Self::Hsv(hsva) => Self::Hsl(Hsla::from_color(hsva)),
-			Self::Lch(lch) => Self::Hsl(Hsla::from_color(lch)),
-			Self::Oklch(oklch) => Self::Hsl(Hsla::from_color(oklch)),
-			Self::Lab(lab) => Self::Hsl(Hsla::from_color(lab)),
-			Self::Oklab(oklab) => Self::Hsl(Hsla::from_color(oklab)),
+			_ => Self::Hsl(Hsla::from_color(self.to_srgb())),
 		}
 	}
 
@@ -261,10 +263,7 @@ impl Color {
 			Self::Hsl(hsla) => Self::Hwb(Hwba::from_color(hsla)),
 			Self::Hwb(_) => self,
 			Self::Hsv(hsva) => Self::Hwb(Hwba::from_color(hsva)),
-			Self::Lch(lch) => Self::Hwb(Hwba::from_color(lch)),
-			Self::Oklch(oklch) => Self::Hwb(Hwba::from_color(oklch)),
-			Self::Lab(lab) => Self::Hwb(Hwba::from_color(lab)),
-			Self::Oklab(oklab) => Self::Hwb(Hwba::from_color(oklab)),
+			_ => Self::Hwb(Hwba::from_color(self.to_srgb())),
 		}
 	}
 
@@ -277,10 +276,7 @@ impl Color {
 			Self::Hsl(hsla) => Self::Hsv(Hsva::from_color(hsla)),
 			Self::Hwb(hwba) => Self::Hsv(Hsva::from_color(hwba)),
 			Self::Hsv(_) => self,
-			Self::Lch(lch) => Self::Hsv(Hsva::from_color(lch)),
-			Self::Oklch(oklch) => Self::Hsv(Hsva::from_color(oklch)),
-			Self::Lab(lab) => Self::Hsv(Hsva::from_color(lab)),
-			Self::Oklab(oklab) => Self::Hsv(Hsva::from_color(oklab)),
+			_ => Self::Hsv(Hsva::from_color(self.to_srgb())),
 		}
 	}
 
@@ -297,6 +293,7 @@ impl Color {
 			Self::Oklch(oklch) => Self::Lch(Lcha::from_color(oklch)),
 			Self::Lab(lab) => Self::Lch(Lcha::from_color(lab)),
 			Self::Oklab(oklab) => Self::Lch(Lcha::from_color(oklab)),
+			Self::Gamut(rgba) => Self::Lch(Lcha::from_color(rgba.to_xyz())),
 		}
 	}
 
@@ -313,6 +310,7 @@ impl Color {
 			Self::Oklch(_) => self,
 			Self::Lab(lab) => Self::Oklch(Oklcha::from_color(lab)),
 			Self::Oklab(oklab) => Self::Oklch(Oklcha::from_color(oklab)),
+			Self::Gamut(rgba) => Self::Oklch(Oklcha::from_color(rgba.to_xyz())),
 		}
 	}
 
@@ -329,6 +327,7 @@ impl Color {
 			Self::Oklch(oklch) => Self::Lab(Laba::from_color(oklch)),
 			Self::Lab(_) => self,
 			Self::Oklab(oklab) => Self::Lab(Laba::from_color(oklab)),
+			Self::Gamut(rgba) => Self::Lab(Laba::from_color(rgba.to_xyz())),
 		}
 	}
 
@@ -345,6 +344,7 @@ impl Color {
 			Self::Oklch(oklch) => Self::Oklab(Oklaba::from_color(oklch)),
 			Self::Lab(lab) => Self::Oklab(Oklaba::from_color(lab)),
 			Self::Oklab(_) => self,
+			Self::Gamut(rgba) => Self::Oklab(Oklaba::from_color(rgba.to_xyz())),
 		}
 	}
 
@@ -505,6 +505,7 @@ impl Color {
 			Self::Oklch(ref oklch) => oklch.alpha,
 			Self::Lab(ref lab) => lab.alpha,
 			Self::Oklab(ref oklab) => oklab.alpha,
+			Self::Gamut(ref rgba) => rgba.alpha,
 		}
 	}
 }
@@ -521,15 +522,79 @@ impl Display for Color {
 			Self::Oklch(ref oklch) => write!(f, "{}", oklch_to_css(oklch)),
 			Self::Lab(ref lab) => write!(f, "{}", lab_to_css(lab)),
 			Self::Oklab(ref oklab) => write!(f, "{}", oklab_to_css(oklab)),
+			Self::Gamut(ref rgba) => write!(f, "{rgba}"),
 		}
 	}
 }
 
+impl Color {
+	/// Parse a css color string and use the `resolve` function to get the
+	/// value of any css variables. The function receives the variable name,
+	/// e.g. `--primary` and should return the css value of the variable.
+	///
+	/// ```rust
+	/// use skribble_color::Color;
+	///
+	/// let resolve = |name: &str| (name == "--primary").then(|| "red".to_string());
+	/// let color = Color::parse_with("rgb(from var(--primary) r g b / 0.5)", resolve);
+	/// assert_eq!(color.unwrap().alpha(), 0.5);
+	/// ```
+	pub fn parse_with(
+		input: impl AsRef<str>,
+		resolve: impl Fn(&str) -> Option<String>,
+	) -> Result<Self, ColorError> {
+		Resolver::new(&resolve).parse(input.as_ref())
+	}
+}
+
 impl FromStr for Color {
 	type Err = ColorError;
 
 	fn from_str(input: &str) -> Result<Self, Self::Err> {
-		parse(input)
+		Resolver::new(&|_| None).parse(input)
+	}
+}
+
+/// The maximum depth of nested css variables and relative colors.
+const MAX_DEPTH: u8 = 16;
+
+/// Resolves the css variables which are referenced while parsing a color.
+pub(crate) struct Resolver<'a> {
+	resolve: &'a dyn Fn(&str) -> Option<String>,
+	depth: u8,
+}
+
+impl<'a> Resolver<'a> {
+	fn new(resolve: &'a dyn Fn(&str) -> Option<String>) -> Self {
+		Self { resolve, depth: 0 }
+	}
+
+	/// Parse a color which is nested within the current color.
+	pub(crate) fn parse(&self, input: &str) -> Result<Color, ColorError> {
+		let nested = Self {
+			resolve: self.resolve,
+			depth: self.depth + 1,
+		};
+
+		if nested.depth > MAX_DEPTH {
+			return Err(ColorError::UnresolvedVariable);
+		}
+
+		parse(input, &nested)
+	}
+
+	/// Resolve the content of the css `var()` function. e.g. `--x, red`.
+	fn parse_variable(&self, content: &str) -> Result<Color, ColorError> {
+		let (name, fallback) = match content.split_once(',') {
+			Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+			None => (content.trim(), None),
+		};
+
+		match ((self.resolve)(name), fallback) {
+			(Some(value), _) => self.parse(&value),
+			(None, Some(fallback)) => self.parse(fallback),
+			(None, None) => Err(ColorError::UnresolvedVariable),
+		}
 	}
 }
 
@@ -555,6 +620,14 @@ pub enum ColorError {
 	InvalidOklch,
 	#[error("invalid color function format")]
 	InvalidFunction,
+	#[error("invalid relative color format")]
+	InvalidRelative,
+	#[error("unknown named color")]
+	InvalidName,
+	#[error("`currentcolor` depends on the element and can't be resolved")]
+	CurrentColor,
+	#[error("unable to resolve the css variable")]
+	UnresolvedVariable,
 	#[error("invalid unknown format")]
 	InvalidUnknown,
 }
@@ -580,10 +653,10 @@ fn hex_to_css(rgba: &Rgba) -> String {
 	let blue = (rgba.blue * 255.0) as u8;
 
 	if !is_alpha {
-		format!("#{red:x}{green:x}{blue:x}")
+		format!("#{red:02x}{green:02x}{blue:02x}")
 	} else {
 		let alpha = (rgba.alpha * 255.0) as u8;
-		format!("#{red:x}{green:x}{blue:x}{alpha:x}")
+		format!("#{red:02x}{green:02x}{blue:02x}{alpha:02x}")
 	}
 }
 
@@ -734,8 +807,8 @@ fn oklab_to_css(laba: &Oklaba) -> String {
 	format!("oklab({l}% {a} {b} / {alpha})")
 }
 
-fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
-	let input = input.as_ref().trim().to_lowercase();
+fn parse(input: &str, resolver: &Resolver<'_>) -> Result<Color, ColorError> {
+	let input = input.trim().to_lowercase();
 
 	// hex format
 	if let Some(hex) = input.strip_prefix('#') {
@@ -747,7 +820,17 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 			.get(..index)
 			.map(|s| s.trim())
 			.ok_or(ColorError::InvalidFunction)?;
-		let extracted: ExtractedParams = content.get(index + 1..).unwrap_or("").into();
+		let params = content.get(index + 1..).unwrap_or("");
+
+		if prefix == "var" {
+			return resolver.parse_variable(params);
+		}
+
+		if let Some(relative) = params.trim_start().strip_prefix("from ") {
+			return relative::parse_relative(prefix, relative, resolver);
+		}
+
+		let mut extracted: ExtractedParams = params.into();
 
 		match prefix {
 			"rgb" | "rgba" => {
@@ -757,6 +840,8 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 					return Err(error);
 				}
 
+				extracted.replace_none(None);
+
 				let red = parse_percent_or_255(extracted.params.first(), error)?;
 				let green = parse_percent_or_255(extracted.params.get(1), error)?;
 				let blue = parse_percent_or_255(extracted.params.get(2), error)?;
@@ -780,6 +865,8 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 					return Err(error);
 				}
 
+				extracted.replace_none(Some(0));
+
 				let hue = parse_angle(extracted.params.first(), error)?;
 				let saturation = parse_percent_or_float(extracted.params.get(1), error)?;
 				let lightness = parse_percent_or_float(extracted.params.get(2), error)?;
@@ -803,6 +890,8 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 					return Err(error);
 				}
 
+				extracted.replace_none(Some(0));
+
 				let hue = parse_angle(extracted.params.first(), error)?;
 				let whiteness = parse_percent_or_float(extracted.params.get(1), error)?;
 				let blackness = parse_percent_or_float(extracted.params.get(2), error)?;
@@ -827,6 +916,8 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 					return Err(error);
 				}
 
+				extracted.replace_none(Some(0));
+
 				let hue = parse_angle(extracted.params.first(), error)?;
 				let saturation = parse_percent_or_float(extracted.params.get(1), error)?;
 				let value = parse_percent_or_float(extracted.params.get(2), error)?;
@@ -854,6 +945,8 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 					return Err(error);
 				}
 
+				extracted.replace_none(None);
+
 				let mut l = parse_percent_or_float(extracted.params.first(), error)?;
 				let mut a = parse_percent_or_float(extracted.params.get(1), error)?;
 				let mut b = parse_percent_or_float(extracted.params.get(2), error)?;
@@ -905,6 +998,8 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 					return Err(error);
 				}
 
+				extracted.replace_none(Some(2));
+
 				let mut l = parse_percent_or_float(extracted.params.first(), error)?;
 				let mut chroma = parse_percent_or_float(extracted.params.get(1), error)?;
 				let hue = parse_angle(extracted.params.get(2), error)?;
@@ -933,14 +1028,27 @@ fn parse<S: AsRef<str>>(input: S) -> Result<Color, ColorError> {
 
 				return Ok(Color::oklch(l.0, chroma.0, hue, alpha.0));
 			}
+			"color" => return parse_color_function(params),
 			_ => return Err(ColorError::InvalidFunction),
 		}
 	}
 
-	if let Ok(color) = parse_hex(input) {
+	if input == "currentcolor" {
+		return Err(ColorError::CurrentColor);
+	}
+
+	if let Some(color) = Color::named(&input) {
 		return Ok(color);
 	}
 
+	if let Ok(color) = parse_hex(&input) {
+		return Ok(color);
+	}
+
+	if input.chars().all(|ch| ch.is_ascii_alphabetic()) {
+		return Err(ColorError::InvalidName);
+	}
+
 	Err(ColorError::InvalidUnknown)
 }
 
@@ -1197,6 +1305,24 @@ impl ExtractedParams {
 	pub fn is_alpha(&self) -> bool {
 		self.params.len() == 4
 	}
+
+	/// Replace the `none` keyword with zero. The channels use a percentage when
+	/// another channel is a percentage so that the units are consistent.
+	pub fn replace_none(&mut self, hue: Option<usize>) {
+		let is_percent = self.params.iter().take(3).any(|param| param.ends_with('%'));
+
+		for (index, param) in self.params.iter_mut().enumerate() {
+			if param != "none" {
+				continue;
+			}
+
+			*param = if is_percent && index < 3 && Some(index) != hue {
+				"0%".into()
+			} else {
+				"0".into()
+			};
+		}
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,257 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use palette::convert::FromColorUnclamped;
+use palette::rgb::Rgba;
+use palette::FromColor;
+use palette::Hsla;
+use palette::Laba;
+use palette::Lcha;
+use palette::LinSrgba;
+use palette::Oklaba;
+use palette::Oklcha;
+
+use crate::Color;
+use crate::ColorError;
+
+/// The chroma (or saturation) below which the hue of a color is considered
+/// powerless while interpolating.
+const POWERLESS_HUE: f32 = 0.0001;
+
+/// The color spaces which can be used to interpolate between two colors. These
+/// match the `<color-space>` accepted by the css `color-mix()` function.
+#[cfg_attr(
+	feature = "serde",
+	derive(::serde::Serialize, ::serde::Deserialize),
+	serde(rename_all = "kebab-case")
+)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+	Srgb,
+	SrgbLinear,
+	Hsl,
+	Lab,
+	Lch,
+	Oklab,
+	#[default]
+	Oklch,
+}
+
+impl ColorSpace {
+	/// The name of the color space as used in css.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Srgb => "srgb",
+			Self::SrgbLinear => "srgb-linear",
+			Self::Hsl => "hsl",
+			Self::Lab => "lab",
+			Self::Lch => "lch",
+			Self::Oklab => "oklab",
+			Self::Oklch => "oklch",
+		}
+	}
+}
+
+impl Display for ColorSpace {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
+impl FromStr for ColorSpace {
+	type Err = ColorError;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		let space = match input.trim() {
+			"srgb" => Self::Srgb,
+			"srgb-linear" => Self::SrgbLinear,
+			"hsl" => Self::Hsl,
+			"lab" => Self::Lab,
+			"lch" => Self::Lch,
+			"oklab" => Self::Oklab,
+			"oklch" => Self::Oklch,
+			_ => return Err(ColorError::InvalidFunction),
+		};
+
+		Ok(space)
+	}
+}
+
+impl Color {
+	/// Mix this color with the `other` color in the provided color space. The
+	/// `amount` is the proportion of the `other` color between `0.0` and
+	/// `1.0`.
+	///
+	/// This produces the same result as the css
+	/// `color-mix(in <space>, <self>, <other> <amount>)` function. Colors are
+	/// interpolated with premultiplied alpha and hues use the shorter arc. The
+	/// returned color is in the provided color space.
+	///
+	/// ```rust
+	/// use skribble_color::Color;
+	/// use skribble_color::ColorSpace;
+	///
+	/// let black: Color = "#000".parse().unwrap();
+	/// let white: Color = "#fff".parse().unwrap();
+	/// let gray = black.mix(&white, 0.5, ColorSpace::Srgb).into_hex();
+	/// assert_eq!(gray.to_string(), "#7f7f7f");
+	/// ```
+	pub fn mix(&self, other: &Color, amount: f32, space: ColorSpace) -> Color {
+		let amount = amount.clamp(0.0, 1.0);
+		let ([first_a, first_b, mut first_c], first_alpha) = components(self, space);
+		let ([second_a, second_b, mut second_c], second_alpha) = components(other, space);
+		let is_polar = is_polar(space);
+
+		if is_polar {
+			// The hue is powerless when there is no chroma (or saturation).
+			match (first_b < POWERLESS_HUE, second_b < POWERLESS_HUE) {
+				(true, false) => first_c = second_c,
+				(false, true) => second_c = first_c,
+				_ => {}
+			}
+
+			let difference = second_c - first_c;
+
+			if difference > 180.0 {
+				first_c += 360.0;
+			} else if difference < -180.0 {
+				second_c += 360.0;
+			}
+		}
+
+		let alpha = first_alpha + (second_alpha - first_alpha) * amount;
+		let interpolate = |first: f32, second: f32| {
+			let premultiplied =
+				first * first_alpha + (second * second_alpha - first * first_alpha) * amount;
+
+			if alpha == 0.0 {
+				premultiplied
+			} else {
+				premultiplied / alpha
+			}
+		};
+		let third = if is_polar {
+			(first_c + (second_c - first_c) * amount).rem_euclid(360.0)
+		} else {
+			interpolate(first_c, second_c)
+		};
+
+		from_components(
+			[
+				interpolate(first_a, second_a),
+				interpolate(first_b, second_b),
+				third,
+			],
+			alpha,
+			space,
+		)
+	}
+
+	/// Increase the OKLCH lightness of the color by the provided amount. The
+	/// lightness is clamped between `0.0` and `1.0`.
+	pub fn lighten(&self, amount: f32) -> Color {
+		self.map_oklch(|oklch| oklch.l += amount)
+	}
+
+	/// Decrease the OKLCH lightness of the color by the provided amount.
+	pub fn darken(&self, amount: f32) -> Color {
+		self.lighten(-amount)
+	}
+
+	/// Increase the OKLCH chroma of the color by the provided amount. The
+	/// result may be outside of the sRGB gamut and is gamut mapped when
+	/// converted into an sRGB format.
+	pub fn saturate(&self, amount: f32) -> Color {
+		self.map_oklch(|oklch| oklch.chroma += amount)
+	}
+
+	/// Decrease the OKLCH chroma of the color by the provided amount.
+	pub fn desaturate(&self, amount: f32) -> Color {
+		self.saturate(-amount)
+	}
+
+	/// Invert the OKLCH lightness of the color while keeping the chroma and
+	/// hue. This is useful for deriving dark mode colors from light mode
+	/// colors.
+	///
+	/// ```rust
+	/// use skribble_color::Color;
+	///
+	/// let white: Color = "#fff".parse().unwrap();
+	/// assert_eq!(white.invert().into_hex().to_string(), "#000000");
+	/// ```
+	pub fn invert(&self) -> Color {
+		self.map_oklch(|oklch| oklch.l = 1.0 - oklch.l)
+	}
+
+	/// Update the OKLCH representation of the color. The lightness and chroma
+	/// are clamped to valid values.
+	pub fn map_oklch(&self, update: impl FnOnce(&mut Oklcha)) -> Color {
+		let mut oklch = self.to_oklch_unclamped();
+
+		update(&mut oklch);
+		oklch.l = oklch.l.clamp(0.0, 1.0);
+		oklch.chroma = oklch.chroma.max(0.0);
+
+		Color::Oklch(oklch)
+	}
+}
+
+/// Whether the color space is polar. The components of polar color spaces are
+/// ordered so that the chroma (or saturation) is second and the hue is last.
+fn is_polar(space: ColorSpace) -> bool {
+	matches!(space, ColorSpace::Hsl | ColorSpace::Lch | ColorSpace::Oklch)
+}
+
+fn components(color: &Color, space: ColorSpace) -> ([f32; 3], f32) {
+	let rgba = color.to_srgb();
+	let oklch = color.to_oklch_unclamped();
+
+	match space {
+		ColorSpace::Srgb => ([rgba.red, rgba.green, rgba.blue], rgba.alpha),
+		ColorSpace::SrgbLinear => {
+			let linear = LinSrgba::from_color(rgba);
+			([linear.red, linear.green, linear.blue], linear.alpha)
+		}
+		ColorSpace::Hsl => {
+			let hsla = Hsla::from_color(rgba);
+			let hue = hsla.hue.into_positive_degrees();
+			([hsla.lightness, hsla.saturation, hue], hsla.alpha)
+		}
+		ColorSpace::Lab => {
+			let lab = Laba::from_color_unclamped(oklch);
+			([lab.l, lab.a, lab.b], lab.alpha)
+		}
+		ColorSpace::Lch => {
+			let lch = Lcha::from_color_unclamped(oklch);
+			(
+				[lch.l, lch.chroma, lch.hue.into_positive_degrees()],
+				lch.alpha,
+			)
+		}
+		ColorSpace::Oklab => {
+			let oklab = Oklaba::from_color_unclamped(oklch);
+			([oklab.l, oklab.a, oklab.b], oklab.alpha)
+		}
+		ColorSpace::Oklch => {
+			(
+				[oklch.l, oklch.chroma, oklch.hue.into_positive_degrees()],
+				oklch.alpha,
+			)
+		}
+	}
+}
+
+fn from_components([first, second, third]: [f32; 3], alpha: f32, space: ColorSpace) -> Color {
+	match space {
+		ColorSpace::Srgb => Color::Rgb(Rgba::new(first, second, third, alpha)),
+		ColorSpace::SrgbLinear => {
+			Color::Rgb(Rgba::from_color(LinSrgba::new(first, second, third, alpha)))
+		}
+		ColorSpace::Hsl => Color::hsl(third, second, first, alpha),
+		ColorSpace::Lab => Color::lab(first, second, third, alpha),
+		ColorSpace::Lch => Color::lch(first, second, third, alpha),
+		ColorSpace::Oklab => Color::oklab(first, second, third, alpha),
+		ColorSpace::Oklch => Color::oklch(first, second, third, alpha),
+	}
+}
@@ -0,0 +1,467 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use palette::convert::FromColorUnclamped;
+use palette::Hsla;
+use palette::Hwba;
+use palette::Laba;
+use palette::Lcha;
+use palette::Oklaba;
+use palette::Oklcha;
+use palette::Srgba;
+
+use crate::color_from_predefined;
+use crate::xyz_to_predefined;
+use crate::Color;
+use crate::ColorError;
+use crate::Resolver;
+
+const ERROR: ColorError = ColorError::InvalidRelative;
+
+/// The channels of the origin color converted into the color space of the
+/// relative color function.
+struct Channels {
+	/// The keywords which reference each channel. e.g. `["l", "c", "h"]`.
+	keywords: [&'static str; 3],
+	values: [f32; 3],
+	/// The value which `100%` represents for each channel.
+	references: [f32; 3],
+	alpha: f32,
+}
+
+impl Channels {
+	fn get(&self, keyword: &str) -> Option<f32> {
+		if keyword == "alpha" {
+			return Some(self.alpha);
+		}
+
+		self.keywords
+			.iter()
+			.zip(self.values)
+			.find_map(|(name, value)| (*name == keyword).then_some(value))
+	}
+}
+
+/// Parse the content of a css relative color function. e.g. for
+/// `oklch(from var(--x) l c calc(h + 30))` the `function` is `oklch` and the
+/// content is `var(--x) l c calc(h + 30)`.
+pub(crate) fn parse_relative(
+	function: &str,
+	content: &str,
+	resolver: &Resolver<'_>,
+) -> Result<Color, ColorError> {
+	let (params, alpha) = split_params(content)?;
+	let mut params = params.into_iter();
+	let origin = resolver.parse(&params.next().ok_or(ERROR)?)?;
+	let space = if function == "color" {
+		Some(params.next().ok_or(ERROR)?)
+	} else {
+		None
+	};
+	let channels = get_channels(function, space.as_deref(), origin)?;
+	let params: Vec<String> = params.collect();
+
+	let [first, second, third] = match params.as_slice() {
+		[first, second, third] => [first, second, third],
+		_ => return Err(ERROR),
+	};
+	let [first_reference, second_reference, third_reference] = channels.references;
+	let values = [
+		evaluate(first, &channels, first_reference)?,
+		evaluate(second, &channels, second_reference)?,
+		evaluate(third, &channels, third_reference)?,
+	];
+	let alpha = match alpha {
+		Some(alpha) => evaluate(&alpha, &channels, 1.0)?,
+		None => channels.alpha,
+	};
+
+	into_color(function, space.as_deref(), values, alpha)
+}
+
+/// Split the content into the space separated params and the optional alpha
+/// which follows a `/`.
+fn split_params(content: &str) -> Result<(Vec<String>, Option<String>), ColorError> {
+	let mut params = vec![];
+	let mut alpha = None;
+	let mut current = String::new();
+	let mut depth = 0_u8;
+
+	for ch in content.chars().chain(Some(' ')) {
+		match ch {
+			'(' => depth += 1,
+			')' => depth = depth.checked_sub(1).ok_or(ERROR)?,
+			_ => {}
+		}
+
+		if depth > 0 || !(ch.is_whitespace() || ch == '/') {
+			current.push(ch);
+			continue;
+		}
+
+		if !current.is_empty() {
+			match alpha {
+				Some(None) => alpha = Some(Some(std::mem::take(&mut current))),
+				Some(Some(_)) => return Err(ERROR),
+				None => params.push(std::mem::take(&mut current)),
+			}
+		}
+
+		if ch == '/' {
+			if alpha.is_some() {
+				return Err(ERROR);
+			}
+
+			alpha = Some(None);
+		}
+	}
+
+	if depth != 0 {
+		return Err(ERROR);
+	}
+
+	match alpha {
+		Some(None) => Err(ERROR),
+		Some(alpha) => Ok((params, alpha)),
+		None => Ok((params, None)),
+	}
+}
+
+fn get_channels(
+	function: &str,
+	space: Option<&str>,
+	origin: Color,
+) -> Result<Channels, ColorError> {
+	let xyz = origin.to_xyz();
+	let alpha = xyz.alpha;
+	let srgb = || Srgba::from_color_unclamped(xyz);
+
+	let channels = match function {
+		"rgb" | "rgba" => {
+			let rgba = srgb();
+
+			Channels {
+				keywords: ["r", "g", "b"],
+				values: [rgba.red, rgba.green, rgba.blue].map(|value| value * 255.0),
+				references: [255.0; 3],
+				alpha,
+			}
+		}
+		"hsl" | "hsla" => {
+			let hsla = Hsla::from_color_unclamped(srgb());
+
+			Channels {
+				keywords: ["h", "s", "l"],
+				values: [
+					hsla.hue.into_positive_degrees(),
+					hsla.saturation * 100.0,
+					hsla.lightness * 100.0,
+				],
+				references: [1.0, 100.0, 100.0],
+				alpha,
+			}
+		}
+		"hwb" => {
+			let hwba = Hwba::from_color_unclamped(srgb());
+
+			Channels {
+				keywords: ["h", "w", "b"],
+				values: [
+					hwba.hue.into_positive_degrees(),
+					hwba.whiteness * 100.0,
+					hwba.blackness * 100.0,
+				],
+				references: [1.0, 100.0, 100.0],
+				alpha,
+			}
+		}
+		"lab" => {
+			let laba = Laba::from_color_unclamped(xyz);
+
+			Channels {
+				keywords: ["l", "a", "b"],
+				values: [laba.l, laba.a, laba.b],
+				references: [100.0, 125.0, 125.0],
+				alpha,
+			}
+		}
+		"lch" => {
+			let lcha = Lcha::from_color_unclamped(xyz);
+
+			Channels {
+				keywords: ["l", "c", "h"],
+				values: [lcha.l, lcha.chroma, lcha.hue.into_positive_degrees()],
+				references: [100.0, 150.0, 1.0],
+				alpha,
+			}
+		}
+		"oklab" => {
+			let oklaba = Oklaba::from_color_unclamped(xyz);
+
+			Channels {
+				keywords: ["l", "a", "b"],
+				values: [oklaba.l, oklaba.a, oklaba.b],
+				references: [1.0, 0.4, 0.4],
+				alpha,
+			}
+		}
+		"oklch" => {
+			let oklcha = Oklcha::from_color_unclamped(xyz);
+
+			Channels {
+				keywords: ["l", "c", "h"],
+				values: [oklcha.l, oklcha.chroma, oklcha.hue.into_positive_degrees()],
+				references: [1.0, 0.4, 1.0],
+				alpha,
+			}
+		}
+		"color" => {
+			let space = space.ok_or(ERROR)?;
+			let keywords = if space.starts_with("xyz") {
+				["x", "y", "z"]
+			} else {
+				["r", "g", "b"]
+			};
+
+			Channels {
+				keywords,
+				values: xyz_to_predefined(space, [xyz.x, xyz.y, xyz.z])?,
+				references: [1.0; 3],
+				alpha,
+			}
+		}
+		_ => return Err(ColorError::InvalidFunction),
+	};
+
+	Ok(channels)
+}
+
+fn into_color(
+	function: &str,
+	space: Option<&str>,
+	[first, second, third]: [f32; 3],
+	alpha: f32,
+) -> Result<Color, ColorError> {
+	let alpha = alpha.clamp(0.0, 1.0);
+	let color = match function {
+		"rgb" | "rgba" => Color::rgb(first / 255.0, second / 255.0, third / 255.0, alpha),
+		"hsl" | "hsla" => Color::hsl(first, second / 100.0, third / 100.0, alpha),
+		"hwb" => Color::hwb(first, second / 100.0, third / 100.0, alpha),
+		"lab" => Color::lab(first, second, third, alpha),
+		"lch" => Color::lch(first, second, third, alpha),
+		"oklab" => Color::oklab(first, second, third, alpha),
+		"oklch" => Color::oklch(first, second, third, alpha),
+		"color" => color_from_predefined(space.ok_or(ERROR)?, [first, second, third], alpha)?,
+		_ => return Err(ColorError::InvalidFunction),
+	};
+
+	Ok(color)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+	Number(f32),
+	Percentage(f32),
+	Dimension(f32, String),
+	Ident(String),
+	Function(String),
+	Close,
+	Comma,
+	Operator(char),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ColorError> {
+	let mut tokens = vec![];
+	let mut chars = input.chars().peekable();
+
+	while let Some(&ch) = chars.peek() {
+		let previous_is_value = matches!(
+			tokens.last(),
+			Some(Token::Number(_) | Token::Percentage(_) | Token::Dimension(..))
+				| Some(Token::Ident(_) | Token::Close)
+		);
+
+		if ch.is_whitespace() {
+			chars.next();
+		} else if ch.is_ascii_digit() || ch == '.' || (ch == '-' || ch == '+') && !previous_is_value
+		{
+			let mut number = String::new();
+			number.push(ch);
+			chars.next();
+
+			while let Some(&ch) = chars.peek() {
+				if !(ch.is_ascii_digit() || ch == '.') {
+					break;
+				}
+
+				number.push(ch);
+				chars.next();
+			}
+
+			if number == "-" || number == "+" {
+				match chars.peek() {
+					Some(ch) if ch.is_ascii_alphabetic() => {
+						// A negated keyword, e.g. `-h`.
+						tokens.push(Token::Number(if number == "-" { -1.0 } else { 1.0 }));
+						tokens.push(Token::Operator('*'));
+						continue;
+					}
+					_ => return Err(ERROR),
+				}
+			}
+
+			let value: f32 = number.parse().map_err(|_| ERROR)?;
+			let mut unit = String::new();
+
+			while let Some(&ch) = chars.peek() {
+				if !(ch.is_ascii_alphabetic() || ch == '%') {
+					break;
+				}
+
+				unit.push(ch);
+				chars.next();
+			}
+
+			tokens.push(match unit.as_str() {
+				"" => Token::Number(value),
+				"%" => Token::Percentage(value),
+				_ => Token::Dimension(value, unit),
+			});
+		} else if ch.is_ascii_alphabetic() {
+			let mut ident = String::new();
+
+			while let Some(&ch) = chars.peek() {
+				if !(ch.is_ascii_alphanumeric() || ch == '-') {
+					break;
+				}
+
+				ident.push(ch);
+				chars.next();
+			}
+
+			if chars.peek() == Some(&'(') {
+				chars.next();
+				tokens.push(Token::Function(ident));
+			} else {
+				tokens.push(Token::Ident(ident));
+			}
+		} else {
+			chars.next();
+
+			tokens.push(match ch {
+				'(' => Token::Function(String::new()),
+				')' => Token::Close,
+				',' => Token::Comma,
+				'+' | '-' | '*' | '/' => Token::Operator(ch),
+				_ => return Err(ERROR),
+			});
+		}
+	}
+
+	Ok(tokens)
+}
+
+/// Evaluate a channel of the relative color syntax which can be a number,
+/// percentage, angle, channel keyword or a math function like `calc()`.
+fn evaluate(input: &str, channels: &Channels, reference: f32) -> Result<f32, ColorError> {
+	let mut evaluator = Evaluator {
+		tokens: tokenize(input)?.into_iter().peekable(),
+		channels,
+		reference,
+	};
+	let value = evaluator.expression()?;
+
+	if evaluator.tokens.next().is_some() {
+		return Err(ERROR);
+	}
+
+	Ok(value)
+}
+
+struct Evaluator<'a> {
+	tokens: Peekable<IntoIter<Token>>,
+	channels: &'a Channels,
+	reference: f32,
+}
+
+impl Evaluator<'_> {
+	fn expression(&mut self) -> Result<f32, ColorError> {
+		let mut value = self.term()?;
+
+		while let Some(Token::Operator(operator @ ('+' | '-'))) = self.tokens.peek().cloned() {
+			self.tokens.next();
+			let other = self.term()?;
+
+			if operator == '+' {
+				value += other;
+			} else {
+				value -= other;
+			}
+		}
+
+		Ok(value)
+	}
+
+	fn term(&mut self) -> Result<f32, ColorError> {
+		let mut value = self.factor()?;
+
+		while let Some(Token::Operator(operator @ ('*' | '/'))) = self.tokens.peek().cloned() {
+			self.tokens.next();
+			let other = self.factor()?;
+
+			if operator == '*' {
+				value *= other;
+			} else {
+				value /= other;
+			}
+		}
+
+		Ok(value)
+	}
+
+	fn factor(&mut self) -> Result<f32, ColorError> {
+		let value = match self.tokens.next().ok_or(ERROR)? {
+			Token::Number(value) => value,
+			Token::Percentage(value) => value / 100.0 * self.reference,
+			Token::Dimension(value, unit) => {
+				match unit.as_str() {
+					"deg" => value,
+					"grad" => value * 360.0 / 400.0,
+					"rad" => value.to_degrees(),
+					"turn" => value * 360.0,
+					_ => return Err(ERROR),
+				}
+			}
+			Token::Ident(ident) => {
+				match ident.as_str() {
+					"none" => 0.0,
+					"pi" => std::f32::consts::PI,
+					"e" => std::f32::consts::E,
+					keyword => self.channels.get(keyword).ok_or(ERROR)?,
+				}
+			}
+			Token::Function(name) => {
+				let mut args = vec![self.expression()?];
+
+				while self.tokens.next_if_eq(&Token::Comma).is_some() {
+					args.push(self.expression()?);
+				}
+
+				if self.tokens.next() != Some(Token::Close) {
+					return Err(ERROR);
+				}
+
+				match (name.as_str(), args.as_slice()) {
+					("" | "calc", [value]) => *value,
+					("min", [first, rest @ ..]) => rest.iter().fold(*first, |a, b| a.min(*b)),
+					("max", [first, rest @ ..]) => rest.iter().fold(*first, |a, b| a.max(*b)),
+					("clamp", [min, value, max]) => value.min(*max).max(*min),
+					_ => return Err(ERROR),
+				}
+			}
+			Token::Close | Token::Comma | Token::Operator(_) => return Err(ERROR),
+		};
+
+		Ok(value)
+	}
+}
@@ -0,0 +1,110 @@
+use palette::rgb::Rgba;
+use palette::Oklcha;
+use typed_builder::TypedBuilder;
+
+use crate::map_into_gamut;
+use crate::Color;
+use crate::Gamut;
+use crate::GamutRgba;
+
+/// The default shades generated for a [`ColorScale`].
+pub const DEFAULT_SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// The lightest shade which is mapped to [`ColorScale::lightest`].
+const LIGHTEST_SHADE: f32 = 50.0;
+/// The darkest shade which is mapped to [`ColorScale::darkest`].
+const DARKEST_SHADE: f32 = 950.0;
+
+/// Generate a perceptually even scale of shades from a single seed color.
+///
+/// The seed provides the hue and the peak chroma of the scale while the
+/// lightness of each shade is spread evenly in the OKLCH color space. Shades
+/// which fall outside of the sRGB gamut are mapped back into it by reducing
+/// their chroma.
+///
+/// ```rust
+/// use skribble_color::Color;
+/// use skribble_color::ColorScale;
+///
+/// let seed: Color = "#3b82f6".parse().unwrap();
+/// let scale = ColorScale::builder().name("brand").seed(seed).build();
+///
+/// let palette = scale.palette();
+/// assert_eq!(palette.len(), 11);
+/// assert_eq!(palette.first().unwrap().0, "brand50");
+/// ```
+#[derive(Debug, Clone, PartialEq, TypedBuilder)]
+pub struct ColorScale {
+	/// The name of the scale which is used as the prefix for each generated
+	/// palette entry. e.g. `brand` will generate `brand50`, `brand100` etc.
+	#[builder(setter(into))]
+	pub name: String,
+	/// The seed color which provides the hue and peak chroma of the scale.
+	#[builder(setter(into))]
+	pub seed: Color,
+	/// The shades to generate. Shades are expected to be between `50` and
+	/// `950`.
+	#[builder(default = DEFAULT_SHADES.to_vec(), setter(into))]
+	pub shades: Vec<u16>,
+	/// The OKLCH lightness of the lightest shade (`50`).
+	#[builder(default = 0.97)]
+	pub lightest: f32,
+	/// The OKLCH lightness of the darkest shade (`950`).
+	#[builder(default = 0.26)]
+	pub darkest: f32,
+	/// How much of the seed chroma is removed towards the lightest and darkest
+	/// shades. `0.0` keeps the chroma constant while `1.0` removes all chroma
+	/// at both ends of the scale.
+	#[builder(default = 0.6)]
+	pub chroma_falloff: f32,
+}
+
+impl ColorScale {
+	/// Generate the color for the provided shade. The returned color is always
+	/// within the sRGB gamut.
+	pub fn shade(&self, shade: u16) -> Color {
+		let Color::Oklch(seed) = self.seed.into_oklch() else {
+			unreachable!("the seed is always converted to oklch");
+		};
+
+		let position =
+			((shade as f32 - LIGHTEST_SHADE) / (DARKEST_SHADE - LIGHTEST_SHADE)).clamp(0.0, 1.0);
+		let distance = (position - 0.5).abs() * 2.0;
+		let falloff = self.chroma_falloff.clamp(0.0, 1.0);
+		let lightness = self.lightest + (self.darkest - self.lightest) * position;
+		let chroma = seed.chroma * (1.0 - falloff * distance.powi(2));
+		let oklch = Oklcha::new(lightness, chroma, seed.hue, seed.alpha);
+
+		Color::Hex(map_into_srgb(oklch))
+	}
+
+	/// Generate all the shades of the scale in order.
+	pub fn shades(&self) -> Vec<(u16, Color)> {
+		self.shades
+			.iter()
+			.map(|&shade| (shade, self.shade(shade)))
+			.collect()
+	}
+
+	/// Generate the palette entries for the scale which can be added directly
+	/// to the `palette` of a style config.
+	pub fn palette(&self) -> Vec<(String, String)> {
+		self.shades()
+			.into_iter()
+			.map(|(shade, color)| (format!("{}{shade}", self.name), color.to_string()))
+			.collect()
+	}
+}
+
+/// Map an OKLCH color into the sRGB gamut.
+pub(crate) fn map_into_srgb(color: Oklcha) -> Rgba {
+	let GamutRgba {
+		red,
+		green,
+		blue,
+		alpha,
+		..
+	} = map_into_gamut(color, Gamut::Srgb);
+
+	Rgba::new(red, green, blue, alpha)
+}
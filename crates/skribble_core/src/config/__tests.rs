@@ -1,3 +1,7 @@
+use rstest::rstest;
+use skribble_color::Color;
+use skribble_color::ColorScale;
+
 use super::*;
 
 #[test]
@@ -11,3 +15,69 @@ fn check_config_can_serialize() {
 fn default_config() {
 	insta::assert_json_snapshot!(StyleConfig::default());
 }
+
+#[test]
+fn palette_from_color_scale() {
+	let scale = ColorScale::builder()
+		.name("brand")
+		.seed(Color::hex(0x3b, 0x82, 0xf6, 0xff))
+		.shades(vec![100, 500, 900])
+		.build();
+	let config = StyleConfig::builder().palette(scale).build();
+	let names = config.palette.keys().cloned().collect::<Vec<_>>();
+
+	assert_eq!(names, ["brand100", "brand500", "brand900"]);
+}
+
+#[rstest]
+#[case(PropertySyntaxValue::LengthPercentage, "100px", true)]
+#[case(PropertySyntaxValue::LengthPercentage, "calc(100% - 2px)", true)]
+#[case(PropertySyntaxValue::LengthPercentage, "red", false)]
+#[case(PropertySyntaxValue::LengthPercentage, "1px 2px", false)]
+#[case(PropertySyntaxValue::Color, "red", true)]
+#[case(PropertySyntaxValue::Color, "var(--sk-p)", true)]
+#[case(PropertySyntaxValue::Color, "inherit", true)]
+#[case(PropertySyntaxValue::Color, "10px", false)]
+#[case(PropertySyntaxValue::Any, "anything goes", true)]
+fn property_syntax_validates_values(
+	#[case] syntax: PropertySyntaxValue,
+	#[case] value: &str,
+	#[case] expected: bool,
+) {
+	assert_eq!(PropertySyntax::from(syntax).is_valid_value(value), expected);
+}
+
+#[test]
+fn property_syntax_list_validates_values() {
+	let syntax = PropertySyntax::from_iterator(["<length-percentage>", "auto"]);
+	assert!(syntax.is_valid_value("auto"));
+	assert!(syntax.is_valid_value("2rem"));
+	assert!(!syntax.is_valid_value("none"));
+}
+
+#[rstest]
+#[case(&[], &[], "blue500", true)]
+#[case(&["gray*"], &[], "gray100", true)]
+#[case(&["gray*"], &[], "blue500", false)]
+#[case(&["*100", "*900"], &[], "blue900", true)]
+#[case(&[], &["gray*"], "gray100", false)]
+#[case(&[], &["gray*"], "blue500", true)]
+#[case(&["gray*"], &["gray9*"], "gray900", false)]
+#[case(&["g*y*0"], &[], "gray100", true)]
+#[case(&["gray"], &[], "gray100", false)]
+#[case(&["gray-*"], &[], "gray100", true)]
+#[case(&["gray-*"], &[], "gray-100", true)]
+#[case(&[], &["gray-*"], "gray900", false)]
+#[case(&["gray-*"], &[], "blue500", false)]
+fn color_field_filters_names(
+	#[case] included: &[&str],
+	#[case] excluded: &[&str],
+	#[case] name: &str,
+	#[case] expected: bool,
+) {
+	let field = ColorField::builder()
+		.included(included.to_vec())
+		.excluded(excluded.to_vec())
+		.build();
+	assert_eq!(field.is_included(name), expected);
+}
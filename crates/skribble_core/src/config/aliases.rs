@@ -52,6 +52,9 @@ pub struct Alias {
 	pub classes: StringList,
 	/// When combined is `true`, it will create a new class that combines all
 	/// the styles of the classes specified, in the order they are specified in.
+	/// The combined class is added to the `alias` layer and any classes with
+	/// media queries or modifiers are written as nested rules. Aliases and css
+	/// chunks can't be combined.
 	///
 	/// It defaults to `false` meaning that the code generation will replace any
 	/// reference to this class with a space separated list of the classes
@@ -13,10 +13,13 @@ use super::LinkedValues;
 use super::OptionalStringMap;
 use super::PrioritizedString;
 use super::Priority;
+use super::PropertySyntax;
 use super::StringList;
+use crate::write_css_declarations;
 use crate::AnyEmptyResult;
 use crate::Arguments;
 use crate::ClassTransformer;
+use crate::CssDeclarations;
 use crate::Placeholder;
 use crate::RunnerConfig;
 
@@ -84,6 +87,12 @@ pub struct Atom {
 	/// The names of the [`ValueSet`]s that will be used to generate the styles.
 	#[builder(default, setter(into))]
 	pub values: LinkedValues,
+	/// The syntax accepted by arbitrary values provided as an argument to this
+	/// atom, e.g. `p:[100px]`. Arguments which don't match are rejected. When
+	/// not provided every argument is accepted.
+	#[serde(default)]
+	#[builder(default, setter(into, strip_option))]
+	pub syntax: Option<PropertySyntax>,
 }
 
 impl Atom {
@@ -93,10 +102,21 @@ impl Atom {
 		config: &RunnerConfig,
 		name: impl AsRef<str>,
 		transformers: &IndexSet<ClassTransformer>,
+	) -> AnyEmptyResult {
+		let mut declarations = CssDeclarations::new();
+		self.collect_css_declarations(&mut declarations, config, name, transformers)?;
+		write_css_declarations(writer, &declarations)
+	}
+
+	pub fn collect_css_declarations(
+		&self,
+		declarations: &mut CssDeclarations,
+		config: &RunnerConfig,
+		name: impl AsRef<str>,
+		transformers: &IndexSet<ClassTransformer>,
 	) -> AnyEmptyResult {
 		self.values
-			.write_css_properties(writer, config, self, name, transformers)?;
-		Ok(())
+			.collect_css_declarations(declarations, config, self, name, transformers)
 	}
 
 	pub fn write_css_argument(
@@ -106,9 +126,25 @@ impl Atom {
 		argument: &Arguments,
 		transformers: &IndexSet<ClassTransformer>,
 	) -> AnyEmptyResult {
-		self.values
-			.write_css_argument(writer, config, self, argument, transformers)?;
-		Ok(())
+		let mut declarations = CssDeclarations::new();
+		self.collect_argument_css_declarations(&mut declarations, config, argument, transformers);
+		write_css_declarations(writer, &declarations)
+	}
+
+	pub fn collect_argument_css_declarations(
+		&self,
+		declarations: &mut CssDeclarations,
+		config: &RunnerConfig,
+		argument: &Arguments,
+		transformers: &IndexSet<ClassTransformer>,
+	) {
+		self.values.collect_argument_css_declarations(
+			declarations,
+			config,
+			self,
+			argument,
+			transformers,
+		);
 	}
 
 	/// Add a value to the [`ValueSet`] that will be used to generate the
@@ -136,6 +172,10 @@ impl Atom {
 			self.priority = other.priority;
 		}
 
+		if other.syntax.is_some() {
+			self.syntax = other.syntax;
+		}
+
 		self.styles.extend(other.styles);
 		self.values.merge(other.values);
 	}
@@ -19,10 +19,18 @@ pub struct ColorField {
 	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub disable_palette: bool,
-	/// Colors that should be excluded from the options.
+	/// Palette and css variable colors that should be excluded from the
+	/// options. Patterns with a `*` wildcard are supported, e.g. `gray-*`.
+	/// Dashes are ignored when matching, so `gray-*` also matches `gray100`.
 	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub excluded: StringList,
+	/// When not empty, only the palette and css variable colors matching these
+	/// names are included in the options. Patterns with a `*` wildcard are
+	/// supported, e.g. `gray-*`. Dashes are ignored when matching.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub included: StringList,
 	/// Additional named color fields.
 	#[serde(flatten, default)]
 	#[builder(default, setter(into))]
@@ -43,9 +51,28 @@ impl ColorField {
 
 		self.disable_named_defaults |= other.disable_named_defaults;
 		self.disable_palette |= other.disable_palette;
+		self.excluded.extend(other.excluded);
+		self.included.extend(other.included);
 		self.named_fields.extend(other.named_fields);
 	}
 
+	/// Check whether the palette or css variable color should be available
+	/// for this field based on the `included` and `excluded` patterns.
+	pub fn is_included(&self, name: impl AsRef<str>) -> bool {
+		let name = name.as_ref();
+		let is_included = self.included.is_empty()
+			|| self
+				.included
+				.iter()
+				.any(|pattern| is_pattern_match(pattern, name));
+
+		is_included
+			&& !self
+				.excluded
+				.iter()
+				.any(|pattern| is_pattern_match(pattern, name))
+	}
+
 	pub fn get_fields(&self) -> IndexMap<String, NamedColorField> {
 		let mut fields = self.named_fields.clone();
 
@@ -74,6 +101,39 @@ impl<T: Into<String>> From<T> for NamedColorField {
 	}
 }
 
+/// Match the name against a pattern where `*` matches any number of
+/// characters. Dashes are ignored so that `gray-*` matches the palette name
+/// `gray100` as well as the css variable `gray-100`.
+fn is_pattern_match(pattern: &str, name: &str) -> bool {
+	let pattern = pattern.replace('-', "");
+	let name = name.replace('-', "");
+	let mut parts = pattern.split('*');
+	let first = parts.next().unwrap_or_default();
+
+	let Some(mut rest) = name.strip_prefix(first) else {
+		return false;
+	};
+
+	let mut parts = parts.peekable();
+
+	if parts.peek().is_none() {
+		return rest.is_empty();
+	}
+
+	while let Some(part) = parts.next() {
+		if parts.peek().is_none() {
+			return rest.ends_with(part);
+		}
+
+		match rest.find(part) {
+			Some(index) => rest = rest.get(index + part.len()..).unwrap_or_default(),
+			None => return false,
+		}
+	}
+
+	true
+}
+
 fn default_fallback_color() -> String {
 	"#000".into()
 }
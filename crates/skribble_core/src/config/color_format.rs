@@ -2,6 +2,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use skribble_color::Color;
 use skribble_color::ColorError;
+use skribble_color::Gamut;
 use skribble_color::Hsla;
 
 use crate::Error;
@@ -29,45 +30,33 @@ pub enum ColorFormat {
 	Lab,
 	#[serde(rename = "oklab")]
 	Oklab,
+	/// Render colors with `color(display-p3 ...)`.
+	#[serde(rename = "display-p3")]
+	DisplayP3,
+	/// Render colors with `color(rec2020 ...)`.
+	#[serde(rename = "rec2020")]
+	Rec2020,
 }
 
 impl ColorFormat {
 	pub fn get_color(&self, value: impl AsRef<str>) -> Result<Color> {
-		let value = value.as_ref();
+		let color = value.as_ref().parse::<Color>().map_err(Error::from)?;
+		Ok(self.convert(color))
+	}
 
+	/// Convert the color into this format.
+	pub fn convert(&self, color: Color) -> Color {
 		match self {
-			Self::Hex => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_hex();
-				Ok(color)
-			}
-			Self::Rgb => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_rgb();
-				Ok(color)
-			}
-			Self::Hsl => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_hsl();
-				Ok(color)
-			}
-			Self::Hwb => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_hwb();
-				Ok(color)
-			}
-			Self::Lch => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_lch();
-				Ok(color)
-			}
-			Self::Oklch => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_oklch();
-				Ok(color)
-			}
-			Self::Lab => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_lab();
-				Ok(color)
-			}
-			Self::Oklab => {
-				let color = value.parse::<Color>().map_err(Error::from)?.into_oklab();
-				Ok(color)
-			}
+			Self::Hex => color.into_hex(),
+			Self::Rgb => color.into_rgb(),
+			Self::Hsl => color.into_hsl(),
+			Self::Hwb => color.into_hwb(),
+			Self::Lch => color.into_lch(),
+			Self::Oklch => color.into_oklch(),
+			Self::Lab => color.into_lab(),
+			Self::Oklab => color.into_oklab(),
+			Self::DisplayP3 => color.into_gamut(Gamut::DisplayP3),
+			Self::Rec2020 => color.into_gamut(Gamut::Rec2020),
 		}
 	}
 
@@ -92,6 +81,8 @@ impl AsRef<str> for ColorFormat {
 			Self::Oklch => "oklch",
 			Self::Lab => "lab",
 			Self::Oklab => "oklab",
+			Self::DisplayP3 => "display-p3",
+			Self::Rec2020 => "rec2020",
 		}
 	}
 }
@@ -106,6 +97,8 @@ impl<T: Into<String>> From<T> for ColorFormat {
 			"oklch" => Self::Oklch,
 			"lab" => Self::Lab,
 			"oklab" => Self::Oklab,
+			"display-p3" => Self::DisplayP3,
+			"rec2020" => Self::Rec2020,
 			_ => Self::Hsl,
 		}
 	}
@@ -0,0 +1,107 @@
+use derive_more::Deref;
+use derive_more::DerefMut;
+use serde::Deserialize;
+use serde::Serialize;
+use typed_builder::TypedBuilder;
+
+use super::Group;
+use super::Priority;
+
+/// Container queries apply styles based on the size of a containing element
+/// rather than the viewport.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Deref, DerefMut)]
+pub struct ContainerQueries(Vec<Group<ContainerQuery>>);
+
+impl ContainerQueries {
+	/// Extend an existing group or create a new one if it does not exist.
+	pub fn extend_group(&mut self, group: impl Into<Group<ContainerQuery>>) {
+		let group = group.into();
+
+		if let Some(existing_group) = self.0.iter_mut().find(|g| g.name == group.name) {
+			existing_group.merge(group);
+		} else {
+			self.0.push(group);
+		}
+	}
+}
+
+impl From<Vec<Group<ContainerQuery>>> for ContainerQueries {
+	fn from(container_queries: Vec<Group<ContainerQuery>>) -> Self {
+		Self(container_queries)
+	}
+}
+
+impl IntoIterator for ContainerQueries {
+	type IntoIter = std::vec::IntoIter<Self::Item>;
+	type Item = Group<ContainerQuery>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<V> FromIterator<V> for ContainerQueries
+where
+	V: Into<Group<ContainerQuery>>,
+{
+	fn from_iter<T>(iter: T) -> Self
+	where
+		T: IntoIterator<Item = V>,
+	{
+		let container_queries = iter.into_iter().map(|value| value.into()).collect();
+		Self(container_queries)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerQuery {
+	/// The name of the container query.
+	#[builder(setter(into))]
+	pub name: String,
+	/// The size or style query to use for the container query, e.g.
+	/// `(min-width: 40rem)`.
+	#[builder(setter(into))]
+	pub query: String,
+	/// The name of the container to query. When not provided the nearest
+	/// ancestor container is used.
+	#[serde(default)]
+	#[builder(default, setter(into, strip_option))]
+	pub container_name: Option<String>,
+	/// A markdown description of what this container query should be used for.
+	#[builder(default, setter(into, strip_option))]
+	pub description: Option<String>,
+	/// The priority of this items.
+	#[builder(default, setter(into))]
+	pub priority: Priority,
+}
+
+impl ContainerQuery {
+	pub fn merge(&mut self, other: impl Into<Self>) {
+		let other = other.into();
+
+		if self.name != other.name {
+			panic!("Cannot merge container queries with different names");
+		}
+
+		if let Some(description) = other.description {
+			self.description = Some(description);
+		}
+
+		if other.priority < self.priority {
+			self.priority = other.priority;
+		}
+
+		self.container_name = other.container_name;
+		self.query = other.query;
+	}
+
+	/// The condition which follows the `@container` at-rule, including the
+	/// container name when one is provided.
+	pub fn condition(&self) -> String {
+		match self.container_name {
+			Some(ref container_name) => format!("{container_name} {}", self.query),
+			None => self.query.clone(),
+		}
+	}
+}
@@ -0,0 +1,273 @@
+use derive_more::Deref;
+use derive_more::DerefMut;
+use indexmap::IndexSet;
+use serde::Deserialize;
+use serde::Serialize;
+use skribble_color::Color;
+use typed_builder::TypedBuilder;
+
+use super::CssVariable;
+use crate::Placeholder;
+use crate::RunnerConfig;
+
+/// Foreground and background color pairs which should be audited for
+/// sufficient contrast.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Deref, DerefMut)]
+pub struct ContrastPairs(Vec<ContrastPair>);
+
+impl<T: Into<ContrastPair>> From<Vec<T>> for ContrastPairs {
+	fn from(pairs: Vec<T>) -> Self {
+		Self::from_iter(pairs)
+	}
+}
+
+impl IntoIterator for ContrastPairs {
+	type IntoIter = std::vec::IntoIter<Self::Item>;
+	type Item = ContrastPair;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<V> FromIterator<V> for ContrastPairs
+where
+	V: Into<ContrastPair>,
+{
+	fn from_iter<T>(iter: T) -> Self
+	where
+		T: IntoIterator<Item = V>,
+	{
+		let pairs = iter.into_iter().map(|v| v.into()).collect();
+
+		Self(pairs)
+	}
+}
+
+/// A foreground and background color which are used together. The colors are
+/// referenced by the name of a [`CssVariable`] or a
+/// palette color. CSS variables are preferred when both exist with the same
+/// name.
+///
+/// The `media_queries` overrides of CSS variables are also audited so that
+/// dark mode colors are checked.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+pub struct ContrastPair {
+	/// The name of the color used for the text.
+	#[builder(setter(into))]
+	pub foreground: String,
+	/// The name of the color used for the background.
+	#[builder(setter(into))]
+	pub background: String,
+	/// The minimum WCAG 2.x level that the pair must meet.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub level: ContrastLevel,
+	/// The minimum absolute APCA lightness contrast (`Lc`) that the pair must
+	/// meet.
+	#[serde(default)]
+	#[builder(default, setter(into, strip_option))]
+	pub min_apca: Option<f32>,
+}
+
+impl ContrastPair {
+	/// Audit the contrast of the pair for the default values and every media
+	/// query override.
+	pub fn audit(&self, config: &RunnerConfig) -> Vec<ContrastIssue> {
+		let mut issues = vec![];
+		let foreground = ContrastSource::new(&self.foreground, config);
+		let background = ContrastSource::new(&self.background, config);
+
+		for (source, name) in [
+			(&foreground, &self.foreground),
+			(&background, &self.background),
+		] {
+			if source.is_none() {
+				issues.push(ContrastIssue::UnknownColor { name: name.clone() });
+			}
+		}
+
+		let (Some(foreground), Some(background)) = (foreground, background) else {
+			return issues;
+		};
+
+		let mut contexts = IndexSet::<(Option<String>, Option<String>)>::new();
+		contexts.insert((None, None));
+		contexts.extend(foreground.contexts());
+		contexts.extend(background.contexts());
+
+		for (media_query, selector) in contexts.iter() {
+			let foreground_color =
+				foreground.color(&self.foreground, media_query, selector, config);
+			let background_color =
+				background.color(&self.background, media_query, selector, config);
+
+			let (foreground_color, background_color) = match (foreground_color, background_color) {
+				(Ok(foreground_color), Ok(background_color)) => {
+					(foreground_color, background_color)
+				}
+				(foreground_color, background_color) => {
+					for issue in [foreground_color.err(), background_color.err()]
+						.into_iter()
+						.flatten()
+					{
+						if !issues.contains(&issue) {
+							issues.push(issue);
+						}
+					}
+
+					continue;
+				}
+			};
+
+			let ratio = foreground_color.contrast_ratio(&background_color);
+			let apca = foreground_color.apca_contrast(&background_color);
+			let fails_apca = self
+				.min_apca
+				.map(|min_apca| apca.abs() < min_apca)
+				.unwrap_or(false);
+
+			if ratio < self.level.min_ratio() || fails_apca {
+				issues.push(ContrastIssue::InsufficientContrast {
+					foreground: self.foreground.clone(),
+					background: self.background.clone(),
+					media_query: media_query
+						.as_ref()
+						.map(|query| Placeholder::normalize_media_query(query, config)),
+					selector: selector
+						.as_ref()
+						.map(|selector| Placeholder::normalize(selector, config)),
+					ratio,
+					apca,
+					level: self.level,
+				});
+			}
+		}
+
+		issues
+	}
+}
+
+/// The WCAG 2.x conformance levels for contrast.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContrastLevel {
+	/// A contrast ratio of at least `3:1` for large text.
+	AaLarge,
+	/// A contrast ratio of at least `4.5:1`.
+	#[default]
+	Aa,
+	/// A contrast ratio of at least `4.5:1` for large text.
+	AaaLarge,
+	/// A contrast ratio of at least `7:1`.
+	Aaa,
+}
+
+impl ContrastLevel {
+	/// The minimum contrast ratio required by the level.
+	pub fn min_ratio(&self) -> f32 {
+		match self {
+			Self::AaLarge => 3.0,
+			Self::Aa | Self::AaaLarge => 4.5,
+			Self::Aaa => 7.0,
+		}
+	}
+}
+
+/// A problem found while auditing the contrast of a [`ContrastPair`].
+#[non_exhaustive]
+#[derive(thiserror::Error, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ContrastIssue {
+	/// The colors don't meet the required contrast.
+	#[serde(rename_all = "camelCase")]
+	#[error(
+		"`{foreground}` on `{background}` has a contrast ratio of {ratio:.2} and an apca contrast \
+		 of {apca:.1} which does not meet the required level `{level:?}`"
+	)]
+	InsufficientContrast {
+		foreground: String,
+		background: String,
+		/// The media query of the override. This is `None` for the default
+		/// values.
+		media_query: Option<String>,
+		/// The selector of the override. This is `None` for the default values.
+		selector: Option<String>,
+		ratio: f32,
+		apca: f32,
+		level: ContrastLevel,
+	},
+	/// The name is not a css variable or palette color.
+	#[error("the color `{name}` could not be found in the css variables or palette")]
+	UnknownColor { name: String },
+	/// The value could not be parsed as a color.
+	#[error("the value `{value}` of the color `{name}` is not a valid color")]
+	InvalidColor { name: String, value: String },
+}
+
+enum ContrastSource<'config> {
+	Variable(&'config CssVariable),
+	Palette(&'config String),
+}
+
+impl<'config> ContrastSource<'config> {
+	fn new(name: &str, config: &'config RunnerConfig) -> Option<Self> {
+		config
+			.css_variables
+			.get(name)
+			.map(Self::Variable)
+			.or_else(|| config.palette.get(name).map(Self::Palette))
+	}
+
+	/// The media query and selector combinations which override the value.
+	fn contexts(&self) -> Vec<(Option<String>, Option<String>)> {
+		let Self::Variable(css_variable) = self else {
+			return vec![];
+		};
+
+		css_variable
+			.media_queries
+			.iter()
+			.flat_map(|(query, selectors)| {
+				selectors.keys().map(|selector| {
+					(
+						(!query.is_empty()).then(|| query.clone()),
+						(!selector.is_empty()).then(|| selector.clone()),
+					)
+				})
+			})
+			.collect()
+	}
+
+	fn color(
+		&self,
+		name: &str,
+		media_query: &Option<String>,
+		selector: &Option<String>,
+		config: &RunnerConfig,
+	) -> Result<Color, ContrastIssue> {
+		let value = match self {
+			Self::Variable(css_variable) => {
+				// Fallback to the value of the media query on the root selector since it is
+				// inherited by the nested selectors.
+				let selectors = css_variable
+					.media_queries
+					.get(media_query.as_deref().unwrap_or_default());
+
+				selectors
+					.and_then(|selectors| selectors.get(selector.as_deref().unwrap_or_default()))
+					.or_else(|| selectors.and_then(|selectors| selectors.get("")))
+					.unwrap_or(&css_variable.value)
+			}
+			Self::Palette(value) => value,
+		};
+
+		config.parse_color(value).map_err(|_| {
+			ContrastIssue::InvalidColor {
+				name: name.to_string(),
+				value: Placeholder::normalize(value, config),
+			}
+		})
+	}
+}
@@ -1,5 +1,3 @@
-use std::fmt::Write;
-
 use derive_more::Deref;
 use derive_more::DerefMut;
 use indexmap::indexmap;
@@ -11,8 +9,8 @@ use serde::Serialize;
 use super::Atom;
 use super::StringMap;
 use crate::apply_transformers;
-use crate::AnyEmptyResult;
 use crate::ClassTransformer;
+use crate::CssDeclarations;
 use crate::Placeholder;
 use crate::RunnerConfig;
 use crate::TransformationRecipient;
@@ -28,13 +26,13 @@ pub enum CssValue {
 }
 
 impl CssValue {
-	pub fn write_css(
+	pub fn collect_css_declarations(
 		&self,
-		writer: &mut dyn Write,
+		declarations: &mut CssDeclarations,
 		config: &RunnerConfig,
 		atom: &Atom,
 		transformers: &IndexSet<ClassTransformer>,
-	) -> AnyEmptyResult {
+	) {
 		match self {
 			Self::Value(value) => {
 				let value = {
@@ -49,7 +47,14 @@ impl CssValue {
 
 				let values: StringMap = indexmap! { "" => value.as_str() }.into();
 
-				write_css_property(writer, atom, config, &values, transformers, Some(value))?;
+				collect_css_property(
+					declarations,
+					atom,
+					config,
+					&values,
+					transformers,
+					Some(value),
+				);
 			}
 			Self::Object(map) => {
 				if atom.styles.is_empty() {
@@ -70,15 +75,13 @@ impl CssValue {
 								TransformationRecipient::Property,
 							)
 						};
-						writeln!(writer, "{property}: {css_value};")?;
+						declarations.push((property, css_value));
 					}
 				} else {
-					write_css_property(writer, atom, config, map, transformers, None)?;
+					collect_css_property(declarations, atom, config, map, transformers, None);
 				}
 			}
 		}
-
-		Ok(())
 	}
 
 	pub fn collect_css_variables(&self, css_variables: &mut IndexSet<String>) {
@@ -95,14 +98,14 @@ impl CssValue {
 	}
 }
 
-fn write_css_property(
-	writer: &mut dyn Write,
+fn collect_css_property(
+	declarations: &mut CssDeclarations,
 	atom: &Atom,
 	config: &RunnerConfig,
 	values: &StringMap,
 	transformers: &IndexSet<ClassTransformer>,
 	value: Option<String>,
-) -> AnyEmptyResult {
+) {
 	let values = values
 		.iter()
 		.map(|(key, value)| {
@@ -126,19 +129,17 @@ fn write_css_property(
 			)
 		}) {
 			Some(css_value) => {
-				writeln!(writer, "{property}: {css_value};")?;
+				declarations.push((property, css_value));
 			}
 			None => {
 				let Some(ref value) = value else {
 					continue;
 				};
 
-				writeln!(writer, "{property}: {value};")?;
+				declarations.push((property, value.clone()));
 			}
 		}
 	}
-
-	Ok(())
 }
 
 impl From<&str> for CssValue {
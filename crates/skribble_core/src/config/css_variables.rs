@@ -6,6 +6,8 @@ use indexmap::IndexMap;
 use serde::Deserialize;
 use serde::Serialize;
 use skribble_color::palette::Hsla;
+use skribble_color::Color;
+use skribble_color::Gamut;
 use skribble_color::HslaCss;
 use typed_builder::TypedBuilder;
 
@@ -16,6 +18,7 @@ use super::PropertySyntax;
 use super::StringMap;
 use crate::format_css_string;
 use crate::indent_writer;
+use crate::is_dynamic_color;
 use crate::wrap_css_variable;
 use crate::AnyEmptyResult;
 use crate::Placeholder;
@@ -154,7 +157,10 @@ impl CssVariable {
 		}
 
 		if self.is_color() {
-			value = options.color_format.get_color(value)?.to_string();
+			value = options
+				.color_format
+				.convert(config.parse_color(value)?)
+				.to_string();
 			let hsla = options.color_format.get_hsla(&value)?;
 
 			if with_parts {
@@ -230,6 +236,16 @@ impl CssVariable {
 				&selector_name,
 				&self.value,
 			)?;
+		} else if !self.media_queries.contains_key("") {
+			// The default value is only provided by the `@property` rule so the
+			// wide gamut override needs to be added separately.
+			self.extend_wide_gamut_dictionary(
+				config,
+				dictionary,
+				&None,
+				":root".into(),
+				&self.value,
+			)?;
 		}
 
 		for (query, selector_map) in self.media_queries.iter() {
@@ -286,7 +302,7 @@ impl CssVariable {
 					None => {
 						let mut writer = String::new();
 						self.write_media_query_css(&mut writer, config, variable_value)?;
-						map.insert(selector, writer);
+						map.insert(selector.clone(), writer);
 					}
 				}
 			}
@@ -295,10 +311,58 @@ impl CssVariable {
 				let mut writer = String::new();
 
 				self.write_media_query_css(&mut writer, config, variable_value)?;
-				map.insert(selector, writer);
+				map.insert(selector.clone(), writer);
 				dictionary.insert(query.clone(), map);
 			}
 		};
+
+		self.extend_wide_gamut_dictionary(config, dictionary, query, selector, variable_value)
+	}
+
+	/// Add an override for colors which are outside of the sRGB gamut when the
+	/// `wide_gamut` option is set. The sRGB fallback is written by
+	/// [`CssVariable::write_media_query_css`].
+	fn extend_wide_gamut_dictionary(
+		&self,
+		config: &RunnerConfig,
+		dictionary: &mut IndexMap<Option<String>, StringMap>,
+		query: &Option<String>,
+		selector: String,
+		variable_value: &str,
+	) -> AnyEmptyResult {
+		let Some(gamut) = config.options().wide_gamut else {
+			return Ok(());
+		};
+
+		if !self.is_color() || gamut == Gamut::Srgb {
+			return Ok(());
+		}
+
+		let variable_value = Placeholder::normalize(variable_value, config);
+
+		if is_dynamic_color(&variable_value) {
+			return Ok(());
+		}
+
+		let color: Color = variable_value.parse()?;
+
+		if color.is_in_gamut(Gamut::Srgb) {
+			return Ok(());
+		}
+
+		let gamut_query = match query {
+			Some(query) => format!("{query} and {}", gamut.media_query()),
+			None => gamut.media_query().to_string(),
+		};
+		let variable_name = self.get_variable(config.options());
+		let writer = dictionary
+			.entry(Some(gamut_query))
+			.or_default()
+			.entry(selector)
+			.or_default();
+
+		writeln!(writer, "{variable_name}: {};", color.into_gamut(gamut))?;
+
 		Ok(())
 	}
 
@@ -312,14 +376,22 @@ impl CssVariable {
 
 		if self.is_color() {
 			let options = config.options();
-			let hsla = options.color_format.get_hsla(&self.value)?;
+			let hsla = options
+				.color_format
+				.get_hsla(config.parse_color(&self.value)?.to_string())?;
 			let HslaColorVariable { h, s, l, a, .. } = self.hsla_color_variable(options);
 			let hsla_css = HslaCss::new(&hsla);
 			let hue = hsla_css.hue();
 			let saturation = hsla_css.saturation();
 			let lightness = hsla_css.lightness();
 			let alpha = hsla_css.alpha();
-			let variable_value = options.color_format.get_color(variable_value)?;
+			let variable_value = Placeholder::normalize(variable_value, config);
+			// Colors which depend on other css variables are resolved by the browser.
+			let variable_value = if is_dynamic_color(&variable_value) {
+				variable_value
+			} else {
+				options.color_format.get_color(variable_value)?.to_string()
+			};
 
 			writeln!(writer, "{h}: {hue};")?;
 			writeln!(writer, "{s}: {saturation};")?;
@@ -0,0 +1,91 @@
+use derive_more::Deref;
+use derive_more::DerefMut;
+use serde::Deserialize;
+use serde::Serialize;
+use typed_builder::TypedBuilder;
+
+use super::Group;
+use super::Priority;
+
+/// Feature queries apply styles only when the browser supports the provided
+/// css features.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Deref, DerefMut)]
+pub struct FeatureQueries(Vec<Group<FeatureQuery>>);
+
+impl FeatureQueries {
+	/// Extend an existing group or create a new one if it does not exist.
+	pub fn extend_group(&mut self, group: impl Into<Group<FeatureQuery>>) {
+		let group = group.into();
+
+		if let Some(existing_group) = self.0.iter_mut().find(|g| g.name == group.name) {
+			existing_group.merge(group);
+		} else {
+			self.0.push(group);
+		}
+	}
+}
+
+impl From<Vec<Group<FeatureQuery>>> for FeatureQueries {
+	fn from(feature_queries: Vec<Group<FeatureQuery>>) -> Self {
+		Self(feature_queries)
+	}
+}
+
+impl IntoIterator for FeatureQueries {
+	type IntoIter = std::vec::IntoIter<Self::Item>;
+	type Item = Group<FeatureQuery>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<V> FromIterator<V> for FeatureQueries
+where
+	V: Into<Group<FeatureQuery>>,
+{
+	fn from_iter<T>(iter: T) -> Self
+	where
+		T: IntoIterator<Item = V>,
+	{
+		let feature_queries = iter.into_iter().map(|value| value.into()).collect();
+		Self(feature_queries)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureQuery {
+	/// The name of the feature query.
+	#[builder(setter(into))]
+	pub name: String,
+	/// The condition to use for the `@supports` rule, e.g. `(display: grid)`.
+	#[builder(setter(into))]
+	pub query: String,
+	/// A markdown description of what this feature query should be used for.
+	#[builder(default, setter(into, strip_option))]
+	pub description: Option<String>,
+	/// The priority of this items.
+	#[builder(default, setter(into))]
+	pub priority: Priority,
+}
+
+impl FeatureQuery {
+	pub fn merge(&mut self, other: impl Into<Self>) {
+		let other = other.into();
+
+		if self.name != other.name {
+			panic!("Cannot merge feature queries with different names");
+		}
+
+		if let Some(description) = other.description {
+			self.description = Some(description);
+		}
+
+		if other.priority < self.priority {
+			self.priority = other.priority;
+		}
+
+		self.query = other.query;
+	}
+}
@@ -1,11 +1,10 @@
-use std::fmt::Write;
-
 use indexmap::indexmap;
 use indexmap::indexset;
 use indexmap::IndexSet;
 use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
+use skribble_color::Color;
 use skribble_color::HslaCss;
 
 use super::Atom;
@@ -14,11 +13,14 @@ use super::NameSet;
 use super::Prioritized;
 use super::Transformation;
 use super::TransformationRecipient;
+use crate::is_dynamic_color;
 use crate::AnyEmptyResult;
 use crate::Arguments;
 use crate::CalcSymbol;
 use crate::ClassTransformer;
 use crate::ColorProperty;
+use crate::CssDeclarations;
+use crate::OklchProperty;
 use crate::Placeholder;
 use crate::RunnerConfig;
 
@@ -55,11 +57,17 @@ impl LinkedValues {
 				names.extend(color_field.get_fields().keys().cloned());
 
 				if !color_field.disable_palette {
-					names.extend(config.palette.keys().cloned());
+					names.extend(
+						config
+							.palette
+							.keys()
+							.filter(|name| color_field.is_included(name))
+							.cloned(),
+					);
 				}
 
 				for (name, variable) in config.css_variables.iter() {
-					if variable.is_color() {
+					if variable.is_color() && color_field.is_included(name) {
 						names.insert(name.to_owned());
 					}
 				}
@@ -95,9 +103,9 @@ impl LinkedValues {
 		}
 	}
 
-	pub fn write_css_properties(
+	pub fn collect_css_declarations(
 		&self,
-		writer: &mut dyn Write,
+		declarations: &mut CssDeclarations,
 		config: &RunnerConfig,
 		atom: &Atom,
 		name: impl AsRef<str>,
@@ -113,7 +121,12 @@ impl LinkedValues {
 						.get(key)
 						.and_then(|value_set| value_set.values.get(name))
 					{
-						css_value.write_css(writer, config, atom, transformers)?;
+						css_value.collect_css_declarations(
+							declarations,
+							config,
+							atom,
+							transformers,
+						);
 						break;
 					}
 				}
@@ -127,7 +140,21 @@ impl LinkedValues {
 					}
 
 					let default_value = if transformers.is_empty() {
-						let transformed_color = variable.get_wrapped_variable(options);
+						let transformed_color =
+							Placeholder::normalize(variable.get_wrapped_variable(options), config);
+						apply_transformers(
+							transformed_color,
+							transformers,
+							config,
+							TransformationRecipient::Value,
+						)
+					} else if !has_color_properties(transformers, config) {
+						let color = TransformedColor::Css(Placeholder::normalize(
+							variable.get_wrapped_variable(options),
+							config,
+						));
+						let transformed_color =
+							apply_color_space_transformers(color, transformers, config);
 						apply_transformers(
 							transformed_color,
 							transformers,
@@ -138,8 +165,13 @@ impl LinkedValues {
 						let parts = variable.hsla_color_variable(options);
 						let mut hsla_css = parts.hsla_css();
 
+						let transformed_color = TransformedColor::Css(apply_color_transformers(
+							transformers,
+							config,
+							&mut hsla_css,
+						));
 						let transformed_color =
-							apply_color_transformers(transformers, config, &mut hsla_css);
+							apply_color_space_transformers(transformed_color, transformers, config);
 						apply_transformers(
 							transformed_color,
 							transformers,
@@ -152,14 +184,14 @@ impl LinkedValues {
 						parts.wrapped_transparent()
 					};
 
-					write_color_values(
+					collect_color_declarations(
+						declarations,
 						&default_value,
 						&transparent_value,
 						atom,
 						config,
 						transformers,
-						writer,
-					)?;
+					);
 
 					return Ok(());
 				}
@@ -169,6 +201,33 @@ impl LinkedValues {
 						return Ok(());
 					}
 
+					// Palette colors which depend on css variables are resolved by the browser.
+					if is_dynamic_color(palette_value) {
+						let value = Placeholder::normalize(palette_value, config);
+						let transformed_color = apply_color_space_transformers(
+							TransformedColor::Css(value.clone()),
+							transformers,
+							config,
+						);
+						let default_value = apply_transformers(
+							transformed_color,
+							transformers,
+							config,
+							TransformationRecipient::Value,
+						);
+						let transparent_value = format!("rgb(from {value} r g b / 0)");
+						collect_color_declarations(
+							declarations,
+							&default_value,
+							&transparent_value,
+							atom,
+							config,
+							transformers,
+						);
+
+						return Ok(());
+					}
+
 					let default_value = if transformers.is_empty() {
 						let transformed_color =
 							options.color_format.get_color(palette_value)?.to_string();
@@ -178,11 +237,26 @@ impl LinkedValues {
 							config,
 							TransformationRecipient::Value,
 						)
+					} else if !has_color_properties(transformers, config) {
+						let color = TransformedColor::Known(palette_value.parse()?);
+						let transformed_color =
+							apply_color_space_transformers(color, transformers, config);
+						apply_transformers(
+							transformed_color,
+							transformers,
+							config,
+							TransformationRecipient::Value,
+						)
 					} else {
 						let hsla = options.color_format.get_hsla(palette_value)?;
 						let mut hsla_css = HslaCss::new(&hsla);
+						let transformed_color = TransformedColor::Css(apply_color_transformers(
+							transformers,
+							config,
+							&mut hsla_css,
+						));
 						let transformed_color =
-							apply_color_transformers(transformers, config, &mut hsla_css);
+							apply_color_space_transformers(transformed_color, transformers, config);
 						apply_transformers(
 							transformed_color,
 							transformers,
@@ -196,14 +270,14 @@ impl LinkedValues {
 						hsla_css.a = Some("0".into());
 						hsla_css.to_string()
 					};
-					write_color_values(
+					collect_color_declarations(
+						declarations,
 						&default_value,
 						&transparent_value,
 						atom,
 						config,
 						transformers,
-						writer,
-					)?;
+					);
 
 					return Ok(());
 				}
@@ -213,7 +287,11 @@ impl LinkedValues {
 					return Ok(());
 				};
 
-				let normalized_value = Placeholder::normalize(&field_value.value, config);
+				let normalized_value = apply_color_space_transformers(
+					TransformedColor::Css(Placeholder::normalize(&field_value.value, config)),
+					transformers,
+					config,
+				);
 				let default_value = apply_transformers(
 					normalized_value,
 					transformers,
@@ -226,14 +304,14 @@ impl LinkedValues {
 					hsla_css.a = Some("0".into());
 					hsla_css.to_string()
 				};
-				write_color_values(
+				collect_color_declarations(
+					declarations,
 					&default_value,
 					&transparent_value,
 					atom,
 					config,
 					transformers,
-					writer,
-				)?;
+				);
 			}
 			Self::Keyframes => {
 				for (keyframe_name, _keyframe) in config.keyframes.iter() {
@@ -257,7 +335,7 @@ impl LinkedValues {
 							)
 						};
 
-						writeln!(writer, "{}: {};", property, css_value)?;
+						declarations.push((property, css_value));
 					}
 
 					break;
@@ -268,16 +346,15 @@ impl LinkedValues {
 		Ok(())
 	}
 
-	pub fn write_css_argument(
+	pub fn collect_argument_css_declarations(
 		&self,
-		writer: &mut dyn Write,
+		declarations: &mut CssDeclarations,
 		config: &RunnerConfig,
 		atom: &Atom,
 		argument: &Arguments,
 		transformers: &IndexSet<ClassTransformer>,
-	) -> AnyEmptyResult {
-		argument.write_css_atom(writer, config, atom, transformers)?;
-		Ok(())
+	) {
+		argument.collect_atom_css_declarations(declarations, config, atom, transformers);
 	}
 
 	pub fn collect_css_variables(
@@ -317,14 +394,14 @@ impl LinkedValues {
 	}
 }
 
-fn write_color_values(
+fn collect_color_declarations(
+	declarations: &mut CssDeclarations,
 	default_value: &str,
 	transparent_value: &str,
 	atom: &Atom,
 	config: &RunnerConfig,
 	transformers: &IndexSet<ClassTransformer>,
-	writer: &mut dyn Write,
-) -> AnyEmptyResult {
+) {
 	let values = indexmap! {
 	  "" => default_value,
 	  "transparent" => transparent_value
@@ -346,10 +423,8 @@ fn write_color_values(
 			)
 		};
 
-		writeln!(writer, "{}: {};", property, css_value)?;
+		declarations.push((property, css_value));
 	}
-
-	Ok(())
 }
 
 /// Apply the color transformers to the given HSLA CSS value.
@@ -416,6 +491,198 @@ fn apply_color_transformers(
 	hsla_css.to_string()
 }
 
+/// Whether any of the transformers update the hsl color properties.
+fn has_color_properties(transformers: &IndexSet<ClassTransformer>, config: &RunnerConfig) -> bool {
+	transformers.iter().any(|ClassTransformer { name, .. }| {
+		config
+			.get_transformer(name)
+			.map(|transformer| matches!(transformer.transformation, Transformation::Color(_)))
+			.unwrap_or(false)
+	})
+}
+
+/// A color which is being transformed. The color is precomputed while it is
+/// known and falls back to css color functions once it depends on a css
+/// variable.
+enum TransformedColor {
+	Known(Color),
+	Css(String),
+}
+
+impl TransformedColor {
+	/// Resolve the color from the name of a color css variable, a palette
+	/// color or a css color.
+	fn resolve(name: &str, config: &RunnerConfig) -> Self {
+		if let Some(variable) = config.css_variables.get(name) {
+			if variable.is_color() {
+				return Self::Css(Placeholder::normalize(
+					variable.get_wrapped_variable(config.options()),
+					config,
+				));
+			}
+		}
+
+		let value = Placeholder::normalize(
+			config.palette.get(name).map_or(name, String::as_str),
+			config,
+		);
+
+		match value.parse() {
+			Ok(color) => Self::Known(color),
+			Err(_) => Self::Css(value),
+		}
+	}
+
+	fn into_css(self, config: &RunnerConfig) -> String {
+		match self {
+			Self::Known(color) => config.options().color_format.convert(color).to_string(),
+			Self::Css(css) => css,
+		}
+	}
+}
+
+/// Apply the mix, oklch and invert transformers to the provided color.
+fn apply_color_space_transformers(
+	mut color: TransformedColor,
+	transformers: &IndexSet<ClassTransformer>,
+	config: &RunnerConfig,
+) -> String {
+	for ClassTransformer { name, value } in transformers.iter() {
+		let Some(transformer) = config.get_transformer(name) else {
+			continue;
+		};
+
+		let value = value.as_ref().and_then(|v| v.get_value(name, config));
+
+		color = match &transformer.transformation {
+			Transformation::Mix(mix) => {
+				let Some((other, amount)) = mix.get_color_and_amount(value.as_deref()) else {
+					continue;
+				};
+
+				let Some(amount) = parse_amount(amount.unwrap_or("50%"), 1.0) else {
+					continue;
+				};
+
+				match (color, TransformedColor::resolve(other, config)) {
+					(TransformedColor::Known(color), TransformedColor::Known(other)) => {
+						TransformedColor::Known(color.mix(&other, amount, mix.space))
+					}
+					(color, other) => {
+						TransformedColor::Css(format!(
+							"color-mix(in {}, {}, {} {}%)",
+							mix.space,
+							color.into_css(config),
+							other.into_css(config),
+							round(amount * 100.0, 2),
+						))
+					}
+				}
+			}
+			Transformation::Oklch(property) => {
+				let Some(amount) = value
+					.as_deref()
+					.and_then(|value| parse_amount(value, property.percentage_reference()))
+				else {
+					continue;
+				};
+
+				match color {
+					TransformedColor::Known(color) => {
+						TransformedColor::Known(color.map_oklch(|oklch| {
+							match *property {
+								OklchProperty::Lightness(symbol) => {
+									oklch.l = calculate(oklch.l, symbol, amount);
+								}
+								OklchProperty::Chroma(symbol) => {
+									oklch.chroma = calculate(oklch.chroma, symbol, amount);
+								}
+								OklchProperty::Hue(symbol) => {
+									let hue = oklch.hue.into_positive_degrees();
+									oklch.hue = calculate(hue, symbol, amount).into();
+								}
+							}
+						}))
+					}
+					TransformedColor::Css(css) => {
+						let expression = |channel: &str, symbol: CalcSymbol| {
+							let amount = round(amount, 4);
+
+							match symbol {
+								CalcSymbol::Set => amount.to_string(),
+								symbol => format!("calc({channel} {symbol} {amount})"),
+							}
+						};
+						let (l, c, h) = match *property {
+							OklchProperty::Lightness(symbol) => {
+								(
+									format!("clamp(0, {}, 1)", expression("l", symbol)),
+									"c".to_string(),
+									"h".to_string(),
+								)
+							}
+							OklchProperty::Chroma(symbol) => {
+								(
+									"l".to_string(),
+									format!("max(0, {})", expression("c", symbol)),
+									"h".to_string(),
+								)
+							}
+							OklchProperty::Hue(symbol) => {
+								("l".to_string(), "c".to_string(), expression("h", symbol))
+							}
+						};
+
+						TransformedColor::Css(format!("oklch(from {css} {l} {c} {h} / alpha)"))
+					}
+				}
+			}
+			Transformation::Invert => {
+				match color {
+					TransformedColor::Known(color) => TransformedColor::Known(color.invert()),
+					TransformedColor::Css(css) => {
+						TransformedColor::Css(format!("oklch(from {css} calc(1 - l) c h / alpha)"))
+					}
+				}
+			}
+			_ => continue,
+		};
+	}
+
+	color.into_css(config)
+}
+
+/// Parse a number or a percentage of the provided reference value.
+fn parse_amount(value: &str, reference: f32) -> Option<f32> {
+	let value = value.trim();
+
+	match value.strip_suffix('%') {
+		Some(percentage) => {
+			percentage
+				.trim()
+				.parse::<f32>()
+				.ok()
+				.map(|v| v / 100.0 * reference)
+		}
+		None => value.parse().ok(),
+	}
+}
+
+fn calculate(current: f32, symbol: CalcSymbol, amount: f32) -> f32 {
+	match symbol {
+		CalcSymbol::Add => current + amount,
+		CalcSymbol::Subtract => current - amount,
+		CalcSymbol::Multiply => current * amount,
+		CalcSymbol::Divide => current / amount,
+		CalcSymbol::Set => amount,
+	}
+}
+
+fn round(value: f32, decimals: i32) -> f32 {
+	let factor = 10_f32.powi(decimals);
+	(value * factor).round() / factor
+}
+
 pub(crate) fn apply_transformers(
 	current_value: impl AsRef<str>,
 	transformers: &IndexSet<ClassTransformer>,
@@ -434,7 +701,10 @@ pub(crate) fn apply_transformers(
 		}
 
 		match &transformer.transformation {
-			Transformation::Color(_) => {
+			Transformation::Color(_)
+			| Transformation::Mix(_)
+			| Transformation::Oklch(_)
+			| Transformation::Invert => {
 				continue;
 			}
 			Transformation::Replacement(replacement) => {
@@ -17,6 +17,12 @@ pub struct MergeRules {
 	#[builder(default, setter(into))]
 	pub media_queries: MergeRule,
 	#[builder(default, setter(into))]
+	#[serde(default)]
+	pub container_queries: MergeRule,
+	#[builder(default, setter(into))]
+	#[serde(default)]
+	pub feature_queries: MergeRule,
+	#[builder(default, setter(into))]
 	pub modifiers: MergeRule,
 	#[builder(default, setter(into))]
 	pub rules: MergeRule,
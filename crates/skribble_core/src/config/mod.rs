@@ -2,9 +2,12 @@ pub use aliases::*;
 pub use atoms::*;
 pub use color_field::*;
 pub use color_format::*;
+pub use container_queries::*;
+pub use contrast_pairs::*;
 pub use css_chunks::*;
 pub use css_values::*;
 pub use css_variables::*;
+pub use feature_queries::*;
 pub use formatter::*;
 pub use group::*;
 pub use keyframes::*;
@@ -27,9 +30,12 @@ mod aliases;
 mod atoms;
 mod color_field;
 mod color_format;
+mod container_queries;
+mod contrast_pairs;
 mod css_chunks;
 mod css_values;
 mod css_variables;
+mod feature_queries;
 mod formatter;
 mod group;
 mod keyframes;
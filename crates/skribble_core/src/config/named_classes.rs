@@ -1,5 +1,3 @@
-use std::fmt::Write;
-
 use derive_more::Deref;
 use derive_more::DerefMut;
 use indexmap::IndexSet;
@@ -9,7 +7,9 @@ use typed_builder::TypedBuilder;
 
 use super::Priority;
 use super::StringMap;
+use crate::write_css_declarations;
 use crate::AnyEmptyResult;
+use crate::CssDeclarations;
 use crate::Placeholder;
 use crate::RunnerConfig;
 
@@ -101,16 +101,24 @@ impl NamedClass {
 
 	pub fn write_css_properties(
 		&self,
-		writer: &mut dyn Write,
+		writer: &mut dyn std::fmt::Write,
 		config: &RunnerConfig,
 	) -> AnyEmptyResult {
+		let mut declarations = CssDeclarations::new();
+		self.collect_css_declarations(&mut declarations, config);
+		write_css_declarations(writer, &declarations)
+	}
+
+	pub fn collect_css_declarations(
+		&self,
+		declarations: &mut CssDeclarations,
+		config: &RunnerConfig,
+	) {
 		for (property, css_value) in self.styles.iter() {
 			let property = Placeholder::normalize(property, config);
 			let css_value = Placeholder::normalize(css_value, config);
-			writeln!(writer, "{}: {};", property, css_value)?;
+			declarations.push((property, css_value));
 		}
-
-		Ok(())
 	}
 
 	pub fn collect_css_variables(&self, css_variables: &mut IndexSet<String>) {
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use serde::Deserialize;
 use serde::Serialize;
+use skribble_color::Gamut;
 use typed_builder::TypedBuilder;
 
 use super::ColorFormat;
@@ -39,6 +40,12 @@ pub struct Options {
 	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub color_format: ColorFormat,
+	/// When set, color css variables with values outside of the sRGB gamut are
+	/// rendered with an sRGB fallback followed by an override in this gamut
+	/// under the matching `@media (color-gamut: ...)` query.
+	#[serde(default)]
+	#[builder(default, setter(into, strip_option))]
+	pub wide_gamut: Option<Gamut>,
 	/// The rules to control how the user configuration is merged with the
 	/// configuration extracted from plugins.
 	#[serde(default)]
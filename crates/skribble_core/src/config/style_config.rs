@@ -5,8 +5,11 @@ use typed_builder::TypedBuilder;
 
 use super::Aliases;
 use super::Atoms;
+use super::ContainerQueries;
+use super::ContrastPairs;
 use super::CssChunks;
 use super::CssVariables;
+use super::FeatureQueries;
 use super::Keyframes;
 use super::MediaQueries;
 use super::Modifiers;
@@ -31,6 +34,7 @@ use crate::Transformers;
 #[serde(rename_all = "camelCase")]
 pub struct StyleConfig {
 	/// The general options.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub options: Options,
 	/// The css layers.
@@ -38,38 +42,62 @@ pub struct StyleConfig {
 	#[builder(default =  default_layers(), setter(into))]
 	pub layers: Layers,
 	/// Raw css which will be added to the end of the generated css.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub css_chunks: CssChunks,
 	/// Setup the keyframes.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub keyframes: Keyframes,
 	/// CSS variables which can be reused throughout the configuration.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub variables: CssVariables,
 	/// Setup the media queries.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub media_queries: MediaQueries,
+	/// Setup the container queries.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub container_queries: ContainerQueries,
+	/// Setup the feature queries which wrap styles in an `@supports` rule.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub feature_queries: FeatureQueries,
 	/// Modifiers are used to nest styles within a selector. They can be parents
 	/// modifiers or child modifiers.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub modifiers: Modifiers,
 	/// Transformers are used to transform the values or properties of atoms.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub transformers: Transformers,
 	/// Set up the style rules which determine the styles that each atom name
 	/// will correspond to.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub atoms: Atoms,
 	/// A list of classes with predefined styles.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub classes: NamedClasses,
 	/// A list of class name aliases.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub aliases: Aliases,
+	/// The foreground and background color pairs which are audited for
+	/// sufficient contrast.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub contrast_pairs: ContrastPairs,
 	/// Hardcoded colors for the pallette.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub palette: Palette,
 	/// The atoms which provide the values.
+	#[serde(default)]
 	#[builder(default, setter(into))]
 	pub value_sets: ValueSets,
 	/// The plugins which can be used to add new functionality and extend the
@@ -97,7 +125,10 @@ impl StyleConfig {
 			atoms,
 			aliases,
 			classes,
+			container_queries,
+			contrast_pairs,
 			css_chunks,
+			feature_queries,
 			keyframes,
 			layers,
 			media_queries,
@@ -116,7 +147,10 @@ impl StyleConfig {
 				atoms,
 				aliases,
 				classes,
+				container_queries,
+				contrast_pairs,
 				css_chunks,
+				feature_queries,
 				keyframes,
 				layers,
 				media_queries,
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use cssparser::Parser;
+use cssparser::ParserInput;
+use lightningcss::values::syntax::SyntaxString;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -27,6 +30,30 @@ impl PropertySyntax {
 		PropertySyntax::List(property)
 	}
 
+	/// Check whether the provided css value matches this syntax. Values which
+	/// reference variables can't be checked and are always considered valid.
+	pub fn is_valid_value(&self, value: impl AsRef<str>) -> bool {
+		let value = value.as_ref().trim();
+
+		if CSS_WIDE_KEYWORDS.contains(&value) || value.contains("var(") || value.contains("env(") {
+			return true;
+		}
+
+		let syntax = self.to_string();
+		let Ok(syntax) = SyntaxString::parse_string(&syntax) else {
+			return false;
+		};
+
+		if syntax == SyntaxString::Universal {
+			return !value.is_empty();
+		}
+
+		let mut input = ParserInput::new(value);
+		let mut parser = Parser::new(&mut input);
+
+		syntax.parse_value(&mut parser).is_ok() && parser.expect_exhausted().is_ok()
+	}
+
 	#[inline]
 	pub fn is_color(&self) -> bool {
 		match self {
@@ -36,6 +63,9 @@ impl PropertySyntax {
 	}
 }
 
+/// The keywords which are valid for every css property.
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
 impl Default for PropertySyntax {
 	fn default() -> Self {
 		PropertySyntax::Value(PropertySyntaxValue::Any)
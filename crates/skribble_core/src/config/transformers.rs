@@ -4,6 +4,7 @@ use derive_more::Deref;
 use derive_more::DerefMut;
 use serde::Deserialize;
 use serde::Serialize;
+use skribble_color::ColorSpace;
 use typed_builder::TypedBuilder;
 
 use super::Group;
@@ -146,6 +147,69 @@ impl<T: Into<String>> From<T> for ColorProperty {
 	}
 }
 
+/// The OKLCH properties of a color which can be updated. Lightness and chroma
+/// values can be numbers (`0.1`) or percentages (`10%`).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum OklchProperty {
+	Lightness(CalcSymbol),
+	Chroma(CalcSymbol),
+	Hue(CalcSymbol),
+}
+
+impl OklchProperty {
+	/// The value of `100%` for this property.
+	pub fn percentage_reference(&self) -> f32 {
+		match self {
+			Self::Lightness(_) => 1.0,
+			Self::Chroma(_) => 0.4,
+			Self::Hue(_) => 360.0,
+		}
+	}
+}
+
+/// Mix the color with another color in the provided color space.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, TypedBuilder)]
+#[serde(rename_all = "camelCase")]
+pub struct ColorMix {
+	/// The color space used to interpolate between the colors.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub space: ColorSpace,
+	/// The color to mix with. This can be the name of a color css variable, a
+	/// palette color or any css color.
+	///
+	/// When not provided the transformer value should be in the format
+	/// `<color>,<amount>`. e.g. `white,20%`. Otherwise the value is only the
+	/// amount.
+	#[serde(default)]
+	#[builder(default, setter(into, strip_option))]
+	pub color: Option<String>,
+}
+
+impl ColorMix {
+	/// Get the name of the color to mix with and the optional amount from the
+	/// provided transformer value.
+	pub fn get_color_and_amount<'a>(
+		&'a self,
+		value: Option<&'a str>,
+	) -> Option<(&'a str, Option<&'a str>)> {
+		let (color, amount) = match (&self.color, value) {
+			(Some(color), amount) => (color.as_str(), amount),
+			(None, Some(value)) => {
+				match value.split_once(',') {
+					Some((color, amount)) => (color, Some(amount)),
+					None => (value, None),
+				}
+			}
+			(None, None) => return None,
+		};
+		let color = color.trim();
+
+		Some((color.strip_prefix('$').unwrap_or(color), amount))
+	}
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(tag = "type", content = "value")]
 pub enum Transformation {
@@ -155,6 +219,14 @@ pub enum Transformation {
 	RegexReplacement { regex: String, replacement: String },
 	/// Update the color using hsl color properties.
 	Color(ColorProperty),
+	/// Mix the color with another color. This is precomputed when both colors
+	/// are known and uses `color-mix()` otherwise.
+	Mix(ColorMix),
+	/// Update the color using OKLCH color properties. This is precomputed when
+	/// the color is known and uses the relative color syntax otherwise.
+	Oklch(OklchProperty),
+	/// Invert the OKLCH lightness of the color. Useful for dark mode.
+	Invert,
 }
 
 impl<T: Into<String>> From<T> for Transformation {
@@ -169,6 +241,18 @@ impl From<ColorProperty> for Transformation {
 	}
 }
 
+impl From<ColorMix> for Transformation {
+	fn from(value: ColorMix) -> Self {
+		Self::Mix(value)
+	}
+}
+
+impl From<OklchProperty> for Transformation {
+	fn from(value: OklchProperty) -> Self {
+		Self::Oklch(value)
+	}
+}
+
 /// Transformers are used to modify the values of an atom based on the value
 /// sets / colors it receives.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, TypedBuilder)]
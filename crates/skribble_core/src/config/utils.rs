@@ -3,6 +3,7 @@ use derive_more::DerefMut;
 use indexmap::IndexMap;
 use serde::Deserialize;
 use serde::Serialize;
+use skribble_color::ColorScale;
 
 /// This is a more usable version of Index<String, String> which allows for
 /// easier construction and fully supports serde with renaming built in.
@@ -16,6 +17,14 @@ impl<K: Into<String>, V: Into<String>> From<IndexMap<K, V>> for StringMap {
 	}
 }
 
+/// Generate the palette entries of a color scale so that it can be used as
+/// the `palette` of a style config.
+impl From<ColorScale> for StringMap {
+	fn from(value: ColorScale) -> Self {
+		Self::from_iter(value.palette())
+	}
+}
+
 impl IntoIterator for StringMap {
 	type IntoIter = indexmap::map::IntoIter<String, String>;
 	type Item = (String, String);
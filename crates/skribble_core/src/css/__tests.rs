@@ -1,20 +1,25 @@
 use indexmap::indexmap;
 use rstest::rstest;
 use similar_asserts::assert_eq;
+use skribble_color::Gamut;
 use skribble_test::set_snapshot_suffix;
 
 use crate::Alias;
 use crate::AnyEmptyResult;
 use crate::Atom;
+use crate::ClassDiagnostic;
 use crate::ClassFactory;
 use crate::Classes;
+use crate::ContainerQuery;
 use crate::CssVariable;
+use crate::FeatureQuery;
 use crate::Group;
 use crate::Keyframe;
 use crate::LinkedValues;
 use crate::MediaQuery;
 use crate::Modifier;
 use crate::NamedClass;
+use crate::Options;
 use crate::Placeholder;
 use crate::PropertySyntaxValue;
 use crate::SkribbleRunner;
@@ -46,6 +51,13 @@ fn class_order(#[case] a: &str, #[case] z: &str) -> AnyEmptyResult {
 #[case("keyframes", &["animate:$spin", "screen:animate:$spin"])]
 #[case("atom_arguments", &["pt:[1px]", "md:pt:[1vh]"])]
 #[case("references", &["needs-custom:$px", "needs-custom:$10", "$custom"])]
+#[case("reference_modifiers", &["needs-custom:$px", "hover:needs-custom:$px", "md:needs-custom:$10", "$custom-reference"])]
+#[case("alias_references", &["$with-reference", "needs-custom:$2"])]
+#[case("combined_alias", &["$combined", "md:$combined"])]
+#[case("combined_alias_nested", &["$combined-nested", "hover:$combined-nested"])]
+#[case("combined_alias_invalid", &["$combined-invalid"])]
+#[case("container_queries", &["pt:$1", "cq-sm:pt:$2", "cq-md:pt:$4", "md:cq-sm:pt:$4", "sidebar:hover:pt:$8", "cq-sm:needs-custom:$1"])]
+#[case("feature_queries", &["pt:$1", "supports-grid:pt:$2", "supports-grid:supports-has:pt:$4", "md:supports-grid:hover:pt:$1", "supports-has:cq-sm:pt:$8"])]
 #[case("modifier_arguments", &["[padding=1px]", "md:[padding=1vh]", "hover:[--something=red]", "aria-hidden:[--something=red]"])]
 fn css(#[case] id: &str, #[case] names: &[&str]) -> AnyEmptyResult {
 	set_snapshot_suffix!("{id}");
@@ -66,6 +78,148 @@ fn css(#[case] id: &str, #[case] names: &[&str]) -> AnyEmptyResult {
 	Ok(())
 }
 
+#[rstest]
+#[case("pt:[1px]", None)]
+#[case("pt:[calc(100% - 1px)]", None)]
+#[case("pt:[var(--custom)]", None)]
+#[case("pt:[red]", Some("red"))]
+#[case("md:pt:[1px 2px]", Some("1px 2px"))]
+fn argument_syntax(#[case] name: &str, #[case] invalid: Option<&str>) -> AnyEmptyResult {
+	let mut runner = SkribbleRunner::try_new(create_config())?;
+	let config = runner.initialize()?;
+	let mut classes = Classes::default();
+	classes.insert_factory(ClassFactory::from_string(config, name));
+
+	let expected = invalid
+		.map(|argument| {
+			ClassDiagnostic::InvalidArgument {
+				atom: "pt".into(),
+				argument: argument.into(),
+				syntax: "<length-percentage>".into(),
+			}
+		})
+		.into_iter()
+		.collect::<Vec<_>>();
+
+	assert_eq!(classes.len(), usize::from(invalid.is_none()));
+	assert_eq!(
+		classes
+			.get_diagnostics()
+			.iter()
+			.cloned()
+			.collect::<Vec<_>>(),
+		expected
+	);
+
+	Ok(())
+}
+
+#[test]
+fn combined_alias_diagnostics() -> AnyEmptyResult {
+	let mut runner = SkribbleRunner::try_new(create_config())?;
+	let config = runner.initialize()?;
+	let mut classes = Classes::default();
+	classes.insert_factory(ClassFactory::from_string(config, "$combined-invalid"));
+
+	assert!(classes.is_empty());
+	assert_eq!(
+		classes
+			.get_diagnostics()
+			.iter()
+			.cloned()
+			.collect::<Vec<_>>(),
+		vec![ClassDiagnostic::InvalidCombinedMember {
+			alias: "combined-invalid".into(),
+			member: "$yo".into(),
+		}]
+	);
+
+	Ok(())
+}
+
+#[test]
+fn classes_serialize_as_sequence() -> AnyEmptyResult {
+	let mut runner = SkribbleRunner::try_new(create_config())?;
+	let config = runner.initialize()?;
+	let mut classes = Classes::default();
+	classes.insert_factories(vec![
+		ClassFactory::from_string(config, "pt:$0"),
+		ClassFactory::from_string(config, "pt:[red]"),
+	]);
+
+	let value = serde_json::to_value(&classes)?;
+	assert_eq!(classes.get_diagnostics().len(), 1);
+	assert_eq!(value.as_array().map(Vec::len), Some(1));
+
+	Ok(())
+}
+
+#[test]
+fn wide_gamut_css() -> AnyEmptyResult {
+	let mut config = create_config();
+	config.options = Options::builder().wide_gamut(Gamut::DisplayP3).build();
+	config.variables.extend(vec![
+		CssVariable::builder()
+			.name("vivid")
+			.variable("--v")
+			.value("color(display-p3 0 1 0)")
+			.syntax(PropertySyntaxValue::Color)
+			.media_queries(indexmap! {
+			  Placeholder::media_query("dark") => indexmap! { "" => "oklch(70% 0.3 30)", ".muted" => "#cccccc" },
+			})
+			.build(),
+	]);
+
+	let mut runner = SkribbleRunner::try_new(config)?;
+	let runner_config = runner.initialize()?;
+	let mut classes = Classes::default();
+	classes.insert_factories(vec![
+		ClassFactory::from_string(runner_config, "bg:$vivid"),
+		ClassFactory::from_string(runner_config, "bg:$primary"),
+	]);
+	let css = classes.to_skribble_css(runner_config)?;
+	insta::assert_display_snapshot!(css);
+
+	Ok(())
+}
+
+#[test]
+fn css_color_syntax() -> AnyEmptyResult {
+	let mut config = create_config();
+	config.palette = indexmap! {
+		"brand" => "rebeccapurple".to_string(),
+		"dynamic" => format!("oklch(from {} l c calc(h + 30))", Placeholder::wrapped_variable("primary", None)),
+	}
+	.into();
+	config.variables.extend(vec![
+		CssVariable::builder()
+			.name("accent")
+			.variable("--a")
+			.value(format!(
+				"hsl(from {} calc(h + 180) s l)",
+				Placeholder::wrapped_variable("primary", None)
+			))
+			.syntax(PropertySyntaxValue::Color)
+			.media_queries(indexmap! {
+			  Placeholder::media_query("dark") => indexmap! { "" => format!("oklch(from {} 0.8 c h)", Placeholder::wrapped_variable("secondary", None)) },
+			})
+			.build(),
+	]);
+
+	let mut runner = SkribbleRunner::try_new(config)?;
+	let runner_config = runner.initialize()?;
+	let mut classes = Classes::default();
+	classes.insert_factories(vec![
+		ClassFactory::from_string(runner_config, "bg:$brand"),
+		ClassFactory::from_string(runner_config, "bg:$dynamic"),
+		ClassFactory::from_string(runner_config, "bg:$accent"),
+	]);
+	let css = classes.to_skribble_css(runner_config)?;
+	insta::assert_display_snapshot!(css);
+
+	Ok(())
+}
+
 fn create_config() -> StyleConfig {
 	StyleConfig::builder()
 		.keyframes(vec![
@@ -82,6 +236,31 @@ fn create_config() -> StyleConfig {
 				.name("yo")
 				.classes(vec!["pt:$0", "bg:$secondary"])
 				.build(),
+			Alias::builder()
+				.name("with-reference")
+				.classes(vec!["needs-custom:$1", "pt:$1"])
+				.build(),
+			Alias::builder()
+				.name("combined")
+				.classes(vec!["pt:$0", "bg:$secondary", "pt:$2", "animate:$spin"])
+				.combined(true)
+				.build(),
+			Alias::builder()
+				.name("combined-nested")
+				.classes(vec![
+					"pt:$1",
+					"needs-custom:$1",
+					"hover:bg:$primary",
+					"md:pt:$4",
+					"md:hover:pt:$8",
+				])
+				.combined(true)
+				.build(),
+			Alias::builder()
+				.name("combined-invalid")
+				.classes(vec!["pt:$1", "$yo"])
+				.combined(true)
+				.build(),
 		])
 		.atoms(vec![
 			Atom::builder()
@@ -100,6 +279,7 @@ fn create_config() -> StyleConfig {
 			Atom::builder()
 				.name("pt")
 				.values(vec!["spacing"])
+				.syntax(PropertySyntaxValue::LengthPercentage)
 				.styles(indexmap! { "padding-top" => None as Option<String> })
 				.build(),
 			Atom::builder()
@@ -132,6 +312,49 @@ fn create_config() -> StyleConfig {
 				])
 				.build(),
 		])
+		.container_queries(vec![
+			Group::builder()
+				.name("container-breakpoints")
+				.description("The breakpoints for the nearest container.")
+				.items(vec![
+					ContainerQuery::builder()
+						.name("cq-sm")
+						.query("(min-width: 24rem)")
+						.build(),
+					ContainerQuery::builder()
+						.name("cq-md")
+						.query("(min-width: 28rem)")
+						.build(),
+				])
+				.build(),
+			Group::builder()
+				.name("named-containers")
+				.description("Query named containers.")
+				.items(vec![
+					ContainerQuery::builder()
+						.name("sidebar")
+						.container_name("sidebar")
+						.query("(min-width: 20rem)")
+						.build(),
+				])
+				.build(),
+		])
+		.feature_queries(vec![
+			Group::builder()
+				.name("supports")
+				.description("Apply styles when a css feature is supported.")
+				.items(vec![
+					FeatureQuery::builder()
+						.name("supports-grid")
+						.query("(display: grid)")
+						.build(),
+					FeatureQuery::builder()
+						.name("supports-has")
+						.query("selector(:has(*))")
+						.build(),
+				])
+				.build(),
+		])
 		.media_queries(vec![
 			Group::builder()
 				.name("device-categories")
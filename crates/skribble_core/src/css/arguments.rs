@@ -1,15 +1,14 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
-use std::fmt::Write;
 
 use indexmap::IndexSet;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::apply_transformers;
-use crate::AnyEmptyResult;
 use crate::Atom;
 use crate::ClassTransformer;
+use crate::CssDeclarations;
 use crate::Placeholder;
 use crate::RunnerConfig;
 use crate::TransformationRecipient;
@@ -51,14 +50,14 @@ impl Arguments {
 		}
 	}
 
-	pub fn write_css(
+	pub fn collect_css_declarations(
 		&self,
-		writer: &mut dyn Write,
+		declarations: &mut CssDeclarations,
 		config: &RunnerConfig,
 		transformers: &IndexSet<ClassTransformer>,
-	) -> AnyEmptyResult {
+	) {
 		let Arguments::KV(ref key, ref value) = self else {
-			return Ok(());
+			return;
 		};
 
 		let property = Placeholder::normalize(key, config);
@@ -78,20 +77,18 @@ impl Arguments {
 			)
 		};
 
-		writeln!(writer, "{property}: {css_value};")?;
-
-		Ok(())
+		declarations.push((property, css_value));
 	}
 
-	pub fn write_css_atom(
+	pub fn collect_atom_css_declarations(
 		&self,
-		writer: &mut dyn Write,
+		declarations: &mut CssDeclarations,
 		config: &RunnerConfig,
 		atom: &Atom,
 		transformers: &IndexSet<ClassTransformer>,
-	) -> AnyEmptyResult {
+	) {
 		let Arguments::V(ref value) = self else {
-			return Ok(());
+			return;
 		};
 
 		let value = {
@@ -119,10 +116,8 @@ impl Arguments {
 				)
 			};
 
-			writeln!(writer, "{property}: {css_value};")?;
+			declarations.push((property, css_value));
 		}
-
-		Ok(())
 	}
 }
 
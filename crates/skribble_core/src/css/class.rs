@@ -3,6 +3,7 @@ use std::fmt::Write;
 use std::hash::Hash;
 use std::hash::Hasher;
 
+use indexmap::IndexMap;
 use indexmap::IndexSet;
 use serde::Deserialize;
 use serde::Serialize;
@@ -13,9 +14,12 @@ use super::ClassScore;
 use super::ClassTransformer;
 use crate::format_css_string;
 use crate::indent_writer;
+use crate::wrap_css_block;
+use crate::write_css_declarations;
 use crate::AnyEmptyResult;
 use crate::AnyResult;
 use crate::AtomType;
+use crate::CssDeclarations;
 use crate::RunnerConfig;
 use crate::ToSkribbleCss;
 
@@ -30,12 +34,23 @@ pub struct Class {
 	/// The names of the media queries.
 	#[builder(setter(into))]
 	media_queries: IndexSet<String>,
+	/// The names of the feature queries.
+	#[builder(setter(into))]
+	feature_queries: IndexSet<String>,
+	/// The names of the container queries.
+	#[builder(setter(into))]
+	container_queries: IndexSet<String>,
 	/// The ordered list of modifiers.
 	#[builder(setter(into))]
 	modifiers: IndexSet<String>,
 	/// The ordered list of transformers.
 	#[builder(setter(into))]
 	transformers: IndexSet<ClassTransformer>,
+	/// Whether the transformers are written after the value in the class
+	/// name, e.g. `bg:$blue500:(mix=white,20%)`.
+	#[builder(default)]
+	#[serde(default)]
+	postfix_transformers: bool,
 	/// The name of the style provided. This must be provided for the
 	/// `class_name` to be valid.
 	#[builder(setter(into))]
@@ -69,6 +84,10 @@ pub struct Class {
 	/// named_classes)
 	#[builder(setter(into))]
 	parent_class_name: Option<String>,
+	/// The member classes of a combined alias. Their declarations are merged
+	/// into the css rule for this class.
+	#[builder(setter(into))]
+	combined: Vec<Class>,
 }
 
 impl Class {
@@ -106,6 +125,42 @@ impl Class {
 		Some(queries)
 	}
 
+	pub fn get_feature_queries(&self) -> &IndexSet<String> {
+		&self.feature_queries
+	}
+
+	/// Join the feature queries into the condition of a single `@supports`
+	/// rule.
+	pub fn join_feature_query(&self, config: &RunnerConfig) -> Option<String> {
+		if self.feature_queries.is_empty() {
+			return None;
+		}
+
+		let queries = self
+			.feature_queries
+			.iter()
+			.filter_map(|name| config.get_feature_query(name))
+			.map(|feature_query| feature_query.query.clone())
+			.collect::<Vec<String>>()
+			.join(" and ");
+
+		Some(queries)
+	}
+
+	pub fn get_container_queries(&self) -> &IndexSet<String> {
+		&self.container_queries
+	}
+
+	/// Get the conditions of the container queries. Each condition is written
+	/// as a nested `@container` rule.
+	pub fn get_container_conditions(&self, config: &RunnerConfig) -> Vec<String> {
+		self.container_queries
+			.iter()
+			.filter_map(|name| config.get_container_query(name))
+			.map(|container_query| container_query.condition())
+			.collect()
+	}
+
 	pub fn get_modifiers(&self) -> &IndexSet<String> {
 		&self.modifiers
 	}
@@ -138,6 +193,29 @@ impl Class {
 		self.alias.as_ref()
 	}
 
+	pub fn get_combined(&self) -> &Vec<Class> {
+		&self.combined
+	}
+
+	/// Checks whether this class was injected as the child of an atom.
+	pub fn is_child(&self) -> bool {
+		self.parent_class_name.is_some()
+	}
+
+	/// Checks whether both classes are children which inject the same named
+	/// class with the same prefixes. These can share a single css rule.
+	pub fn is_same_child(&self, other: &Self) -> bool {
+		self.is_child()
+			&& other.is_child()
+			&& self.named_class == other.named_class
+			&& self.layer == other.layer
+			&& self.media_queries == other.media_queries
+			&& self.feature_queries == other.feature_queries
+			&& self.container_queries == other.container_queries
+			&& self.modifiers == other.modifiers
+			&& self.transformers == other.transformers
+	}
+
 	pub fn collect_css_variables(&self, css_variables: &mut IndexSet<String>) {
 		css_variables.extend(self.css_variables.iter().cloned());
 	}
@@ -171,12 +249,22 @@ impl Class {
 			tokens.push(media_query.to_string());
 		}
 
+		for feature_query in self.feature_queries.iter() {
+			tokens.push(feature_query.to_string());
+		}
+
+		for container_query in self.container_queries.iter() {
+			tokens.push(container_query.to_string());
+		}
+
 		for modifier in self.modifiers.iter() {
 			tokens.push(modifier.to_string());
 		}
 
-		for transformer in self.transformers.iter() {
-			tokens.push(transformer.to_string());
+		if !self.postfix_transformers {
+			for transformer in self.transformers.iter() {
+				tokens.push(transformer.to_string());
+			}
 		}
 
 		if let Some(ref named_class) = self.named_class {
@@ -200,6 +288,12 @@ impl Class {
 			tokens.push(format!("[{argument}]"));
 		};
 
+		if self.postfix_transformers {
+			for transformer in self.transformers.iter() {
+				tokens.push(transformer.to_string());
+			}
+		}
+
 		write!(writer, "{}", tokens.join(":"))?;
 
 		Ok(())
@@ -207,6 +301,15 @@ impl Class {
 
 	fn write_selector(&self, writer: &mut dyn Write, config: &RunnerConfig) -> AnyEmptyResult {
 		let selector = format!(".{}", format_css_string(self.class_name()?));
+		let selectors = self.apply_modifiers(selector, config);
+
+		write!(writer, "{}", selectors.join(", "))?;
+
+		Ok(())
+	}
+
+	/// Apply all the modifiers of this class to the provided selector.
+	fn apply_modifiers(&self, selector: String, config: &RunnerConfig) -> Vec<String> {
 		let mut selectors = vec![selector];
 		let mut class_modifiers = vec![];
 
@@ -248,21 +351,27 @@ impl Class {
 			selectors = new_selectors;
 		}
 
-		write!(writer, "{}", selectors.join(", "))?;
-
-		Ok(())
+		selectors
 	}
 
-	fn write_css_properties(
-		&self,
-		writer: &mut dyn Write,
-		config: &RunnerConfig,
-	) -> AnyEmptyResult {
+	fn collect_css_declarations(&self, config: &RunnerConfig) -> AnyResult<CssDeclarations> {
+		let mut declarations = CssDeclarations::new();
+
 		if let Some(atom) = self.get_atom().and_then(|atom| config.atoms.get(atom)) {
 			if let Some(value_set_name) = self.get_value_name() {
-				atom.write_css_properties(writer, config, value_set_name, self.get_transformers())?;
+				atom.collect_css_declarations(
+					&mut declarations,
+					config,
+					value_set_name,
+					self.get_transformers(),
+				)?;
 			} else if let Some(argument) = self.get_argument() {
-				atom.write_css_argument(writer, config, argument, self.get_transformers())?;
+				atom.collect_argument_css_declarations(
+					&mut declarations,
+					config,
+					argument,
+					self.get_transformers(),
+				);
 			}
 		}
 
@@ -270,17 +379,113 @@ impl Class {
 			.get_named_class()
 			.and_then(|name| config.classes.get(name))
 		{
-			named_class.write_css_properties(writer, config)?;
+			named_class.collect_css_declarations(&mut declarations, config);
 		}
 
 		if let Some(argument) = self.get_argument() {
-			argument.write_css(writer, config, self.get_transformers())?;
+			argument.collect_css_declarations(&mut declarations, config, self.get_transformers());
+		}
+
+		Ok(declarations)
+	}
+
+	/// Write the merged declarations of the combined alias members. Members
+	/// with media queries or modifiers are written as nested rules.
+	fn write_combined_css(&self, writer: &mut dyn Write, config: &RunnerConfig) -> AnyEmptyResult {
+		let mut media_query_groups = IndexMap::<
+			Option<String>,
+			IndexMap<(Option<String>, Vec<String>, Option<String>), Vec<&Class>>,
+		>::new();
+
+		for member in self.combined.iter() {
+			let selectors = member.apply_modifiers("&".into(), config);
+			let nested_selector = if selectors == ["&"] {
+				None
+			} else {
+				Some(selectors.join(", "))
+			};
+
+			media_query_groups
+				.entry(member.join_media_query(config))
+				.or_default()
+				.entry((
+					member.join_feature_query(config),
+					member.get_container_conditions(config),
+					nested_selector,
+				))
+				.or_default()
+				.push(member);
+		}
+
+		// Declarations without a nested rule must come first.
+		media_query_groups.sort_by(|a, _, z, _| a.is_some().cmp(&z.is_some()));
+
+		for (media_query, groups) in media_query_groups.iter_mut() {
+			groups.sort_by(
+				|(a_feature, a_conditions, a_selector),
+				 _,
+				 (z_feature, z_conditions, z_selector),
+				 _| {
+					a_feature
+						.is_some()
+						.cmp(&z_feature.is_some())
+						.then((!a_conditions.is_empty()).cmp(&!z_conditions.is_empty()))
+						.then(a_selector.is_some().cmp(&z_selector.is_some()))
+				},
+			);
+			let mut content = String::new();
+
+			for ((feature_query, conditions, nested_selector), members) in groups.iter() {
+				let mut declarations = String::new();
+				write_css_declarations(&mut declarations, &merge_declarations(config, members)?)?;
+
+				let mut block = match nested_selector {
+					Some(selector) => wrap_css_block(selector, &declarations)?,
+					None => declarations,
+				};
+
+				for condition in conditions.iter().rev() {
+					block = wrap_css_block(format!("@container {condition}"), &block)?;
+				}
+
+				if let Some(query) = feature_query {
+					block = wrap_css_block(format!("@supports {query}"), &block)?;
+				}
+
+				write!(content, "{}", block)?;
+			}
+
+			match media_query {
+				Some(query) => {
+					write!(
+						writer,
+						"{}",
+						wrap_css_block(format!("@media {query}"), &content)?
+					)?
+				}
+				None => write!(writer, "{}", content)?,
+			}
 		}
 
 		Ok(())
 	}
 }
 
+/// Merge the declarations of the provided classes in order. When a property
+/// is declared more than once, the last declaration wins.
+fn merge_declarations(config: &RunnerConfig, classes: &[&Class]) -> AnyResult<CssDeclarations> {
+	let mut declarations = IndexMap::<String, String>::new();
+
+	for class in classes {
+		for (property, value) in class.collect_css_declarations(config)? {
+			declarations.shift_remove(&property);
+			declarations.insert(property, value);
+		}
+	}
+
+	Ok(declarations.into_iter().collect())
+}
+
 impl ToSkribbleCss for Class {
 	fn write_skribble_css(&self, writer: &mut dyn Write, config: &RunnerConfig) -> AnyEmptyResult {
 		if let Some(css_chunk) = self
@@ -293,9 +498,40 @@ impl ToSkribbleCss for Class {
 		}
 
 		self.write_selector(writer, config)?;
+		self.write_css_block(writer, config)?;
+
+		Ok(())
+	}
+}
+
+impl Class {
+	/// Write the css for all the provided child classes as a single rule. The
+	/// children should all share the same named class.
+	pub(crate) fn write_children_css(
+		writer: &mut dyn Write,
+		config: &RunnerConfig,
+		children: &[&Class],
+	) -> AnyEmptyResult {
+		let Some(first) = children.first() else {
+			return Ok(());
+		};
+
+		let selectors = children
+			.iter()
+			.map(|child| child.selector(config))
+			.collect::<AnyResult<Vec<String>>>()?;
+
+		write!(writer, "{}", selectors.join(", "))?;
+		first.write_css_block(writer, config)?;
+
+		Ok(())
+	}
+
+	fn write_css_block(&self, writer: &mut dyn Write, config: &RunnerConfig) -> AnyEmptyResult {
 		writeln!(writer, " {{")?;
 		let mut indented = indent_writer();
-		self.write_css_properties(&mut indented, config)?;
+		write_css_declarations(&mut indented, &self.collect_css_declarations(config)?)?;
+		self.write_combined_css(&mut indented, config)?;
 		write!(writer, "{}", indented.get_ref())?;
 		writeln!(writer, "}}")?;
 
@@ -312,6 +548,14 @@ impl Hash for Class {
 			mq.hash(state);
 		}
 
+		for fq in self.feature_queries.iter() {
+			fq.hash(state);
+		}
+
+		for cq in self.container_queries.iter() {
+			cq.hash(state);
+		}
+
 		for mq in self.modifiers.iter() {
 			mq.hash(state);
 		}
@@ -326,6 +570,7 @@ impl Hash for Class {
 		self.argument.hash(state);
 		self.atom_type.hash(state);
 		self.parent_class_name.hash(state);
+		self.combined.hash(state);
 	}
 }
 
@@ -1,12 +1,14 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use indexmap::IndexSet;
 use serde::Deserialize;
 use serde::Serialize;
 use typed_builder::TypedBuilder;
 
 use crate::Arguments;
 use crate::RunnerConfig;
+use crate::Transformation;
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub enum TransformerValue {
@@ -75,6 +77,37 @@ impl ClassTransformer {
 			.as_ref()
 			.and_then(|value| value.get_reference_value())
 	}
+
+	/// Collect the color css variables which are mixed in by this transformer.
+	pub fn collect_css_variables(
+		&self,
+		config: &RunnerConfig,
+		css_variables: &mut IndexSet<String>,
+	) {
+		let Some(Transformation::Mix(mix)) = config
+			.get_transformer(&self.name)
+			.map(|transformer| &transformer.transformation)
+		else {
+			return;
+		};
+
+		let value = self
+			.value
+			.as_ref()
+			.and_then(|value| value.get_value(&self.name, config));
+
+		let Some((name, _)) = mix.get_color_and_amount(value.as_deref()) else {
+			return;
+		};
+
+		if config
+			.css_variables
+			.get(name)
+			.map_or(false, |variable| variable.is_color())
+		{
+			css_variables.insert(name.to_string());
+		}
+	}
 }
 
 impl Display for ClassTransformer {
@@ -11,7 +11,9 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::Class;
+use super::ClassDiagnostic;
 use crate::indent_writer;
+use crate::wrap_css_block;
 use crate::AnyEmptyResult;
 use crate::ClassFactory;
 use crate::RunnerConfig;
@@ -19,7 +21,16 @@ use crate::StringMap;
 use crate::ToSkribbleCss;
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Deref, DerefMut)]
-pub struct Classes(IndexSet<Class>);
+#[serde(transparent)]
+pub struct Classes {
+	#[deref]
+	#[deref_mut]
+	classes: IndexSet<Class>,
+	/// The problems found with the class names which couldn't be added. These
+	/// are reported while scanning and aren't serialized.
+	#[serde(skip)]
+	diagnostics: IndexSet<ClassDiagnostic>,
+}
 
 impl Classes {
 	pub fn insert_factory(&mut self, class_factory: ClassFactory) {
@@ -28,17 +39,29 @@ impl Classes {
 
 	pub fn insert_factories(&mut self, class_factories: Vec<ClassFactory>) {
 		for class_factory in class_factories {
-			self.extend(class_factory.into_classes());
+			let (classes, diagnostics) = class_factory.into_classes_and_diagnostics();
+			self.diagnostics.extend(diagnostics);
+			self.extend(classes);
 		}
 
 		self.sort_by_class();
 	}
 
 	pub fn merge(&mut self, other: impl Into<Self>) {
-		self.extend(other.into().0);
+		let other = other.into();
+		self.diagnostics.extend(other.diagnostics);
+		self.extend(other.classes);
 		self.sort_by_class();
 	}
 
+	pub fn insert_diagnostic(&mut self, diagnostic: ClassDiagnostic) {
+		self.diagnostics.insert(diagnostic);
+	}
+
+	pub fn get_diagnostics(&self) -> &IndexSet<ClassDiagnostic> {
+		&self.diagnostics
+	}
+
 	pub fn sort_by_class(&mut self) {
 		self.sort_by(|a, z| a.cmp(z));
 	}
@@ -46,7 +69,12 @@ impl Classes {
 	fn write_keyframes(&self, writer: &mut dyn Write, config: &RunnerConfig) -> AnyEmptyResult {
 		let mut set = indexset! {};
 
-		for class in self.iter() {
+		// The members of combined aliases can also reference keyframes.
+		let classes = self
+			.iter()
+			.flat_map(|class| std::iter::once(class).chain(class.get_combined()));
+
+		for class in classes {
 			let Some(keyframe) = class.get_keyframe() else {
 				continue;
 			};
@@ -195,16 +223,7 @@ impl Classes {
 				return Ordering::Greater;
 			};
 
-			let a_pos = config
-				.get_media_queries()
-				.iter()
-				.position(|query| &query.query == a);
-			let z_pos = config
-				.get_media_queries()
-				.iter()
-				.position(|query| &query.query == z);
-
-			a_pos.cmp(&z_pos)
+			get_media_query_order(a, config).cmp(&get_media_query_order(z, config))
 		});
 
 		write!(writer, "{}", css)?;
@@ -229,9 +248,96 @@ impl Classes {
 		config: &RunnerConfig,
 		classes: &Vec<&Class>,
 	) -> AnyEmptyResult {
+		let mut feature_query_classes = IndexMap::<Option<String>, Vec<&Class>>::new();
+
+		for class in classes {
+			feature_query_classes
+				.entry(class.join_feature_query(config))
+				.or_default()
+				.push(class);
+		}
+
+		// Classes without feature queries are written first.
+		feature_query_classes.sort_by(|a, _, z, _| a.is_some().cmp(&z.is_some()));
+
+		for (feature_query, classes) in feature_query_classes.iter() {
+			let mut content = String::new();
+			self.write_feature_query_css(&mut content, config, classes)?;
+
+			if let Some(query) = feature_query {
+				content = wrap_css_block(format!("@supports {query}"), &content)?;
+			}
+
+			write!(writer, "{}", content)?;
+		}
+
+		Ok(())
+	}
+
+	fn write_feature_query_css(
+		&self,
+		writer: &mut dyn Write,
+		config: &RunnerConfig,
+		classes: &Vec<&Class>,
+	) -> AnyEmptyResult {
+		let mut container_query_classes = IndexMap::<Vec<String>, Vec<&Class>>::new();
+
+		for class in classes {
+			container_query_classes
+				.entry(class.get_container_conditions(config))
+				.or_default()
+				.push(class);
+		}
+
+		// Classes without container queries are written first.
+		container_query_classes.sort_by(|a, _, z, _| (!a.is_empty()).cmp(&!z.is_empty()));
+
+		for (conditions, classes) in container_query_classes.iter() {
+			let mut content = String::new();
+			self.write_container_query_css(&mut content, config, classes)?;
+
+			for condition in conditions.iter().rev() {
+				content = wrap_css_block(format!("@container {condition}"), &content)?;
+			}
+
+			write!(writer, "{}", content)?;
+		}
+
+		Ok(())
+	}
+
+	fn write_container_query_css(
+		&self,
+		writer: &mut dyn Write,
+		config: &RunnerConfig,
+		classes: &Vec<&Class>,
+	) -> AnyEmptyResult {
+		let mut written_children = IndexSet::<&Class>::new();
+
 		for class in classes {
-			class.write_skribble_css(writer, config)?;
+			if !class.is_child() {
+				class.write_skribble_css(writer, config)?;
+				continue;
+			}
+
+			if written_children
+				.iter()
+				.any(|child| child.is_same_child(class))
+			{
+				continue;
+			}
+
+			// Every atom which injects the same child shares a single rule.
+			let children = classes
+				.iter()
+				.filter(|child| child.is_same_child(class))
+				.copied()
+				.collect::<Vec<&Class>>();
+
+			Class::write_children_css(writer, config, &children)?;
+			written_children.insert(class);
 		}
+
 		Ok(())
 	}
 }
@@ -289,13 +395,16 @@ impl ToSkribbleCss for Classes {
 
 impl From<Vec<Class>> for Classes {
 	fn from(classes: Vec<Class>) -> Self {
-		Self(classes.into_iter().collect())
+		classes.into_iter().collect()
 	}
 }
 
 impl From<IndexSet<Class>> for Classes {
 	fn from(classes: IndexSet<Class>) -> Self {
-		Self(classes.into_iter().collect())
+		Self {
+			classes,
+			diagnostics: IndexSet::new(),
+		}
 	}
 }
 
@@ -304,12 +413,36 @@ impl IntoIterator for Classes {
 	type Item = Class;
 
 	fn into_iter(self) -> Self::IntoIter {
-		self.0.into_iter()
+		self.classes.into_iter()
 	}
 }
 
 impl FromIterator<Class> for Classes {
 	fn from_iter<T: IntoIterator<Item = Class>>(iter: T) -> Self {
-		Self(iter.into_iter().collect())
+		Self {
+			classes: iter.into_iter().collect(),
+			diagnostics: IndexSet::new(),
+		}
 	}
 }
+
+/// The order of a media query based on the position of the configured media
+/// queries. Wide gamut overrides are placed directly after the media query
+/// they override.
+fn get_media_query_order(media_query: &str, config: &RunnerConfig) -> (Option<usize>, bool) {
+	let (media_query, is_wide_gamut) = match config
+		.options()
+		.wide_gamut
+		.and_then(|gamut| media_query.strip_suffix(gamut.media_query()))
+	{
+		Some(base) => (base.trim_end_matches(" and "), true),
+		None => (media_query, false),
+	};
+
+	let position = config
+		.get_media_queries()
+		.iter()
+		.position(|query| query.query == media_query);
+
+	(position, is_wide_gamut)
+}
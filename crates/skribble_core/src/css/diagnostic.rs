@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A problem found while creating a class from a class name. The class is not
+/// added to the stylesheet.
+#[non_exhaustive]
+#[derive(thiserror::Error, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ClassDiagnostic {
+	/// The arbitrary value provided to an atom doesn't match the atom syntax.
+	#[error("the argument `{argument}` provided to the atom `{atom}` is not a valid `{syntax}`")]
+	InvalidArgument {
+		atom: String,
+		argument: String,
+		syntax: String,
+	},
+	/// A member of a combined alias which isn't an atom or named class that can
+	/// be merged into a single rule.
+	#[error(
+		"the class `{member}` in the combined alias `{alias}` can't be merged into a single rule"
+	)]
+	InvalidCombinedMember { alias: String, member: String },
+	/// A chain of generated methods which starts from a binding or function
+	/// that couldn't be followed while scanning.
+	#[error(
+		"the class chain starting with `{root}` in `{file}` at {line}:{column} can't be resolved \
+		 statically"
+	)]
+	UnresolvedChain {
+		root: String,
+		file: String,
+		line: usize,
+		column: usize,
+	},
+}
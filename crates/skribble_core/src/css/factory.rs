@@ -3,9 +3,11 @@ use indexmap::IndexSet;
 
 use super::Arguments;
 use super::Class;
+use super::ClassDiagnostic;
 use super::ClassScore;
 use super::ClassTransformer;
 use crate::AtomType;
+use crate::Placeholder;
 use crate::RunnerConfig;
 
 /// Skribble classes represent a css class.
@@ -31,10 +33,17 @@ pub struct ClassFactory<'config> {
 	layer: Option<String>,
 	/// The names of the media queries.
 	media_queries: IndexMap<String, usize>,
+	/// The names of the feature queries.
+	feature_queries: IndexMap<String, usize>,
+	/// The names of the container queries.
+	container_queries: IndexMap<String, usize>,
 	/// The ordered list of modifiers.
 	modifiers: IndexMap<String, usize>,
 	/// The ordered list of modifiers.
 	transformers: IndexMap<ClassTransformer, (usize, usize)>,
+	/// Whether the transformers were written after the value, e.g.
+	/// `bg:$blue500:(mix=white,20%)`.
+	postfix_transformers: bool,
 	/// The name of the shorthand class.
 	named_class: Option<String>,
 	/// The score of this class. This is used to determine the order of the
@@ -46,6 +55,10 @@ pub struct ClassFactory<'config> {
 	value_name: Option<String>,
 	/// The parent selector of this class. This only applies to named classes.
 	parent_class_name: Option<String>,
+	/// The resolved member classes when this is a combined alias.
+	combined: Vec<Class>,
+	/// The problems found while adding tokens to this factory.
+	diagnostics: Vec<ClassDiagnostic>,
 }
 
 impl<'config> ClassFactory<'config> {
@@ -56,6 +69,17 @@ impl<'config> ClassFactory<'config> {
 
 		for token in string.split(':') {
 			if token.starts_with('(') && token.ends_with(')') {
+				let is_postfix = factory.atom.is_some()
+					|| factory.named_class.is_some()
+					|| factory.alias.is_some();
+
+				// The transformers are either all written before or all after the
+				// value so that the class name matches the authored class.
+				if is_postfix && !factory.postfix_transformers && !factory.transformers.is_empty() {
+					factory.valid = Some(false);
+				}
+
+				factory.postfix_transformers = is_postfix;
 				let transformer = ClassTransformer::from(token);
 				factory.add_transformer(&transformer);
 				continue;
@@ -92,13 +116,18 @@ impl<'config> ClassFactory<'config> {
 			atom_type: None,
 			layer: None,
 			media_queries: IndexMap::new(),
+			feature_queries: IndexMap::new(),
+			container_queries: IndexMap::new(),
 			modifiers: IndexMap::new(),
 			transformers: IndexMap::new(),
+			postfix_transformers: false,
 			named_class: None,
 			score: ClassScore::default(),
 			valid: None,
 			value_name: None,
 			parent_class_name: None,
+			combined: vec![],
+			diagnostics: vec![],
 		}
 	}
 }
@@ -112,11 +141,29 @@ impl<'config> ClassFactory<'config> {
 		self.atom.as_ref()
 	}
 
+	pub fn get_diagnostics(&self) -> &Vec<ClassDiagnostic> {
+		&self.diagnostics
+	}
+
 	pub fn add_argument(&mut self, argument: Arguments) -> &Self {
 		match argument {
-			Arguments::V(_) => {
+			Arguments::V(ref value) => {
 				if self.argument.is_some() || self.atom.is_none() || self.named_class.is_some() {
 					self.valid = Some(false);
+				} else if let Some((atom, syntax)) = self
+					.atom
+					.as_ref()
+					.and_then(|name| self.config.atoms.get(name))
+					.and_then(|atom| atom.syntax.as_ref().map(|syntax| (atom, syntax)))
+					.filter(|(_, syntax)| {
+						!syntax.is_valid_value(Placeholder::normalize(value, self.config))
+					}) {
+					self.valid = Some(false);
+					self.diagnostics.push(ClassDiagnostic::InvalidArgument {
+						atom: atom.name.clone(),
+						argument: value.clone(),
+						syntax: syntax.to_string(),
+					});
 				} else {
 					self.score.argument = argument.to_string().into();
 					self.argument = Some(argument);
@@ -156,6 +203,8 @@ impl<'config> ClassFactory<'config> {
 			|| self.atom.is_some()
 			|| self.named_class.is_some()
 			|| !self.media_queries.is_empty()
+			|| !self.feature_queries.is_empty()
+			|| !self.container_queries.is_empty()
 			|| !self.modifiers.is_empty()
 		{
 			self.valid = Some(false);
@@ -189,7 +238,11 @@ impl<'config> ClassFactory<'config> {
 			}
 		}
 		// media_query
-		else if self.add_media_query_token(&token) || self.add_modifier_token(&token) {
+		else if self.add_media_query_token(&token)
+			|| self.add_feature_query_token(&token)
+			|| self.add_container_query_token(&token)
+			|| self.add_modifier_token(&token)
+		{
 			// Prevent further branches being run
 		}
 		// atom.
@@ -203,30 +256,24 @@ impl<'config> ClassFactory<'config> {
 			}
 		}
 		// named_class.
-		else if let Some((index, named_class)) = self
+		else if self.add_named_class_token(&token) {
+			// Prevent further branches being run
+		} else if let Some((index, alias)) = self
 			.config
-			.get_named_class_index(&token)
-			.zip(self.config.classes.get(token.as_ref()))
+			.get_alias_index(&token)
+			.zip(self.config.aliases.get(token.as_ref()))
 		{
-			if self.named_class.is_some() {
-				self.valid = Some(false);
-			} else {
-				self.named_class = Some(token.as_ref().to_string());
-				self.score.named_class = index.checked_add(1).unwrap_or(index);
-
-				if let Some(ref name) = named_class.layer {
-					self.layer = Some(name.clone());
-				};
-
-				self.valid = Some(true);
-			}
-		} else if let Some(index) = self.config.get_alias_index(&token) {
 			if self.alias.is_some() {
 				self.valid = Some(false);
 			} else {
 				self.alias = Some(token.as_ref().to_string());
 				self.score.alias = index.checked_add(1).unwrap_or(index);
 				self.valid = Some(true);
+
+				// Combined aliases are rendered as a single rule in the alias layer.
+				if alias.combined {
+					self.layer = Some("alias".into());
+				}
 			}
 		}
 		// invalid value received.
@@ -237,6 +284,36 @@ impl<'config> ClassFactory<'config> {
 		self
 	}
 
+	/// Add a named class token. Returns `false` when no named class with this
+	/// name exists.
+	fn add_named_class_token(&mut self, token: impl AsRef<str>) -> bool {
+		let Some((index, named_class)) = self
+			.config
+			.get_named_class_index(&token)
+			.zip(self.config.classes.get(token.as_ref()))
+		else {
+			return false;
+		};
+
+		// Reference classes can only be injected as the child of an atom.
+		if self.named_class.is_some()
+			|| (named_class.is_reference() && self.parent_class_name.is_none())
+		{
+			self.valid = Some(false);
+		} else {
+			self.named_class = Some(token.as_ref().to_string());
+			self.score.named_class = index.checked_add(1).unwrap_or(index);
+
+			if let Some(ref name) = named_class.layer {
+				self.layer = Some(name.clone());
+			};
+
+			self.valid = Some(true);
+		}
+
+		true
+	}
+
 	fn add_modifier_token(&mut self, token: impl AsRef<str>) -> bool {
 		if let Some(index) = self.config.get_modifier_index(&token) {
 			if self.modifiers.contains_key(token.as_ref()) {
@@ -304,6 +381,48 @@ impl<'config> ClassFactory<'config> {
 		}
 	}
 
+	fn add_feature_query_token(&mut self, token: impl AsRef<str>) -> bool {
+		if let Some(index) = self.config.get_feature_query_index(&token) {
+			if self.feature_queries.contains_key(token.as_ref()) {
+				self.valid = Some(false);
+			} else {
+				self.feature_queries
+					.insert(token.as_ref().to_string(), index);
+				self.score
+					.feature_queries
+					.push(index.checked_add(1).unwrap_or(index));
+				self.score.feature_queries.sort();
+				self.feature_queries
+					.sort_by(|_, a_index, _, z_index| a_index.cmp(z_index));
+			}
+
+			true
+		} else {
+			false
+		}
+	}
+
+	fn add_container_query_token(&mut self, token: impl AsRef<str>) -> bool {
+		if let Some(index) = self.config.get_container_query_index(&token) {
+			if self.container_queries.contains_key(token.as_ref()) {
+				self.valid = Some(false);
+			} else {
+				self.container_queries
+					.insert(token.as_ref().to_string(), index);
+				self.score
+					.container_queries
+					.push(index.checked_add(1).unwrap_or(index));
+				self.score.container_queries.sort();
+				self.container_queries
+					.sort_by(|_, a_index, _, z_index| a_index.cmp(z_index));
+			}
+
+			true
+		} else {
+			false
+		}
+	}
+
 	/// Create a new class from this factory. It will return none if the class
 	/// is not valid.
 	fn into_class(self) -> Option<Class> {
@@ -313,6 +432,10 @@ impl<'config> ClassFactory<'config> {
 
 		let mut css_variables = IndexSet::new();
 
+		for member in self.combined.iter() {
+			member.collect_css_variables(&mut css_variables);
+		}
+
 		if let Some(atom) = self
 			.atom
 			.as_ref()
@@ -329,8 +452,22 @@ impl<'config> ClassFactory<'config> {
 			named_class.collect_css_variables(&mut css_variables)
 		}
 
+		for transformer in self.transformers.keys() {
+			transformer.collect_css_variables(self.config, &mut css_variables);
+		}
+
 		let media_queries: IndexSet<String> =
 			self.media_queries.into_iter().map(|(key, _)| key).collect();
+		let feature_queries: IndexSet<String> = self
+			.feature_queries
+			.into_iter()
+			.map(|(key, _)| key)
+			.collect();
+		let container_queries: IndexSet<String> = self
+			.container_queries
+			.into_iter()
+			.map(|(key, _)| key)
+			.collect();
 		let modifiers: IndexSet<String> = self.modifiers.into_iter().map(|(key, _)| key).collect();
 		let transformers: IndexSet<ClassTransformer> =
 			self.transformers.into_iter().map(|(key, _)| key).collect();
@@ -344,19 +481,30 @@ impl<'config> ClassFactory<'config> {
 			.atom_type(self.atom_type)
 			.layer(self.layer)
 			.media_queries(media_queries)
+			.feature_queries(feature_queries)
+			.container_queries(container_queries)
 			.modifiers(modifiers)
 			.transformers(transformers)
+			.postfix_transformers(self.postfix_transformers)
 			.named_class(self.named_class)
 			.score(self.score)
 			.value_name(self.value_name)
 			.parent_class_name(self.parent_class_name)
+			.combined(self.combined)
 			.build();
 
 		Some(class)
 	}
 
 	pub fn into_classes(self) -> Vec<Class> {
+		self.into_classes_and_diagnostics().0
+	}
+
+	/// Create the classes along with the problems found in this class name and
+	/// the class names of the alias members.
+	pub fn into_classes_and_diagnostics(self) -> (Vec<Class>, Vec<ClassDiagnostic>) {
 		let mut classes = vec![];
+		let mut diagnostics = self.diagnostics.clone();
 		let config = self.config;
 
 		if let Some(alias) = self
@@ -372,6 +520,14 @@ impl<'config> ClassFactory<'config> {
 						factory.add_media_query_token(token);
 					}
 
+					for token in self.feature_queries.keys() {
+						factory.add_feature_query_token(token);
+					}
+
+					for token in self.container_queries.keys() {
+						factory.add_container_query_token(token);
+					}
+
 					for token in self.modifiers.keys() {
 						factory.add_modifier_token(token);
 					}
@@ -380,51 +536,136 @@ impl<'config> ClassFactory<'config> {
 						factory.add_transformer(transformer);
 					}
 
-					let Some(class) = factory.into_class() else {
-						continue;
+					let (member_classes, member_diagnostics) =
+						factory.into_classes_and_diagnostics();
+					classes.extend(member_classes);
+					diagnostics.extend(member_diagnostics);
+				}
+			} else {
+				let mut factory = self;
+				let mut members = vec![];
+
+				for name in alias.classes.iter() {
+					let member = Self::from_string(config, name);
+					diagnostics.extend(member.diagnostics.iter().cloned());
+
+					// Only atoms and named classes can be merged into a single rule.
+					let member = if member.alias.is_some() || member.css_chunk.is_some() {
+						None
+					} else {
+						member.into_class()
 					};
 
-					classes.push(class);
+					let Some(member) = member else {
+						diagnostics.push(ClassDiagnostic::InvalidCombinedMember {
+							alias: alias.name.clone(),
+							member: name.clone(),
+						});
+
+						return (classes, diagnostics);
+					};
+
+					members.push(member);
+				}
+
+				factory.combined = members;
+
+				let Some(class) = factory.into_class() else {
+					return (classes, diagnostics);
+				};
+
+				for member in class.get_combined() {
+					classes.extend(Self::child_classes(config, &class, Some(member)));
 				}
-			} else if let Some(class) = self.into_class() {
-				// TODO this should only be done if `alias.combine == true`
-				// Currently this is a noop because combining the class will take a lot more
-				// work.
+
 				classes.push(class);
 			}
 		} else if let Some(class) = self.into_class().take() {
-			if let Some((atom, selector)) = class
-				.get_atom()
-				.and_then(|atom_name| config.atoms.get(atom_name))
-				.zip(class.class_name().ok())
-			{
-				for named_class in atom.children.iter() {
-					let mut factory = Self::new(config);
+			classes.extend(Self::child_classes(config, &class, None));
+			classes.push(class);
+		}
 
-					for token in class.get_media_queries() {
-						factory.add_media_query_token(token);
-					}
+		(classes, diagnostics)
+	}
 
-					for token in class.get_modifiers() {
-						factory.add_modifier_token(token);
-					}
+	/// Create the classes for the children of an atom. The children are
+	/// injected into the `parent` selector. When the atom belongs to a
+	/// `member` of a combined alias, the prefixes of both classes are used.
+	fn child_classes(
+		config: &'config RunnerConfig,
+		parent: &Class,
+		member: Option<&Class>,
+	) -> Vec<Class> {
+		let mut classes = vec![];
+		let class = member.unwrap_or(parent);
 
-					for transformer in class.get_transformers() {
-						factory.add_transformer(transformer);
-					}
+		let Some((atom, selector)) = class
+			.get_atom()
+			.and_then(|atom_name| config.atoms.get(atom_name))
+			.zip(parent.class_name().ok())
+		else {
+			return classes;
+		};
 
-					factory.add_token(named_class);
-					factory.parent_class_name = Some(selector.clone());
+		let media_queries = member.map(|member| member.get_media_queries());
+		let feature_queries = member.map(|member| member.get_feature_queries());
+		let container_queries = member.map(|member| member.get_container_queries());
+		let modifiers = member.map(|member| member.get_modifiers());
+		let transformers = member.map(|member| member.get_transformers());
+
+		for named_class in atom.children.iter() {
+			let mut factory = Self::new(config);
+			factory.score.parent_class_name = selector.clone().into();
+			factory.parent_class_name = Some(selector.clone());
+
+			for token in parent
+				.get_media_queries()
+				.iter()
+				.chain(media_queries.into_iter().flatten())
+			{
+				factory.add_media_query_token(token);
+			}
 
-					let Some(child_class) = factory.into_class() else {
-						continue;
-					};
+			for token in parent
+				.get_feature_queries()
+				.iter()
+				.chain(feature_queries.into_iter().flatten())
+			{
+				factory.add_feature_query_token(token);
+			}
 
-					classes.push(child_class);
-				}
+			for token in parent
+				.get_container_queries()
+				.iter()
+				.chain(container_queries.into_iter().flatten())
+			{
+				factory.add_container_query_token(token);
 			}
 
-			classes.push(class);
+			for token in parent
+				.get_modifiers()
+				.iter()
+				.chain(modifiers.into_iter().flatten())
+			{
+				factory.add_modifier_token(token);
+			}
+
+			for transformer in parent
+				.get_transformers()
+				.iter()
+				.chain(transformers.into_iter().flatten())
+			{
+				factory.add_transformer(transformer);
+			}
+
+			// Children are always named classes, even when an atom shares the name.
+			factory.add_named_class_token(named_class);
+
+			let Some(child_class) = factory.into_class() else {
+				continue;
+			};
+
+			classes.push(child_class);
 		}
 
 		classes
@@ -2,6 +2,7 @@ pub use arguments::*;
 pub use class::*;
 pub use class_transformer::*;
 pub use classes::*;
+pub use diagnostic::*;
 pub use factory::*;
 pub use score::*;
 
@@ -9,6 +10,7 @@ mod arguments;
 mod class;
 mod class_transformer;
 mod classes;
+mod diagnostic;
 mod factory;
 mod score;
 
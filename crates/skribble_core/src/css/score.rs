@@ -8,6 +8,8 @@ pub struct ClassScore {
 	pub layer: usize,
 	pub css_chunk: usize,
 	pub media_queries: Vec<usize>,
+	pub feature_queries: Vec<usize>,
+	pub container_queries: Vec<usize>,
 	pub modifiers: Vec<usize>,
 	pub transformers: Vec<(usize, usize)>,
 	pub atom: usize,
@@ -30,6 +32,8 @@ impl Ord for ClassScore {
 			.cmp(&other.layer)
 			.then(self.css_chunk.cmp(&other.css_chunk))
 			.then(self.media_queries.cmp(&other.media_queries))
+			.then(self.feature_queries.cmp(&other.feature_queries))
+			.then(self.container_queries.cmp(&other.container_queries))
 			.then(self.modifiers.cmp(&other.modifiers))
 			.then(self.transformers.cmp(&other.transformers))
 			.then(self.atom.cmp(&other.atom))
@@ -60,6 +60,12 @@ pub enum Error {
 		 `generate()`"
 	)]
 	RunnerNotSetup,
+	#[error(
+		"the environment variable `{0}` is not set, make sure to call this from a `build.rs` file"
+	)]
+	MissingEnvironmentVariable(String),
+	#[error("could not write the cargo instructions of the build script")]
+	BuildInstructionError(#[source] std::io::Error),
 	#[error("color conversion error for color: {0}")]
 	Color(#[from] ColorError),
 	#[error("could not parse inner color")]
@@ -11,6 +11,7 @@ pub use error::*;
 pub use macros::*;
 pub use plugin::*;
 pub use runner::*;
+pub use skribble_color;
 pub use traits::*;
 pub use utils::*;
 pub use vfs;
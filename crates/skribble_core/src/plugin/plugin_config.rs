@@ -8,7 +8,10 @@ pub struct PluginConfig {
 	pub atoms: Atoms,
 	pub aliases: Aliases,
 	pub classes: NamedClasses,
+	pub container_queries: ContainerQueries,
+	pub contrast_pairs: ContrastPairs,
 	pub css_chunks: CssChunks,
+	pub feature_queries: FeatureQueries,
 	pub keyframes: Keyframes,
 	pub layers: Layers,
 	pub media_queries: MediaQueries,
@@ -1 +1,123 @@
+use indexmap::indexmap;
+use vfs::MemoryFS;
+use vfs::VfsPath;
 
+use crate::AnyEmptyResult;
+use crate::ContrastLevel;
+use crate::ContrastPair;
+use crate::CssVariable;
+use crate::Group;
+use crate::MediaQuery;
+use crate::Options;
+use crate::Placeholder;
+use crate::PropertySyntaxValue;
+use crate::SkribbleRunner;
+use crate::StyleConfig;
+
+#[test]
+fn audit_contrast() -> AnyEmptyResult {
+	let config = StyleConfig::builder()
+		.media_queries(vec![
+			Group::builder()
+				.name("color-scheme")
+				.items(vec![
+					MediaQuery::builder()
+						.name("dark")
+						.query("(prefers-color-scheme: dark)")
+						.build(),
+				])
+				.build(),
+		])
+		.palette(indexmap! {
+			"white" => "#ffffff",
+			"black" => "#000000",
+			"gray" => "#999999",
+		})
+		.variables(vec![
+			CssVariable::builder()
+				.name("text")
+				.variable("--text")
+				.syntax(PropertySyntaxValue::Color)
+				.value(Placeholder::palette("black"))
+				.media_queries(indexmap! {
+					Placeholder::media_query("dark") => indexmap! { "" => "#cccccc" },
+				})
+				.build(),
+			CssVariable::builder()
+				.name("surface")
+				.variable("--surface")
+				.syntax(PropertySyntaxValue::Color)
+				.value("#ffffff")
+				.media_queries(indexmap! {
+					Placeholder::media_query("dark") => indexmap! { "" => "#ffffff", ".dim" => "#111111" },
+				})
+				.build(),
+			CssVariable::builder()
+				.name("invalid")
+				.variable("--invalid")
+				.value("inherit")
+				.build(),
+		])
+		.contrast_pairs(vec![
+			ContrastPair::builder()
+				.foreground("text")
+				.background("surface")
+				.build(),
+			ContrastPair::builder()
+				.foreground("gray")
+				.background("white")
+				.level(ContrastLevel::AaLarge)
+				.min_apca(75.0)
+				.build(),
+			ContrastPair::builder()
+				.foreground("missing")
+				.background("invalid")
+				.build(),
+			ContrastPair::builder()
+				.foreground("black")
+				.background("invalid")
+				.build(),
+		])
+		.build();
+	let mut runner = SkribbleRunner::try_new(config)?;
+	let runner_config = runner.initialize()?;
+
+	insta::assert_json_snapshot!(runner_config.audit_contrast());
+
+	Ok(())
+}
+
+#[test]
+fn file_globs_are_relative_to_the_root() -> AnyEmptyResult {
+	let vfs: VfsPath = MemoryFS::new().into();
+
+	for path in [
+		"src/lib.rs",
+		"src/nested/mod.rs",
+		"src/skip/mod.rs",
+		"target/out.rs",
+	] {
+		let file = vfs.join(path)?;
+		file.parent().create_dir_all()?;
+		file.create_file()?;
+	}
+
+	let config = StyleConfig::builder()
+		.options(
+			Options::builder()
+				.files(vec!["src/**/*.rs".into(), "!src/skip/**".into()])
+				.build(),
+		)
+		.build();
+	let runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let mut files = runner
+		.get_files()?
+		.iter()
+		.map(|file| file.as_str().to_string())
+		.collect::<Vec<_>>();
+	files.sort();
+
+	assert_eq!(files, vec!["/src/lib.rs", "/src/nested/mod.rs"]);
+
+	Ok(())
+}
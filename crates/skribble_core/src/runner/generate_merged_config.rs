@@ -9,9 +9,11 @@ use super::RunnerConfig;
 use crate::default_layers;
 use crate::Alias;
 use crate::Atom;
+use crate::ContainerQuery;
 use crate::CssChunk;
 use crate::CssVariable;
 use crate::Error;
+use crate::FeatureQuery;
 use crate::Keyframe;
 use crate::MediaQuery;
 use crate::Modifier;
@@ -34,6 +36,15 @@ pub(crate) fn generate_merged_config(
 	plugin_config
 		.media_queries
 		.extend(config.media_queries.clone());
+	plugin_config
+		.container_queries
+		.extend(config.container_queries.clone());
+	plugin_config
+		.feature_queries
+		.extend(config.feature_queries.clone());
+	plugin_config
+		.contrast_pairs
+		.extend(config.contrast_pairs.clone());
 	plugin_config.modifiers.extend(config.modifiers.clone());
 	plugin_config.value_sets.extend(config.value_sets.clone());
 	plugin_config.atoms.extend(config.atoms.clone());
@@ -50,6 +61,8 @@ pub(crate) fn generate_merged_config(
 	let mut keyframes = IndexMap::<String, Keyframe>::new();
 	let mut layers = indexset! {};
 	let mut media_queries = IndexMap::<String, IndexMap<String, MediaQuery>>::new();
+	let mut container_queries = IndexMap::<String, IndexMap<String, ContainerQuery>>::new();
+	let mut feature_queries = IndexMap::<String, IndexMap<String, FeatureQuery>>::new();
 	let mut modifiers = IndexMap::<String, IndexMap<String, Modifier>>::new();
 	let mut transformers = IndexMap::<String, IndexMap<String, Transformer>>::new();
 	let mut palette = StringMap::default();
@@ -155,6 +168,70 @@ pub(crate) fn generate_merged_config(
 		}
 	}
 
+	// container_queries
+	let mut wrapped_container_queries = plugin_config.container_queries;
+	wrapped_container_queries.sort_by(|a, z| z.priority.cmp(&a.priority));
+
+	for container_query_group in wrapped_container_queries.into_iter() {
+		let group_name = container_query_group.name.clone();
+		let mut group = IndexMap::<String, ContainerQuery>::new();
+
+		for container_query in container_query_group.into_iter() {
+			let key = &container_query.name;
+			match group.get_mut(key) {
+				Some(existing) => {
+					existing.merge(container_query);
+				}
+				None => {
+					group.insert(key.clone(), container_query);
+				}
+			}
+		}
+
+		group.sort_by(|_, a_value, _, z_value| z_value.priority.cmp(&a_value.priority));
+
+		match container_queries.get_mut(&group_name) {
+			Some(existing) => {
+				existing.extend(group);
+			}
+			None => {
+				container_queries.insert(group_name, group);
+			}
+		}
+	}
+
+	// feature_queries
+	let mut wrapped_feature_queries = plugin_config.feature_queries;
+	wrapped_feature_queries.sort_by(|a, z| z.priority.cmp(&a.priority));
+
+	for feature_query_group in wrapped_feature_queries.into_iter() {
+		let group_name = feature_query_group.name.clone();
+		let mut group = IndexMap::<String, FeatureQuery>::new();
+
+		for feature_query in feature_query_group.into_iter() {
+			let key = &feature_query.name;
+			match group.get_mut(key) {
+				Some(existing) => {
+					existing.merge(feature_query);
+				}
+				None => {
+					group.insert(key.clone(), feature_query);
+				}
+			}
+		}
+
+		group.sort_by(|_, a_value, _, z_value| z_value.priority.cmp(&a_value.priority));
+
+		match feature_queries.get_mut(&group_name) {
+			Some(existing) => {
+				existing.extend(group);
+			}
+			None => {
+				feature_queries.insert(group_name, group);
+			}
+		}
+	}
+
 	// modifiers
 	let mut wrapped_modifiers = plugin_config.modifiers;
 	wrapped_modifiers.sort_by(|a, z| z.priority.cmp(&a.priority));
@@ -283,6 +360,14 @@ pub(crate) fn generate_merged_config(
 		.iter()
 		.flat_map(|(_, query)| query.keys().cloned())
 		.collect();
+	let container_query_names = container_queries
+		.iter()
+		.flat_map(|(_, query)| query.keys().cloned())
+		.collect();
+	let feature_query_names = feature_queries
+		.iter()
+		.flat_map(|(_, query)| query.keys().cloned())
+		.collect();
 	let modifier_names = modifiers
 		.iter()
 		.flat_map(|(_, query)| query.keys().cloned())
@@ -299,6 +384,8 @@ pub(crate) fn generate_merged_config(
 	names.insert("css_chunks".into(), css_chunk_names);
 	names.insert("aliases".into(), alias_names);
 	names.insert("media_queries".into(), media_query_names);
+	names.insert("container_queries".into(), container_query_names);
+	names.insert("feature_queries".into(), feature_query_names);
 	names.insert("modifiers".into(), modifier_names);
 	names.insert("transformers".into(), transformer_names);
 
@@ -312,6 +399,9 @@ pub(crate) fn generate_merged_config(
 		.keyframes(keyframes)
 		.layers(layers)
 		.media_queries(media_queries)
+		.container_queries(container_queries)
+		.feature_queries(feature_queries)
+		.contrast_pairs(plugin_config.contrast_pairs)
 		.modifiers(modifiers)
 		.transformers(transformers)
 		.names(names)
@@ -4,19 +4,25 @@ use indexmap::IndexMap;
 use indexmap::IndexSet;
 use serde::Deserialize;
 use serde::Serialize;
+use skribble_color::Color;
 use typed_builder::TypedBuilder;
 
 use crate::Alias;
 use crate::Atom;
 use crate::AtomType;
+use crate::ContainerQuery;
+use crate::ContrastIssue;
+use crate::ContrastPairs;
 use crate::CssChunk;
 use crate::CssVariable;
 use crate::Error;
+use crate::FeatureQuery;
 use crate::Keyframe;
 use crate::MediaQuery;
 use crate::Modifier;
 use crate::NamedClass;
 use crate::Options;
+use crate::Placeholder;
 use crate::Result;
 use crate::StringMap;
 use crate::Transformer;
@@ -35,6 +41,12 @@ pub struct RunnerConfig {
 	pub keyframes: IndexMap<String, Keyframe>,
 	pub layers: IndexSet<String>,
 	pub media_queries: IndexMap<String, IndexMap<String, MediaQuery>>,
+	#[builder(default)]
+	pub container_queries: IndexMap<String, IndexMap<String, ContainerQuery>>,
+	#[builder(default)]
+	pub feature_queries: IndexMap<String, IndexMap<String, FeatureQuery>>,
+	#[builder(default)]
+	pub contrast_pairs: ContrastPairs,
 	pub modifiers: IndexMap<String, IndexMap<String, Modifier>>,
 	pub transformers: IndexMap<String, IndexMap<String, Transformer>>,
 	#[builder(default)]
@@ -71,6 +83,44 @@ impl RunnerConfig {
 			.and_then(|map| map.get_index_of(value_name.as_ref()))
 	}
 
+	pub fn get_container_queries(&self) -> Vec<&ContainerQuery> {
+		self.container_queries
+			.values()
+			.flat_map(|map| map.values())
+			.collect()
+	}
+
+	pub fn get_container_query(&self, name: impl AsRef<str>) -> Option<&ContainerQuery> {
+		self.get_container_queries()
+			.into_iter()
+			.find(|&container_query| container_query.name == name.as_ref())
+	}
+
+	pub fn get_container_query_index(&self, name: impl AsRef<str>) -> Option<usize> {
+		self.names
+			.get("container_queries")
+			.and_then(|map| map.get_index_of(name.as_ref()))
+	}
+
+	pub fn get_feature_queries(&self) -> Vec<&FeatureQuery> {
+		self.feature_queries
+			.values()
+			.flat_map(|map| map.values())
+			.collect()
+	}
+
+	pub fn get_feature_query(&self, name: impl AsRef<str>) -> Option<&FeatureQuery> {
+		self.get_feature_queries()
+			.into_iter()
+			.find(|&feature_query| feature_query.name == name.as_ref())
+	}
+
+	pub fn get_feature_query_index(&self, name: impl AsRef<str>) -> Option<usize> {
+		self.names
+			.get("feature_queries")
+			.and_then(|map| map.get_index_of(name.as_ref()))
+	}
+
 	pub fn get_css_chunk_index(&self, name: impl AsRef<str>) -> Option<usize> {
 		self.names
 			.get("css_chunks")
@@ -158,6 +208,24 @@ impl RunnerConfig {
 			.unwrap_or(false)
 	}
 
+	pub fn has_container_query(&self, name: impl AsRef<str>) -> bool {
+		let name = name.as_ref().to_string();
+		self.names
+			.get("container_queries")
+			.as_ref()
+			.map(|map| map.contains(&name))
+			.unwrap_or(false)
+	}
+
+	pub fn has_feature_query(&self, name: impl AsRef<str>) -> bool {
+		let name = name.as_ref().to_string();
+		self.names
+			.get("feature_queries")
+			.as_ref()
+			.map(|map| map.contains(&name))
+			.unwrap_or(false)
+	}
+
 	pub fn has_css_variable(&self, name: impl AsRef<str>) -> bool {
 		let name = name.as_ref().to_string();
 		self.names
@@ -194,6 +262,30 @@ impl RunnerConfig {
 			.unwrap_or(false)
 	}
 
+	/// Audit the contrast of all the configured [`ContrastPairs`] and return
+	/// the failing combinations.
+	pub fn audit_contrast(&self) -> Vec<ContrastIssue> {
+		self.contrast_pairs
+			.iter()
+			.flat_map(|pair| pair.audit(self))
+			.collect()
+	}
+
+	/// Parse a css color after normalizing the placeholders. References to
+	/// the configured css variables, e.g. `oklch(from var(--sk-p) l c h)`, are
+	/// resolved with the default value of the variable.
+	pub fn parse_color(&self, value: impl AsRef<str>) -> Result<Color> {
+		let value = Placeholder::normalize(value, self);
+		let resolve = |name: &str| {
+			self.css_variables
+				.values()
+				.find(|variable| variable.get_variable(self.options()) == name)
+				.map(|variable| Placeholder::normalize(&variable.value, self))
+		};
+
+		Color::parse_with(value, resolve).map_err(Error::from)
+	}
+
 	/// Load the options
 	pub fn options(&self) -> &Options {
 		&self._options
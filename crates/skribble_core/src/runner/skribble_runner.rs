@@ -28,7 +28,7 @@ use crate::WrappedPlugin;
 pub struct SkribbleRunner {
 	options: Arc<Options>,
 	base_config: Arc<PluginConfig>,
-	plugins: Arc<Mutex<Vec<WrappedPlugin>>>,
+	plugins: Mutex<Vec<WrappedPlugin>>,
 	config: Option<RunnerConfig>,
 	fs: Arc<VfsPath>,
 }
@@ -51,7 +51,7 @@ impl SkribbleRunner {
 
 		// Extract the plugins from the config and sort them by priority.
 		plugins.sort_by_priority();
-		let plugins = Arc::new(Mutex::new(plugins.extract_plugins()));
+		let plugins = Mutex::new(plugins.extract_plugins());
 		let vfs = vfs.unwrap_or_else(|| PhysicalFS::new(cwd).into());
 		let fs = Arc::new(vfs);
 
@@ -136,13 +136,49 @@ impl SkribbleRunner {
 		Ok(generated_files)
 	}
 
+	/// Scan the files and generate the css. Use
+	/// [`SkribbleRunner::scan_classes`] to access the diagnostics of the class
+	/// names which couldn't be used.
 	pub fn scan(&self) -> Result<ToCssResult> {
 		let Some(ref config) = self.config else {
 			return Err(Error::RunnerNotSetup);
 		};
 
-		let entries =
-			walk_directory(self.fs.as_ref(), &self.options.files).map_err(Error::FileScanError)?;
+		let classes = self.scan_classes()?;
+		let css = classes
+			.to_skribble_css(config)
+			.map_err(Error::GenerateCssError)?;
+		println!("{}", css);
+		transform_css(&css, self.options.minify)
+	}
+
+	/// Generate the css of the scanned classes.
+	pub fn generate_css(&self, classes: &Classes) -> Result<ToCssResult> {
+		let Some(ref config) = self.config else {
+			return Err(Error::RunnerNotSetup);
+		};
+
+		let css = classes
+			.to_skribble_css(config)
+			.map_err(Error::GenerateCssError)?;
+		transform_css(&css, self.options.minify)
+	}
+
+	/// The files which match the [`Options::files`] globs and are scanned by
+	/// the plugins.
+	pub fn get_files(&self) -> Result<Vec<VfsPath>> {
+		walk_directory(self.fs.as_ref(), &self.options.files).map_err(Error::FileScanError)
+	}
+
+	/// Scan the files with the plugins and collect the classes. Class names
+	/// which couldn't be used are available from
+	/// [`Classes::get_diagnostics`].
+	pub fn scan_classes(&self) -> Result<Classes> {
+		let Some(ref config) = self.config else {
+			return Err(Error::RunnerNotSetup);
+		};
+
+		let entries = self.get_files()?;
 		let mut plugins = self.plugins.lock().unwrap();
 		let mut classes = Classes::default();
 
@@ -176,11 +212,7 @@ impl SkribbleRunner {
 			}
 		}
 
-		let css = classes
-			.to_skribble_css(config)
-			.map_err(Error::GenerateCssError)?;
-		println!("{}", css);
-		transform_css(&css, self.options.minify)
+		Ok(classes)
 	}
 
 	fn generate_plugin_config(&self) -> Result<PluginConfig> {
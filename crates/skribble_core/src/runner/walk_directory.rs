@@ -11,7 +11,8 @@ pub(crate) fn walk_directory(fs: &VfsPath, glob_rules: &Vec<String>) -> AnyResul
 		.walk_dir()?
 		.filter_map(|entry| entry.ok())
 		.filter(|entry| entry.is_file().unwrap_or(false))
-		.filter(|entry| glob_set_pair.is_match(entry.as_str()))
+		// The globs are relative to the root of the file system.
+		.filter(|entry| glob_set_pair.is_match(entry.as_str().trim_start_matches('/')))
 		.collect::<Vec<_>>();
 
 	Ok(entries)
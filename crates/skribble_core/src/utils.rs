@@ -1,12 +1,18 @@
+use std::fmt::Write;
+
 use indent_write::fmt::IndentWriter;
 use regex::Regex;
+use skribble_color::Color;
+use skribble_color::ColorError;
 use typed_builder::TypedBuilder;
 
 use crate::constants::INDENTATION;
+use crate::AnyEmptyResult;
+use crate::AnyResult;
 
 const ESCAPE_CHARS: &[char] = &[
 	'#', '&', '~', '=', '>', '\'', ':', '"', '!', ';', ',', '.', '*', '+', '\\', ' ', '[', ']',
-	'(', ')', '/', '^', '$', '|',
+	'(', ')', '/', '^', '$', '|', '%',
 ];
 
 /// Format the provided string to be a valid string.
@@ -98,10 +104,49 @@ pub fn wrap_indent(content: impl AsRef<str>, level: u8) -> String {
 	result
 }
 
+/// The css declarations of a rule as `(property, value)` pairs in the order
+/// they are written.
+pub type CssDeclarations = Vec<(String, String)>;
+
+/// Write every declaration on its own line.
+pub fn write_css_declarations(
+	writer: &mut dyn Write,
+	declarations: &CssDeclarations,
+) -> AnyEmptyResult {
+	for (property, value) in declarations.iter() {
+		writeln!(writer, "{property}: {value};")?;
+	}
+
+	Ok(())
+}
+
 pub fn indent_writer<'i>() -> IndentWriter<'i, String> {
 	IndentWriter::new(INDENTATION, String::new())
 }
 
+/// Wrap the provided css content in a block with the provided selector or
+/// at-rule.
+pub fn wrap_css_block(selector: impl AsRef<str>, content: impl AsRef<str>) -> AnyResult<String> {
+	let mut indented = indent_writer();
+	write!(indented, "{}", content.as_ref())?;
+
+	Ok(format!(
+		"{} {{\n{}}}\n",
+		selector.as_ref(),
+		indented.get_ref()
+	))
+}
+
+/// Whether the css color depends on the value of a css variable, e.g.
+/// `oklch(from var(--sk-primary) l c h)`, and can only be resolved by the
+/// browser.
+pub fn is_dynamic_color(value: impl AsRef<str>) -> bool {
+	matches!(
+		value.as_ref().parse::<Color>(),
+		Err(ColorError::UnresolvedVariable)
+	)
+}
+
 pub fn wrap_css_variable(value: impl AsRef<str>, default: Option<String>) -> String {
 	let value = value.as_ref();
 
@@ -0,0 +1,119 @@
+#![deny(clippy::all)]
+#![forbid(clippy::indexing_slicing)]
+
+doc_comment::doctest!("../readme.md");
+
+use std::env;
+use std::path::PathBuf;
+
+use lazy_static::lazy_static;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use skribble_core::PluginContainer;
+use skribble_core::RunnerConfig;
+use skribble_core::SkribbleRunner;
+use skribble_core::StyleConfig;
+use skribble_preset::PresetPlugin;
+use skribble_rust::expand_sk_macro;
+use skribble_rust::CONFIG_CACHE_PATH;
+
+/// The environment variable which overrides the path of the resolved
+/// configuration.
+const CONFIG_ENV: &str = "SKRIBBLE_CONFIG";
+
+lazy_static! {
+	static ref CONFIG: Result<LoadedConfig, String> = load_config();
+}
+
+/// Create a class name which is validated against the `skribble`
+/// configuration at compile time.
+///
+/// ```rust
+/// use skribble_macros::sk;
+///
+/// const CLASS: &str = sk!(md hover p $2);
+/// assert_eq!(CLASS, "md:hover:p:$2");
+/// assert_eq!(sk!("md:p:$2", "bg:$red100"), "md:p:$2 bg:$red100");
+/// ```
+///
+/// The resolved configuration is read from `./cache/skribble_config.json`
+/// which is generated by the `RustPlugin`. Set the `SKRIBBLE_CONFIG`
+/// environment variable to the `config_cache_path` of the `RustPlugin` when it
+/// has been changed. The default preset is used
+/// when no configuration has been generated.
+#[proc_macro]
+pub fn sk(input: TokenStream) -> TokenStream {
+	let loaded = match CONFIG.as_ref() {
+		Ok(loaded) => loaded,
+		Err(message) => {
+			return syn::Error::new(Span::call_site(), message)
+				.to_compile_error()
+				.into();
+		}
+	};
+
+	let class_names = match expand_sk_macro(&loaded.config, input.into()) {
+		Ok(class_names) => class_names,
+		Err(error) => return error.to_compile_error().into(),
+	};
+
+	// Including the configuration recompiles the macro call when it changes.
+	let expanded = match loaded.path {
+		Some(ref path) => {
+			let path = path.display().to_string();
+			quote! {{
+				const _: &[u8] = include_bytes!(#path);
+				#class_names
+			}}
+		}
+		None => quote!(#class_names),
+	};
+
+	expanded.into()
+}
+
+struct LoadedConfig {
+	config: RunnerConfig,
+	/// The path of the configuration file when it was loaded from disk.
+	path: Option<PathBuf>,
+}
+
+fn load_config() -> Result<LoadedConfig, String> {
+	let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+	let path = match env::var(CONFIG_ENV) {
+		Ok(path) => root.join(path),
+		Err(_) => root.join(CONFIG_CACHE_PATH),
+	};
+
+	if path.is_file() {
+		let content = std::fs::read_to_string(&path).map_err(|error| {
+			format!(
+				"could not read the skribble config `{}`: {error}",
+				path.display()
+			)
+		})?;
+		let config = serde_json::from_str::<RunnerConfig>(&content).map_err(|error| {
+			format!(
+				"the skribble config `{}` is invalid: {error}",
+				path.display()
+			)
+		})?;
+
+		return Ok(LoadedConfig {
+			config,
+			path: Some(path),
+		});
+	}
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![PluginContainer::from(PresetPlugin::default())])
+		.build();
+	let mut runner = SkribbleRunner::try_new(config).map_err(|error| error.to_string())?;
+	let config = runner
+		.initialize()
+		.map_err(|error| error.to_string())?
+		.clone();
+
+	Ok(LoadedConfig { config, path: None })
+}
@@ -0,0 +1,30 @@
+use rstest::rstest;
+use skribble_macros::sk;
+
+#[rstest]
+#[case::breakpoint_padding(sk!(md p $px), "md:p:$px")]
+#[case::chained_modifiers(sk!(md hover p $2), "md:hover:p:$2")]
+#[case::string(sk!("md:hover:p:$2"), "md:hover:p:$2")]
+#[case::hyphenated_names(sk!(space-x $px), "space-x:$px")]
+#[case::negative_value(sk!(mt $-4), "mt:$-4")]
+#[case::argument(sk!(md pt [1px]), "md:pt:[1px]")]
+#[case::string_argument(sk!(border ["1px solid red"]), "border:[1px solid red]")]
+#[case::spaced_argument(sk!(w [calc(100% - 1rem)]), "w:[calc(100% - 1rem)]")]
+#[case::ratio_argument(sk!(aspect [2/1]), "aspect:[2/1]")]
+#[case::key_value_argument(sk!(md [padding=1px]), "md:[padding=1px]")]
+#[case::transformer(sk!(md (darken==050) bg $red100), "md:(darken==050):bg:$red100")]
+#[case::transformer_args(sk!((mix=white,20%) bg $blue500), "(mix=white,20%):bg:$blue500")]
+#[case::spaced_transformer_args(sk!((mix = white, 20%) bg $blue500), "(mix=white,20%):bg:$blue500")]
+#[case::important_named_class(sk!((important) $sr-only), "(important):$sr-only")]
+#[case::alias(sk!(md block), "md:display:$block")]
+#[case::multiple(sk!(bg $red100, md p $2), "bg:$red100 md:p:$2")]
+#[case::multiple_strings(sk!("bg:$red100 md:p:$2"), "bg:$red100 md:p:$2")]
+fn sk_macro_class_names(#[case] input: &str, #[case] expected: &str) {
+	assert_eq!(input, expected);
+}
+
+#[test]
+fn sk_macro_is_const() {
+	const CLASS: &str = sk!(dark p $px);
+	assert_eq!(CLASS, "dark:p:$px");
+}
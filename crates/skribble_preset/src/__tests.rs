@@ -29,9 +29,15 @@ fn default_can_be_added_to_runner() -> AnyEmptyResult {
 #[case("important", &["(important):font-size:$xs", "(important):font-size:$8xl"])]
 #[case("darken", &["(darken==005):bg:$red100", "(darken=5%):bg:$pink900", "(darken==050):bg:$primary", "(darken=50%):bg:$secondary"])]
 #[case("lighten", &["(lighten==005):bg:$red100", "(lighten=5%):bg:$pink900", "(lighten==050):bg:$primary", "(lighten=50%):bg:$secondary"])]
+#[case("mix", &["bg:$blue500:(mix=white,20%)", "(alpha=0.5):bg:$blue500:(mix=white,20%)", "(mix=#ffffff,20%):bg:$blue500", "(mix=$secondary,50%):bg:$primary", "(tint==020):bg:$red500", "(shade==050):text:$primary"])]
+#[case("oklch", &["(oklch-lighten==010):bg:$red500", "(oklch-darken=5%):bg:$primary", "(oklch-desaturate=0.05):bg:$pink900", "(oklch-invert):bg:$red100", "(oklch-invert):text:$primary"])]
 #[case("alpha", &["(alpha==005):bg:$red100", "(alpha=0.05):bg:$pink900", "(alpha==050):bg:$primary", "(alpha=0.5):bg:$secondary"])]
 #[case("scale", &["scale:$50", "$transform-gpu"])]
 #[case("divide", &["divide-x:$2", "divide-y:$2"])]
+#[case("filter", &["blur:$sm", "brightness:$50", "md:blur:$lg", "$filter"])]
+#[case("arguments", &["p:[10px]", "p:[red]", "m:[auto]", "mt:[none]"])]
+#[case("feature_queries", &["supports-grid:$grid", "supports-backdrop:backdrop-blur:$md", "md:supports-grid:cq-md:gap:$4", "supports-grid:supports-subgrid:grid-cols:[subgrid]"])]
+#[case("container_queries", &["container:$inline-size", "container-name:[card]", "cq-md:p:$4", "md:cq-sm:p:$2"])]
 fn css_from_class_names(#[case] id: &str, #[case] names: &[&str]) -> AnyEmptyResult {
 	let plugin = PresetPlugin::default();
 	let config: StyleConfig = StyleConfig::builder()
@@ -53,6 +59,20 @@ fn css_from_class_names(#[case] id: &str, #[case] names: &[&str]) -> AnyEmptyRes
 	Ok(())
 }
 
+#[test]
+fn default_contrast_audit() -> AnyEmptyResult {
+	let plugin = PresetPlugin::default();
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![PluginContainer::from(plugin)])
+		.build();
+	let mut runner = SkribbleRunner::try_new(config)?;
+	let runner_config = runner.initialize()?;
+
+	insta::assert_json_snapshot!(runner_config.audit_contrast());
+
+	Ok(())
+}
+
 #[test]
 fn auto_generate_reset_css() -> AnyEmptyResult {
 	let plugin = PresetPlugin::builder().reset("tailwindCompat").build();
@@ -69,6 +89,128 @@ fn auto_generate_reset_css() -> AnyEmptyResult {
 	Ok(())
 }
 
+#[test]
+fn can_import_tailwind_config() -> AnyEmptyResult {
+	let imported = TailwindImport::from_json(TAILWIND_CONFIG)?;
+	insta::assert_json_snapshot!(imported);
+
+	Ok(())
+}
+
+#[test]
+fn tailwind_config_reports_unsupported_keys() -> AnyEmptyResult {
+	let imported = TailwindImport::from_json(TAILWIND_CONFIG)?;
+
+	similar_asserts::assert_eq!(
+		imported.unsupported,
+		vec![
+			"prefix",
+			"plugins",
+			"theme.boxShadow",
+			"theme.extend.colors.gradient"
+		]
+	);
+
+	Ok(())
+}
+
+#[test]
+fn tailwind_theme_replaces_preset_values() -> AnyEmptyResult {
+	let imported = TailwindImport::from_json(indoc::indoc! {r##"
+		{
+			"theme": {
+				"colors": { "brand": "#3b82f6" },
+				"spacing": { "1": "4px" },
+				"keyframes": {},
+				"extend": {
+					"opacity": { "15": "0.15" }
+				}
+			}
+		}
+	"##})?;
+	let mut runner = SkribbleRunner::try_new(imported.config)?;
+	let runner_config = runner.initialize()?;
+	let values = |name: &str| {
+		runner_config
+			.value_sets
+			.get(name)
+			.map(|value_set| value_set.values.keys().cloned().collect::<Vec<_>>())
+			.unwrap_or_default()
+	};
+
+	assert_eq!(
+		runner_config.palette.keys().collect::<Vec<_>>(),
+		vec!["brand"]
+	);
+	assert_eq!(values("spacing"), vec!["1"]);
+	assert_eq!(values("negative-spacing"), vec!["-1"]);
+	assert!(values("opacity").contains(&"0".to_string()));
+	assert!(values("opacity").contains(&"15".to_string()));
+	assert_eq!(imported.unsupported, vec!["theme.keyframes"]);
+
+	Ok(())
+}
+
+#[test]
+fn css_from_tailwind_config() -> AnyEmptyResult {
+	let imported = TailwindImport::from_json(TAILWIND_CONFIG)?;
+	let mut runner = SkribbleRunner::try_new(imported.config)?;
+	let runner_config = runner.initialize()?;
+	let mut classes = Classes::default();
+
+	for name in [
+		"bg:$brand",
+		"text:$brand700",
+		"p:$18",
+		"mt:$-18",
+		"tablet:p:$4",
+		"blur:$xs",
+		"font-size:$huge",
+		"$animate-wiggle",
+	] {
+		classes.insert_factory(ClassFactory::from_string(runner_config, name));
+	}
+
+	classes.sort_by_class();
+	insta::assert_display_snapshot!(classes.to_skribble_css(runner_config)?);
+
+	Ok(())
+}
+
+#[rstest]
+#[case::media(r#""media""#, DarkMode::Media, false)]
+#[case::class(r#""class""#, DarkMode::Class, false)]
+#[case::selector(r#"["selector", ".dark"]"#, DarkMode::Class, false)]
+#[case::custom_selector(r#"["class", "[data-mode='dark']"]"#, DarkMode::Class, true)]
+#[case::invalid(r#"true"#, DarkMode::Media, true)]
+fn tailwind_dark_mode(
+	#[case] dark_mode: &str,
+	#[case] expected: DarkMode,
+	#[case] unsupported: bool,
+) -> AnyEmptyResult {
+	let imported = TailwindImport::from_json(format!(r#"{{ "darkMode": {dark_mode} }}"#))?;
+
+	assert_eq!(imported.dark_mode, expected);
+	assert_eq!(
+		imported.unsupported.contains(&"darkMode".into()),
+		unsupported
+	);
+
+	Ok(())
+}
+
+#[test]
+fn invalid_tailwind_config() {
+	assert!(matches!(
+		TailwindImport::from_json("[]"),
+		Err(TailwindError::InvalidRoot)
+	));
+	assert!(matches!(
+		TailwindImport::from_json("{"),
+		Err(TailwindError::InvalidJson(_))
+	));
+}
+
 fn create_memory_fs() -> AnyResult<VfsPath> {
 	let vfs: VfsPath = MemoryFS::new().into();
 
@@ -83,3 +225,55 @@ fn create_memory_fs() -> AnyResult<VfsPath> {
 }
 
 const FILES: &[(&str, &str)] = &[];
+
+const TAILWIND_CONFIG: &str = indoc::indoc! {r##"
+	{
+		"content": ["./src/**/*.rs"],
+		"darkMode": "class",
+		"prefix": "tw-",
+		"plugins": ["@tailwindcss/forms"],
+		"theme": {
+			"screens": {
+				"tablet": "640px",
+				"laptop": { "min": "1024px", "max": "1279px" },
+				"print": { "raw": "print" }
+			},
+			"boxShadow": {
+				"sm": "0 1px 2px 0 rgb(0 0 0 / 0.05)"
+			},
+			"extend": {
+				"colors": {
+					"transparent": "transparent",
+					"brand": {
+						"DEFAULT": "#3b82f6",
+						"700": "rgb(29 78 216 / <alpha-value>)"
+					},
+					"gradient": ["#fff", "#000"]
+				},
+				"spacing": {
+					"18": "4.5rem",
+					"gutter": "var(--gutter)"
+				},
+				"blur": {
+					"xs": "2px"
+				},
+				"fontSize": {
+					"huge": ["5rem", { "lineHeight": "1" }],
+					"tiny": "0.625rem"
+				},
+				"fontFamily": {
+					"display": ["Oswald", "sans-serif"]
+				},
+				"keyframes": {
+					"wiggle": {
+						"0%, 100%": { "transform": "rotate(-3deg)" },
+						"50%": { "transform": "rotate(3deg)", "animationTimingFunction": "ease-in" }
+					}
+				},
+				"animation": {
+					"wiggle": "wiggle 1s ease-in-out infinite"
+				}
+			}
+		}
+	}
+"##};
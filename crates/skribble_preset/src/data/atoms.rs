@@ -6,6 +6,7 @@ use skribble_core::LinkedValues;
 use skribble_core::NamedColorField;
 use skribble_core::OptionalStringMap;
 use skribble_core::Placeholder;
+use skribble_core::PropertySyntaxValue;
 
 lazy_static! {
   pub(crate) static ref ATOMS: Vec<Atom> = {
@@ -299,131 +300,157 @@ lazy_static! {
 	  Atom::builder()
 		.name("p")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding" => none })
 		.build(),
 	  Atom::builder()
 		.name("py")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-top" => none, "padding-bottom" => none })
 		.build(),
 	  Atom::builder()
 		.name("px")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-right" => none, "padding-left" => none })
 		.build(),
 	  Atom::builder()
 		.name("p-block")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-block" => none })
 		.build(),
 	  Atom::builder()
 		.name("pbs")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-block-start" => none })
 		.build(),
 	  Atom::builder()
 		.name("pbe")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-block-end" => none })
 		.build(),
 	  Atom::builder()
 		.name("p-inline")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-inline" => none })
 		.build(),
 	  Atom::builder()
 		.name("ps")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-inline-start" => none })
 		.build(),
 	  Atom::builder()
 		.name("pe")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-inline-end" => none })
 		.build(),
 	  Atom::builder()
 		.name("pt")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-top" => none })
 		.build(),
 	  Atom::builder()
 		.name("pr")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-right" => none })
 		.build(),
 	  Atom::builder()
 		.name("pb")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-bottom" => none })
 		.build(),
 	  Atom::builder()
 		.name("pl")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(PropertySyntaxValue::LengthPercentage)
 		.styles(indexmap! { "padding-left" => none })
 		.build(),
 	  Atom::builder()
 		.name("m")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin" => none })
 		.build(),
 	  Atom::builder()
 		.name("my")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-top" => none, "margin-bottom" => none })
 		.build(),
 	  Atom::builder()
 		.name("mx")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-right" => none, "margin-left" => none })
 		.build(),
 	  Atom::builder()
 		.name("m-block")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-block" => none })
 		.build(),
 	  Atom::builder()
 		.name("mbs")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-block-start" => none })
 		.build(),
 	  Atom::builder()
 		.name("mbe")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-block-end" => none })
 		.build(),
 	  Atom::builder()
 		.name("m-inline")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-inline" => none })
 		.build(),
 	  Atom::builder()
 		.name("ms")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-inline-start" => none })
 		.build(),
 	  Atom::builder()
 		.name("me")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-inline-end" => none })
 		.build(),
 	  Atom::builder()
 		.name("mt")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-top" => none })
 		.build(),
 	  Atom::builder()
 		.name("mr")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-right" => none })
 		.build(),
 	  Atom::builder()
 		.name("mb")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-bottom" => none })
 		.build(),
 	  Atom::builder()
 		.name("ml")
 		.values(vec!["spacing", "negative-spacing"])
+		.syntax(vec![PropertySyntaxValue::LengthPercentage, "auto".into()])
 		.styles(indexmap! { "margin-left" => none })
 		.build(),
 	  Atom::builder()
@@ -560,6 +587,19 @@ lazy_static! {
 		.values(vec!["overflow"])
 		.styles(indexmap! { "overflow-y" => none })
 		.build(),
+	  Atom::builder()
+		.name("container")
+		.description("Establish an element as a query container for container queries.")
+		.values(vec!["container-type"])
+		.styles(indexmap! { "container-type" => none })
+		.build(),
+	  Atom::builder()
+		.name("container-name")
+		.description("Name a query container so that container queries can target it.")
+		.values(vec!["none"])
+		.syntax(PropertySyntaxValue::CustomIdent)
+		.styles(indexmap! { "container-name" => none })
+		.build(),
 	  Atom::builder()
 		.name("overscroll")
 		.description("Control how the browser behaves when reaching the boundary of a scrolling area.")
@@ -0,0 +1,44 @@
+use lazy_static::lazy_static;
+use skribble_core::ContainerQuery;
+use skribble_core::Group;
+
+lazy_static! {
+	pub(crate) static ref CONTAINER_QUERIES: Vec<Group<ContainerQuery>> = vec![
+		Group::builder()
+			.name("container-breakpoints")
+			.description("The breakpoints for the size of the nearest query container.")
+			.items(vec![
+				ContainerQuery::builder()
+					.name("cq-xs")
+					.query("(min-width: 20rem)")
+					.description("The breakpoint for containers wider than 20rem.")
+					.build(),
+				ContainerQuery::builder()
+					.name("cq-sm")
+					.query("(min-width: 24rem)")
+					.description("The breakpoint for containers wider than 24rem.")
+					.build(),
+				ContainerQuery::builder()
+					.name("cq-md")
+					.query("(min-width: 28rem)")
+					.description("The breakpoint for containers wider than 28rem.")
+					.build(),
+				ContainerQuery::builder()
+					.name("cq-lg")
+					.query("(min-width: 32rem)")
+					.description("The breakpoint for containers wider than 32rem.")
+					.build(),
+				ContainerQuery::builder()
+					.name("cq-xl")
+					.query("(min-width: 36rem)")
+					.description("The breakpoint for containers wider than 36rem.")
+					.build(),
+				ContainerQuery::builder()
+					.name("cq-xxl")
+					.query("(min-width: 42rem)")
+					.description("The breakpoint for containers wider than 42rem.")
+					.build(),
+			])
+			.build(),
+	];
+}
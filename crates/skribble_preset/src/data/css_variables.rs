@@ -1,5 +1,6 @@
 use indexmap::indexmap;
 use lazy_static::lazy_static;
+use skribble_core::ContrastPair;
 use skribble_core::CssVariable;
 use skribble_core::Placeholder;
 use skribble_core::PropertySyntax;
@@ -155,6 +156,44 @@ lazy_static! {
 			.syntax(Color)
 			.build(),
 	];
+	pub(crate) static ref COLOR_CONTRAST_PAIRS: Vec<ContrastPair> = vec![
+		ContrastPair::builder()
+			.foreground("primary-content")
+			.background("primary")
+			.build(),
+		ContrastPair::builder()
+			.foreground("secondary-content")
+			.background("secondary")
+			.build(),
+		ContrastPair::builder()
+			.foreground("accent-content")
+			.background("accent")
+			.build(),
+		ContrastPair::builder()
+			.foreground("neutral-content")
+			.background("neutral")
+			.build(),
+		ContrastPair::builder()
+			.foreground("base-content")
+			.background("base100")
+			.build(),
+		ContrastPair::builder()
+			.foreground("info-content")
+			.background("info")
+			.build(),
+		ContrastPair::builder()
+			.foreground("success-content")
+			.background("success")
+			.build(),
+		ContrastPair::builder()
+			.foreground("warning-content")
+			.background("warning")
+			.build(),
+		ContrastPair::builder()
+			.foreground("error-content")
+			.background("error")
+			.build(),
+	];
 	pub(crate) static ref CSS_VARIABLES: Vec<CssVariable> = vec![
 		CssVariable::builder()
 			.name("contained-max-width")
@@ -0,0 +1,39 @@
+use lazy_static::lazy_static;
+use skribble_core::FeatureQuery;
+use skribble_core::Group;
+
+lazy_static! {
+	pub(crate) static ref FEATURE_QUERIES: Vec<Group<FeatureQuery>> = vec![
+		Group::builder()
+			.name("supports")
+			.description("Apply styles only when the browser supports a css feature.")
+			.items(vec![
+				FeatureQuery::builder()
+					.name("supports-grid")
+					.query("(display: grid)")
+					.description("Apply when the browser supports grid layouts.")
+					.build(),
+				FeatureQuery::builder()
+					.name("supports-subgrid")
+					.query("(grid-template-columns: subgrid)")
+					.description("Apply when the browser supports subgrid layouts.")
+					.build(),
+				FeatureQuery::builder()
+					.name("supports-backdrop")
+					.query("((backdrop-filter: blur(0)) or (-webkit-backdrop-filter: blur(0)))")
+					.description("Apply when the browser supports backdrop filters.")
+					.build(),
+				FeatureQuery::builder()
+					.name("supports-container")
+					.query("(container-type: inline-size)")
+					.description("Apply when the browser supports container queries.")
+					.build(),
+				FeatureQuery::builder()
+					.name("supports-has")
+					.query("selector(:has(*))")
+					.description("Apply when the browser supports the `:has()` selector.")
+					.build(),
+			])
+			.build(),
+	];
+}
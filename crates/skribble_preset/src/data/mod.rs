@@ -1,7 +1,9 @@
 pub(crate) use aliases::*;
 pub(crate) use atoms::*;
+pub(crate) use container_queries::*;
 pub(crate) use css::*;
 pub(crate) use css_variables::*;
+pub(crate) use feature_queries::*;
 pub(crate) use keyframes::*;
 pub(crate) use media_queries::*;
 pub(crate) use modifiers::*;
@@ -12,8 +14,10 @@ pub(crate) use value_sets::*;
 
 mod aliases;
 mod atoms;
+mod container_queries;
 mod css;
 mod css_variables;
+mod feature_queries;
 mod keyframes;
 mod media_queries;
 mod modifiers;
@@ -1,8 +1,12 @@
 use indexmap::indexmap;
 use lazy_static::lazy_static;
 use skribble_core::CalcSymbol;
+use skribble_core::ColorMix;
 use skribble_core::ColorProperty;
 use skribble_core::Group;
+use skribble_core::OklchProperty;
+use skribble_core::StringMap;
+use skribble_core::Transformation;
 use skribble_core::Transformer;
 
 lazy_static! {
@@ -222,5 +226,174 @@ lazy_static! {
 					.build(),
 			])
 			.build(),
+		Group::builder()
+			.name("mix")
+			.description(
+				"Mix colors in the OKLCH color space. The result is precomputed for palette \
+				 colors and uses `color-mix()` for css variables."
+			)
+			.items(vec![
+				Transformer::builder()
+					.name("mix")
+					.description(
+						"Mix the color with another color. The value should be in the format \
+						 `<color>,<amount>`. e.g. `(mix=white,20%)`."
+					)
+					.transformation(ColorMix::default())
+					.values(StringMap::default())
+					.scope("color")
+					.recipient("value")
+					.build(),
+				Transformer::builder()
+					.name("tint")
+					.description("Mix the color with white by the provided percentage.")
+					.transformation(ColorMix::builder().color("#ffffff").build())
+					.values(indexmap! {
+					  "005" => "5%",
+					  "010" => "10%",
+					  "020" => "20%",
+					  "025" => "25%",
+					  "030" => "30%",
+					  "040" => "40%",
+					  "050" => "50%",
+					  "060" => "60%",
+					  "070" => "70%",
+					  "075" => "75%",
+					  "080" => "80%",
+					  "090" => "90%",
+					  "095" => "95%",
+					})
+					.scope("color")
+					.recipient("value")
+					.build(),
+				Transformer::builder()
+					.name("shade")
+					.description("Mix the color with black by the provided percentage.")
+					.transformation(ColorMix::builder().color("#000000").build())
+					.values(indexmap! {
+					  "005" => "5%",
+					  "010" => "10%",
+					  "020" => "20%",
+					  "025" => "25%",
+					  "030" => "30%",
+					  "040" => "40%",
+					  "050" => "50%",
+					  "060" => "60%",
+					  "070" => "70%",
+					  "075" => "75%",
+					  "080" => "80%",
+					  "090" => "90%",
+					  "095" => "95%",
+					})
+					.scope("color")
+					.recipient("value")
+					.build(),
+			])
+			.build(),
+		Group::builder()
+			.name("oklch")
+			.description("Perceptually even lightness and chroma adjustments using OKLCH.")
+			.items(vec![
+				Transformer::builder()
+					.name("oklch-lighten")
+					.description(
+						"Increase the OKLCH lightness of the color by the provided percentage."
+					)
+					.transformation(OklchProperty::Lightness(CalcSymbol::Add))
+					.values(indexmap! {
+					  "001" => "1%",
+					  "002" => "2%",
+					  "003" => "3%",
+					  "004" => "4%",
+					  "005" => "5%",
+					  "010" => "10%",
+					  "015" => "15%",
+					  "020" => "20%",
+					  "025" => "25%",
+					  "030" => "30%",
+					  "040" => "40%",
+					  "050" => "50%",
+					})
+					.scope("color")
+					.recipient("value")
+					.build(),
+				Transformer::builder()
+					.name("oklch-darken")
+					.description(
+						"Decrease the OKLCH lightness of the color by the provided percentage."
+					)
+					.transformation(OklchProperty::Lightness(CalcSymbol::Subtract))
+					.values(indexmap! {
+					  "001" => "1%",
+					  "002" => "2%",
+					  "003" => "3%",
+					  "004" => "4%",
+					  "005" => "5%",
+					  "010" => "10%",
+					  "015" => "15%",
+					  "020" => "20%",
+					  "025" => "25%",
+					  "030" => "30%",
+					  "040" => "40%",
+					  "050" => "50%",
+					})
+					.scope("color")
+					.recipient("value")
+					.build(),
+				Transformer::builder()
+					.name("oklch-saturate")
+					.description(
+						"Increase the OKLCH chroma of the color by the provided percentage."
+					)
+					.transformation(OklchProperty::Chroma(CalcSymbol::Add))
+					.values(indexmap! {
+					  "001" => "1%",
+					  "002" => "2%",
+					  "003" => "3%",
+					  "004" => "4%",
+					  "005" => "5%",
+					  "010" => "10%",
+					  "015" => "15%",
+					  "020" => "20%",
+					  "025" => "25%",
+					  "030" => "30%",
+					  "040" => "40%",
+					  "050" => "50%",
+					})
+					.scope("color")
+					.recipient("value")
+					.build(),
+				Transformer::builder()
+					.name("oklch-desaturate")
+					.description(
+						"Decrease the OKLCH chroma of the color by the provided percentage."
+					)
+					.transformation(OklchProperty::Chroma(CalcSymbol::Subtract))
+					.values(indexmap! {
+					  "001" => "1%",
+					  "002" => "2%",
+					  "003" => "3%",
+					  "004" => "4%",
+					  "005" => "5%",
+					  "010" => "10%",
+					  "015" => "15%",
+					  "020" => "20%",
+					  "025" => "25%",
+					  "030" => "30%",
+					  "040" => "40%",
+					  "050" => "50%",
+					})
+					.scope("color")
+					.recipient("value")
+					.build(),
+				Transformer::builder()
+					.name("oklch-invert")
+					.description("Invert the OKLCH lightness of the color. Useful for dark mode.")
+					.transformation(Transformation::Invert)
+					.scope("color")
+					.recipient("value")
+					.build(),
+			])
+			.build(),
 	];
 }
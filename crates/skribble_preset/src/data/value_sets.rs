@@ -698,6 +698,14 @@ lazy_static! {
           "scroll" => "scroll",
         })
         .build(),
+      ValueSet::builder()
+        .name("container-type")
+        .values(indexmap! {
+          "normal" => "normal",
+          "size" => "size",
+          "inline-size" => "inline-size",
+        })
+        .build(),
       ValueSet::builder()
         .name("overscroll")
         .values(indexmap! {
@@ -8,10 +8,12 @@ use enums::*;
 use serde::Deserialize;
 use serde::Serialize;
 use skribble_core::*;
+pub use tailwind::*;
 use typed_builder::TypedBuilder;
 
 pub(crate) mod data;
 mod enums;
+mod tailwind;
 
 #[derive(Debug, Clone, Default, Deserialize, TypedBuilder, Serialize)]
 pub struct PresetPlugin {
@@ -26,6 +28,22 @@ pub struct PresetPlugin {
 	/// custom theme.
 	#[builder(default, setter(into))]
 	pub ignore_colors: bool,
+	/// Choose to ignore the palette colors so that you can replace them. The
+	/// color variables reference the palette and should also be ignored with
+	/// `ignore_colors`.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub ignore_palette: bool,
+	/// The names of the value sets which are left out of the preset so that
+	/// you can replace them, e.g. `spacing`.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub ignore_value_sets: Vec<String>,
+	/// The names of the media query groups which are left out of the preset so
+	/// that you can replace them, e.g. `breakpoints`.
+	#[serde(default)]
+	#[builder(default, setter(into))]
+	pub ignore_media_query_groups: Vec<String>,
 	/// The reset to use for the CSS.
 	#[builder(default, setter(into, strip_option))]
 	reset: Option<CssReset>,
@@ -50,12 +68,15 @@ impl Plugin for PresetPlugin {
 		self.update_css_chunks(&mut config.css_chunks);
 		self.update_keyframes(&mut config.keyframes);
 		self.update_media_queries(&mut config.media_queries);
+		self.update_feature_queries(&mut config.feature_queries);
+		self.update_container_queries(&mut config.container_queries);
 		self.update_modifiers(&mut config.modifiers);
 		self.update_transformers(&mut config.transformers);
 		self.update_named_classes(&mut config.classes);
 		self.update_palette(&mut config.palette);
 		self.update_value_sets(&mut config.value_sets);
 		self.update_variables(&mut config.variables);
+		self.update_contrast_pairs(&mut config.contrast_pairs);
 
 		Ok(())
 	}
@@ -91,13 +112,27 @@ impl PresetPlugin {
 	}
 
 	fn update_media_queries(&self, media_queries: &mut MediaQueries) {
-		media_queries.extend(MEDIA_QUERIES.clone());
+		media_queries.extend(
+			MEDIA_QUERIES
+				.iter()
+				.filter(|group| !self.ignore_media_query_groups.contains(&group.name))
+				.cloned()
+				.collect::<Vec<_>>(),
+		);
 
 		if self.dark_mode == DarkMode::Media {
 			media_queries.extend(DARK_MEDIA_QUERIES.clone());
 		}
 	}
 
+	fn update_feature_queries(&self, feature_queries: &mut FeatureQueries) {
+		feature_queries.extend(FEATURE_QUERIES.clone());
+	}
+
+	fn update_container_queries(&self, container_queries: &mut ContainerQueries) {
+		container_queries.extend(CONTAINER_QUERIES.clone());
+	}
+
 	fn update_modifiers(&self, modifiers: &mut Modifiers) {
 		modifiers.extend(MODIFIERS.clone());
 
@@ -115,11 +150,25 @@ impl PresetPlugin {
 	}
 
 	fn update_palette(&self, palette: &mut Palette) {
-		palette.extend(self.palette.palette());
+		if !self.ignore_palette {
+			palette.extend(self.palette.palette());
+		}
 	}
 
 	fn update_value_sets(&self, value_sets: &mut ValueSets) {
-		value_sets.extend(ATOM_VALUE_SETS.clone());
+		value_sets.extend(
+			ATOM_VALUE_SETS
+				.iter()
+				.filter(|value_set| !self.ignore_value_sets.contains(&value_set.name))
+				.cloned()
+				.collect::<Vec<_>>(),
+		);
+	}
+
+	fn update_contrast_pairs(&self, contrast_pairs: &mut ContrastPairs) {
+		if !self.ignore_colors {
+			contrast_pairs.extend(COLOR_CONTRAST_PAIRS.clone());
+		}
 	}
 
 	fn update_variables(&self, css_variables: &mut CssVariables) {
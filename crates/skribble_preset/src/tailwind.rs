@@ -0,0 +1,550 @@
+use heck::ToKebabCase;
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use skribble_core::*;
+
+use crate::DarkMode;
+use crate::PresetPlugin;
+
+pub type TailwindResult<T> = core::result::Result<T, TailwindError>;
+
+#[non_exhaustive]
+#[derive(thiserror::Error, Debug)]
+pub enum TailwindError {
+	#[error("the tailwind config is not valid json")]
+	InvalidJson(#[source] serde_json::Error),
+	#[error("the tailwind config must be a json object")]
+	InvalidRoot,
+}
+
+/// The tailwind theme keys which map directly onto a preset value set. The
+/// third item is the css function the values are wrapped with.
+const VALUE_SET_KEYS: &[(&str, &str, Option<&str>)] = &[
+	("spacing", "spacing", None),
+	("opacity", "opacity", None),
+	("zIndex", "z-index", None),
+	("maxWidth", "max-width", None),
+	("fontFamily", "font-family", None),
+	("fontWeight", "font-weight", None),
+	("letterSpacing", "letter-spacing", None),
+	("lineHeight", "line-height", None),
+	("borderRadius", "border-radius", None),
+	("borderWidth", "border-width", None),
+	("transitionDuration", "duration", None),
+	("transitionTimingFunction", "easing", None),
+	("aspectRatio", "ratio", None),
+	("order", "order", None),
+	("flex", "flex", None),
+	("flexGrow", "flex-grow", None),
+	("flexShrink", "flex-shrink", None),
+	("strokeWidth", "stroke-width", None),
+	("outlineWidth", "outline-width", None),
+	("ringWidth", "ring-width", None),
+	("scale", "scale", None),
+	("skew", "skew", None),
+	("rotate", "rotation", None),
+	("transformOrigin", "origin", None),
+	("textIndent", "text-indent", None),
+	("blur", "blur", Some("blur")),
+	("brightness", "brightness", Some("brightness")),
+	("contrast", "contrast", Some("contrast")),
+	("grayscale", "grayscale", Some("grayscale")),
+	("invert", "invert", Some("invert")),
+	("sepia", "sepia", Some("sepia")),
+	("saturate", "saturate", Some("saturate")),
+	("hueRotate", "hue-rotate", Some("hue-rotate")),
+];
+
+/// The named colors which are already provided by every color atom.
+const NAMED_COLORS: &[&str] = &["inherit", "current", "transparent"];
+
+/// The result of importing a `tailwind.config.js` which has been exported as
+/// JSON.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TailwindImport {
+	/// The configuration which is layered on top of the [`PresetPlugin`]. The
+	/// preset is already added to the plugins.
+	pub config: StyleConfig,
+	/// The dark mode used by the [`PresetPlugin`].
+	pub dark_mode: DarkMode,
+	/// The keys which could not be converted, e.g. `theme.boxShadow`.
+	pub unsupported: Vec<String>,
+}
+
+impl TailwindImport {
+	/// Import the JSON content of a tailwind config.
+	///
+	/// The preset already provides the default tailwind theme. The keys of
+	/// `theme.extend` are merged into the preset values while the keys of
+	/// `theme` replace them. The preset keyframes are used by its atoms so the
+	/// `theme.keyframes` and `theme.animation` keys are unsupported.
+	pub fn from_json(content: impl AsRef<str>) -> TailwindResult<Self> {
+		let value: Value =
+			serde_json::from_str(content.as_ref()).map_err(TailwindError::InvalidJson)?;
+		Self::from_value(&value)
+	}
+
+	/// Import the parsed JSON value of a tailwind config.
+	pub fn from_value(value: &Value) -> TailwindResult<Self> {
+		let Value::Object(root) = value else {
+			return Err(TailwindError::InvalidRoot);
+		};
+
+		let mut importer = TailwindImporter::default();
+		importer.import(root);
+
+		Ok(importer.finish())
+	}
+}
+
+#[derive(Default)]
+struct TailwindImporter {
+	dark_mode: DarkMode,
+	files: Vec<String>,
+	ignore_palette: bool,
+	ignore_value_sets: Vec<String>,
+	ignore_media_query_groups: Vec<String>,
+	palette: Palette,
+	value_sets: IndexMap<String, CssValues>,
+	screens: Vec<MediaQuery>,
+	keyframes: Vec<Keyframe>,
+	classes: Vec<NamedClass>,
+	unsupported: Vec<String>,
+}
+
+impl TailwindImporter {
+	fn import(&mut self, root: &Map<String, Value>) {
+		for (key, value) in root.iter() {
+			match key.as_str() {
+				"theme" => self.import_theme(value, "theme"),
+				"darkMode" => self.import_dark_mode(value),
+				"content" => self.import_content(value),
+				"plugins" | "presets" | "safelist" | "blocklist"
+					if value.as_array().map_or(false, Vec::is_empty) => {}
+				_ => self.unsupported.push(key.clone()),
+			}
+		}
+	}
+
+	fn finish(self) -> TailwindImport {
+		let preset = PresetPlugin::builder()
+			.dark_mode(self.dark_mode)
+			.ignore_colors(self.ignore_palette)
+			.ignore_palette(self.ignore_palette)
+			.ignore_value_sets(self.ignore_value_sets)
+			.ignore_media_query_groups(self.ignore_media_query_groups)
+			.build();
+		let value_sets = self
+			.value_sets
+			.into_iter()
+			.map(|(name, values)| ValueSet::builder().name(name).values(values).build())
+			.collect::<Vec<_>>();
+		let media_queries = if self.screens.is_empty() {
+			vec![]
+		} else {
+			vec![
+				Group::builder()
+					.name("breakpoints")
+					.items(self.screens)
+					.build(),
+			]
+		};
+
+		let mut options = Options::default();
+
+		if !self.files.is_empty() {
+			options.files = self.files;
+		}
+
+		let config = StyleConfig::builder()
+			.options(options)
+			.palette(self.palette)
+			.value_sets(value_sets)
+			.media_queries(media_queries)
+			.keyframes(self.keyframes)
+			.classes(self.classes)
+			.plugins(vec![PluginContainer::from(preset)])
+			.build();
+
+		TailwindImport {
+			config,
+			dark_mode: self.dark_mode,
+			unsupported: self.unsupported,
+		}
+	}
+
+	fn import_theme(&mut self, value: &Value, path: &str) {
+		let Value::Object(theme) = value else {
+			self.unsupported.push(path.into());
+			return;
+		};
+
+		// The top level theme keys replace the preset values.
+		let replace = path == "theme";
+
+		for (key, value) in theme.iter() {
+			let path = format!("{path}.{key}");
+
+			match key.as_str() {
+				"extend" if replace => self.import_theme(value, &path),
+				"colors" => {
+					self.ignore_palette |= replace;
+					self.import_colors(value, "", &path);
+				}
+				"screens" => {
+					if replace {
+						self.ignore_media_query_groups.push("breakpoints".into());
+					}
+
+					self.import_screens(value, &path);
+				}
+				"keyframes" | "animation" if replace => self.unsupported.push(path),
+				"keyframes" => self.import_keyframes(value, &path),
+				"animation" => self.import_animation(value, &path),
+				"fontSize" => {
+					if replace {
+						self.ignore_value_sets.push("font-size".into());
+					}
+
+					self.import_font_size(value, &path);
+				}
+				key => {
+					match VALUE_SET_KEYS.iter().find(|(name, ..)| *name == key) {
+						Some((_, value_set, wrapper)) => {
+							if replace {
+								self.ignore_value_sets.push(value_set.to_string());
+
+								if *value_set == "spacing" {
+									self.ignore_value_sets.push("negative-spacing".into());
+								}
+							}
+
+							self.import_value_set(value, value_set, *wrapper, &path);
+						}
+						None => self.unsupported.push(path),
+					}
+				}
+			}
+		}
+	}
+
+	fn import_dark_mode(&mut self, value: &Value) {
+		let (strategy, selector) = match value {
+			Value::String(strategy) => (strategy.as_str(), None),
+			Value::Array(items) => {
+				let strategy = items.first().and_then(Value::as_str).unwrap_or_default();
+				(strategy, items.get(1).and_then(Value::as_str))
+			}
+			_ => ("", None),
+		};
+
+		match strategy {
+			"media" => self.dark_mode = DarkMode::Media,
+			"class" | "selector" if selector.map_or(true, |selector| selector == ".dark") => {
+				self.dark_mode = DarkMode::Class;
+			}
+			"class" | "selector" => {
+				self.dark_mode = DarkMode::Class;
+				self.unsupported.push("darkMode".into());
+			}
+			_ => self.unsupported.push("darkMode".into()),
+		}
+	}
+
+	fn import_content(&mut self, value: &Value) {
+		let files = match value {
+			Value::Array(files) => files,
+			Value::Object(map) => {
+				match map.get("files") {
+					Some(Value::Array(files)) => files,
+					_ => {
+						self.unsupported.push("content".into());
+						return;
+					}
+				}
+			}
+			_ => {
+				self.unsupported.push("content".into());
+				return;
+			}
+		};
+
+		for file in files.iter() {
+			match file.as_str() {
+				Some(file) => self.files.push(file.into()),
+				None => self.unsupported.push("content".into()),
+			}
+		}
+	}
+
+	/// Flatten the nested colors so that `gray.100` becomes `gray100` and
+	/// `brand.DEFAULT` becomes `brand`.
+	fn import_colors(&mut self, value: &Value, prefix: &str, path: &str) {
+		match value {
+			Value::String(color) => {
+				if !NAMED_COLORS.contains(&prefix) {
+					let color = color.replace("<alpha-value>", "1");
+					self.palette.insert(prefix.into(), color);
+				}
+			}
+			Value::Object(colors) => {
+				for (key, value) in colors.iter() {
+					let name = if key == "DEFAULT" {
+						prefix.to_string()
+					} else {
+						format!("{prefix}{key}")
+					};
+
+					self.import_colors(value, &name, &format!("{path}.{key}"));
+				}
+			}
+			_ => self.unsupported.push(path.into()),
+		}
+	}
+
+	fn import_screens(&mut self, value: &Value, path: &str) {
+		let Value::Object(screens) = value else {
+			self.unsupported.push(path.into());
+			return;
+		};
+
+		for (name, value) in screens.iter() {
+			let query = match value {
+				Value::String(width) => Some(format!("(min-width: {width})")),
+				Value::Object(range) => get_screen_query(range),
+				Value::Array(ranges) => {
+					ranges
+						.iter()
+						.map(|range| range.as_object().and_then(get_screen_query))
+						.collect::<Option<Vec<_>>>()
+						.map(|queries| queries.join(", "))
+				}
+				_ => None,
+			};
+
+			match query {
+				Some(query) => {
+					self.screens
+						.push(MediaQuery::builder().name(name).query(query).build());
+				}
+				None => self.unsupported.push(format!("{path}.{name}")),
+			}
+		}
+	}
+
+	fn import_keyframes(&mut self, value: &Value, path: &str) {
+		let Value::Object(keyframes) = value else {
+			self.unsupported.push(path.into());
+			return;
+		};
+
+		for (name, offsets) in keyframes.iter() {
+			let Some(offsets) = offsets.as_object() else {
+				self.unsupported.push(format!("{path}.{name}"));
+				continue;
+			};
+
+			let mut rules = NestedStringMap::default();
+
+			for (offset, properties) in offsets.iter() {
+				let mut map = StringMap::default();
+
+				for (property, value) in properties.as_object().into_iter().flatten() {
+					let Some(value) = get_string(value) else {
+						self.unsupported
+							.push(format!("{path}.{name}.{offset}.{property}"));
+						continue;
+					};
+
+					let property = if property.starts_with("--") {
+						property.clone()
+					} else {
+						property.to_kebab_case()
+					};
+
+					map.insert(property, value);
+				}
+
+				rules.insert(offset.clone(), map);
+			}
+
+			self.keyframes
+				.push(Keyframe::builder().name(name).rules(rules).build());
+		}
+	}
+
+	/// The `animation` shorthands become named classes, e.g. `$animate-spin`.
+	fn import_animation(&mut self, value: &Value, path: &str) {
+		let Value::Object(animations) = value else {
+			self.unsupported.push(path.into());
+			return;
+		};
+
+		for (name, value) in animations.iter() {
+			let Some(animation) = get_string(value) else {
+				self.unsupported.push(format!("{path}.{name}"));
+				continue;
+			};
+
+			let name = if name == "DEFAULT" {
+				"animate".to_string()
+			} else {
+				format!("animate-{name}")
+			};
+
+			self.classes.push(
+				NamedClass::builder()
+					.name(name)
+					.styles(
+						vec![("animation", animation)]
+							.into_iter()
+							.collect::<StringMap>(),
+					)
+					.build(),
+			);
+		}
+	}
+
+	/// Font sizes can be a size or a tuple of the size and line height.
+	fn import_font_size(&mut self, value: &Value, path: &str) {
+		let Value::Object(sizes) = value else {
+			self.unsupported.push(path.into());
+			return;
+		};
+
+		for (key, value) in sizes.iter() {
+			let (size, height) = match value {
+				Value::Array(items) => {
+					let size = items.first().and_then(get_string);
+					let height = items.get(1).and_then(|item| {
+						match item {
+							Value::Object(options) => {
+								options.get("lineHeight").and_then(get_string)
+							}
+							item => get_string(item),
+						}
+					});
+					(size, height)
+				}
+				value => (get_string(value), None),
+			};
+
+			let Some(size) = size else {
+				self.unsupported.push(format!("{path}.{key}"));
+				continue;
+			};
+
+			let height = height.unwrap_or_else(|| "inherit".into());
+			let values: StringMap = vec![("size", size), ("height", height)]
+				.into_iter()
+				.collect();
+
+			self.value_sets
+				.entry("font-size".into())
+				.or_default()
+				.insert(get_value_name(key), values.into());
+		}
+	}
+
+	fn import_value_set(&mut self, value: &Value, name: &str, wrapper: Option<&str>, path: &str) {
+		let Value::Object(values) = value else {
+			self.unsupported.push(path.into());
+			return;
+		};
+
+		for (key, value) in values.iter() {
+			let Some(value) = get_string(value) else {
+				self.unsupported.push(format!("{path}.{key}"));
+				continue;
+			};
+
+			let value = match wrapper {
+				Some(wrapper) => format!("{wrapper}({value})"),
+				None => value,
+			};
+
+			if name == "spacing" {
+				self.value_sets
+					.entry("negative-spacing".into())
+					.or_default()
+					.insert(format!("-{key}"), negate(&value).into());
+			}
+
+			self.value_sets
+				.entry(name.into())
+				.or_default()
+				.insert(get_value_name(key), value.into());
+		}
+	}
+}
+
+/// Tailwind uses `DEFAULT` for the value used without a suffix.
+fn get_value_name(key: &str) -> String {
+	if key == "DEFAULT" {
+		"default".into()
+	} else {
+		key.into()
+	}
+}
+
+/// Get the css value from strings, numbers and font family lists.
+fn get_string(value: &Value) -> Option<String> {
+	match value {
+		Value::String(value) => Some(value.clone()),
+		Value::Number(value) => Some(value.to_string()),
+		Value::Array(items) => {
+			let first = items.first()?;
+
+			// `["Inter", { fontFeatureSettings: "..." }]` style font families.
+			if items.iter().skip(1).all(Value::is_object) && first.is_array() {
+				return get_string(first);
+			}
+
+			items
+				.iter()
+				.map(|item| item.as_str().map(String::from))
+				.collect::<Option<Vec<_>>>()
+				.map(|items| items.join(","))
+		}
+		_ => None,
+	}
+}
+
+fn get_screen_query(range: &Map<String, Value>) -> Option<String> {
+	if let Some(raw) = range.get("raw") {
+		return raw.as_str().map(String::from);
+	}
+
+	let min = range.get("min").and_then(Value::as_str);
+	let max = range.get("max").and_then(Value::as_str);
+
+	match (min, max) {
+		(Some(min), Some(max)) => Some(format!("(min-width: {min}) and (max-width: {max})")),
+		(Some(min), None) => Some(format!("(min-width: {min})")),
+		(None, Some(max)) => Some(format!("(max-width: {max})")),
+		(None, None) => None,
+	}
+}
+
+/// Negate the spacing value.
+fn negate(value: &str) -> String {
+	let value = value.trim();
+
+	if let Some(value) = value.strip_prefix('-') {
+		return value.into();
+	}
+
+	let is_dimension = value.starts_with(|ch: char| ch.is_ascii_digit() || ch == '.')
+		&& value
+			.chars()
+			.all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '%'));
+
+	if is_dimension {
+		format!("-{value}")
+	} else {
+		format!("calc({value} * -1)")
+	}
+}
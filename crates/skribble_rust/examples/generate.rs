@@ -1,43 +1,72 @@
 use std::env;
 use std::fs;
+use std::io::Write;
 
+use skribble_core::vfs::MemoryFS;
 use skribble_core::*;
 use skribble_preset::PresetPlugin;
+use skribble_rust::RustCodeMode;
 use skribble_rust::RustPlugin;
 
+/// The classes which decide the atoms of the pruned modules.
+const PRUNED_ATOMS: &str = include_str!("../tests/fixtures/pruned_atoms.rs");
+
 fn main() -> AnyEmptyResult {
-	let default_preset = PresetPlugin::builder().build();
-	let rust_plugin = RustPlugin::builder().build();
-	let options = Options::builder()
-		.formatters(vec![
-			Formatter::builder()
-				.command("dprint")
-				.args(vec!["fmt"])
-				.globs(vec!["**/*.rs"])
-				.build(),
-		])
-		.build();
+	let current_dir = env::current_dir()?;
+	let first_arg = env::args().nth(1).unwrap();
+	let directory = current_dir.join(first_arg);
 
+	fs::create_dir_all(&directory)?;
+
+	let owned = generate(RustPlugin::builder().build(), None)?;
+	fs::write(directory.join("generated_code.rs"), owned)?;
+
+	let static_code = generate(
+		RustPlugin::builder().mode(RustCodeMode::Static).build(),
+		None,
+	)?;
+	fs::write(directory.join("generated_static_code.rs"), static_code)?;
+
+	let modules = generate(
+		RustPlugin::builder()
+			.split_modules(true)
+			.module_cfg("not(feature = \"skribble-skip-{module}\")")
+			.prune_atoms(true)
+			.build(),
+		Some(PRUNED_ATOMS),
+	)?;
+	fs::write(directory.join("generated_modules_code.rs"), modules)?;
+
+	Ok(())
+}
+
+/// Generate the rust code with the default preset. When a `source` is
+/// provided it is scanned first, so that the unused atoms can be pruned.
+fn generate(rust_plugin: RustPlugin, source: Option<&str>) -> AnyResult<String> {
+	let default_preset = PresetPlugin::builder().build();
 	let config: StyleConfig = StyleConfig::builder()
 		.plugins(vec![
 			PluginContainer::from(default_preset),
 			PluginContainer::from(rust_plugin),
 		])
-		.options(options)
 		.build();
 
-	let mut runner = SkribbleRunner::try_new(config)?;
-	let _ = runner.initialize();
-	let result = runner.generate()?;
-	let generated = result.first().unwrap();
-	let content = &generated.content;
+	let vfs: VfsPath = MemoryFS::new().into();
 
-	let current_dir = env::current_dir()?;
-	let first_arg = env::args().nth(1).unwrap();
-	let path = current_dir.join(&first_arg).join("generated_code.rs");
+	if let Some(source) = source {
+		vfs.join("src")?.create_dir_all()?;
+		write!(vfs.join("src/lib.rs")?.create_file()?, "{source}")?;
+	}
 
-	fs::create_dir_all(&first_arg)?;
-	std::fs::write(path, content)?;
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
 
-	Ok(())
+	if source.is_some() {
+		let _ = runner.scan_classes()?;
+	}
+
+	let result = runner.generate()?;
+	let generated = result.first().ok_or(Error::Unknown)?;
+
+	Ok(generated.content.clone())
 }
@@ -1,4 +1,6 @@
 use hex::ToHex;
+use indexmap::indexmap;
+use rstest::fixture;
 use rstest::rstest;
 use skribble_core::vfs::MemoryFS;
 use skribble_core::*;
@@ -29,6 +31,28 @@ fn can_generate_skribble_rust_code() -> AnyEmptyResult {
 	Ok(())
 }
 
+#[test]
+fn can_generate_static_skribble_rust_code() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder().mode(RustCodeMode::Static).build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = MemoryFS::new().into();
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let result = runner.generate()?;
+	let GeneratedFile { content, .. } = result.first().ok_or(Error::Unknown)?;
+	insta::assert_display_snapshot!(content);
+
+	Ok(())
+}
+
 #[test]
 fn can_generate_skribble_rust_methods() -> AnyEmptyResult {
 	let default_preset = PresetPlugin::builder().build();
@@ -51,12 +75,182 @@ fn can_generate_skribble_rust_methods() -> AnyEmptyResult {
 	Ok(())
 }
 
+#[test]
+fn can_configure_generated_paths_and_names() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder()
+		.output_path("./crates/app/src/styles.rs")
+		.cache_path("./target/skribble/methods.json")
+		.config_cache_path("./target/skribble/config.json")
+		.root_function("cx")
+		.variables_function("tokens")
+		.visibility("pub(crate)")
+		.build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = create_memory_fs(&[(
+		"crates/app/src/lib.rs",
+		"pub fn app() -> String { cx().md().p().n2() }",
+	)])?;
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let result = runner.generate()?;
+	let paths = result
+		.iter()
+		.map(|file| file.path.to_string_lossy().to_string())
+		.collect::<Vec<_>>();
+	let GeneratedFile { content, .. } = result.first().ok_or(Error::Unknown)?;
+
+	assert_eq!(
+		paths,
+		vec![
+			"./crates/app/src/styles.rs",
+			"./target/skribble/config.json",
+			"./target/skribble/methods.json",
+		]
+	);
+	assert!(content.contains("pub(crate) fn cx() -> GeneratedSkribbleRoot {"));
+	assert!(content.contains("pub(crate) struct GeneratedSkribbleRoot(String);"));
+	assert!(content.contains("pub(crate) fn tokens() -> GeneratedCssVariables {"));
+	assert!(content.contains("pub(crate) trait GeneratedNamedClasses: GeneratedSkribbleValue {"));
+	assert!(!content.contains("\npub trait "));
+	assert!(!content.contains("\npub struct "));
+
+	let class_names = runner
+		.scan_classes()?
+		.iter()
+		.map(|class| class.class_name())
+		.collect::<AnyResult<Vec<_>>>()?;
+	assert_eq!(class_names, vec!["md:p:$2"]);
+
+	Ok(())
+}
+
+#[test]
+fn can_generate_an_included_module() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder().include_module(true).build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = MemoryFS::new().into();
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let result = runner.generate()?;
+	let GeneratedFile { content, .. } = result.first().ok_or(Error::Unknown)?;
+
+	assert!(
+		content.starts_with("#[allow(clippy::all)]\n#[allow(unused)]\nmod skribble_generated {")
+	);
+	assert!(content.ends_with("}\npub use skribble_generated::*;\n"));
+	assert!(!content.contains("#!["));
+
+	Ok(())
+}
+
+#[rstest]
+#[case("owned", RustCodeMode::Owned)]
+#[case("static", RustCodeMode::Static)]
+fn can_generate_framework_integrations(
+	#[case] id: &str,
+	#[case] mode: RustCodeMode,
+) -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder()
+		.mode(mode)
+		.integrations(vec![RustIntegration::Leptos])
+		.build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = MemoryFS::new().into();
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let result = runner.generate()?;
+	let GeneratedFile { content, .. } = result.first().ok_or(Error::Unknown)?;
+
+	let leptos = content
+		.find("impl leptos::IntoAttribute for ClassList {")
+		.ok_or(Error::Unknown)?;
+	let end = content
+		.get(leptos..)
+		.and_then(|rest| rest.find("\n}\n"))
+		.map(|index| leptos + index + 2)
+		.ok_or(Error::Unknown)?;
+
+	set_snapshot_suffix!("{id}");
+	insta::assert_display_snapshot!(content.get(..end).ok_or(Error::Unknown)?);
+
+	Ok(())
+}
+
+#[test]
+fn can_limit_generated_atom_colors() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder().build();
+	let atoms = vec![
+		Atom::builder()
+			.name("tint")
+			.styles(indexmap! { "color" => None::<String> })
+			.values(ColorField::builder().included(vec!["gray-*"]).build())
+			.build(),
+		Atom::builder()
+			.name("glow")
+			.styles(indexmap! { "background-color" => None::<String> })
+			.values(ColorField::builder().excluded(vec!["gray*"]).build())
+			.build(),
+	];
+
+	let config: StyleConfig = StyleConfig::builder()
+		.atoms(atoms)
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = MemoryFS::new().into();
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let result = runner.generate()?;
+	let GeneratedFile { content, .. } = result.first().ok_or(Error::Unknown)?;
+
+	assert!(content.contains("impl GeneratedColorGray500 for GeneratedAtomTintChild {}"));
+	assert!(!content.contains("impl GeneratedColorBlue500 for GeneratedAtomTintChild {}"));
+	assert!(!content.contains("impl GeneratedColorGray500 for GeneratedAtomGlowChild {}"));
+	assert!(content.contains("impl GeneratedColorBlue500 for GeneratedAtomGlowChild {}"));
+
+	Ok(())
+}
+
 #[rstest]
 #[case("function-default", &[("src/lib.rs", function("default", DEFAULT_NAMES))])]
 #[case("basic-component-default", &[("src/lib.rs", basic_component("default", DEFAULT_NAMES))])]
 #[case("component-default", &[("src/lib.rs", component("default", DEFAULT_NAMES))])]
 #[case("variables-default", &[("src/lib.rs", variables(DEFAULT_NAMES))])]
 #[case("gradients", &[("src/lib.rs", GRADIENTS)])]
+#[case("sk-macro", &[("src/lib.rs", SK_MACRO)])]
+#[case("yew", &[("src/lib.rs", YEW)])]
+#[case("dioxus", &[("src/lib.rs", DIOXUS)])]
+#[case("sycamore", &[("src/lib.rs", SYCAMORE)])]
+#[case("expression-macros", &[("src/lib.rs", EXPRESSION_MACROS)])]
+#[case("class-list", &[("src/lib.rs", CLASS_LIST)])]
 fn can_scan_and_generate_css<S: AsRef<str>>(
 	#[case] id: &str,
 	#[case] files: &[(&str, S)],
@@ -82,6 +276,248 @@ fn can_scan_and_generate_css<S: AsRef<str>>(
 	Ok(())
 }
 
+#[test]
+fn can_track_chains_through_bindings_and_functions() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder().build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = create_memory_fs(&[("src/lib.rs", CHAIN_BINDINGS)])?;
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let _ = runner.generate()?;
+	let classes = runner.scan_classes()?;
+	let class_names = classes
+		.iter()
+		.map(|class| class.class_name())
+		.collect::<AnyResult<Vec<_>>>()?;
+	let diagnostics = classes
+		.get_diagnostics()
+		.iter()
+		.map(|diagnostic| diagnostic.to_string())
+		.collect::<Vec<_>>();
+
+	insta::assert_debug_snapshot!((class_names, diagnostics));
+
+	Ok(())
+}
+
+#[test]
+fn can_scan_static_transformer_arguments() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder().mode(RustCodeMode::Static).build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = create_memory_fs(&[("src/lib.rs", STATIC_TRANSFORMERS)])?;
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let _ = runner.generate()?;
+	let class_names = runner
+		.scan_classes()?
+		.iter()
+		.map(|class| class.class_name())
+		.collect::<AnyResult<Vec<_>>>()?;
+
+	assert_eq!(
+		class_names,
+		vec!["(mix=white,20%):bg:$blue500", "md:(alpha=50%):bg:$red100"]
+	);
+
+	Ok(())
+}
+
+#[test]
+fn can_split_modules_and_prune_unused_atoms() -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder()
+		.split_modules(true)
+		.module_cfg("not(feature = \"skribble-skip-{module}\")")
+		.prune_atoms(true)
+		.build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = create_memory_fs(&[("src/lib.rs", PRUNED_ATOMS)])?;
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let full = runner.generate()?;
+	let _ = runner.scan_classes()?;
+	let pruned = runner.generate()?;
+	let GeneratedFile {
+		content: full_content,
+		..
+	} = full.first().ok_or(Error::Unknown)?;
+	let GeneratedFile { content, .. } = pruned.first().ok_or(Error::Unknown)?;
+
+	// The method names don't depend on the pruned atoms.
+	assert_eq!(full.last(), pruned.last());
+	assert!(full_content.contains("mod atom_columns {"));
+	assert!(!content.contains("mod atom_columns {"));
+	assert!(content.contains(indoc! {r#"
+		#[cfg(not(feature = "skribble-skip-atom-p"))]
+		mod atom_p {
+		  use super::*;"#
+	}));
+	assert!(
+		content.contains("#[cfg(not(feature = \"skribble-skip-atom-p\"))]\npub use atom_p::*;")
+	);
+	insta::assert_display_snapshot!(content);
+
+	Ok(())
+}
+
+#[rstest]
+#[case("leptos-literals", &[("src/lib.rs", LEPTOS_LITERALS)])]
+#[case("template-literals", &[("src/lib.rs", TEMPLATE_LITERALS)])]
+fn can_scan_literal_classes<S: AsRef<str>>(
+	#[case] id: &str,
+	#[case] files: &[(&str, S)],
+) -> AnyEmptyResult {
+	let default_preset = PresetPlugin::builder().build();
+	let rust_plugin = RustPlugin::builder()
+		.scan_literals(true)
+		.literal_functions(vec!["class_list"])
+		.build();
+
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![
+			PluginContainer::from(default_preset),
+			PluginContainer::from(rust_plugin),
+		])
+		.build();
+
+	let vfs: VfsPath = create_memory_fs(files)?;
+	let mut runner = SkribbleRunner::new(config, "/", Some(vfs));
+	let _ = runner.initialize()?;
+	let _ = runner.generate()?;
+	let scanned = runner.scan()?;
+	set_snapshot_suffix!("{id}");
+	insta::assert_display_snapshot!(scanned.code);
+
+	Ok(())
+}
+
+#[rstest]
+#[case(
+	"md:hover:bg-red-500",
+	"md:hover:bg:$red500",
+	"sk().md().hover().bg().red500()"
+)]
+#[case("p-4", "p:$4", "sk().p().n4()")]
+#[case("-mt-4", "mt:$-4", "sk().mt().m4()")]
+#[case("2xl:w-1/2", "xxl:w:$half", "sk().xxl().w().half()")]
+#[case("w-1/3", "w:$1-of-3", "sk().w().n1_of_3()")]
+#[case("p-[10px]", "p:[10px]", "sk().p_(\"10px\")")]
+#[case(
+	"dark:text-gray-100",
+	"dark:text:$gray100",
+	"sk().dark().text().gray100()"
+)]
+#[case("text-lg", "$text-lg", "sk().text_lg()")]
+#[case("md:flex", "md:display:$flex", "sk().md().display().flex()")]
+#[case("!font-bold", "(important):$font-bold", "sk().important().font_bold()")]
+#[case(
+	"bg-blue-500/50",
+	"(alpha=0.5):bg:$blue500",
+	"sk().alpha(\"0.5\").bg().blue500()"
+)]
+#[case(
+	"[mask-type:luminance]",
+	"[mask-type=luminance]",
+	"sk().__(\"mask-type\", \"luminance\")"
+)]
+fn can_map_tailwind_classes(
+	generated_preset: &(RunnerConfig, IndexMap<String, String>),
+	#[case] tailwind: &str,
+	#[case] class_name: &str,
+	#[case] expression: &str,
+) -> AnyEmptyResult {
+	let (config, method_names) = generated_preset;
+	let mapping = TailwindMapping::new(config, method_names);
+	let mapped = mapping.get_class(tailwind).ok_or(Error::Unknown)?;
+
+	assert_eq!(mapped.class_name, class_name);
+	assert_eq!(mapped.expression, expression);
+
+	Ok(())
+}
+
+#[rstest]
+fn can_migrate_tailwind_class_attributes(
+	generated_preset: &(RunnerConfig, IndexMap<String, String>),
+) -> AnyEmptyResult {
+	let (config, method_names) = generated_preset;
+	let mapping = TailwindMapping::new(config, method_names);
+	let migrated = mapping.migrate(TAILWIND_COMPONENT)?;
+
+	insta::assert_display_snapshot!(migrated.content);
+	insta::assert_json_snapshot!(migrated.unmapped);
+
+	Ok(())
+}
+
+#[rstest]
+#[case::unknown_modifier("md hovr p $2", "unknown token `hovr`, did you mean `hover`?")]
+#[case::unknown_value("p $1000", "unknown value `1000` for the atom `p`")]
+#[case::unknown_color(
+	"bg $red5000",
+	"unknown value `red5000` for the atom `bg`, did you mean `red500`, `red50`, `red100`?"
+)]
+#[case::unknown_transformer(
+	"(darkn==050) bg $red100",
+	"unknown transformer `darkn`, did you mean `darken`?"
+)]
+#[case::missing_value("md p", "the atom `p` requires a value, e.g. `p $value`")]
+#[case::extra_token("p $2 $4", "unexpected token `$4` after the complete class `p:$2`")]
+#[case::empty("", "expected at least one class, e.g. `sk!(md p $2)`")]
+fn sk_macro_errors(
+	generated_preset: &(RunnerConfig, IndexMap<String, String>),
+	#[case] input: &str,
+	#[case] expected: &str,
+) -> AnyEmptyResult {
+	let (config, _) = generated_preset;
+	let error = expand_sk_macro(config, input.parse()?)
+		.err()
+		.ok_or(Error::Unknown)?;
+
+	assert_eq!(error.to_string(), expected);
+
+	Ok(())
+}
+
+/// The preset configuration and the generated method names which are shared
+/// between tests since generating the code is slow.
+#[fixture]
+#[once]
+fn generated_preset() -> (RunnerConfig, IndexMap<String, String>) {
+	let config: StyleConfig = StyleConfig::builder()
+		.plugins(vec![PluginContainer::from(PresetPlugin::builder().build())])
+		.build();
+	let mut runner = SkribbleRunner::try_new(config).unwrap();
+	let config = runner.initialize().unwrap().clone();
+	let (_, method_names) =
+		generate::generate_file_contents(&config, &RustPlugin::default(), None).unwrap();
+
+	(config, method_names)
+}
+
 fn create_memory_fs<S: AsRef<str>>(files: &[(&str, S)]) -> AnyResult<VfsPath> {
 	let vfs: VfsPath = MemoryFS::new().into();
 
@@ -193,3 +629,174 @@ const DEFAULT_NAMES: &[&str] = &[
 	r#"sk().md().darken_050().bg().red100()"#,
 	r#"sk().md().alpha("0.5").bg().pink100()"#,
 ];
+
+const SK_MACRO: &str = r#"
+use skribble_macros::sk;
+
+#[component]
+fn Macro(cx: Scope) -> impl IntoView {
+  view! {
+    cx,
+    <div class=sk!(md hover p $2, block)>
+      <span class=sk!("bg:$red100 (important):$sr-only")>"Hello"</span>
+      <span class=sk!(md hovr p $2)>"Invalid"</span>
+    </div>
+  }
+}
+"#;
+
+const YEW: &str = r#"
+use yew::prelude::*;
+
+#[function_component]
+fn Yew() -> Html {
+  let items = vec!["a", "b"];
+
+  html! {
+    <div class={sk().md().p().n2()}>
+      <span class={classes!(sk().bg().red100(), "custom")}>{ "Hello" }</span>
+      <Child ..props.clone() />
+      { for items.iter().map(|item| html! { <b class={sk().important().sr_only()}>{ item }</b> }) }
+    </div>
+  }
+}
+"#;
+
+const DIOXUS: &str = r#"
+use dioxus::prelude::*;
+
+fn Dioxus(cx: Scope) -> Element {
+  cx.render(rsx! {
+    div {
+      class: sk().md().p().n2(),
+      onclick: move |_| {},
+      span {
+        class: sk().bg().red100(),
+        "Hello {name}"
+      }
+      for item in items {
+        b { class: sk().important().sr_only(), "{item}" }
+      }
+    }
+  })
+}
+"#;
+
+const SYCAMORE: &str = r#"
+use sycamore::prelude::*;
+
+#[component]
+fn Sycamore<G: Html>(cx: Scope) -> View<G> {
+  view! { cx,
+    div(class=sk().md().p().n2()) {
+      span(class=sk().bg().red100(), id="hello") { "Hello" }
+      (if visible { view! { cx, b(class=sk().important().sr_only()) } } else { view! { cx, } })
+    }
+  }
+}
+"#;
+
+const EXPRESSION_MACROS: &str = r#"
+pub fn classes() -> Vec<String> {
+  let name = format!("{} {}", sk().md().p().n2(), sk().bg().red100());
+  vec![name, sk().important().sr_only()]
+}
+"#;
+
+const CLASS_LIST: &str = r#"
+pub fn card(active: bool, dark: bool) -> String {
+  ClassList::new()
+    .add(sk().p().n2())
+    .add_if(active, sk().bg().red100())
+    .add_if(dark, if active { "text:$red500" } else { "text:$blue500" })
+    .extend(["md:p:$4", "not-a-class"])
+    .into()
+}
+"#;
+
+const STATIC_TRANSFORMERS: &str = r#"
+fn static_transformers() -> [String; 2] {
+  [
+    sk().md().alpha_("50%", sk().bg().red100()),
+    sk().mix_("white,20%", sk().bg().blue500()),
+  ]
+}
+"#;
+
+const LEPTOS_LITERALS: &str = r#"
+use leptos::*;
+
+#[component]
+fn Literals(cx: Scope) -> impl IntoView {
+  view! {
+    cx,
+    <div class="md:p:$2 hover:bg:$red500 flex">
+      <span class=("(important):$sr-only", move || true)>"Hello"</span>
+      <span class:underline=move || true title="p:$4">"Underlined"</span>
+    </div>
+  }
+}
+
+pub fn helper() -> String {
+  class_list("bg:$red100", ignored("p:$8"))
+}
+"#;
+
+const TEMPLATE_LITERALS: &str = r#"
+fn Yew() -> Html {
+  html! { <div class="md:p:$2">{ "yew" }</div> }
+}
+
+fn Dioxus(cx: Scope) -> Element {
+  cx.render(rsx! { div { class: "hover:bg:$red500 custom", "dioxus" } })
+}
+
+fn Sycamore<G: Html>(cx: Scope) -> View<G> {
+  view! { cx, div(class="(important):$sr-only") }
+}
+"#;
+
+const CHAIN_BINDINGS: &str = r#"
+fn card() -> GeneratedMediaQueryBreakpointsChild {
+  sk().md()
+}
+
+fn hover_card() -> GeneratedModifierActionChild {
+  let base = card();
+  base.hover()
+}
+
+pub fn component(base: GeneratedSkribbleRoot) -> String {
+  let md = sk().md();
+  let padding = md.p().px();
+  let shadowed = sk().dark();
+  let shadowed = other();
+  let colors = [card().bg().red100(), hover_card().bg().red500()].join(" ");
+  let unresolved = base.p().n4();
+
+  format!("{padding} {colors} {} {}", shadowed.p().n2(), md.important().sr_only())
+}
+"#;
+
+const PRUNED_ATOMS: &str = include_str!("../tests/fixtures/pruned_atoms.rs");
+
+const TAILWIND_COMPONENT: &str = r#"
+use leptos::*;
+
+#[component]
+fn Card(cx: Scope) -> impl IntoView {
+  view! {
+    cx,
+    <div class="md:hover:bg-red-500 p-4">
+      <h1 class="text-lg font-bold">"Título"</h1>
+      <p class="card-body mt-2">"Hello"</p>
+      <span class="custom">{move || view! { cx, <b class="underline-offset-2">"nested"</b> }}</span>
+    </div>
+  }
+}
+
+#[function_component]
+fn Yew() -> Html {
+  html! { <div class="flex">{ "yew" }</div> }
+}
+"#;
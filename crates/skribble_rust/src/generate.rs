@@ -1,12 +1,17 @@
 use std::fmt::Write;
 
+use heck::ToKebabCase;
 use indexmap::indexmap;
 use indexmap::IndexMap;
+use indexmap::IndexSet;
+use skribble_core::is_dynamic_color;
 use skribble_core::wrap_indent;
 use skribble_core::AnyEmptyResult;
 use skribble_core::AnyResult;
+use skribble_core::Atom;
 use skribble_core::AtomType;
 use skribble_core::LinkedValues;
+use skribble_core::Placeholder;
 use skribble_core::PrioritizedString;
 use skribble_core::ToSkribbleCss;
 use skribble_core::TransformationScope;
@@ -15,6 +20,9 @@ use typed_builder::TypedBuilder;
 
 use super::indoc;
 use super::RunnerConfig;
+use super::RustCodeMode;
+use super::RustIntegration;
+use super::RustPlugin;
 use super::ToPascalCase;
 use super::ToSnakeCase;
 
@@ -29,10 +37,371 @@ struct StructProp {
 }
 
 type StructNames = IndexMap<String, StructProp>;
+/// The sections of every generated module. The root module has an empty name.
+type Modules = IndexMap<String, Vec<String>>;
+
+/// Renders the parts of the generated code which depend on the
+/// [`RustCodeMode`].
+struct Templates {
+	mode: RustCodeMode,
+	root_function: String,
+	variables_function: String,
+	visibility: String,
+	integrations: Vec<RustIntegration>,
+	split_modules: bool,
+	module_cfg: Option<String>,
+	/// The module which receives the generated items.
+	module: String,
+	/// The module of every generated trait and struct.
+	item_modules: IndexMap<String, String>,
+	/// The tokens referenced by the typestate paths of the static mode.
+	tokens: IndexSet<String>,
+	/// The classes of the aliases referenced by the typestate paths of the
+	/// static mode.
+	aliases: IndexSet<Vec<String>>,
+}
+
+impl Templates {
+	fn new(plugin: &RustPlugin) -> Self {
+		Self {
+			mode: plugin.mode,
+			root_function: plugin.root_function.clone(),
+			variables_function: plugin.variables_function.clone(),
+			visibility: plugin.visibility.clone(),
+			integrations: plugin.integrations.clone(),
+			split_modules: plugin.split_modules,
+			module_cfg: plugin.module_cfg.clone(),
+			module: String::new(),
+			item_modules: IndexMap::new(),
+			tokens: IndexSet::new(),
+			aliases: IndexSet::new(),
+		}
+	}
+
+	fn get_token_index(&mut self, token: impl Into<String>) -> usize {
+		self.tokens.insert_full(token.into()).0
+	}
+
+	/// Set the module which receives the following items. Everything is
+	/// generated in the root module unless the modules are split.
+	fn enter_module(&mut self, name: impl Into<String>) -> String {
+		self.module = if self.split_modules {
+			name.into()
+		} else {
+			String::new()
+		};
+
+		self.module.clone()
+	}
+
+	fn register_item(&mut self, name: impl Into<String>) {
+		self.item_modules.insert(name.into(), self.module.clone());
+	}
+
+	fn get_cfg_predicate(&self, module: impl AsRef<str>) -> Option<String> {
+		let module = module.as_ref().to_kebab_case();
+		self.module_cfg
+			.as_ref()
+			.map(|cfg| cfg.replace("{module}", &module))
+	}
+
+	/// The `cfg` attribute of an item which references the items of other
+	/// gated modules.
+	fn get_cfg_attribute(&self, items: &[&str]) -> String {
+		let predicates = items
+			.iter()
+			.filter_map(|item| {
+				let module = self.item_modules.get(*item)?;
+
+				if module.is_empty() || module == &self.module {
+					return None;
+				}
+
+				self.get_cfg_predicate(module)
+			})
+			.collect::<IndexSet<_>>()
+			.into_iter()
+			.collect::<Vec<_>>();
+
+		match predicates.len() {
+			0 => String::new(),
+			1 => format!("#[cfg({})]\n", predicates.join(", ")),
+			_ => format!("#[cfg(all({}))]\n", predicates.join(", ")),
+		}
+	}
+
+	fn generate_trait(&mut self, trait_name: impl AsRef<str>) -> String {
+		let trait_name = trait_name.as_ref();
+		self.register_item(trait_name);
+
+		format!(
+			"{} trait {}: GeneratedSkribbleValue {{",
+			self.visibility, trait_name
+		)
+	}
+
+	/// The struct which is returned by the methods of a chain.
+	fn generate_struct(&mut self, name: impl AsRef<str>) -> String {
+		let name = name.as_ref();
+		self.register_item(name);
+
+		match self.mode {
+			RustCodeMode::Owned => {
+				format!(
+					"{}\n{}",
+					generate_struct(name, &self.visibility),
+					generate_impl_skribble_value(name)
+				)
+			}
+			RustCodeMode::Static => {
+				format!(
+					indoc!(
+						"
+            {1} struct {0}<P>(PhantomData<P>);
+            impl<P: private::GeneratedSkribblePath> GeneratedSkribbleValue for {0}<P> {{
+              type Path = P;
+              #[inline]
+              fn new() -> Self {{
+                Self(PhantomData)
+              }}
+            }}"
+					),
+					name, self.visibility
+				)
+			}
+		}
+	}
+
+	fn impl_trait(&self, trait_name: impl AsRef<str>, struct_name: impl AsRef<str>) -> String {
+		let trait_name = trait_name.as_ref();
+		let struct_name = struct_name.as_ref();
+		let cfg = self.get_cfg_attribute(&[trait_name, struct_name]);
+
+		match self.mode {
+			RustCodeMode::Owned => format!("{cfg}impl {trait_name} for {struct_name} {{}}"),
+			RustCodeMode::Static => {
+				format!(
+					"{cfg}impl<P: private::GeneratedSkribblePath> {trait_name} for \
+					 {struct_name}<P> {{}}"
+				)
+			}
+		}
+	}
+
+	/// A method which continues the chain. The `append` expression is used by
+	/// the owned mode and the `token` is added to the typestate path by the
+	/// static mode.
+	fn chain_method(
+		&mut self,
+		method_name: &str,
+		struct_name: &str,
+		append: impl AsRef<str>,
+		token: impl Into<String>,
+	) -> String {
+		let (return_type, body) = match self.mode {
+			RustCodeMode::Owned => {
+				(
+					struct_name.to_string(),
+					format!("{struct_name}::from_ref({})", append.as_ref()),
+				)
+			}
+			RustCodeMode::Static => {
+				let index = self.get_token_index(token);
+				(
+					format!("{struct_name}<private::Token<Self::Path, {index}>>"),
+					format!("{struct_name}::new()"),
+				)
+			}
+		};
+
+		generate_method(method_name, return_type, body)
+	}
+
+	/// A method which completes the chain with a value.
+	fn value_method(&mut self, method_name: &str, value: impl Into<String>) -> String {
+		match self.mode {
+			RustCodeMode::Owned => {
+				let value = value.into();
+				generate_method(
+					method_name,
+					"String",
+					format!("self.append_value(\"{value}\")"),
+				)
+			}
+			RustCodeMode::Static => {
+				let index = self.get_token_index(value);
+				generate_method(
+					method_name,
+					"&'static str",
+					format!("private::Class::<private::Value<Self::Path, {index}>>::VALUE"),
+				)
+			}
+		}
+	}
+
+	/// A method which completes the chain with the classes of an alias.
+	fn alias_method(&mut self, method_name: &str, classes: &[String]) -> String {
+		match self.mode {
+			RustCodeMode::Owned => {
+				let classes = classes
+					.iter()
+					.map(|class| format!("\"{class}\""))
+					.collect::<Vec<String>>()
+					.join(", ");
+				generate_method(
+					method_name,
+					"String",
+					format!("[{classes}].map(|class| self.append(class)).join(\" \")"),
+				)
+			}
+			RustCodeMode::Static => {
+				let index = self.aliases.insert_full(classes.to_vec()).0;
+				generate_method(
+					method_name,
+					"&'static str",
+					format!("private::Class::<private::Alias<Self::Path, {index}>>::VALUE"),
+				)
+			}
+		}
+	}
+
+	/// A method of the css variables struct.
+	fn variable_method(&self, method_name: &str, variable_name: &str) -> String {
+		let (signature, body) = match self.mode {
+			RustCodeMode::Owned => {
+				(
+					format!("#[inline]\npub fn {method_name}(&self) -> String {{"),
+					format!("\"{variable_name}\".into()"),
+				)
+			}
+			RustCodeMode::Static => {
+				(
+					format!("#[inline]\npub const fn {method_name}(&self) -> &'static str {{"),
+					format!("\"{variable_name}\""),
+				)
+			}
+		};
+
+		[
+			wrap_indent(signature, 1),
+			wrap_indent(body, 2),
+			wrap_indent("}", 1),
+		]
+		.join("\n")
+	}
+
+	/// The header with the configured root function and visibility, followed by
+	/// the `ClassList` and the enabled integrations.
+	fn get_header(&self) -> String {
+		let header = match self.mode {
+			RustCodeMode::Owned => HEADER,
+			RustCodeMode::Static => STATIC_HEADER,
+		};
+
+		let mut header = header
+			.replace("$visibility", &self.visibility)
+			.replace("$root_function", &self.root_function);
+
+		header.push('\n');
+		header.push_str(&CLASS_LIST.replace("$visibility", &self.visibility));
+
+		for integration in self.integrations.iter() {
+			let content = match integration {
+				RustIntegration::Leptos => LEPTOS_INTEGRATION,
+			};
+
+			header.push('\n');
+			header.push_str(content);
+		}
+
+		header
+	}
+
+	/// Join the sections of the root module with the wrapped sections of the
+	/// other modules. Every module is re-exported from the root.
+	fn join_modules(&self, mut modules: Modules) -> String {
+		let mut sections = modules.shift_remove("").unwrap_or_default();
+
+		for (name, content) in modules {
+			if content.iter().all(|section| section.is_empty()) {
+				continue;
+			}
+
+			let cfg = self
+				.get_cfg_predicate(&name)
+				.map(|predicate| format!("#[cfg({predicate})]\n"))
+				.unwrap_or_default();
+
+			sections.push(format!(
+				"{cfg}mod {name} {{\n{}\n{}\n}}",
+				wrap_indent("use super::*;", 1),
+				wrap_indent(content.join("\n"), 1)
+			));
+			sections.push(format!("{cfg}{} use {name}::*;", self.visibility));
+		}
+
+		sections.join("\n")
+	}
+
+	fn combine_sections_with_header(&self, modules: Modules) -> String {
+		let header = self.get_header();
+		let content = self.join_modules(modules);
+
+		match self.mode {
+			RustCodeMode::Owned => format!("{header}\n{content}"),
+			RustCodeMode::Static => {
+				let tokens = self
+					.tokens
+					.iter()
+					.map(|token| wrap_indent(format!("{token:?},"), 1))
+					.collect::<Vec<_>>()
+					.join("\n");
+				let aliases = self
+					.aliases
+					.iter()
+					.map(|classes| {
+						let classes = classes
+							.iter()
+							.map(|class| format!("{class:?}"))
+							.collect::<Vec<_>>()
+							.join(", ");
+						wrap_indent(format!("&[{classes}],"), 1)
+					})
+					.collect::<Vec<_>>()
+					.join("\n");
+
+				format!(
+					"{header}\n{content}\nconst TOKENS: &[&str] = &[\n{tokens}\n];\nconst \
+					 ALIASES: &[&[&str]] = &[\n{aliases}\n];"
+				)
+			}
+		}
+	}
+}
+
+fn generate_method(
+	method_name: &str,
+	return_type: impl AsRef<str>,
+	body: impl AsRef<str>,
+) -> String {
+	[
+		wrap_indent(
+			format!(
+				"#[inline]\nfn {method_name}(&self) -> {} {{",
+				return_type.as_ref()
+			),
+			1,
+		),
+		wrap_indent(body, 2),
+		wrap_indent("}", 1),
+	]
+	.join("\n")
+}
 
 fn generate_media_queries(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 	struct_names_map: &mut StructNames,
 	trait_names: &mut Vec<String>,
@@ -41,10 +410,9 @@ fn generate_media_queries(
 		let mut section = Vec::<String>::new();
 		let trait_name = format!("GeneratedMediaQuery{}", key.to_pascal_case());
 		let struct_name = format!("{trait_name}Child");
-		section.push(generate_struct(&struct_name));
-		section.push(generate_impl_skribble_value(&struct_name));
+		section.push(templates.generate_struct(&struct_name));
 
-		let mut methods = vec![format!("pub trait {trait_name}: GeneratedSkribbleValue {{")];
+		let mut methods = vec![templates.generate_trait(&trait_name)];
 
 		for (name, media_query) in map.iter() {
 			let method_name = get_method_name(name, GLOBAL_PREFIX, method_names)?;
@@ -62,15 +430,85 @@ fn generate_media_queries(
 			}
 
 			methods.push(css_docs);
+			methods.push(templates.chain_method(
+				&method_name,
+				&struct_name,
+				format!("self.append(\"{name}\")"),
+				name,
+			));
 			methods.push(wrap_indent(
-				format!("#[inline]\nfn {method_name}(&self) -> {struct_name} {{"),
+				format!(
+					"#[inline]\nfn {method_name}_(&self, property: &'static str, value: &'static \
+					 str) -> String {{"
+				),
 				1,
 			));
 			methods.push(wrap_indent(
-				format!("{struct_name}::from_ref(self.append(\"{name}\"))"),
+				format!(
+					"self.append(format!(\"{name}:[{{}}={{}}]\", property.trim(), value.trim()))"
+				),
 				2,
 			));
 			methods.push(wrap_indent("}", 1));
+		}
+
+		methods.push("}".into());
+		section.push(methods.join("\n"));
+
+		trait_names.push(trait_name);
+		struct_names_map.insert(
+			struct_name,
+			StructProp::builder().index(trait_names.len()).build(),
+		);
+		sections.push(section.join("\n"));
+	}
+
+	Ok(())
+}
+
+fn media_query_docs(query: impl AsRef<str>) -> String {
+	let query = query.as_ref();
+	format!("@media {query} {{\n  /* ... */\n}}")
+}
+
+fn generate_feature_queries(
+	config: &RunnerConfig,
+	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
+	sections: &mut Vec<String>,
+	struct_names_map: &mut StructNames,
+	trait_names: &mut Vec<String>,
+) -> AnyEmptyResult {
+	for (key, map) in config.feature_queries.iter() {
+		let mut section = Vec::<String>::new();
+		let trait_name = format!("GeneratedFeatureQuery{}", key.to_pascal_case());
+		let struct_name = format!("{trait_name}Child");
+		section.push(templates.generate_struct(&struct_name));
+
+		let mut methods = vec![templates.generate_trait(&trait_name)];
+
+		for (name, feature_query) in map.iter() {
+			let method_name = get_method_name(name, GLOBAL_PREFIX, method_names)?;
+			let css_docs = wrap_indent(
+				wrap_docs(wrap_in_code_block(
+					feature_query_docs(&feature_query.query),
+					"css",
+				)),
+				1,
+			);
+
+			if let Some(ref description) = feature_query.description {
+				methods.push(wrap_indent(wrap_docs(description), 1));
+				methods.push(wrap_indent(wrap_docs("\n"), 1));
+			}
+
+			methods.push(css_docs);
+			methods.push(templates.chain_method(
+				&method_name,
+				&struct_name,
+				format!("self.append(\"{name}\")"),
+				name,
+			));
 			methods.push(wrap_indent(
 				format!(
 					"#[inline]\nfn {method_name}_(&self, property: &'static str, value: &'static \
@@ -101,9 +539,82 @@ fn generate_media_queries(
 	Ok(())
 }
 
-fn media_query_docs(query: impl AsRef<str>) -> String {
+fn feature_query_docs(query: impl AsRef<str>) -> String {
 	let query = query.as_ref();
-	format!("@media {query} {{\n  /* ... */\n}}")
+	format!("@supports {query} {{\n  /* ... */\n}}")
+}
+
+fn generate_container_queries(
+	config: &RunnerConfig,
+	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
+	sections: &mut Vec<String>,
+	struct_names_map: &mut StructNames,
+	trait_names: &mut Vec<String>,
+) -> AnyEmptyResult {
+	for (key, map) in config.container_queries.iter() {
+		let mut section = Vec::<String>::new();
+		let trait_name = format!("GeneratedContainerQuery{}", key.to_pascal_case());
+		let struct_name = format!("{trait_name}Child");
+		section.push(templates.generate_struct(&struct_name));
+
+		let mut methods = vec![templates.generate_trait(&trait_name)];
+
+		for (name, container_query) in map.iter() {
+			let method_name = get_method_name(name, GLOBAL_PREFIX, method_names)?;
+			let css_docs = wrap_indent(
+				wrap_docs(wrap_in_code_block(
+					container_query_docs(container_query.condition()),
+					"css",
+				)),
+				1,
+			);
+
+			if let Some(ref description) = container_query.description {
+				methods.push(wrap_indent(wrap_docs(description), 1));
+				methods.push(wrap_indent(wrap_docs("\n"), 1));
+			}
+
+			methods.push(css_docs);
+			methods.push(templates.chain_method(
+				&method_name,
+				&struct_name,
+				format!("self.append(\"{name}\")"),
+				name,
+			));
+			methods.push(wrap_indent(
+				format!(
+					"#[inline]\nfn {method_name}_(&self, property: &'static str, value: &'static \
+					 str) -> String {{"
+				),
+				1,
+			));
+			methods.push(wrap_indent(
+				format!(
+					"self.append(format!(\"{name}:[{{}}={{}}]\", property.trim(), value.trim()))"
+				),
+				2,
+			));
+			methods.push(wrap_indent("}", 1));
+		}
+
+		methods.push("}".into());
+		section.push(methods.join("\n"));
+
+		trait_names.push(trait_name);
+		struct_names_map.insert(
+			struct_name,
+			StructProp::builder().index(trait_names.len()).build(),
+		);
+		sections.push(section.join("\n"));
+	}
+
+	Ok(())
+}
+
+fn container_query_docs(condition: impl AsRef<str>) -> String {
+	let condition = condition.as_ref();
+	format!("@container {condition} {{\n  /* ... */\n}}")
 }
 
 fn modifier_docs(values: &[String]) -> String {
@@ -114,6 +625,7 @@ fn modifier_docs(values: &[String]) -> String {
 fn generate_modifiers(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 	struct_names_map: &mut StructNames,
 	trait_names: &mut Vec<String>,
@@ -122,10 +634,9 @@ fn generate_modifiers(
 		let mut section = Vec::<String>::new();
 		let trait_name = format!("GeneratedModifier{}", key.to_pascal_case());
 		let struct_name = format!("{trait_name}Child");
-		section.push(generate_struct(&struct_name));
-		section.push(generate_impl_skribble_value(&struct_name));
+		section.push(templates.generate_struct(&struct_name));
 
-		let mut methods = vec![format!("pub trait {trait_name}: GeneratedSkribbleValue {{")];
+		let mut methods = vec![templates.generate_trait(&trait_name)];
 
 		for (name, modifier) in map.iter() {
 			let method_name = get_method_name(name, GLOBAL_PREFIX, method_names)?;
@@ -140,15 +651,12 @@ fn generate_modifiers(
 			}
 
 			methods.push(css_docs);
-			methods.push(wrap_indent(
-				format!("#[inline]\nfn {method_name}(&self) -> {struct_name} {{"),
-				1,
-			));
-			methods.push(wrap_indent(
-				format!("{struct_name}::from_ref(self.append(\"{name}\"))"),
-				2,
+			methods.push(templates.chain_method(
+				&method_name,
+				&struct_name,
+				format!("self.append(\"{name}\")"),
+				name,
 			));
-			methods.push(wrap_indent("}", 1));
 
 			methods.push(wrap_indent(
 				format!(
@@ -183,6 +691,7 @@ fn generate_modifiers(
 fn generate_transformers(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 	struct_names_map: &mut StructNames,
 	trait_names: &mut Vec<String>,
@@ -190,14 +699,13 @@ fn generate_transformers(
 	for (key, map) in config.transformers.iter() {
 		let mut section = Vec::<String>::new();
 		let trait_name = format!("GeneratedTransformer{}", key.to_pascal_case());
-		let mut methods = vec![format!("pub trait {trait_name}: GeneratedSkribbleValue {{")];
+		let mut methods = vec![templates.generate_trait(&trait_name)];
 		trait_names.push(trait_name.clone());
 
 		for (name, transformer) in map.iter() {
 			let method_name = get_method_name(name, TRANSFORMER_PREFIX, method_names)?;
 			let struct_name = format!("{trait_name}Group{}Child", name.to_pascal_case());
-			section.push(generate_struct(&struct_name));
-			section.push(generate_impl_skribble_value(&struct_name));
+			section.push(templates.generate_struct(&struct_name));
 
 			match transformer.values.as_ref() {
 				None => {
@@ -206,17 +714,12 @@ fn generate_transformers(
 						methods.push(wrap_indent(wrap_docs("\n"), 1));
 					}
 
-					methods.push(wrap_indent(
-						format!("#[inline]\nfn {method_name}(&self) -> {struct_name} {{"),
-						1,
-					));
-					methods.push(wrap_indent(
-						format!(
-							"{struct_name}::from_ref(self.append_transformer(\"{name}\", None))"
-						),
-						2,
+					methods.push(templates.chain_method(
+						&method_name,
+						&struct_name,
+						format!("self.append_transformer(\"{name}\", None)"),
+						format!("({name})"),
 					));
-					methods.push(wrap_indent("}", 1));
 				}
 				Some(values) => {
 					if let Some(ref description) = transformer.description {
@@ -224,21 +727,44 @@ fn generate_transformers(
 						methods.push(wrap_indent(wrap_docs("\n"), 1));
 					}
 
-					methods.push(wrap_indent(
-						format!(
-							"#[inline]\nfn {method_name}(&self, value: &'static str,) -> \
-							 {struct_name} {{"
-						),
-						1,
-					));
-					methods.push(wrap_indent(
-						format!(
-							"{struct_name}::from_ref(self.append_transformer(\"{name}\", \
-							 Some(value)))"
-						),
-						2,
-					));
-					methods.push(wrap_indent("}", 1));
+					match templates.mode {
+						RustCodeMode::Owned => {
+							methods.push(wrap_indent(
+								format!(
+									"#[inline]\nfn {method_name}(&self, value: &'static str,) -> \
+									 {struct_name} {{"
+								),
+								1,
+							));
+							methods.push(wrap_indent(
+								format!(
+									"{struct_name}::from_ref(self.append_transformer(\"{name}\", \
+									 Some(value)))"
+								),
+								2,
+							));
+							methods.push(wrap_indent("}", 1));
+						}
+						// Arbitrary values can't be part of the typestate so the
+						// transformer is applied to the provided class name.
+						RustCodeMode::Static => {
+							methods.push(wrap_indent(
+								format!(
+									"#[inline]\nfn {method_name}_(&self, value: &'static str, \
+									 class: &str) -> String {{"
+								),
+								1,
+							));
+							methods.push(wrap_indent(
+								format!(
+									"self.append(format!(\"({name}={{}}):{{}}\", value.trim(), \
+									 class))"
+								),
+								2,
+							));
+							methods.push(wrap_indent("}", 1));
+						}
+					}
 
 					for (value_name, _value) in values.iter() {
 						let value_method_name = get_method_name(
@@ -246,18 +772,12 @@ fn generate_transformers(
 							TRANSFORMER_PREFIX,
 							method_names,
 						)?;
-						methods.push(wrap_indent(
-							format!("#[inline]\nfn {value_method_name}(&self) -> {struct_name} {{"),
-							1,
-						));
-						methods.push(wrap_indent(
-							format!(
-								"{struct_name}::from_ref(self.append_transformer(\"{name}\", \
-								 Some(\"={value_name}\")))"
-							),
-							2,
+						methods.push(templates.chain_method(
+							&value_method_name,
+							&struct_name,
+							format!("self.append_transformer(\"{name}\", Some(\"={value_name}\"))"),
+							format!("({name}=={value_name})"),
 						));
-						methods.push(wrap_indent("}", 1));
 					}
 				}
 			}
@@ -309,13 +829,12 @@ fn get_keyframe_trait_name(keyframe_name: impl AsRef<str>) -> String {
 fn generate_keyframes(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 ) -> AnyEmptyResult {
 	for (name, keyframe) in config.keyframes.iter() {
 		let keyframe_trait_name = get_keyframe_trait_name(name);
-		sections.push(format!(
-			"pub trait {keyframe_trait_name}: GeneratedSkribbleValue {{"
-		));
+		sections.push(templates.generate_trait(&keyframe_trait_name));
 		let method_name = get_method_name(name, KEYFRAMES_PREFIX, method_names)?;
 
 		let css_docs = wrap_indent(
@@ -330,14 +849,7 @@ fn generate_keyframes(
 
 		sections.push(css_docs);
 
-		sections.push(wrap_indent(
-			format!("#[inline]\nfn {method_name}(&self) -> String {{"),
-			1,
-		));
-
-		sections.push(wrap_indent(format!("self.append_value(\"{name}\")"), 2));
-
-		sections.push(wrap_indent("}", 1));
+		sections.push(templates.value_method(&method_name, name));
 		sections.push("}".into());
 	}
 
@@ -347,10 +859,11 @@ fn generate_keyframes(
 fn generate_named_classes(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 	trait_names: &mut Vec<String>,
 ) -> AnyEmptyResult {
-	sections.push("pub trait GeneratedNamedClasses: GeneratedSkribbleValue {".into());
+	sections.push(templates.generate_trait("GeneratedNamedClasses"));
 
 	for (name, named_class) in config.classes.iter() {
 		if named_class.is_reference() {
@@ -359,18 +872,22 @@ fn generate_named_classes(
 
 		let method_name = get_method_name(name, GLOBAL_PREFIX, method_names)?;
 
+		let mut css = String::new();
+		named_class.write_css_properties(&mut css, config)?;
+
 		if let Some(ref description) = named_class.description {
 			sections.push(wrap_indent(wrap_docs(description), 1));
-		}
 
-		sections.push(wrap_indent(
-			format!("#[inline]\nfn {method_name}(&self) -> String {{"),
-			1,
-		));
+			if !css.trim().is_empty() {
+				sections.push(wrap_indent(wrap_docs("\n"), 1));
+			}
+		}
 
-		sections.push(wrap_indent(format!("self.append_value(\"{name}\")"), 2));
+		if !css.trim().is_empty() {
+			sections.push(wrap_indent(wrap_docs(wrap_in_code_block(css, "css")), 1));
+		}
 
-		sections.push(wrap_indent("}", 1));
+		sections.push(templates.value_method(&method_name, name));
 	}
 
 	trait_names.push("GeneratedNamedClasses".into());
@@ -382,33 +899,25 @@ fn generate_named_classes(
 fn generate_aliases(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 	trait_names: &mut Vec<String>,
 ) -> AnyEmptyResult {
-	sections.push("pub trait GeneratedAliases: GeneratedSkribbleValue {".into());
+	sections.push(templates.generate_trait("GeneratedAliases"));
 
 	for (alias_name, alias) in config.aliases.iter() {
 		let method_name = get_method_name(alias_name, GLOBAL_PREFIX, method_names)?;
-		let classes = alias
-			.classes
-			.iter()
-			.map(|class| format!("\"{class}\""))
-			.collect::<Vec<String>>()
-			.join(", ");
-		let classes_array = format!("[{classes}].map(|class| self.append(class)).join(\" \")");
 
 		if let Some(ref description) = alias.description {
 			sections.push(wrap_indent(wrap_docs(description), 1));
 		}
 
-		sections.push(wrap_indent(
-			format!("#[inline]\nfn {method_name}(&self) -> String {{"),
-			1,
-		));
-
-		sections.push(wrap_indent(&classes_array, 2));
-
-		sections.push(wrap_indent("}", 1));
+		// Combined aliases are a single class in the stylesheet.
+		if alias.combined {
+			sections.push(templates.value_method(&method_name, alias_name));
+		} else {
+			sections.push(templates.alias_method(&method_name, &alias.classes));
+		}
 	}
 
 	trait_names.push("GeneratedAliases".into());
@@ -425,39 +934,47 @@ fn generate_atom_trait_name(name: impl AsRef<str>) -> String {
 fn generate_atoms(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
-	sections: &mut Vec<String>,
+	templates: &mut Templates,
+	modules: &mut Modules,
 	trait_names: &mut Vec<String>,
+	used_atoms: Option<&IndexSet<String>>,
 ) -> AnyEmptyResult {
-	let mut struct_content = Vec::<String>::new();
-	let mut trait_content = Vec::<String>::new();
+	let mut struct_contents = Modules::new();
+	let mut trait_contents = Modules::new();
 
 	for (atom_name, atom) in config.atoms.iter() {
 		let atom_trait_name = generate_atom_trait_name(atom_name);
 		let method_name = get_method_name(atom_name, GLOBAL_PREFIX, method_names)?;
 		let atom_struct_name = format!("{atom_trait_name}Child");
-		trait_content.push(format!(
-			"pub trait {atom_trait_name}: GeneratedSkribbleValue {{"
-		));
 
-		struct_content.push(generate_struct(&atom_struct_name));
-		struct_content.push(generate_impl_skribble_value(&atom_struct_name));
+		if used_atoms.map_or(false, |used_atoms| !used_atoms.contains(atom_name)) {
+			reserve_atom_method_names(atom_name, &atom.values, config, method_names)?;
+			continue;
+		}
+
+		let module = format!("{ATOM_MODULE_PREFIX}{}", atom_name.to_snake_case());
+		let sections = enter_module(module, templates, modules);
+		let struct_content = struct_contents.entry(templates.module.clone()).or_default();
+		let trait_content = trait_contents.entry(templates.module.clone()).or_default();
+		trait_content.push(templates.generate_trait(&atom_trait_name));
+
+		struct_content.push(templates.generate_struct(&atom_struct_name));
 
 		match atom.values {
 			LinkedValues::Color(ref color_field) => {
 				for name in color_field.named_fields.keys() {
 					let unique_name = format!("{atom_name}-{name}");
-					generate_color_method(&unique_name, sections, method_names, None)?;
+					generate_color_method(&unique_name, sections, method_names, templates, None)?;
 					let color_trait_name = get_color_trait_name(unique_name);
-					struct_content.push(format!(
-						"impl {color_trait_name} for {atom_struct_name} {{}}"
-					));
+					struct_content.extend(atom_value_docs(config, atom_name, atom, name)?);
+					struct_content.push(templates.impl_trait(&color_trait_name, &atom_struct_name));
 				}
 
 				let mut valid_color_names = Vec::<String>::new();
 
 				valid_color_names.extend(config.css_variables.iter().filter_map(
 					|(name, variable)| {
-						if variable.is_color() && !color_field.excluded.contains(name) {
+						if variable.is_color() && color_field.is_included(name) {
 							Some(name.clone())
 						} else {
 							None
@@ -466,7 +983,13 @@ fn generate_atoms(
 				));
 
 				if !color_field.disable_palette {
-					valid_color_names.extend(config.palette.keys().cloned());
+					valid_color_names.extend(
+						config
+							.palette
+							.keys()
+							.filter(|name| color_field.is_included(name))
+							.cloned(),
+					);
 				}
 
 				let fields = DEFAULT_COLOR_FIELDS.clone();
@@ -474,17 +997,16 @@ fn generate_atoms(
 
 				for name in valid_color_names.iter() {
 					let color_trait_name = get_color_trait_name(name);
-					struct_content.push(format!(
-						"impl {color_trait_name} for {atom_struct_name} {{}}"
-					));
+					struct_content.extend(atom_value_docs(config, atom_name, atom, name)?);
+					struct_content.push(templates.impl_trait(&color_trait_name, &atom_struct_name));
 				}
 			}
 			LinkedValues::Keyframes => {
 				for name in config.keyframes.keys() {
 					let keyframe_trait_name = get_keyframe_trait_name(name);
-					struct_content.push(format!(
-						"impl {keyframe_trait_name} for {atom_struct_name} {{}}"
-					));
+					struct_content.extend(atom_value_docs(config, atom_name, atom, name)?);
+					struct_content
+						.push(templates.impl_trait(&keyframe_trait_name, &atom_struct_name));
 				}
 			}
 			LinkedValues::Values(ref value_sets) => {
@@ -501,9 +1023,10 @@ fn generate_atoms(
 						get_method_name(value_name, atom_name, method_names)?;
 						let value_set_trait_name =
 							get_value_set_trait_name(value_set_name, value_name);
-						struct_content.push(format!(
-							"impl {value_set_trait_name} for {atom_struct_name} {{}}",
-						));
+						struct_content
+							.extend(atom_value_docs(config, atom_name, atom, value_name)?);
+						struct_content
+							.push(templates.impl_trait(&value_set_trait_name, &atom_struct_name));
 					}
 				}
 			}
@@ -514,18 +1037,13 @@ fn generate_atoms(
 			trait_content.push(wrap_indent(wrap_docs(description), 1));
 		}
 
-		trait_content.push(wrap_indent(
-			format!("#[inline]\nfn {method_name}(&self) -> {atom_struct_name} {{"),
-			1,
-		));
-
-		trait_content.push(wrap_indent(
-			format!("{atom_struct_name}::from_ref(self.append(\"{atom_name}\"))"),
-			2,
+		trait_content.push(templates.chain_method(
+			&method_name,
+			&atom_struct_name,
+			format!("self.append(\"{atom_name}\")"),
+			atom_name,
 		));
 
-		trait_content.push(wrap_indent("}", 1));
-
 		// The atom argument
 		trait_content.push(wrap_indent(
 			format!("#[inline]\nfn {method_name}_(&self, value: &'static str) -> String {{"),
@@ -542,8 +1060,47 @@ fn generate_atoms(
 		trait_names.push(atom_trait_name);
 	}
 
-	sections.push(struct_content.join("\n"));
-	sections.push(trait_content.join("\n"));
+	for (module, struct_content) in struct_contents {
+		let trait_content = trait_contents.shift_remove(&module).unwrap_or_default();
+		let sections = modules.entry(module).or_default();
+		sections.push(struct_content.join("\n"));
+		sections.push(trait_content.join("\n"));
+	}
+
+	Ok(())
+}
+
+/// Reserve the method names of an atom which isn't generated so that the
+/// method names of the scanned code don't depend on the pruned atoms.
+fn reserve_atom_method_names(
+	atom_name: &String,
+	values: &LinkedValues,
+	config: &RunnerConfig,
+	method_names: &mut IndexMap<String, String>,
+) -> AnyEmptyResult {
+	match values {
+		LinkedValues::Color(ref color_field) => {
+			for name in color_field.named_fields.keys() {
+				get_method_name(format!("{atom_name}-{name}"), COLORS_PREFIX, method_names)?;
+			}
+		}
+		LinkedValues::Keyframes => {}
+		LinkedValues::Values(ref value_sets) => {
+			for PrioritizedString {
+				value: value_set_name,
+				..
+			} in value_sets.iter()
+			{
+				let Some(value_set) = config.value_sets.get(value_set_name) else {
+					continue;
+				};
+
+				for value_name in value_set.values.keys() {
+					get_method_name(value_name, atom_name, method_names)?;
+				}
+			}
+		}
+	}
 
 	Ok(())
 }
@@ -560,6 +1117,7 @@ fn get_value_set_trait_name(
 fn generate_value_sets(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 ) -> AnyEmptyResult {
 	for (value_set_name, value_set) in config.value_sets.iter() {
@@ -571,21 +1129,16 @@ fn generate_value_sets(
 				method_names,
 			)?;
 
-			sections.push(format!(
-				"pub trait {value_set_trait_name}: GeneratedSkribbleValue {{",
-			));
-
+			sections.push(templates.generate_trait(&value_set_trait_name));
 			sections.push(wrap_indent(
-				format!("#[inline]\nfn {method_name}(&self) -> String {{"),
+				wrap_docs(format!(
+					"The `{value_name}` value of the `{value_set_name}` value set. The \
+					 declarations which each atom produces are documented on its implementation \
+					 of this trait."
+				)),
 				1,
 			));
-
-			sections.push(wrap_indent(
-				format!("self.append_value(\"{value_name}\")"),
-				2,
-			));
-
-			sections.push(wrap_indent("}", 1));
+			sections.push(templates.value_method(&method_name, value_name));
 
 			sections.push("}".into());
 		}
@@ -602,6 +1155,7 @@ fn get_color_trait_name(color_name: impl AsRef<str>) -> String {
 fn generate_colors(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 ) -> AnyEmptyResult {
 	for (name, css_variable) in config.css_variables.iter() {
@@ -614,8 +1168,8 @@ fn generate_colors(
 		let mut css_docs = String::new();
 
 		if let Some(ref description) = css_variable.description {
-			write!(css_docs, "{}", wrap_indent(wrap_docs(description), 1))?;
-			write!(css_docs, "{}", wrap_indent(wrap_docs("\n"), 1))?;
+			writeln!(css_docs, "{}", wrap_indent(wrap_docs(description), 1))?;
+			writeln!(css_docs, "{}", wrap_indent(wrap_docs("\n"), 1))?;
 		}
 
 		write!(
@@ -624,17 +1178,28 @@ fn generate_colors(
 			wrap_indent(wrap_docs(wrap_in_code_block(property_rule, "css")), 1)
 		)?;
 
-		generate_color_method(name, sections, method_names, Some(css_docs))?;
+		generate_color_method(name, sections, method_names, templates, Some(css_docs))?;
 	}
 
-	for name in config.palette.keys() {
-		generate_color_method(name, sections, method_names, None)?;
+	for (name, palette_value) in config.palette.iter() {
+		let color = if is_dynamic_color(palette_value) {
+			Placeholder::normalize(palette_value, config)
+		} else {
+			config
+				.options()
+				.color_format
+				.get_color(palette_value)?
+				.to_string()
+		};
+		let css_docs = wrap_indent(wrap_docs(color_swatch(color)), 1);
+
+		generate_color_method(name, sections, method_names, templates, Some(css_docs))?;
 	}
 
 	let named_colors = DEFAULT_COLOR_FIELDS.clone();
 
 	for name in named_colors.keys() {
-		generate_color_method(name, sections, method_names, None)?;
+		generate_color_method(name, sections, method_names, templates, None)?;
 	}
 
 	Ok(())
@@ -644,25 +1209,19 @@ fn generate_color_method(
 	name: &String,
 	sections: &mut Vec<String>,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	css_docs: Option<String>,
 ) -> AnyEmptyResult {
 	let method_name = get_method_name(name, COLORS_PREFIX, method_names)?;
 	let color_trait_name = get_color_trait_name(name);
 
-	sections.push(format!(
-		"pub trait {color_trait_name}: GeneratedSkribbleValue {{"
-	));
+	sections.push(templates.generate_trait(color_trait_name));
 
 	if let Some(docs) = css_docs {
 		sections.push(docs);
 	}
 
-	sections.push(wrap_indent(
-		format!("#[inline]\nfn {method_name}(&self) -> String {{"),
-		1,
-	));
-	sections.push(wrap_indent(format!("self.append_value(\"{name}\")"), 2));
-	sections.push(wrap_indent("}", 1));
+	sections.push(templates.value_method(&method_name, name));
 	sections.push("}".into());
 
 	Ok(())
@@ -671,19 +1230,26 @@ fn generate_color_method(
 fn generate_css_variables(
 	config: &RunnerConfig,
 	method_names: &mut IndexMap<String, String>,
+	templates: &mut Templates,
 	sections: &mut Vec<String>,
 ) -> AnyEmptyResult {
-	let mut entries = vec![
+	let constness = match templates.mode {
+		RustCodeMode::Owned => "",
+		RustCodeMode::Static => "const ",
+	};
+	let mut entries = vec![format!(
 		indoc!(
 			"
-    pub fn vars() -> GeneratedCssVariables {
+    {visibility} {constness}fn {name}() -> GeneratedCssVariables {{
       GeneratedCssVariables
-    }
-    pub struct GeneratedCssVariables;
-    impl GeneratedCssVariables {"
-		)
-		.into(),
-	];
+    }}
+    {visibility} struct GeneratedCssVariables;
+    impl GeneratedCssVariables {{"
+		),
+		visibility = templates.visibility,
+		constness = constness,
+		name = templates.variables_function,
+	)];
 
 	for (name, css_variable) in config.css_variables.iter() {
 		let method_name = get_method_name(name, VARIABLES_PREFIX, method_names)?;
@@ -699,14 +1265,7 @@ fn generate_css_variables(
 
 		entries.push(css_docs);
 
-		entries.push(wrap_indent(
-			format!("#[inline]\npub fn {method_name}(&self) -> String {{"),
-			1,
-		));
-
-		entries.push(wrap_indent(format!("\"{variable_name}\".into()",), 2));
-
-		entries.push(wrap_indent("}", 1));
+		entries.push(templates.variable_method(&method_name, &variable_name));
 	}
 
 	entries.push("}".into());
@@ -733,6 +1292,42 @@ fn wrap_in_code_block(content: impl AsRef<str>, r#type: impl AsRef<str>) -> Stri
 	)
 }
 
+/// Document the declarations which the atom produces for the value on a
+/// single line, rendered in the same way as the stylesheet.
+fn atom_value_docs(
+	config: &RunnerConfig,
+	atom_name: &str,
+	atom: &Atom,
+	value_name: &str,
+) -> AnyResult<Option<String>> {
+	let mut declarations = String::new();
+	atom.write_css_properties(&mut declarations, config, value_name, &IndexSet::new())?;
+
+	if declarations.trim().is_empty() {
+		return Ok(None);
+	}
+
+	let declarations = declarations
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty())
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	Ok(Some(wrap_docs(format!(
+		"`{atom_name}:${value_name}`: `{declarations}`"
+	))))
+}
+
+/// A swatch of the color which is rendered by rustdoc.
+fn color_swatch(color: impl AsRef<str>) -> String {
+	let color = color.as_ref();
+	format!(
+		"<span style=\"display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; \
+		 border-radius: 2px; background-color: {color};\"></span> `{color}`"
+	)
+}
+
 fn generate_impl_skribble_value(name: impl AsRef<str>) -> String {
 	format!(
 		indoc!(
@@ -753,11 +1348,12 @@ fn generate_impl_skribble_value(name: impl AsRef<str>) -> String {
 }
 
 fn generate_struct_implementations(
+	templates: &mut Templates,
 	struct_names_map: &StructNames,
 	trait_names: &[String],
-	sections: &mut Vec<String>,
+	modules: &mut Modules,
 ) {
-	let mut content = Vec::<String>::new();
+	let mut contents = Modules::new();
 	for (struct_name, prop) in struct_names_map.iter() {
 		for (index, trait_name) in trait_names.iter().enumerate() {
 			if prop.index > index {
@@ -776,16 +1372,34 @@ fn generate_struct_implementations(
 				}
 			}
 
-			content.push(format!("impl {trait_name} for {struct_name} {{}}"));
+			// The implementation is generated in the module of the trait.
+			let module = templates.item_modules.get(trait_name).cloned();
+			templates.enter_module(module.unwrap_or_default());
+			contents
+				.entry(templates.module.clone())
+				.or_default()
+				.push(templates.impl_trait(trait_name, struct_name));
 		}
 	}
 
-	sections.push(content.join("\n"));
+	for (module, content) in contents {
+		modules.entry(module).or_default().push(content.join("\n"));
+	}
+}
+
+/// Route the following sections and items to the module.
+fn enter_module<'a>(
+	name: impl Into<String>,
+	templates: &mut Templates,
+	modules: &'a mut Modules,
+) -> &'a mut Vec<String> {
+	let module = templates.enter_module(name);
+	modules.entry(module).or_default()
 }
 
-fn generate_struct(name: impl AsRef<str>) -> String {
+fn generate_struct(name: impl AsRef<str>, visibility: &str) -> String {
 	let name = name.as_ref();
-	format!("pub struct {name}(String);")
+	format!("{visibility} struct {name}(String);")
 }
 
 fn get_method_name(
@@ -858,6 +1472,14 @@ const RESERVED_WORDS: &[&str] = &[
 	"type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
 ];
 const ATOM_TRAIT_NAME: &str = "GeneratedAtom";
+const ATOM_MODULE_PREFIX: &str = "atom_";
+const MEDIA_QUERIES_MODULE: &str = "media_queries";
+const MODIFIERS_MODULE: &str = "modifiers";
+const TRANSFORMERS_MODULE: &str = "transformers";
+const KEYFRAMES_MODULE: &str = "keyframes";
+const PALETTE_MODULE: &str = "palette";
+const VALUE_SETS_MODULE: &str = "value_sets";
+const CLASSES_MODULE: &str = "classes";
 pub(crate) const GLOBAL_PREFIX: &str = "global";
 pub(crate) const VALUE_SET_PREFIX: &str = "values";
 pub(crate) const TRANSFORMER_PREFIX: &str = "transformers";
@@ -869,10 +1491,10 @@ const HEADER: &str = r#"#![allow(clippy::all)]
 #![allow(unused)]
 // This file was generated by skribble.
 use private::GeneratedSkribbleValue;
-pub fn sk() -> GeneratedSkribbleRoot {
+$visibility fn $root_function() -> GeneratedSkribbleRoot {
   GeneratedSkribbleRoot::from_ref("")
 }
-pub struct GeneratedSkribbleRoot(String);
+$visibility struct GeneratedSkribbleRoot(String);
 impl GeneratedSkribbleValue for GeneratedSkribbleRoot {
   #[inline]
   fn from_ref(value: impl AsRef<str>) -> Self {
@@ -919,50 +1541,329 @@ mod private {
   }
 }"#;
 
-fn combine_sections_with_header(sections: Vec<String>) -> String {
-	format!("{HEADER}\n{}", sections.join("\n"))
+const CLASS_LIST: &str = r#"/// A list of class names which can be built up conditionally.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+$visibility struct ClassList(Vec<String>);
+impl ClassList {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+  /// Add the class names.
+  #[inline]
+  pub fn add(mut self, class: impl Into<String>) -> Self {
+    let class = class.into();
+    if !class.is_empty() {
+      self.0.push(class);
+    }
+    self
+  }
+  /// Add the class names when the condition is `true`.
+  #[inline]
+  pub fn add_if(self, condition: bool, class: impl Into<String>) -> Self {
+    if condition {
+      self.add(class)
+    } else {
+      self
+    }
+  }
+  /// Add the class names of every item.
+  #[inline]
+  pub fn extend<C: Into<String>>(self, classes: impl IntoIterator<Item = C>) -> Self {
+    classes.into_iter().fold(self, |list, class| list.add(class))
+  }
+}
+impl core::fmt::Display for ClassList {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&self.0.join(" "))
+  }
+}
+impl From<ClassList> for String {
+  #[inline]
+  fn from(list: ClassList) -> Self {
+    list.0.join(" ")
+  }
+}"#;
+
+const LEPTOS_INTEGRATION: &str = r#"impl leptos::IntoAttribute for ClassList {
+  #[inline]
+  fn into_attribute(self, cx: leptos::Scope) -> leptos::Attribute {
+    leptos::IntoAttribute::into_attribute(String::from(self), cx)
+  }
+  #[inline]
+  fn into_attribute_boxed(self: Box<Self>, cx: leptos::Scope) -> leptos::Attribute {
+    leptos::IntoAttribute::into_attribute(String::from(*self), cx)
+  }
+}"#;
+
+const STATIC_HEADER: &str = r#"#![allow(clippy::all)]
+#![allow(unused)]
+// This file was generated by skribble.
+extern crate alloc;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use private::GeneratedSkribbleValue;
+$visibility const fn $root_function() -> GeneratedSkribbleRoot<private::Root> {
+  GeneratedSkribbleRoot(PhantomData)
+}
+$visibility struct GeneratedSkribbleRoot<P>(PhantomData<P>);
+impl<P: private::GeneratedSkribblePath> GeneratedSkribbleValue for GeneratedSkribbleRoot<P> {
+  type Path = P;
+  #[inline]
+  fn new() -> Self {
+    Self(PhantomData)
+  }
 }
+impl<P: private::GeneratedSkribblePath> GeneratedSkribbleRoot<P> {
+  pub fn __(&self, property: &'static str, value: &'static str) -> String {
+    self.append(format!("[{}={}]", property.trim(), value.trim()))
+  }
+}
+mod private {
+  use super::format;
+  use super::PhantomData;
+  use super::String;
+  /// The maximum length of a class name.
+  const CAPACITY: usize = 256;
+  /// A fixed size buffer which builds the class names at compile time.
+  #[doc(hidden)]
+  #[derive(Clone, Copy)]
+  pub struct Buffer {
+    bytes: [u8; CAPACITY],
+    len: usize,
+  }
+  impl Buffer {
+    const EMPTY: Self = Self { bytes: [0; CAPACITY], len: 0 };
+    const fn write(mut self, value: &str) -> Self {
+      let bytes = value.as_bytes();
+      let mut index = 0;
+      while index < bytes.len() {
+        if self.len == CAPACITY {
+          panic!("the skribble class name is too long");
+        }
+        self.bytes[self.len] = bytes[index];
+        self.len += 1;
+        index += 1;
+      }
+      self
+    }
+    const fn token(self, value: &str) -> Self {
+      if self.len == 0 {
+        self.write(value)
+      } else {
+        self.write(":").write(value)
+      }
+    }
+    const fn value(self, value: &str) -> Self {
+      self.token("$").write(value)
+    }
+    const fn classes(self, classes: &[&str]) -> Self {
+      let mut result = Self::EMPTY;
+      let mut index = 0;
+      while index < classes.len() {
+        if index > 0 {
+          result = result.write(" ");
+        }
+        result = result.write(self.token(classes[index]).as_str());
+        index += 1;
+      }
+      result
+    }
+    const fn as_str(&self) -> &str {
+      let mut bytes: &[u8] = &self.bytes;
+      while bytes.len() > self.len {
+        if let [rest @ .., _] = bytes {
+          bytes = rest;
+        }
+      }
+      match core::str::from_utf8(bytes) {
+        Ok(value) => value,
+        Err(_) => panic!("the skribble class name is not valid utf-8"),
+      }
+    }
+  }
+  #[doc(hidden)]
+  pub trait GeneratedSkribblePath {
+    const BUFFER: Buffer;
+  }
+  #[doc(hidden)]
+  pub struct Root;
+  impl GeneratedSkribblePath for Root {
+    const BUFFER: Buffer = Buffer::EMPTY;
+  }
+  #[doc(hidden)]
+  pub struct Token<P, const N: usize>(PhantomData<P>);
+  impl<P: GeneratedSkribblePath, const N: usize> GeneratedSkribblePath for Token<P, N> {
+    const BUFFER: Buffer = P::BUFFER.token(super::TOKENS[N]);
+  }
+  #[doc(hidden)]
+  pub struct Value<P, const N: usize>(PhantomData<P>);
+  impl<P: GeneratedSkribblePath, const N: usize> GeneratedSkribblePath for Value<P, N> {
+    const BUFFER: Buffer = P::BUFFER.value(super::TOKENS[N]);
+  }
+  #[doc(hidden)]
+  pub struct Alias<P, const N: usize>(PhantomData<P>);
+  impl<P: GeneratedSkribblePath, const N: usize> GeneratedSkribblePath for Alias<P, N> {
+    const BUFFER: Buffer = P::BUFFER.classes(super::ALIASES[N]);
+  }
+  #[doc(hidden)]
+  pub struct Class<P>(PhantomData<P>);
+  impl<P: GeneratedSkribblePath> Class<P> {
+    const BUFFER: &'static Buffer = &P::BUFFER;
+    /// The class name which is computed at compile time.
+    pub const VALUE: &'static str = Self::BUFFER.as_str();
+  }
+  #[doc(hidden)]
+  pub trait GeneratedSkribbleValue {
+    type Path: GeneratedSkribblePath;
+    fn new() -> Self;
+    #[inline]
+    fn append(&self, value: impl AsRef<str>) -> String {
+      let current_value = Class::<Self::Path>::VALUE;
+
+      if current_value.is_empty() {
+        value.as_ref().into()
+      } else {
+        format!("{current_value}:{}", value.as_ref())
+      }
+    }
+  }
+}"#;
 
 pub(crate) fn generate_file_contents(
 	config: &RunnerConfig,
+	plugin: &RustPlugin,
+	used_atoms: Option<&IndexSet<String>>,
 ) -> AnyResult<(String, IndexMap<String, String>)> {
 	let mut method_names = IndexMap::<String, String>::new();
+	let mut templates = Templates::new(plugin);
 
-	let mut sections = Vec::<String>::new();
+	let mut modules = Modules::new();
 	let mut trait_names = vec![];
 	let mut struct_names_map: StructNames = indexmap! {
 	  "GeneratedSkribbleRoot".into() => StructProp::builder().index(0).build()
 	};
 
-	generate_css_variables(config, &mut method_names, &mut sections)?;
+	let sections = enter_module("", &mut templates, &mut modules);
+	generate_css_variables(config, &mut method_names, &mut templates, sections)?;
+
+	let sections = enter_module(MEDIA_QUERIES_MODULE, &mut templates, &mut modules);
 	generate_media_queries(
 		config,
 		&mut method_names,
-		&mut sections,
+		&mut templates,
+		sections,
 		&mut struct_names_map,
 		&mut trait_names,
 	)?;
+
+	let sections = enter_module(MEDIA_QUERIES_MODULE, &mut templates, &mut modules);
+	generate_feature_queries(
+		config,
+		&mut method_names,
+		&mut templates,
+		sections,
+		&mut struct_names_map,
+		&mut trait_names,
+	)?;
+
+	let sections = enter_module(MEDIA_QUERIES_MODULE, &mut templates, &mut modules);
+	generate_container_queries(
+		config,
+		&mut method_names,
+		&mut templates,
+		sections,
+		&mut struct_names_map,
+		&mut trait_names,
+	)?;
+
+	let sections = enter_module(MODIFIERS_MODULE, &mut templates, &mut modules);
 	generate_modifiers(
 		config,
 		&mut method_names,
-		&mut sections,
+		&mut templates,
+		sections,
 		&mut struct_names_map,
 		&mut trait_names,
 	)?;
+
+	let sections = enter_module(TRANSFORMERS_MODULE, &mut templates, &mut modules);
 	generate_transformers(
 		config,
 		&mut method_names,
-		&mut sections,
+		&mut templates,
+		sections,
 		&mut struct_names_map,
 		&mut trait_names,
 	)?;
-	generate_keyframes(config, &mut method_names, &mut sections)?;
-	generate_colors(config, &mut method_names, &mut sections)?;
-	generate_value_sets(config, &mut method_names, &mut sections)?;
-	generate_atoms(config, &mut method_names, &mut sections, &mut trait_names)?;
-	generate_named_classes(config, &mut method_names, &mut sections, &mut trait_names)?;
-	generate_aliases(config, &mut method_names, &mut sections, &mut trait_names)?;
-	generate_struct_implementations(&struct_names_map, &trait_names, &mut sections);
-
-	Ok((combine_sections_with_header(sections), method_names))
+
+	let sections = enter_module(KEYFRAMES_MODULE, &mut templates, &mut modules);
+	generate_keyframes(config, &mut method_names, &mut templates, sections)?;
+
+	let sections = enter_module(PALETTE_MODULE, &mut templates, &mut modules);
+	generate_colors(config, &mut method_names, &mut templates, sections)?;
+
+	let sections = enter_module(VALUE_SETS_MODULE, &mut templates, &mut modules);
+	generate_value_sets(config, &mut method_names, &mut templates, sections)?;
+
+	generate_atoms(
+		config,
+		&mut method_names,
+		&mut templates,
+		&mut modules,
+		&mut trait_names,
+		used_atoms,
+	)?;
+
+	let sections = enter_module(CLASSES_MODULE, &mut templates, &mut modules);
+	generate_named_classes(
+		config,
+		&mut method_names,
+		&mut templates,
+		sections,
+		&mut trait_names,
+	)?;
+
+	let sections = enter_module(CLASSES_MODULE, &mut templates, &mut modules);
+	generate_aliases(
+		config,
+		&mut method_names,
+		&mut templates,
+		sections,
+		&mut trait_names,
+	)?;
+
+	generate_struct_implementations(
+		&mut templates,
+		&struct_names_map,
+		&trait_names,
+		&mut modules,
+	);
+
+	Ok((
+		templates.combine_sections_with_header(modules),
+		method_names,
+	))
+}
+
+/// The inner attributes of the generated file can't be used with `include!`,
+/// so the items are wrapped in a module with the same outer attributes and
+/// re-exported.
+pub(crate) fn to_included_module(content: &str) -> String {
+	let mut attributes = vec![];
+	let mut lines = content.lines().peekable();
+
+	while let Some(attribute) = lines.next_if(|line| line.starts_with("#![")) {
+		attributes.push(attribute.replacen("#![", "#[", 1));
+	}
+
+	let body = lines.collect::<Vec<_>>().join("\n");
+
+	format!(
+		"{}\nmod skribble_generated {{\n{body}\n}}\npub use skribble_generated::*;\n",
+		attributes.join("\n")
+	)
 }
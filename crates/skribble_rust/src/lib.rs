@@ -3,14 +3,20 @@
 
 doc_comment::doctest!("../readme.md");
 
+use std::path::PathBuf;
+
 use generate::generate_file_contents;
+use generate::to_included_module;
 use heck::ToPascalCase;
 use heck::ToSnakeCase;
 use indexmap::IndexMap;
+use indexmap::IndexSet;
 use indoc::indoc;
+pub use migrate::*;
 use scan::scan;
 use serde::Deserialize;
 use serde::Serialize;
+pub use sk_macro::*;
 use skribble_core::crate_version;
 use skribble_core::AnyResult;
 use skribble_core::Classes;
@@ -19,19 +25,120 @@ use skribble_core::GeneratedFiles;
 use skribble_core::Plugin;
 use skribble_core::PluginData;
 use skribble_core::RunnerConfig;
+use skribble_core::StringList;
 use typed_builder::TypedBuilder;
 
 mod generate;
+mod migrate;
 mod scan;
+mod sk_macro;
+
+/// The default path of the resolved configuration which is read by the `sk!`
+/// macro.
+pub const CONFIG_CACHE_PATH: &str = "./cache/skribble_config.json";
 
 /// This plugin generates `rust` code from the configuration.
-#[derive(Debug, Clone, Default, Deserialize, TypedBuilder, Serialize)]
+#[derive(Debug, Clone, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct RustPlugin {
+	/// The path of the generated rust file, relative to the root.
+	#[builder(default = default_output_path(), setter(into))]
+	#[serde(default = "default_output_path")]
+	pub output_path: PathBuf,
+	/// Wrap the generated code in a re-exported module so it can be embedded
+	/// with `include!`, which doesn't accept the inner attributes of the
+	/// header.
+	#[builder(default)]
+	#[serde(default)]
+	pub include_module: bool,
+	/// The path of the json file which maps the generated method names to the
+	/// names in the configuration, relative to the root.
+	#[builder(default = default_cache_path(), setter(into))]
+	#[serde(default = "default_cache_path")]
+	pub cache_path: PathBuf,
+	/// The path of the resolved configuration which is read by the `sk!`
+	/// macro, relative to the root. When this is changed the `SKRIBBLE_CONFIG`
+	/// environment variable should be set to the same path so the macro can
+	/// find it.
+	#[builder(default = default_config_cache_path(), setter(into))]
+	#[serde(default = "default_config_cache_path")]
+	pub config_cache_path: PathBuf,
+	/// The name of the generated function which starts every class name chain.
+	#[builder(default = default_root_function(), setter(into))]
+	#[serde(default = "default_root_function")]
+	pub root_function: String,
+	/// The name of the generated function which returns the css variables.
+	#[builder(default = default_variables_function(), setter(into))]
+	#[serde(default = "default_variables_function")]
+	pub variables_function: String,
+	/// The visibility of the generated functions, structs and traits, e.g.
+	/// `pub(crate)`.
+	#[builder(default = default_visibility(), setter(into))]
+	#[serde(default = "default_visibility")]
+	pub visibility: String,
+	/// The style of the generated code.
+	#[builder(default)]
+	#[serde(default)]
+	pub mode: RustCodeMode,
+	/// Split the generated code into a module for each atom, the palette, the
+	/// media queries and the other groups of traits. The items of every module
+	/// are re-exported so the `sk()` api is unchanged.
+	#[builder(default)]
+	#[serde(default)]
+	pub split_modules: bool,
+	/// The `cfg` predicate which gates every generated module when
+	/// [`RustPlugin::split_modules`] is enabled. `{module}` is replaced with
+	/// the kebab case name of the module, e.g. `feature = "skribble-{module}"`
+	/// generates the `palette` module behind the `skribble-palette` feature.
+	#[builder(default, setter(into, strip_option))]
+	#[serde(default)]
+	pub module_cfg: Option<String>,
+	/// Only generate the traits of the atoms which were used by the classes of
+	/// the last scan. Everything is generated until the first scan.
+	#[builder(default)]
+	#[serde(default)]
+	pub prune_atoms: bool,
+	/// The frameworks which can use the generated `ClassList` directly, e.g. as
+	/// the `class` attribute of a Leptos element.
+	#[builder(default, setter(into))]
+	#[serde(default)]
+	pub integrations: Vec<RustIntegration>,
+	/// The paths of macros whose bodies are parsed as comma separated rust
+	/// expressions when scanning for classes, e.g. `vec![sk().p().n2()]`.
+	#[builder(default = default_expression_macros(), setter(into))]
+	#[serde(default = "default_expression_macros")]
+	pub expression_macros: StringList,
+	/// Also scan the string literals of `class` attributes, e.g.
+	/// `class="md:p:$2"`, and the string literals passed to the
+	/// [`RustPlugin::literal_functions`]. Literals which aren't valid class
+	/// names are ignored.
+	#[builder(default)]
+	#[serde(default)]
+	pub scan_literals: bool,
+	/// The paths of functions whose string literal arguments are scanned for
+	/// class names when [`RustPlugin::scan_literals`] is enabled.
+	#[builder(default, setter(into))]
+	#[serde(default)]
+	pub literal_functions: StringList,
 	/// The method names used in the generated code. This is also used to remap
 	/// method names to the stored names.
 	#[builder(default, setter(skip))]
 	#[serde(skip)]
 	method_names: IndexMap<String, String>,
+	/// The atoms used by the classes of the current scan.
+	#[builder(default, setter(skip))]
+	#[serde(skip)]
+	scanned_atoms: IndexSet<String>,
+	/// The atoms used by the classes of the last completed scan.
+	#[builder(default, setter(skip))]
+	#[serde(skip)]
+	used_atoms: IndexSet<String>,
+}
+
+impl Default for RustPlugin {
+	fn default() -> Self {
+		Self::builder().build()
+	}
 }
 
 impl Plugin for RustPlugin {
@@ -50,21 +157,37 @@ impl Plugin for RustPlugin {
 
 	fn generate_code(&mut self, config: &RunnerConfig) -> AnyResult<GeneratedFiles> {
 		let mut files = GeneratedFiles::default();
-		let (contents, method_names) = generate_file_contents(config)?;
+
+		// A scan has completed since the last generation.
+		if !self.scanned_atoms.is_empty() {
+			self.used_atoms = std::mem::take(&mut self.scanned_atoms);
+		}
+
+		let (mut contents, method_names) =
+			generate_file_contents(config, self, self.get_used_atoms())?;
 		let method_names_json = serde_json::to_string_pretty(&method_names)?;
 
+		if self.include_module {
+			contents = to_included_module(&contents);
+		}
+
 		self.method_names = method_names;
 
 		files.insert(
 			GeneratedFile::builder()
-				.path("./src/skribble.rs")
+				.path(&self.output_path)
 				.content(contents)
 				.build(),
 		);
 		files.insert(
 			GeneratedFile::builder()
-				// TODO where should this be placed
-				.path("./cache/skribble_rust.json")
+				.path(&self.config_cache_path)
+				.content(config.to_json()?)
+				.build(),
+		);
+		files.insert(
+			GeneratedFile::builder()
+				.path(&self.cache_path)
 				.content(method_names_json)
 				.build(),
 		);
@@ -78,7 +201,23 @@ impl Plugin for RustPlugin {
 		file_path: &str,
 		content: &str,
 	) -> AnyResult<Classes> {
-		scan(config, file_path, content, &self.method_names)
+		// The method names are needed to scan the classes before the code has been
+		// generated. Pruning every atom only reserves their method names.
+		if self.method_names.is_empty() {
+			let (_, method_names) = generate_file_contents(config, self, Some(&IndexSet::new()))?;
+			self.method_names = method_names;
+		}
+
+		let classes = scan(config, file_path, content, self)?;
+
+		// The members of combined aliases are also generated from atoms.
+		let atoms = classes
+			.iter()
+			.flat_map(|class| std::iter::once(class).chain(class.get_combined()))
+			.filter_map(|class| class.get_atom());
+		self.scanned_atoms.extend(atoms.cloned());
+
+		Ok(classes)
 	}
 }
 
@@ -86,6 +225,85 @@ impl RustPlugin {
 	pub fn get_method_names(&self) -> &IndexMap<String, String> {
 		&self.method_names
 	}
+
+	/// The atoms which are generated when [`RustPlugin::prune_atoms`] is
+	/// enabled. `None` means every atom is generated.
+	pub fn get_used_atoms(&self) -> Option<&IndexSet<String>> {
+		if self.prune_atoms && !self.used_atoms.is_empty() {
+			Some(&self.used_atoms)
+		} else {
+			None
+		}
+	}
+
+	/// Rewrite the tailwind `class` strings of the rust file content as `sk()`
+	/// method chains. This should be called after the code has been generated.
+	pub fn migrate_tailwind(
+		&self,
+		config: &RunnerConfig,
+		content: impl AsRef<str>,
+	) -> AnyResult<MigratedFile> {
+		TailwindMapping::new(config, &self.method_names)
+			.with_root_function(&self.root_function)
+			.migrate(content)
+	}
+}
+
+fn default_output_path() -> PathBuf {
+	"./src/skribble.rs".into()
+}
+
+fn default_cache_path() -> PathBuf {
+	"./cache/skribble_rust.json".into()
+}
+
+fn default_config_cache_path() -> PathBuf {
+	CONFIG_CACHE_PATH.into()
+}
+
+fn default_root_function() -> String {
+	"sk".into()
+}
+
+fn default_variables_function() -> String {
+	"vars".into()
+}
+
+fn default_visibility() -> String {
+	"pub".into()
+}
+
+fn default_expression_macros() -> StringList {
+	vec!["classes", "format", "vec"].into()
+}
+
+/// The style of the generated `rust` code.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RustCodeMode {
+	/// Every method appends to an owned `String` which is returned by the last
+	/// method of the chain.
+	#[default]
+	Owned,
+	/// Every method returns a zero sized typestate struct which records the
+	/// chain in its type. The class names are computed at compile time and the
+	/// last method of the chain returns a `&'static str` without allocating.
+	///
+	/// Methods which receive runtime arguments, e.g. `p_("10px")`, still
+	/// allocate and return a `String`. A runtime value can't continue the
+	/// compile time chain, so transformers with arbitrary values receive the
+	/// rest of the class as an argument instead, e.g. `alpha_("50%",
+	/// sk().bg().red100())` rather than the owned `alpha("50%").bg().red100()`.
+	Static,
+}
+
+/// A framework which the generated `ClassList` can be used with.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RustIntegration {
+	/// Implement `leptos::IntoAttribute` so the `ClassList` can be used as the
+	/// value of a `class` attribute. The crate must depend on `leptos`.
+	Leptos,
 }
 
 #[cfg(test)]
@@ -0,0 +1,770 @@
+use indexmap::IndexMap;
+use proc_macro2::LineColumn;
+use rstml::node::KeyedAttributeValue;
+use rstml::node::Node;
+use rstml::node::NodeAttribute;
+use rstml::node::NodeBlock;
+use rstml::parse2;
+use serde::Serialize;
+use skribble_core::AnyResult;
+use skribble_core::AtomType;
+use skribble_core::ClassFactory;
+use skribble_core::RunnerConfig;
+use syn::visit;
+use syn::visit::Visit;
+use syn::File;
+
+use crate::generate::COLORS_PREFIX;
+use crate::generate::GLOBAL_PREFIX;
+use crate::generate::KEYFRAMES_PREFIX;
+use crate::generate::TRANSFORMER_PREFIX;
+
+/// The tailwind utility prefixes which map onto differently named preset
+/// atoms. Prefixes which share the name of an atom are mapped automatically.
+const TAILWIND_ATOMS: &[(&str, &[&str])] = &[
+	("text", &["text", "font-size", "text-align"]),
+	("font", &["font-weight", "font-family"]),
+	("bg", &["bg", "bg-position", "bg-size"]),
+	("border", &["border", "border-color", "border-style"]),
+	(
+		"border-x",
+		&["border-x", "border-color-x", "border-style-x"],
+	),
+	(
+		"border-y",
+		&["border-y", "border-color-y", "border-style-y"],
+	),
+	(
+		"border-t",
+		&["border-top", "border-color-top", "border-style-top"],
+	),
+	(
+		"border-r",
+		&["border-right", "border-color-right", "border-style-right"],
+	),
+	(
+		"border-b",
+		&[
+			"border-bottom",
+			"border-color-bottom",
+			"border-style-bottom",
+		],
+	),
+	(
+		"border-l",
+		&["border-left", "border-color-left", "border-style-left"],
+	),
+	(
+		"border-s",
+		&["border-inline-start", "border-color-inline-start"],
+	),
+	(
+		"border-e",
+		&["border-inline-end", "border-color-inline-end"],
+	),
+	("rounded-s", &["rounded-start"]),
+	("rounded-e", &["rounded-end"]),
+	("rounded-t", &["rounded-top"]),
+	("rounded-r", &["rounded-right"]),
+	("rounded-b", &["rounded-bottom"]),
+	("rounded-l", &["rounded-left"]),
+	("rounded-ss", &["rounded-start-start"]),
+	("rounded-se", &["rounded-start-end"]),
+	("rounded-ee", &["rounded-end-end"]),
+	("rounded-es", &["rounded-end-start"]),
+	("rounded-tl", &["rounded-top-left"]),
+	("rounded-tr", &["rounded-top-right"]),
+	("rounded-br", &["rounded-bottom-right"]),
+	("rounded-bl", &["rounded-bottom-left"]),
+	("from", &["from-color", "from-position"]),
+	("via", &["via-color", "via-position"]),
+	("to", &["to-color", "to-position"]),
+	("shadow", &["shadow", "shadow-color"]),
+	("ring", &["ring", "ring-color"]),
+	("ring-offset", &["ring-offset", "ring-offset-color"]),
+	("outline", &["outline-width", "outline", "outline-style"]),
+	("divide", &["divide", "divide-style"]),
+	(
+		"decoration",
+		&["decoration", "decoration-thickness", "decoration-style"],
+	),
+	("flex", &["flex", "flex-direction", "flex-wrap"]),
+	("grow", &["flex-grow"]),
+	("shrink", &["flex-shrink"]),
+	("list", &["list-type", "list-position", "list-image"]),
+	("object", &["object-fit", "object-position"]),
+	("stroke", &["stroke", "stroke-width"]),
+	("snap", &["snap", "snap-align", "snap-stop"]),
+	(
+		"break",
+		&["break", "break-after", "break-before", "break-inside"],
+	),
+];
+
+/// The tailwind variants which are named differently in the preset.
+const TAILWIND_VARIANTS: &[(&str, &str)] = &[("2xl", "xxl")];
+
+/// Translates tailwind class names into `skribble` classes and the generated
+/// `sk()` method chains.
+///
+/// The mapping table is built from the atoms of the provided configuration
+/// so it should be created with the configuration which includes the
+/// `PresetPlugin`.
+pub struct TailwindMapping<'config, 'names> {
+	config: &'config RunnerConfig,
+	/// The generated method names keyed by the prefix and stored value.
+	methods: IndexMap<String, Vec<&'names str>>,
+	prefixes: IndexMap<String, Vec<String>>,
+	root_function: String,
+}
+
+impl<'config, 'names> TailwindMapping<'config, 'names> {
+	/// The `method_names` are the names returned from the generated code and
+	/// are available via [`crate::RustPlugin::get_method_names`].
+	pub fn new(
+		config: &'config RunnerConfig,
+		method_names: &'names IndexMap<String, String>,
+	) -> Self {
+		let mut methods = IndexMap::<String, Vec<&str>>::new();
+		let mut prefixes = IndexMap::<String, Vec<String>>::new();
+
+		for (key, value) in method_names.iter() {
+			if let Some((prefix, method)) = key.rsplit_once(":::") {
+				methods
+					.entry(format!("{prefix}:::{value}"))
+					.or_default()
+					.push(method);
+			}
+		}
+
+		for name in config.atoms.keys() {
+			prefixes.insert(name.clone(), vec![name.clone()]);
+		}
+
+		for (prefix, atoms) in TAILWIND_ATOMS.iter() {
+			let atoms = atoms
+				.iter()
+				.filter(|atom| config.has_atom(atom))
+				.map(|atom| atom.to_string())
+				.collect::<Vec<_>>();
+
+			if !atoms.is_empty() {
+				prefixes.insert(prefix.to_string(), atoms);
+			}
+		}
+
+		Self {
+			config,
+			methods,
+			prefixes,
+			root_function: "sk".into(),
+		}
+	}
+
+	/// Use a different name for the generated root function, as configured by
+	/// [`crate::RustPlugin::root_function`].
+	pub fn with_root_function(mut self, root_function: impl Into<String>) -> Self {
+		self.root_function = root_function.into();
+		self
+	}
+
+	/// The atoms which can be used for the tailwind utility prefix.
+	pub fn get_atoms(&self, prefix: impl AsRef<str>) -> Option<&Vec<String>> {
+		self.prefixes.get(prefix.as_ref())
+	}
+
+	/// Map a single tailwind class, e.g. `md:hover:bg-red-500`. Returns `None`
+	/// when the class has no equivalent in the configuration.
+	pub fn get_class(&self, tailwind: impl AsRef<str>) -> Option<MappedClass> {
+		let tailwind = tailwind.as_ref().trim();
+		let mut segments = split_variants(tailwind);
+		let utility = segments.pop()?;
+		let variants = segments
+			.iter()
+			.map(|variant| {
+				TAILWIND_VARIANTS
+					.iter()
+					.find(|(name, _)| name == variant)
+					.map_or(variant.to_string(), |(_, value)| value.to_string())
+			})
+			.collect::<Vec<_>>();
+
+		let (utility, important) = match (utility.strip_prefix('!'), utility.strip_suffix('!')) {
+			(Some(utility), _) | (None, Some(utility)) => (utility, true),
+			(None, None) => (utility, false),
+		};
+
+		let mut transformers = vec![];
+
+		if important {
+			transformers.push(("important".to_string(), None));
+		}
+
+		self.get_utilities(utility)
+			.into_iter()
+			.map(|utility| {
+				Candidate {
+					variants: variants.clone(),
+					transformers: transformers.clone(),
+					utility,
+				}
+			})
+			.chain(self.get_alpha_candidates(utility, &variants, &transformers))
+			.find(|candidate| self.is_valid(candidate))
+			.and_then(|candidate| {
+				Some(MappedClass {
+					tailwind: tailwind.into(),
+					class_name: candidate.class_name(),
+					expression: self.get_expression(&candidate)?,
+				})
+			})
+	}
+
+	/// Rewrite every string `class` attribute within the `view!` and `html!`
+	/// macros of the rust file as `sk()` method chains.
+	///
+	/// Classes which can't be mapped are kept in the attribute and listed in
+	/// [`MigratedFile::unmapped`].
+	pub fn migrate(&self, content: impl AsRef<str>) -> AnyResult<MigratedFile> {
+		let content = content.as_ref();
+		let syntax_tree: File = syn::parse_str(content)?;
+		let mut visitor = ClassAttributeVisitor::default();
+		visitor.visit_file(&syntax_tree);
+
+		let mut attributes = visitor.attributes;
+		attributes.sort_by_key(|attribute| (attribute.start.line, attribute.start.column));
+
+		let mut edits = vec![];
+		let mut migrated = vec![];
+		let mut unmapped = vec![];
+
+		for attribute in attributes.iter() {
+			let mut expressions = vec![];
+			let mut remaining = vec![];
+
+			for tailwind in attribute.value.split_whitespace() {
+				match self.get_class(tailwind) {
+					Some(mapped) => {
+						expressions.push(mapped.expression.clone());
+						migrated.push(mapped);
+					}
+					None => {
+						remaining.push(tailwind);
+						unmapped.push(UnmappedClass {
+							tailwind: tailwind.into(),
+							line: attribute.start.line,
+							column: attribute.start.column + 1,
+						});
+					}
+				}
+			}
+
+			if expressions.is_empty() {
+				continue;
+			}
+
+			if !remaining.is_empty() {
+				expressions.push(format!("{:?}.into()", remaining.join(" ")));
+			}
+
+			let replacement = match expressions.as_slice() {
+				[expression] => format!("{{{expression}}}"),
+				expressions => format!("{{[{}].join(\" \")}}", expressions.join(", ")),
+			};
+
+			let start = get_offset(content, attribute.start);
+			let end = get_offset(content, attribute.end);
+
+			if let Some((start, end)) = start.zip(end) {
+				edits.push((start, end, replacement));
+			}
+		}
+
+		let mut content = content.to_string();
+
+		for (start, end, replacement) in edits.into_iter().rev() {
+			content.replace_range(start..end, &replacement);
+		}
+
+		Ok(MigratedFile {
+			content,
+			migrated,
+			unmapped,
+		})
+	}
+
+	fn get_utilities(&self, utility: &str) -> Vec<Utility> {
+		let mut utilities = vec![];
+
+		// Arbitrary properties, e.g. `[mask-type:luminance]`.
+		if let Some((property, value)) = utility
+			.strip_prefix('[')
+			.and_then(|utility| utility.strip_suffix(']'))
+			.and_then(|utility| utility.split_once(':'))
+		{
+			utilities.push(Utility::Property {
+				property: property.into(),
+				value: get_arbitrary_value(value),
+			});
+
+			return utilities;
+		}
+
+		let (utility, negative) = match utility.strip_prefix('-') {
+			Some(utility) => (utility, true),
+			None => (utility, false),
+		};
+
+		if !negative {
+			if self.config.classes.contains_key(utility) {
+				utilities.push(Utility::Class(utility.into()));
+			}
+
+			if let Some(alias) = self.config.aliases.get(utility) {
+				utilities.push(Utility::Class(utility.into()));
+
+				// The alias method is shadowed when an atom shares its name so the
+				// aliased class is used directly.
+				if let [class_name] = alias.classes.as_slice() {
+					if let Some((atom, value)) = class_name
+						.split_once(":$")
+						.filter(|(atom, _)| self.config.has_atom(atom))
+					{
+						utilities.push(Utility::Value {
+							atom: atom.into(),
+							value: value.into(),
+						});
+					}
+				}
+			}
+		}
+
+		let mut positions = utility
+			.match_indices('-')
+			.map(|(index, _)| index)
+			.collect::<Vec<_>>();
+		positions.push(utility.len());
+
+		for position in positions.into_iter().rev() {
+			let Some(prefix) = utility.get(..position) else {
+				continue;
+			};
+			let Some(atoms) = self.prefixes.get(prefix) else {
+				continue;
+			};
+			let rest = utility.get(position + 1..).unwrap_or_default();
+
+			for atom in atoms.iter() {
+				if let Some(value) = rest
+					.strip_prefix('[')
+					.and_then(|rest| rest.strip_suffix(']'))
+				{
+					let value = get_arbitrary_value(value);
+					utilities.push(Utility::Argument {
+						atom: atom.clone(),
+						value: if negative { format!("-{value}") } else { value },
+					});
+
+					continue;
+				}
+
+				let values = if rest.is_empty() {
+					vec!["default".to_string(), "DEFAULT".to_string()]
+				} else if negative {
+					vec![format!("-{rest}")]
+				} else {
+					get_value_names(rest)
+				};
+
+				for value in values {
+					utilities.push(Utility::Value {
+						atom: atom.clone(),
+						value,
+					});
+				}
+			}
+		}
+
+		utilities
+	}
+
+	/// Colors with an opacity modifier, e.g. `bg-red-500/50`.
+	fn get_alpha_candidates(
+		&self,
+		utility: &str,
+		variants: &[String],
+		transformers: &[(String, Option<String>)],
+	) -> Vec<Candidate> {
+		let Some((utility, alpha)) = utility.rsplit_once('/') else {
+			return vec![];
+		};
+
+		let alpha = match alpha
+			.strip_prefix('[')
+			.and_then(|alpha| alpha.strip_suffix(']'))
+		{
+			Some(alpha) => alpha.to_string(),
+			None => {
+				let Ok(alpha) = alpha.parse::<u32>() else {
+					return vec![];
+				};
+
+				(f64::from(alpha) / 100.0).to_string()
+			}
+		};
+
+		let mut transformers = transformers.to_vec();
+		transformers.push(("alpha".into(), Some(alpha)));
+
+		self.get_utilities(utility)
+			.into_iter()
+			.filter(|utility| {
+				matches!(utility, Utility::Value { atom, .. } if self.config.get_atom_type(atom) == Some(AtomType::Color))
+			})
+			.map(|utility| {
+				Candidate {
+					variants: variants.to_vec(),
+					transformers: transformers.clone(),
+					utility,
+				}
+			})
+			.collect()
+	}
+
+	fn is_valid(&self, candidate: &Candidate) -> bool {
+		let factory = ClassFactory::from_string(self.config, candidate.class_name());
+		factory.is_valid() && self.get_expression(candidate).is_some()
+	}
+
+	fn get_expression(&self, candidate: &Candidate) -> Option<String> {
+		let mut expression = format!("{}()", self.root_function);
+		let (last_variant, variants) = match (&candidate.utility, candidate.variants.split_last()) {
+			(Utility::Property { .. }, Some((last, variants))) => (Some(last), variants),
+			_ => (None, candidate.variants.as_slice()),
+		};
+
+		for variant in variants.iter() {
+			expression.push_str(&format!(
+				".{}()",
+				self.get_global_method(variant, GlobalKind::Variant)?
+			));
+		}
+
+		for (name, value) in candidate.transformers.iter() {
+			let method = self.get_method(TRANSFORMER_PREFIX, name)?;
+
+			match value {
+				Some(value) => expression.push_str(&format!(".{method}({value:?})")),
+				None => expression.push_str(&format!(".{method}()")),
+			}
+		}
+
+		match &candidate.utility {
+			Utility::Class(name) => {
+				expression.push_str(&format!(
+					".{}()",
+					self.get_global_method(name, GlobalKind::Class)?
+				));
+			}
+			Utility::Value { atom, value } => {
+				let prefix = match self.config.get_atom_type(atom) {
+					Some(AtomType::Color) => COLORS_PREFIX,
+					Some(AtomType::Keyframes) => KEYFRAMES_PREFIX,
+					_ => atom,
+				};
+				let atom_method = self.get_global_method(atom, GlobalKind::Atom)?;
+				let value_method = self.get_method(prefix, value)?;
+				expression.push_str(&format!(".{atom_method}().{value_method}()"));
+			}
+			Utility::Argument { atom, value } => {
+				let atom_method = self.get_global_method(atom, GlobalKind::Atom)?;
+				expression.push_str(&format!(".{atom_method}_({value:?})"));
+			}
+			Utility::Property { property, value } => {
+				let method = match last_variant {
+					Some(variant) => self.get_global_method(variant, GlobalKind::Variant)?,
+					None => "_".into(),
+				};
+				expression.push_str(&format!(".{method}_({property:?}, {value:?})"));
+			}
+		}
+
+		Some(expression)
+	}
+
+	/// Get the generated method name for the stored value.
+	fn get_method(&self, prefix: &str, value: &str) -> Option<String> {
+		self.get_nth_method(prefix, value, 0)
+	}
+
+	/// Media queries, modifiers, atoms, named classes and aliases share the
+	/// same namespace. When names clash the generated method receives a numeric
+	/// suffix in the order the methods were generated.
+	fn get_global_method(&self, name: &str, kind: GlobalKind) -> Option<String> {
+		let config = self.config;
+		let kinds = [
+			config.has_media_query(name),
+			config.has_feature_query(name),
+			config.has_container_query(name),
+			config.has_modifier(name),
+			config.has_atom(name),
+			config.classes.contains_key(name),
+			config.aliases.contains_key(name),
+		];
+
+		let index = match kind {
+			GlobalKind::Variant => kinds.iter().take(4).position(|kind| *kind)?,
+			GlobalKind::Atom => 4,
+			GlobalKind::Class => kinds.iter().skip(5).position(|kind| *kind)? + 5,
+		};
+		let nth = kinds.iter().take(index).filter(|kind| **kind).count();
+
+		self.get_nth_method(GLOBAL_PREFIX, name, nth)
+	}
+
+	fn get_nth_method(&self, prefix: &str, value: &str, nth: usize) -> Option<String> {
+		self.methods
+			.get(&format!("{prefix}:::{value}"))
+			.and_then(|methods| methods.get(nth))
+			.map(|method| method.to_string())
+	}
+}
+
+/// A tailwind class which has been mapped to `skribble`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MappedClass {
+	/// The original tailwind class, e.g. `md:p-4`.
+	pub tailwind: String,
+	/// The `skribble` class name, e.g. `md:p:$4`.
+	pub class_name: String,
+	/// The generated rust expression, e.g. `sk().md().p().n4()`.
+	pub expression: String,
+}
+
+/// A tailwind class which has no equivalent in the configuration.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmappedClass {
+	pub tailwind: String,
+	/// The line of the `class` attribute value.
+	pub line: usize,
+	/// The column of the `class` attribute value.
+	pub column: usize,
+}
+
+/// The result of migrating the tailwind classes of a rust file.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedFile {
+	/// The updated content of the file.
+	pub content: String,
+	/// The classes which were rewritten.
+	pub migrated: Vec<MappedClass>,
+	/// The classes which couldn't be mapped and were left as strings.
+	pub unmapped: Vec<UnmappedClass>,
+}
+
+#[derive(Clone, Debug)]
+struct Candidate {
+	variants: Vec<String>,
+	transformers: Vec<(String, Option<String>)>,
+	utility: Utility,
+}
+
+impl Candidate {
+	fn class_name(&self) -> String {
+		let mut tokens = self.variants.clone();
+
+		for (name, value) in self.transformers.iter() {
+			match value {
+				Some(value) => tokens.push(format!("({name}={value})")),
+				None => tokens.push(format!("({name})")),
+			}
+		}
+
+		match &self.utility {
+			Utility::Class(name) => tokens.push(format!("${name}")),
+			Utility::Value { atom, value } => {
+				tokens.push(atom.clone());
+				tokens.push(format!("${value}"));
+			}
+			Utility::Argument { atom, value } => {
+				tokens.push(atom.clone());
+				tokens.push(format!("[{value}]"));
+			}
+			Utility::Property { property, value } => tokens.push(format!("[{property}={value}]")),
+		}
+
+		tokens.join(":")
+	}
+}
+
+#[derive(Clone, Debug)]
+enum Utility {
+	/// A named class or alias.
+	Class(String),
+	/// An atom with a value from the configuration.
+	Value { atom: String, value: String },
+	/// An atom with an arbitrary value, e.g. `p-[10px]`.
+	Argument { atom: String, value: String },
+	/// An arbitrary property, e.g. `[mask-type:luminance]`.
+	Property { property: String, value: String },
+}
+
+#[derive(Clone, Copy)]
+enum GlobalKind {
+	/// Media queries, feature queries, container queries and modifiers.
+	Variant,
+	Atom,
+	/// Named classes and aliases.
+	Class,
+}
+
+#[derive(Default)]
+struct ClassAttributeVisitor {
+	attributes: Vec<ClassAttribute>,
+}
+
+struct ClassAttribute {
+	value: String,
+	start: LineColumn,
+	end: LineColumn,
+}
+
+impl ClassAttributeVisitor {
+	fn visit_view_macro(&mut self, node: &syn::Macro) -> bool {
+		let Some(segment) = node.path.segments.last() else {
+			return false;
+		};
+
+		if segment.ident != "view" && segment.ident != "html" {
+			return false;
+		}
+
+		let Ok(nodes) = parse2(node.tokens.clone()) else {
+			return false;
+		};
+
+		self.visit_nodes(&nodes);
+		true
+	}
+
+	fn visit_nodes(&mut self, nodes: &[Node]) {
+		for node in nodes.iter() {
+			match node {
+				Node::Fragment(fragment) => self.visit_nodes(&fragment.children),
+				Node::Element(element) => {
+					self.visit_nodes(&element.children);
+
+					for attribute in element.attributes().iter() {
+						match attribute {
+							NodeAttribute::Block(NodeBlock::ValidBlock(block)) => {
+								self.visit_block(block);
+							}
+							NodeAttribute::Attribute(attribute) => {
+								let KeyedAttributeValue::Value(value) = &attribute.possible_value
+								else {
+									continue;
+								};
+
+								match &value.value {
+									syn::Expr::Lit(syn::ExprLit {
+										lit: syn::Lit::Str(literal),
+										..
+									}) if attribute.key.to_string() == "class" => {
+										self.attributes.push(ClassAttribute {
+											value: literal.value(),
+											start: literal.span().start(),
+											end: literal.span().end(),
+										});
+									}
+									expression => self.visit_expr(expression),
+								}
+							}
+							_ => {}
+						}
+					}
+				}
+				Node::Block(NodeBlock::ValidBlock(block)) => self.visit_block(block),
+				_ => {}
+			}
+		}
+	}
+}
+
+impl<'ast> Visit<'ast> for ClassAttributeVisitor {
+	fn visit_macro(&mut self, node: &'ast syn::Macro) {
+		if self.visit_view_macro(node) {
+			return;
+		}
+
+		visit::visit_macro(self, node);
+	}
+}
+
+/// Split the variants of a tailwind class while ignoring the `:` within
+/// arbitrary values.
+fn split_variants(class_name: &str) -> Vec<&str> {
+	let mut segments = vec![];
+	let mut depth = 0_usize;
+	let mut start = 0;
+
+	for (index, ch) in class_name.char_indices() {
+		match ch {
+			'[' => depth += 1,
+			']' => depth = depth.saturating_sub(1),
+			':' if depth == 0 => {
+				segments.extend(class_name.get(start..index));
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+
+	segments.extend(class_name.get(start..));
+	segments
+}
+
+/// The preset value names which may match the tailwind value, e.g. `red500`
+/// for `red-500` and `1-of-3` for `1/3`.
+fn get_value_names(value: &str) -> Vec<String> {
+	let mut names = vec![value.to_string(), value.replace('-', "")];
+
+	if let Some((numerator, denominator)) = value.split_once('/') {
+		if value == "1/2" {
+			names.push("half".into());
+		}
+
+		names.push(format!("{numerator}-of-{denominator}"));
+	}
+
+	names.dedup();
+	names
+}
+
+/// Tailwind uses underscores for spaces within arbitrary values.
+fn get_arbitrary_value(value: &str) -> String {
+	value.replace('_', " ")
+}
+
+/// Convert the line (1-indexed) and column (0-indexed characters) into a byte
+/// offset.
+fn get_offset(content: &str, location: LineColumn) -> Option<usize> {
+	let line_start = if location.line <= 1 {
+		0
+	} else {
+		content
+			.match_indices('\n')
+			.nth(location.line - 2)
+			.map(|(index, _)| index + 1)?
+	};
+
+	let line = content.get(line_start..)?;
+	let column = line
+		.char_indices()
+		.nth(location.column)
+		.map_or(line.len(), |(index, _)| index);
+
+	Some(line_start + column)
+}
@@ -1,6 +1,11 @@
 use std::fmt::Write;
 
 use indexmap::IndexMap;
+use indexmap::IndexSet;
+use proc_macro2::Delimiter;
+use proc_macro2::Spacing;
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
 use rstml::node::KeyedAttributeValue;
 use rstml::node::Node;
 use rstml::node::NodeAttribute;
@@ -9,9 +14,12 @@ use rstml::parse2;
 use skribble_core::AnyResult;
 use skribble_core::Arguments;
 use skribble_core::AtomType;
+use skribble_core::ClassDiagnostic;
 use skribble_core::ClassFactory;
 use skribble_core::Classes;
 use skribble_core::RunnerConfig;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::visit;
 use syn::visit::Visit;
 use syn::File;
@@ -20,23 +28,129 @@ use crate::generate::COLORS_PREFIX;
 use crate::generate::GLOBAL_PREFIX;
 use crate::generate::KEYFRAMES_PREFIX;
 use crate::generate::TRANSFORMER_PREFIX;
+use crate::get_class_names;
+use crate::parse_sk_macro;
+use crate::RustPlugin;
 
 #[readonly::make]
-struct ScanVisitor<'config, 'names> {
+struct ScanVisitor<'config, 'plugin> {
 	pub classes: Classes,
 	config: &'config RunnerConfig,
-	method_names: &'names IndexMap<String, String>,
+	plugin: &'plugin RustPlugin,
+	file_path: String,
+	/// The names of all generated methods.
+	generated_methods: IndexSet<&'plugin str>,
+	/// The chains returned by the functions of the file.
+	functions: IndexMap<String, Chain>,
+	/// The chains stored in the local bindings of every block being visited.
+	/// Bindings which shadow a chain with another value are stored as `None`.
+	bindings: Vec<IndexMap<String, Option<Chain>>>,
 }
 
-impl<'config, 'names> ScanVisitor<'config, 'names> {
+impl<'config, 'plugin> ScanVisitor<'config, 'plugin> {
 	pub fn new(
 		config: &'config RunnerConfig,
-		method_names: &'names IndexMap<String, String>,
+		plugin: &'plugin RustPlugin,
+		file_path: impl Into<String>,
 	) -> Self {
+		let generated_methods = plugin
+			.method_names
+			.keys()
+			.filter_map(|key| key.rsplit(":::").next())
+			.collect();
+
 		Self {
 			classes: Classes::default(),
 			config,
-			method_names,
+			plugin,
+			file_path: file_path.into(),
+			generated_methods,
+			functions: IndexMap::new(),
+			bindings: vec![],
+		}
+	}
+
+	/// Add the whitespace separated class names of a string literal. Invalid
+	/// class names are ignored since they are probably not `skribble` classes.
+	fn insert_literal_classes(&mut self, value: &str) {
+		for class_name in value.split_whitespace() {
+			let factory = ClassFactory::from_string(self.config, class_name);
+
+			if factory.is_valid() {
+				self.classes.insert_factory(factory);
+			}
+		}
+	}
+
+	/// Add the class names of the literals in a `class` attribute value. Leptos
+	/// also supports the tuple `("name", signal)`.
+	fn insert_attribute_literal_classes(&mut self, expression: &syn::Expr) {
+		match expression {
+			syn::Expr::Lit(syn::ExprLit {
+				lit: syn::Lit::Str(literal),
+				..
+			}) => self.insert_literal_classes(&literal.value()),
+			syn::Expr::Tuple(tuple) => {
+				if let Some(first) = tuple.elems.first() {
+					self.insert_attribute_literal_classes(first);
+				}
+			}
+			syn::Expr::Paren(paren) => self.insert_attribute_literal_classes(&paren.expr),
+			syn::Expr::Block(block) => {
+				if let Some(syn::Stmt::Expr(expression, None)) = block.block.stmts.last() {
+					self.insert_attribute_literal_classes(expression);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/// Add the class names of the string literals passed to the methods of a
+	/// `ClassList`. The classes of `add_if` are added whatever the condition.
+	fn insert_class_list_classes(&mut self, node: &syn::ExprMethodCall) {
+		let argument = match node.method.to_string().as_str() {
+			"add" | "extend" => node.args.first(),
+			"add_if" => node.args.last(),
+			_ => return,
+		};
+
+		if let (true, Some(argument)) = (is_class_list(&node.receiver), argument) {
+			self.insert_expression_literal_classes(argument);
+		}
+	}
+
+	/// Add the class names of the string literals in both branches of an `if`
+	/// expression and in arrays.
+	fn insert_expression_literal_classes(&mut self, expression: &syn::Expr) {
+		match expression {
+			syn::Expr::Lit(syn::ExprLit {
+				lit: syn::Lit::Str(literal),
+				..
+			}) => self.insert_literal_classes(&literal.value()),
+			syn::Expr::Array(array) => {
+				for element in array.elems.iter() {
+					self.insert_expression_literal_classes(element);
+				}
+			}
+			syn::Expr::Reference(reference) => {
+				self.insert_expression_literal_classes(&reference.expr);
+			}
+			syn::Expr::Paren(paren) => self.insert_expression_literal_classes(&paren.expr),
+			syn::Expr::Block(block) => self.insert_block_literal_classes(&block.block),
+			syn::Expr::If(expression_if) => {
+				self.insert_block_literal_classes(&expression_if.then_branch);
+
+				if let Some((_, else_branch)) = expression_if.else_branch.as_ref() {
+					self.insert_expression_literal_classes(else_branch);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	fn insert_block_literal_classes(&mut self, block: &syn::Block) {
+		if let Some(syn::Stmt::Expr(expression, None)) = block.stmts.last() {
+			self.insert_expression_literal_classes(expression);
 		}
 	}
 
@@ -46,7 +160,7 @@ impl<'config, 'names> ScanVisitor<'config, 'names> {
 		transformers: &[String],
 		arguments: &String,
 	) {
-		if !starts_with_sk(tokens) {
+		if !starts_with_root(tokens, &self.plugin.root_function) {
 			return;
 		}
 
@@ -86,7 +200,7 @@ impl<'config, 'names> ScanVisitor<'config, 'names> {
 					None => format!("{GLOBAL_PREFIX}:::{token_identifier}"),
 				};
 
-				let token = if let Some(value) = self.method_names.get(&key) {
+				let token = if let Some(value) = self.plugin.method_names.get(&key) {
 					value
 				} else {
 					token_identifier
@@ -117,6 +231,17 @@ impl<'config, 'names> ScanVisitor<'config, 'names> {
 			return false;
 		}
 
+		// Sycamore also uses `view!` but without any `<element>` tags.
+		let has_elements = node
+			.tokens
+			.clone()
+			.into_iter()
+			.any(|tree| matches!(tree, TokenTree::Punct(punct) if punct.as_char() == '<'));
+
+		if !has_elements {
+			return false;
+		}
+
 		let Ok(nodes) = parse2(node.tokens.clone()) else {
 			return false;
 		};
@@ -125,6 +250,162 @@ impl<'config, 'names> ScanVisitor<'config, 'names> {
 		true
 	}
 
+	/// Add the classes of the `sk!` macro. Invalid classes are reported by the
+	/// macro itself so they are skipped here.
+	fn visit_sk_macro(&mut self, node: &syn::Macro) -> bool {
+		let Some(segment) = node.path.segments.last() else {
+			return false;
+		};
+
+		if segment.ident != "sk" {
+			return false;
+		}
+
+		for class in parse_sk_macro(node.tokens.clone()).into_iter().flatten() {
+			for class_name in get_class_names(self.config, &class).into_iter().flatten() {
+				self.classes
+					.insert_factory(ClassFactory::from_string(self.config, class_name));
+			}
+		}
+
+		true
+	}
+
+	/// Visit the macros whose bodies are a comma separated list of rust
+	/// expressions, as configured by [`crate::RustPlugin::expression_macros`].
+	fn visit_expression_macro(&mut self, node: &syn::Macro) -> bool {
+		if !self
+			.plugin
+			.expression_macros
+			.iter()
+			.any(|path| path_matches(path, &node.path))
+		{
+			return false;
+		}
+
+		match node.parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated) {
+			Ok(expressions) => {
+				for expression in expressions.iter() {
+					self.visit_expr(expression);
+				}
+			}
+			Err(_) => self.visit_template_tokens(node.tokens.clone()),
+		}
+
+		true
+	}
+
+	/// Visit the template macros of frameworks which don't use the `rstml`
+	/// syntax.
+	///
+	/// - Yew `html! { <div class={sk().p().n2()} /> }`
+	/// - Dioxus `rsx! { div { class: sk().p().n2() } }`
+	/// - Sycamore `view! { div(class=sk().p().n2()) }`
+	fn visit_template_macro(&mut self, node: &syn::Macro) -> bool {
+		let Some(segment) = node.path.segments.last() else {
+			return false;
+		};
+
+		if !TEMPLATE_MACROS.iter().any(|name| segment.ident == name) {
+			return false;
+		}
+
+		if self.plugin.scan_literals {
+			self.insert_template_literal_classes(node.tokens.clone());
+		}
+
+		self.visit_template_tokens(node.tokens.clone());
+		true
+	}
+
+	/// Add the class names of the `class="..."` and `class: "..."` attributes
+	/// within the tokens of a template macro.
+	fn insert_template_literal_classes(&mut self, tokens: TokenStream) {
+		let trees = tokens.into_iter().collect::<Vec<_>>();
+
+		for tree in trees.iter() {
+			if let TokenTree::Group(group) = tree {
+				self.insert_template_literal_classes(group.stream());
+			}
+		}
+
+		for window in trees.windows(3) {
+			let [
+				TokenTree::Ident(name),
+				TokenTree::Punct(punct),
+				TokenTree::Literal(literal),
+			] = window
+			else {
+				continue;
+			};
+
+			if name != "class" || !matches!(punct.as_char(), '=' | ':') {
+				continue;
+			}
+
+			if let syn::Lit::Str(literal) = syn::Lit::new(literal.clone()) {
+				self.insert_literal_classes(&literal.value());
+			}
+		}
+	}
+
+	/// Split the tokens of a template into comma separated segments and visit
+	/// every segment which can be parsed as a rust expression. The remaining
+	/// segments are searched for blocks and nested groups.
+	fn visit_template_tokens(&mut self, tokens: TokenStream) {
+		let mut segment = Vec::<TokenTree>::new();
+
+		for tree in tokens {
+			match tree {
+				TokenTree::Punct(ref punct) if matches!(punct.as_char(), ',' | ';') => {
+					self.visit_template_segment(&segment);
+					segment.clear();
+				}
+				tree => segment.push(tree),
+			}
+		}
+
+		self.visit_template_segment(&segment);
+	}
+
+	fn visit_template_segment(&mut self, segment: &[TokenTree]) {
+		// Dioxus attributes are written as `name: value`.
+		let value = match segment {
+			[TokenTree::Ident(_), TokenTree::Punct(punct), rest @ ..]
+				if punct.as_char() == ':'
+					&& punct.spacing() == Spacing::Alone
+					&& !rest.is_empty() =>
+			{
+				rest
+			}
+			segment => segment,
+		};
+
+		if value.is_empty() {
+			return;
+		}
+
+		if let Ok(expression) = syn::parse2::<syn::Expr>(value.iter().cloned().collect()) {
+			self.visit_expr(&expression);
+			return;
+		}
+
+		for tree in value.iter() {
+			let TokenTree::Group(group) = tree else {
+				continue;
+			};
+
+			if group.delimiter() == Delimiter::Brace {
+				if let Ok(block) = syn::parse2::<syn::Block>(tree.clone().into()) {
+					self.visit_block(&block);
+					continue;
+				}
+			}
+
+			self.visit_template_tokens(group.stream());
+		}
+	}
+
 	fn visit_leptos_nodes(&mut self, nodes: &[Node]) {
 		for node in nodes.iter() {
 			match node {
@@ -140,6 +421,18 @@ impl<'config, 'names> ScanVisitor<'config, 'names> {
 								self.visit_block(block)
 							}
 							NodeAttribute::Attribute(attribute) => {
+								if self.plugin.scan_literals {
+									let key = attribute.key.to_string();
+
+									if let Some(class_name) = key.strip_prefix("class:") {
+										self.insert_literal_classes(class_name);
+									} else if let ("class", KeyedAttributeValue::Value(value)) =
+										(key.as_str(), &attribute.possible_value)
+									{
+										self.insert_attribute_literal_classes(&value.value);
+									}
+								}
+
 								match &attribute.possible_value {
 									KeyedAttributeValue::None => {
 										continue;
@@ -168,31 +461,208 @@ impl<'config, 'names> ScanVisitor<'config, 'names> {
 	}
 
 	fn update_skribble_method(&mut self, node: &syn::ExprMethodCall) -> bool {
+		if self.update_static_transformer_method(node) {
+			return true;
+		}
+
 		let mut tokens = vec![node.method.to_string()];
 		let mut arguments = String::new();
 		let mut transformers = vec![];
 		read_arguments_from_method_call(node, &mut arguments);
-		read_tokens_from_expression(
-			self.method_names,
+		self.get_resolver().read_tokens_from_expression(
 			node.receiver.as_ref(),
 			&mut tokens,
 			&mut transformers,
 		);
+
+		if !starts_with_root(&tokens, &self.plugin.root_function) {
+			self.check_unresolved_chain(node, &tokens);
+			return false;
+		}
+
 		self.update_with_tokens(&tokens, &transformers, &arguments);
+		true
+	}
+
+	/// The static mode applies transformers with arbitrary values to the class
+	/// name which is passed in, e.g. `sk().alpha_("50%", sk().bg().red100())`.
+	fn update_static_transformer_method(&mut self, node: &syn::ExprMethodCall) -> bool {
+		let method_name = node.method.to_string();
+		let transformer = method_name
+			.strip_suffix('_')
+			.map(|name| format!("{TRANSFORMER_PREFIX}:::{name}"))
+			.and_then(|key| self.plugin.method_names.get(&key));
+
+		let (
+			Some(transformer),
+			2,
+			Some(syn::Expr::Lit(syn::ExprLit {
+				lit: syn::Lit::Str(value),
+				..
+			})),
+			Some(class),
+		) = (
+			transformer,
+			node.args.len(),
+			node.args.first(),
+			node.args.last(),
+		)
+		else {
+			return false;
+		};
+
+		let resolver = self.get_resolver();
+		let Some(class_chain) = resolver.resolve(class) else {
+			return false;
+		};
 
-		starts_with_sk(&tokens)
+		let mut chain = Chain::default();
+		resolver.read_tokens_from_expression(
+			node.receiver.as_ref(),
+			&mut chain.tokens,
+			&mut chain.transformers,
+		);
+
+		if !starts_with_root(&chain.tokens, &self.plugin.root_function) {
+			return false;
+		}
+
+		let mut arguments = String::new();
+
+		if let syn::Expr::MethodCall(method) = class {
+			read_arguments_from_method_call(method, &mut arguments);
+		}
+
+		chain
+			.transformers
+			.push(format!("{transformer}={}", value.value()));
+		chain.tokens.extend(class_chain.tokens.into_iter().skip(1));
+		chain.transformers.extend(class_chain.transformers);
+		self.update_with_tokens(&chain.tokens, &chain.transformers, &arguments);
+
+		true
+	}
+
+	fn get_resolver(&self) -> ChainResolver<'_> {
+		ChainResolver {
+			plugin: self.plugin,
+			functions: &self.functions,
+			bindings: &self.bindings,
+		}
+	}
+
+	/// Warn about method chains which look like generated methods but don't
+	/// start with `sk()`, e.g. when the chain starts with a function parameter.
+	fn check_unresolved_chain(&mut self, node: &syn::ExprMethodCall, tokens: &[String]) {
+		let Some((root, methods)) = tokens.split_first() else {
+			return;
+		};
+
+		if methods.len() < 2
+			|| !methods
+				.iter()
+				.all(|method| self.generated_methods.contains(method.as_str()))
+		{
+			return;
+		}
+
+		let mut receiver = node.receiver.as_ref();
+
+		while let syn::Expr::MethodCall(method) = receiver {
+			receiver = method.receiver.as_ref();
+		}
+
+		let start = receiver.span().start();
+		self.classes
+			.insert_diagnostic(ClassDiagnostic::UnresolvedChain {
+				root: root.clone(),
+				file: self.file_path.clone(),
+				line: start.line,
+				column: start.column + 1,
+			});
 	}
 }
 
-impl<'ast, 'config, 'names> Visit<'ast> for ScanVisitor<'config, 'names> {
+impl<'ast, 'config, 'plugin> Visit<'ast> for ScanVisitor<'config, 'plugin> {
+	fn visit_file(&mut self, node: &'ast File) {
+		self.functions = collect_chain_functions(self.plugin, &node.items);
+		visit::visit_file(self, node);
+	}
+
+	fn visit_item_fn(&mut self, node: &'ast syn::ItemFn) {
+		// Bindings from the enclosing function aren't visible in nested functions.
+		let bindings = std::mem::take(&mut self.bindings);
+		visit::visit_item_fn(self, node);
+		self.bindings = bindings;
+	}
+
+	fn visit_impl_item_fn(&mut self, node: &'ast syn::ImplItemFn) {
+		let bindings = std::mem::take(&mut self.bindings);
+		visit::visit_impl_item_fn(self, node);
+		self.bindings = bindings;
+	}
+
+	fn visit_block(&mut self, node: &'ast syn::Block) {
+		self.bindings.push(IndexMap::new());
+		visit::visit_block(self, node);
+		self.bindings.pop();
+	}
+
+	fn visit_local(&mut self, node: &'ast syn::Local) {
+		visit::visit_local(self, node);
+
+		let Some(name) = get_binding_name(&node.pat) else {
+			return;
+		};
+
+		let chain = node
+			.init
+			.as_ref()
+			.and_then(|init| self.get_resolver().resolve(&init.expr));
+
+		if let Some(scope) = self.bindings.last_mut() {
+			scope.insert(name, chain);
+		}
+	}
+
+	fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+		if let (true, syn::Expr::Path(function)) = (self.plugin.scan_literals, node.func.as_ref()) {
+			let is_literal_function = self
+				.plugin
+				.literal_functions
+				.iter()
+				.any(|path| path_matches(path, &function.path));
+
+			if is_literal_function {
+				for argument in node.args.iter() {
+					if let syn::Expr::Lit(syn::ExprLit {
+						lit: syn::Lit::Str(literal),
+						..
+					}) = argument
+					{
+						self.insert_literal_classes(&literal.value());
+					}
+				}
+			}
+		}
+
+		visit::visit_expr_call(self, node);
+	}
+
 	fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+		self.insert_class_list_classes(node);
+
 		if !self.update_skribble_method(node) {
 			visit::visit_expr_method_call(self, node);
 		}
 	}
 
 	fn visit_macro(&mut self, node: &'ast syn::Macro) {
-		if self.visit_leptos_view_macro(node) {
+		if self.visit_leptos_view_macro(node)
+			|| self.visit_sk_macro(node)
+			|| self.visit_expression_macro(node)
+			|| self.visit_template_macro(node)
+		{
 			return;
 		}
 
@@ -224,60 +694,262 @@ fn read_arguments_from_method_call(method: &syn::ExprMethodCall, arguments: &mut
 	}
 }
 
-fn read_tokens_from_expression(
-	method_names: &IndexMap<String, String>,
-	node: &syn::Expr,
-	tokens: &mut Vec<String>,
-	transformers: &mut Vec<String>,
-) {
+/// Whether the method chain starts with a `ClassList`, e.g.
+/// `ClassList::new()`.
+fn is_class_list(node: &syn::Expr) -> bool {
 	match node {
-		syn::Expr::MethodCall(method) => {
-			let method_name = method.method.to_string();
-			let mut arguments = String::new();
-			read_arguments_from_method_call(method, &mut arguments);
-			let key = format!("{}:::{method_name}", TRANSFORMER_PREFIX);
+		syn::Expr::MethodCall(method) => is_class_list(&method.receiver),
+		syn::Expr::Paren(paren) => is_class_list(&paren.expr),
+		syn::Expr::Call(call) => {
+			let syn::Expr::Path(function) = call.func.as_ref() else {
+				return false;
+			};
+
+			function
+				.path
+				.segments
+				.iter()
+				.any(|segment| segment.ident == CLASS_LIST)
+		}
+		_ => false,
+	}
+}
+
+/// The name of the generated class list struct.
+const CLASS_LIST: &str = "ClassList";
+
+/// A partial `sk()` chain which is stored in a local binding or returned from
+/// a function.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Chain {
+	tokens: Vec<String>,
+	transformers: Vec<String>,
+}
+
+/// Reads the tokens of a method chain and follows the local bindings and
+/// function calls which resolve to partial chains.
+struct ChainResolver<'a> {
+	plugin: &'a RustPlugin,
+	functions: &'a IndexMap<String, Chain>,
+	bindings: &'a [IndexMap<String, Option<Chain>>],
+}
+
+impl<'a> ChainResolver<'a> {
+	/// Get the partial chain of an expression when it starts with `sk()`.
+	fn resolve(&self, node: &syn::Expr) -> Option<Chain> {
+		let node = match node {
+			syn::Expr::Paren(paren) => paren.expr.as_ref(),
+			syn::Expr::Return(syn::ExprReturn {
+				expr: Some(expr), ..
+			}) => expr.as_ref(),
+			node => node,
+		};
+
+		let mut chain = Chain::default();
+		self.read_tokens_from_expression(node, &mut chain.tokens, &mut chain.transformers);
+
+		if starts_with_root(&chain.tokens, &self.plugin.root_function) {
+			Some(chain)
+		} else {
+			None
+		}
+	}
 
-			if let Some(transformer) = method_names.get(&key) {
-				if arguments.is_empty() {
-					transformers.push(transformer.into());
+	fn get_binding(&self, name: &str) -> Option<&Chain> {
+		self.bindings
+			.iter()
+			.rev()
+			.find_map(|scope| scope.get(name))
+			.and_then(|chain| chain.as_ref())
+	}
+
+	fn read_tokens_from_expression(
+		&self,
+		node: &syn::Expr,
+		tokens: &mut Vec<String>,
+		transformers: &mut Vec<String>,
+	) {
+		match node {
+			syn::Expr::MethodCall(method) => {
+				let method_name = method.method.to_string();
+				let mut arguments = String::new();
+				read_arguments_from_method_call(method, &mut arguments);
+				let key = format!("{}:::{method_name}", TRANSFORMER_PREFIX);
+
+				if let Some(transformer) = self.plugin.method_names.get(&key) {
+					if arguments.is_empty() {
+						transformers.push(transformer.into());
+					} else {
+						transformers.push(format!("{transformer}={arguments}"));
+					}
 				} else {
-					transformers.push(format!("{transformer}={arguments}"));
+					tokens.insert(0, method_name);
+				}
+
+				self.read_tokens_from_expression(method.receiver.as_ref(), tokens, transformers);
+			}
+			syn::Expr::Call(call) => {
+				let function =
+					get_path_ident(call.func.as_ref()).and_then(|name| self.functions.get(&name));
+
+				match function {
+					Some(chain) => prepend_chain(chain, tokens, transformers),
+					None => {
+						self.read_tokens_from_expression(call.func.as_ref(), tokens, transformers)
+					}
 				}
-			} else {
-				tokens.insert(0, method_name);
 			}
+			syn::Expr::Path(path) => {
+				if let Some(chain) = get_path_ident(node).and_then(|name| self.get_binding(&name)) {
+					prepend_chain(chain, tokens, transformers);
+				} else if let Some(path) = path
+					.path
+					.segments
+					.last()
+					.map(|segment| segment.ident.to_string())
+				{
+					tokens.insert(0, path);
+				}
+			}
+			_ => {}
+		}
+	}
+}
+
+fn prepend_chain(chain: &Chain, tokens: &mut Vec<String>, transformers: &mut Vec<String>) {
+	tokens.splice(0..0, chain.tokens.iter().cloned());
+	transformers.extend(chain.transformers.iter().cloned());
+}
+
+/// The name of a path expression with a single segment, e.g. `base`.
+fn get_path_ident(node: &syn::Expr) -> Option<String> {
+	let syn::Expr::Path(path) = node else {
+		return None;
+	};
+
+	path.path.get_ident().map(|ident| ident.to_string())
+}
+
+fn get_binding_name(pat: &syn::Pat) -> Option<String> {
+	match pat {
+		syn::Pat::Ident(ident) => Some(ident.ident.to_string()),
+		syn::Pat::Type(pat_type) => get_binding_name(&pat_type.pat),
+		_ => None,
+	}
+}
+
+/// Find the functions of the file (and its inline modules) which return a
+/// partial `sk()` chain from their final expression. Functions which share a
+/// name are ignored since the call can't be resolved without the module path.
+fn collect_chain_functions(plugin: &RustPlugin, items: &[syn::Item]) -> IndexMap<String, Chain> {
+	let mut item_fns = IndexMap::<String, Option<&syn::ItemFn>>::new();
+	collect_item_fns(items, &mut item_fns);
+
+	let mut functions = IndexMap::<String, Chain>::new();
+
+	// Functions can call each other so keep resolving until nothing changes.
+	for _ in 0..=item_fns.len() {
+		let mut changed = false;
+
+		for (name, item_fn) in item_fns.iter() {
+			let Some(item_fn) = item_fn else {
+				continue;
+			};
+
+			let Some(chain) = resolve_function_chain(plugin, &functions, item_fn) else {
+				continue;
+			};
 
-			read_tokens_from_expression(
-				method_names,
-				method.receiver.as_ref(),
-				tokens,
-				transformers,
-			);
+			if functions.get(name) != Some(&chain) {
+				functions.insert(name.clone(), chain);
+				changed = true;
+			}
 		}
-		syn::Expr::Call(call) => {
-			read_tokens_from_expression(method_names, call.func.as_ref(), tokens, transformers);
+
+		if !changed {
+			break;
 		}
-		syn::Expr::Path(path) => {
-			if let Some(path) = path
-				.path
-				.segments
-				.last()
-				.map(|segment| segment.ident.to_string())
-			{
-				tokens.insert(0, path);
+	}
+
+	functions
+}
+
+fn collect_item_fns<'a>(
+	items: &'a [syn::Item],
+	item_fns: &mut IndexMap<String, Option<&'a syn::ItemFn>>,
+) {
+	for item in items.iter() {
+		match item {
+			syn::Item::Fn(item_fn) => {
+				let name = item_fn.sig.ident.to_string();
+
+				if item_fns.contains_key(&name) {
+					item_fns.insert(name, None);
+				} else {
+					item_fns.insert(name, Some(item_fn));
+				}
 			}
+			syn::Item::Mod(syn::ItemMod {
+				content: Some((_, items)),
+				..
+			}) => collect_item_fns(items, item_fns),
+			_ => {}
 		}
-		_ => {}
 	}
 }
 
+fn resolve_function_chain(
+	plugin: &RustPlugin,
+	functions: &IndexMap<String, Chain>,
+	item_fn: &syn::ItemFn,
+) -> Option<Chain> {
+	let mut bindings = vec![IndexMap::<String, Option<Chain>>::new()];
+	let (last, statements) = item_fn.block.stmts.split_last()?;
+
+	for statement in statements.iter() {
+		let syn::Stmt::Local(local) = statement else {
+			continue;
+		};
+
+		let Some(name) = get_binding_name(&local.pat) else {
+			continue;
+		};
+
+		let resolver = ChainResolver {
+			plugin,
+			functions,
+			bindings: &bindings,
+		};
+		let chain = local
+			.init
+			.as_ref()
+			.and_then(|init| resolver.resolve(&init.expr));
+
+		if let Some(scope) = bindings.last_mut() {
+			scope.insert(name, chain);
+		}
+	}
+
+	let syn::Stmt::Expr(expression, None) = last else {
+		return None;
+	};
+
+	let resolver = ChainResolver {
+		plugin,
+		functions,
+		bindings: &bindings,
+	};
+
+	resolver.resolve(expression)
+}
+
 pub(crate) fn scan(
 	config: &RunnerConfig,
-	_file_path: impl AsRef<str>,
+	file_path: impl AsRef<str>,
 	content: impl AsRef<str>,
-	method_names: &IndexMap<String, String>,
+	plugin: &RustPlugin,
 ) -> AnyResult<Classes> {
-	let mut visitor = ScanVisitor::new(config, method_names);
+	let mut visitor = ScanVisitor::new(config, plugin, file_path.as_ref());
 	let syntax_tree: File = syn::parse_str(content.as_ref())?;
 
 	visitor.visit_file(&syntax_tree);
@@ -285,6 +957,20 @@ pub(crate) fn scan(
 	Ok(visitor.classes)
 }
 
-fn starts_with_sk(tokens: &[String]) -> bool {
-	tokens.first() == Some(&"sk".to_string())
+/// The names of the template macros which are scanned token by token.
+const TEMPLATE_MACROS: &[&str] = &["html", "rsx", "view"];
+
+/// Check whether the configured `path`, e.g. `yew::classes`, matches the path
+/// of a macro or function. Only the trailing segments are compared so
+/// `classes!` and `yew::classes!` both match.
+fn path_matches(path: &str, macro_path: &syn::Path) -> bool {
+	path.rsplit("::")
+		.zip(macro_path.segments.iter().rev())
+		.all(|(name, segment)| segment.ident == name)
+}
+
+/// Check whether the tokens start with the generated root function, `sk` by
+/// default.
+fn starts_with_root(tokens: &[String], root_function: &str) -> bool {
+	tokens.first().map(String::as_str) == Some(root_function)
 }
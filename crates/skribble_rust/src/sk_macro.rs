@@ -0,0 +1,428 @@
+use proc_macro2::Delimiter;
+use proc_macro2::Group;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
+use skribble_core::ClassFactory;
+use skribble_core::ClassTransformer;
+use skribble_core::RunnerConfig;
+use syn::Error;
+use syn::Lit;
+use syn::Result;
+
+/// The names which are searched for suggestions when a global token is
+/// unknown.
+const GLOBAL_NAMES: &[&str] = &[
+	"media_queries",
+	"feature_queries",
+	"container_queries",
+	"modifiers",
+	"atoms",
+	"classes",
+	"aliases",
+];
+
+/// A single class name passed to the `sk!` macro.
+#[derive(Clone, Debug)]
+pub struct MacroClass {
+	pub tokens: Vec<MacroToken>,
+}
+
+impl MacroClass {
+	/// The class name with the tokens separated by `:`.
+	pub fn class_name(&self) -> String {
+		self.tokens
+			.iter()
+			.map(|token| token.value.as_str())
+			.collect::<Vec<_>>()
+			.join(":")
+	}
+}
+
+/// A token of a class name along with the span used for error reporting.
+#[derive(Clone, Debug)]
+pub struct MacroToken {
+	pub value: String,
+	pub span: Span,
+}
+
+/// Parse the input of the `sk!` macro.
+///
+/// Classes can be written as space separated tokens, `sk!(md hover p $2)`, or
+/// as a string, `sk!("md:hover:p:$2")`. Multiple classes are separated by
+/// commas in the token form and by whitespace in the string form.
+pub fn parse_sk_macro(input: TokenStream) -> Result<Vec<MacroClass>> {
+	let mut parser = MacroParser::default();
+
+	for tree in input {
+		parser.push(tree)?;
+	}
+
+	parser.finish_class();
+
+	if parser.classes.is_empty() {
+		return Err(Error::new(
+			Span::call_site(),
+			"expected at least one class, e.g. `sk!(md p $2)`",
+		));
+	}
+
+	Ok(parser.classes)
+}
+
+/// Validate the input of the `sk!` macro against the configuration and return
+/// the space separated class names.
+pub fn expand_sk_macro(config: &RunnerConfig, input: TokenStream) -> Result<String> {
+	let mut class_names = vec![];
+	let mut errors: Option<Error> = None;
+
+	for class in parse_sk_macro(input)?.iter() {
+		match get_class_names(config, class) {
+			Ok(names) => class_names.extend(names),
+			Err(error) => {
+				match errors.as_mut() {
+					Some(errors) => errors.combine(error),
+					None => errors = Some(error),
+				}
+			}
+		}
+	}
+
+	match errors {
+		Some(errors) => Err(errors),
+		None => Ok(class_names.join(" ")),
+	}
+}
+
+/// Get the content of a `(transformer)` or `[argument]` group with the
+/// spacing of the source, e.g. `[calc(100% - 1rem)]`. The spacing of tokens
+/// which don't come from the source is unknown, so a string literal is
+/// required when the group contains more than one token.
+fn get_group_content(group: &Group) -> Result<String> {
+	if let Some(source) = group.span().source_text() {
+		let content = source.get(1..source.len() - 1).unwrap_or_default();
+		return Ok(content.split_whitespace().collect::<Vec<_>>().join(" "));
+	}
+
+	let mut tokens = group.stream().into_iter();
+
+	match (tokens.next(), tokens.next()) {
+		(Some(token), None) => Ok(token.to_string()),
+		_ => {
+			Err(Error::new(
+				group.span(),
+				"use a string literal for this group, e.g. `[\"calc(100% - 1rem)\"]`",
+			))
+		}
+	}
+}
+
+/// Validate every token of the class with the [`ClassFactory`] and get the
+/// class names which are written to the stylesheet. Aliases are expanded into
+/// the classes they reference.
+pub fn get_class_names(config: &RunnerConfig, class: &MacroClass) -> Result<Vec<String>> {
+	let mut factory = ClassFactory::new(config);
+
+	for (index, token) in class.tokens.iter().enumerate() {
+		let value = token.value.as_str();
+
+		if value.starts_with('(') && value.ends_with(')') {
+			let transformer = ClassTransformer::from(value);
+
+			if !factory.add_transformer(&transformer) {
+				let names = config
+					.transformers
+					.values()
+					.flat_map(|map| map.keys())
+					.map(String::as_str);
+
+				return Err(unknown_token(
+					&transformer.name,
+					token.span,
+					"transformer",
+					names,
+				));
+			}
+
+			continue;
+		}
+
+		if factory.is_locked() {
+			let complete = class
+				.tokens
+				.iter()
+				.take(index)
+				.map(|token| token.value.as_str())
+				.collect::<Vec<_>>()
+				.join(":");
+
+			return Err(Error::new(
+				token.span,
+				format!("unexpected token `{value}` after the complete class `{complete}`"),
+			));
+		}
+
+		if let Some(argument) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+			factory.add_argument(argument.into());
+
+			if factory.is_invalid() {
+				let message = match factory.get_diagnostics().first() {
+					Some(diagnostic) => diagnostic.to_string(),
+					None => format!("the argument `{argument}` is not valid here"),
+				};
+
+				return Err(Error::new(token.span, message));
+			}
+
+			continue;
+		}
+
+		let name = value.strip_prefix('$').unwrap_or(value);
+		let atom = factory.get_atom().cloned();
+		factory.add_token(name);
+
+		if !factory.is_invalid() {
+			continue;
+		}
+
+		return Err(match atom {
+			Some(atom) => {
+				let names = config
+					.names
+					.get(&format!("atom:{atom}"))
+					.into_iter()
+					.flatten()
+					.map(String::as_str);
+				let kind = format!("value `{name}` for the atom `{atom}`");
+				unknown_name(name, token.span, &kind, names)
+			}
+			None => {
+				let names = GLOBAL_NAMES
+					.iter()
+					.filter_map(|key| config.names.get(*key))
+					.flatten()
+					.map(String::as_str);
+				unknown_token(name, token.span, "token", names)
+			}
+		});
+	}
+
+	if !factory.is_valid() {
+		let span = class
+			.tokens
+			.last()
+			.map_or(Span::call_site(), |token| token.span);
+		let message = match factory.get_atom() {
+			Some(atom) => format!("the atom `{atom}` requires a value, e.g. `{atom} $value`"),
+			None => {
+				format!(
+					"the class `{}` is incomplete and needs an atom, named class or alias",
+					class.class_name()
+				)
+			}
+		};
+
+		return Err(Error::new(span, message));
+	}
+
+	factory
+		.into_classes()
+		.iter()
+		.map(|class| {
+			class
+				.class_name()
+				.map_err(|error| Error::new(Span::call_site(), error.to_string()))
+		})
+		.collect()
+}
+
+fn unknown_token<'a>(
+	value: &str,
+	span: Span,
+	kind: &str,
+	names: impl Iterator<Item = &'a str>,
+) -> Error {
+	unknown_name(value, span, &format!("{kind} `{value}`"), names)
+}
+
+/// Create the error for an unknown name with the closest `names` as
+/// suggestions. The `description` is used in the message, e.g. ``token
+/// `hovr` ``.
+fn unknown_name<'a>(
+	value: &str,
+	span: Span,
+	description: &str,
+	names: impl Iterator<Item = &'a str>,
+) -> Error {
+	let suggestions = get_suggestions(value, names);
+	let message = match suggestions.as_slice() {
+		[] => format!("unknown {description}"),
+		suggestions => {
+			let suggestions = suggestions
+				.iter()
+				.map(|name| format!("`{name}`"))
+				.collect::<Vec<_>>()
+				.join(", ");
+			format!("unknown {description}, did you mean {suggestions}?")
+		}
+	};
+
+	Error::new(span, message)
+}
+
+/// Get the closest names to the unknown value.
+fn get_suggestions<'a>(value: &str, names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+	let mut scored = names
+		.map(|name| (strsim::damerau_levenshtein(value, name), name))
+		.filter(|(distance, name)| *distance <= (value.len().max(name.len()) / 3).max(1))
+		.collect::<Vec<_>>();
+
+	scored.sort_by_key(|(distance, _)| *distance);
+	scored.dedup_by_key(|(_, name)| *name);
+	scored.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+#[derive(Default)]
+struct MacroParser {
+	classes: Vec<MacroClass>,
+	tokens: Vec<MacroToken>,
+	current: Option<MacroToken>,
+	/// Whether the next identifier or literal continues the current token.
+	joined: bool,
+}
+
+impl MacroParser {
+	fn push(&mut self, tree: TokenTree) -> Result<()> {
+		match tree {
+			TokenTree::Punct(punct) => {
+				match punct.as_char() {
+					',' => self.finish_class(),
+					':' => self.finish_token(),
+					'$' => {
+						self.finish_token();
+						self.append("$", punct.span());
+						self.joined = true;
+					}
+					'-' | '.' | '/' | '%' => {
+						self.append(&punct.to_string(), punct.span());
+						self.joined = true;
+					}
+					ch => {
+						return Err(Error::new(
+							punct.span(),
+							format!("unexpected `{ch}` in the class name"),
+						));
+					}
+				}
+			}
+			TokenTree::Ident(ident) => self.push_word(ident.to_string(), ident.span()),
+			TokenTree::Literal(literal) => {
+				let span = literal.span();
+
+				match Lit::new(literal) {
+					Lit::Str(string) => {
+						if self.current.is_some() || !self.tokens.is_empty() {
+							return Err(Error::new(
+								span,
+								"string classes can't be mixed with tokens",
+							));
+						}
+
+						for class_name in string.value().split_whitespace() {
+							self.tokens = class_name
+								.split(':')
+								.map(|value| {
+									MacroToken {
+										value: value.into(),
+										span,
+									}
+								})
+								.collect();
+							self.finish_class();
+						}
+					}
+					literal => {
+						let value = match literal {
+							Lit::Int(int) => int.to_string(),
+							Lit::Float(float) => float.to_string(),
+							_ => {
+								return Err(Error::new(
+									span,
+									"unexpected literal in the class name",
+								));
+							}
+						};
+
+						self.push_word(value, span);
+					}
+				}
+			}
+			TokenTree::Group(group) => {
+				let span = group.span();
+				let content = match syn::parse2::<syn::LitStr>(group.stream()) {
+					Ok(string) => string.value(),
+					Err(_) => get_group_content(&group)?,
+				};
+
+				let value = match group.delimiter() {
+					// Transformers never contain spaces.
+					Delimiter::Parenthesis => {
+						format!("({})", content.split_whitespace().collect::<String>())
+					}
+					Delimiter::Bracket => format!("[{content}]"),
+					_ => {
+						return Err(Error::new(
+							span,
+							"use `(transformer)` or `[argument]` groups",
+						));
+					}
+				};
+
+				self.finish_token();
+				self.append(&value, span);
+				self.finish_token();
+			}
+		}
+
+		Ok(())
+	}
+
+	fn push_word(&mut self, value: String, span: Span) {
+		if !self.joined {
+			self.finish_token();
+		}
+
+		self.append(&value, span);
+		self.joined = false;
+	}
+
+	fn append(&mut self, value: &str, span: Span) {
+		match self.current.as_mut() {
+			Some(token) => token.value.push_str(value),
+			None => {
+				self.current = Some(MacroToken {
+					value: value.into(),
+					span,
+				});
+			}
+		}
+	}
+
+	fn finish_token(&mut self) {
+		self.joined = false;
+
+		if let Some(token) = self.current.take() {
+			self.tokens.push(token);
+		}
+	}
+
+	fn finish_class(&mut self) {
+		self.finish_token();
+
+		if !self.tokens.is_empty() {
+			self.classes.push(MacroClass {
+				tokens: std::mem::take(&mut self.tokens),
+			});
+		}
+	}
+}
@@ -0,0 +1,23 @@
+use rstest_reuse::template;
+
+/// The class names which every generated code mode supports.
+#[template]
+#[export]
+#[rstest]
+#[case::custom(sk().__("height", "40px"), "[height=40px]")]
+#[case::breakpoint_padding(sk().md().p().px(), "md:p:$px")]
+#[case::background_color(sk().bg().accent(), "bg:$accent")]
+#[case::breakpoint_padding_argument(sk().md().pt_("1px"), "md:pt:[1px]")]
+#[case::breakpoint_key_value_argument(sk().md_("padding", "1px"), "md:[padding=1px]")]
+#[case::feature_query_padding(sk().md().supports_grid().p().n4(), "md:supports-grid:p:$4")]
+#[case::aspect_ratio(sk().aspect().square(), "aspect:$square")]
+#[case::aliases(sk().hover().inline_block(), "hover:display:$inline-block")]
+#[case::important_named_class(sk().important().sr_only(), "(important):$sr-only")]
+#[case::transformer_color(sk().md().darken_050().bg().red100(), "md:(darken==050):bg:$red100")]
+#[case::transformer_invert(sk().md().oklch_invert().text().primary(), "md:(oklch-invert):text:$primary")]
+#[case::variables(vars().primary(), "--sk-p")]
+#[case::class_list(
+  ClassList::new().add(sk().p().n2()).add_if(true, sk().bg().red100()).add_if(false, "hidden").extend(["flex"]).to_string(),
+  "p:$2 bg:$red100 flex"
+)]
+fn generated_class_name_cases(#[case] input: impl AsRef<str>, #[case] expected: &str) {}
@@ -0,0 +1,10 @@
+pub fn classes() -> Vec<String> {
+  vec![
+    sk().md().p().px(),
+    sk().md().pt_("1px"),
+    sk().bg().accent(),
+    sk().md().darken_050().bg().red100(),
+    sk().aspect().square(),
+    sk().md().oklch_invert().text().primary(),
+  ]
+}
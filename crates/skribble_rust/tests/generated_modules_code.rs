@@ -0,0 +1,17888 @@
+#![allow(clippy::all)]
+#![allow(unused)]
+// This file was generated by skribble.
+use private::GeneratedSkribbleValue;
+pub fn sk() -> GeneratedSkribbleRoot {
+  GeneratedSkribbleRoot::from_ref("")
+}
+pub struct GeneratedSkribbleRoot(String);
+impl GeneratedSkribbleValue for GeneratedSkribbleRoot {
+  #[inline]
+  fn from_ref(value: impl AsRef<str>) -> Self {
+    Self(value.as_ref().to_string())
+  }
+  #[inline]
+  fn get_skribble_value(&self) -> &String {
+    &self.0
+  }
+}
+impl GeneratedSkribbleRoot {
+  pub fn __(&self, property: &'static str, value: &'static str) -> String {
+    self.append(format!("[{}={}]", property.trim(), value.trim()))
+  }
+}
+mod private {
+  #[doc(hidden)]
+  pub trait GeneratedSkribbleValue {
+    fn from_ref(value: impl AsRef<str>) -> Self;
+    fn get_skribble_value(&self) -> &String;
+    #[inline]
+    fn append(&self, value: impl AsRef<str>) -> String {
+      let current_value = self.get_skribble_value();
+      let prefix = if current_value.is_empty() {
+        "".into()
+      } else {
+        format!("{current_value}:")
+      };
+
+      format!("{}{}", prefix, value.as_ref())
+    }
+    #[inline]
+    fn append_transformer(&self, name: impl AsRef<str>, value: Option<&'static str>) -> String {
+      self.append(if let Some(value) = value {
+        format!("({}={})", name.as_ref(), value.trim())
+      } else {
+        format!("({})", name.as_ref())
+      })
+    }
+    #[inline]
+    fn append_value(&self, value: impl AsRef<str>) -> String {
+      format!("{}:${}", self.get_skribble_value(), value.as_ref())
+    }
+  }
+}
+/// A list of class names which can be built up conditionally.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClassList(Vec<String>);
+impl ClassList {
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+  /// Add the class names.
+  #[inline]
+  pub fn add(mut self, class: impl Into<String>) -> Self {
+    let class = class.into();
+    if !class.is_empty() {
+      self.0.push(class);
+    }
+    self
+  }
+  /// Add the class names when the condition is `true`.
+  #[inline]
+  pub fn add_if(self, condition: bool, class: impl Into<String>) -> Self {
+    if condition {
+      self.add(class)
+    } else {
+      self
+    }
+  }
+  /// Add the class names of every item.
+  #[inline]
+  pub fn extend<C: Into<String>>(self, classes: impl IntoIterator<Item = C>) -> Self {
+    classes.into_iter().fold(self, |list, class| list.add(class))
+  }
+}
+impl core::fmt::Display for ClassList {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&self.0.join(" "))
+  }
+}
+impl From<ClassList> for String {
+  #[inline]
+  fn from(list: ClassList) -> Self {
+    list.0.join(" ")
+  }
+}
+pub fn vars() -> GeneratedCssVariables {
+  GeneratedCssVariables
+}
+pub struct GeneratedCssVariables;
+impl GeneratedCssVariables {
+  /// The primary color. Useful for primary buttons.
+  ///
+  /// ```css
+  /// @property --sk-p {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(258.89362 94.37751% 51.17647%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn primary(&self) -> String {
+    "--sk-p".into()
+  }
+  /// The primary content color
+  ///
+  /// ```css
+  /// @property --sk-pc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn primary_content(&self) -> String {
+    "--sk-pc".into()
+  }
+  /// The secondary color. Useful for secondary buttons.
+  ///
+  /// ```css
+  /// @property --sk-s {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(314 100% 47.058823%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn secondary(&self) -> String {
+    "--sk-s".into()
+  }
+  /// The secondary content color. Useful for text within secondary buttons.
+  ///
+  /// ```css
+  /// @property --sk-sc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn secondary_content(&self) -> String {
+    "--sk-sc".into()
+  }
+  /// Color for accents.
+  ///
+  /// ```css
+  /// @property --sk-a {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(174 60.000008% 50.980396%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn accent(&self) -> String {
+    "--sk-a".into()
+  }
+  /// Color for content within accents.
+  ///
+  /// ```css
+  /// @property --sk-ac {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(174.70587 43.589737% 15.294118%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn accent_content(&self) -> String {
+    "--sk-ac".into()
+  }
+  /// The neutral color.
+  ///
+  /// ```css
+  /// @property --sk-n {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(218.99998 14.084508% 27.843138%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn neutral(&self) -> String {
+    "--sk-n".into()
+  }
+  /// The neutral content color.
+  ///
+  /// ```css
+  /// @property --sk-nc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn neutral_content(&self) -> String {
+    "--sk-nc".into()
+  }
+  /// The base color.
+  ///
+  /// ```css
+  /// @property --sk-b1 {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn base100(&self) -> String {
+    "--sk-b1".into()
+  }
+  /// The secondary base color.
+  ///
+  /// ```css
+  /// @property --sk-b2 {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 94.90196%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn base200(&self) -> String {
+    "--sk-b2".into()
+  }
+  /// The tertiary base color.
+  ///
+  /// ```css
+  /// @property --sk-b3 {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(180 1.9607836% 90%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn base300(&self) -> String {
+    "--sk-b3".into()
+  }
+  /// The base content color. This is useful for text.
+  ///
+  /// ```css
+  /// @property --sk-bc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(215 27.906975% 16.862745%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn base_content(&self) -> String {
+    "--sk-bc".into()
+  }
+  /// The info color. Useful for info buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-in {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(212.34569 100% 47.64706%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn info(&self) -> String {
+    "--sk-in".into()
+  }
+  /// The info content color. Useful for text within info buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-inc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn info_content(&self) -> String {
+    "--sk-inc".into()
+  }
+  /// The success color. Useful for success buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-su {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(136.8421 72.1519% 46.47059%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn success(&self) -> String {
+    "--sk-su".into()
+  }
+  /// The success content color. Useful for text within success buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-suc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn success_content(&self) -> String {
+    "--sk-suc".into()
+  }
+  /// The warning color. Useful for warning buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-wa {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(4.5614033 100% 66.47059%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn warning(&self) -> String {
+    "--sk-wa".into()
+  }
+  /// The warning content color. Useful for text within warning buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-wac {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn warning_content(&self) -> String {
+    "--sk-wac".into()
+  }
+  /// The error color. Useful for error buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-er {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(325.05154 77.600006% 49.019608%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn error(&self) -> String {
+    "--sk-er".into()
+  }
+  /// The error content color. Useful for text within error buttons and alerts.
+  ///
+  /// ```css
+  /// @property --sk-erc {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn error_content(&self) -> String {
+    "--sk-erc".into()
+  }
+  /// ```css
+  /// @property --sk-cmw {
+  ///   syntax: "*";
+  ///   inherits: true;
+  ///   initial-value: inherit;
+  /// }
+  /// ```
+  #[inline]
+  pub fn contained_max_width(&self) -> String {
+    "--sk-cmw".into()
+  }
+  /// ```css
+  /// @property --sk-empty {
+  ///   syntax: "*";
+  ///   inherits: true;
+  ///   initial-value: /* */;
+  /// }
+  /// ```
+  #[inline]
+  pub fn empty(&self) -> String {
+    "--sk-empty".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_blur(&self) -> String {
+    "--sk-filter-blur".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_brightness(&self) -> String {
+    "--sk-filter-brightness".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_contrast(&self) -> String {
+    "--sk-filter-contrast".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_custom(&self) -> String {
+    "--sk-filter-custom".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_grayscale(&self) -> String {
+    "--sk-filter-grayscale".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_hue_rotate(&self) -> String {
+    "--sk-filter-hue-rotate".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_invert(&self) -> String {
+    "--sk-filter-invert".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_saturate(&self) -> String {
+    "--sk-filter-saturate".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_sepia(&self) -> String {
+    "--sk-filter-sepia".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn filter_drop_shadow(&self) -> String {
+    "--sk-filter-drop-shadow".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_blur(&self) -> String {
+    "--sk-backdrop-blur".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_brightness(&self) -> String {
+    "--sk-backdrop-brightness".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_contrast(&self) -> String {
+    "--sk-backdrop-contrast".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_custom(&self) -> String {
+    "--sk-backdrop-custom".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_grayscale(&self) -> String {
+    "--sk-backdrop-grayscale".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_hue_rotate(&self) -> String {
+    "--sk-backdrop-hue-rotate".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_invert(&self) -> String {
+    "--sk-backdrop-invert".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_saturate(&self) -> String {
+    "--sk-backdrop-saturate".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_sepia(&self) -> String {
+    "--sk-backdrop-sepia".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn backdrop_drop_shadow(&self) -> String {
+    "--sk-backdrop-drop-shadow".into()
+  }
+  /// ```css
+  /// @property --sk-transition-duration {
+  ///   syntax: "<time>";
+  ///   inherits: true;
+  ///   initial-value: 150ms;
+  /// }
+  /// ```
+  #[inline]
+  pub fn transition_duration(&self) -> String {
+    "--sk-transition-duration".into()
+  }
+  /// ```css
+  /// @property --sk-animation-duration {
+  ///   syntax: "<time>";
+  ///   inherits: true;
+  ///   initial-value: 1s;
+  /// }
+  /// ```
+  #[inline]
+  pub fn animation_duration(&self) -> String {
+    "--sk-animation-duration".into()
+  }
+  /// ```css
+  /// @property --sk-enter-opacity {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn enter_opacity(&self) -> String {
+    "--sk-enter-opacity".into()
+  }
+  /// ```css
+  /// @property --sk-enter-tx {
+  ///   syntax: "<length-percentage>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn enter_translate_x(&self) -> String {
+    "--sk-enter-tx".into()
+  }
+  /// ```css
+  /// @property --sk-enter-ty {
+  ///   syntax: "<length-percentage>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn enter_translate_y(&self) -> String {
+    "--sk-enter-ty".into()
+  }
+  /// ```css
+  /// @property --sk-enter-sx {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn enter_scale_x(&self) -> String {
+    "--sk-enter-sx".into()
+  }
+  /// ```css
+  /// @property --sk-enter-sy {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn enter_scale_y(&self) -> String {
+    "--sk-enter-sy".into()
+  }
+  /// ```css
+  /// @property --sk-enter-rotate {
+  ///   syntax: "<angle>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn enter_rotate(&self) -> String {
+    "--sk-enter-rotate".into()
+  }
+  /// ```css
+  /// @property --sk-exit-opacity {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn exit_opacity(&self) -> String {
+    "--sk-exit-opacity".into()
+  }
+  /// ```css
+  /// @property --sk-exit-tx {
+  ///   syntax: "<length-percentage>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn exit_translate_x(&self) -> String {
+    "--sk-exit-tx".into()
+  }
+  /// ```css
+  /// @property --sk-exit-ty {
+  ///   syntax: "<length-percentage>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn exit_translate_y(&self) -> String {
+    "--sk-exit-ty".into()
+  }
+  /// ```css
+  /// @property --sk-exit-sx {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn exit_scale_x(&self) -> String {
+    "--sk-exit-sx".into()
+  }
+  /// ```css
+  /// @property --sk-exit-sy {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn exit_scale_y(&self) -> String {
+    "--sk-exit-sy".into()
+  }
+  /// ```css
+  /// @property --sk-exit-rotate {
+  ///   syntax: "<angle>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn exit_rotate(&self) -> String {
+    "--sk-exit-rotate".into()
+  }
+  /// ```css
+  /// @property --sk-space-x-reverse {
+  ///   syntax: "<number>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn space_x_reverse(&self) -> String {
+    "--sk-space-x-reverse".into()
+  }
+  /// ```css
+  /// @property --sk-space-y-reverse {
+  ///   syntax: "<number>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn space_y_reverse(&self) -> String {
+    "--sk-space-y-reverse".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn gradient_stops(&self) -> String {
+    "--sk-gradient-stops".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn gradient_from(&self) -> String {
+    "--sk-gradient-from".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn gradient_to(&self) -> String {
+    "--sk-gradient-to".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn gradient_from_position(&self) -> String {
+    "--sk-gradient-from-position".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn gradient_to_position(&self) -> String {
+    "--sk-gradient-to-position".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn gradient_via_position(&self) -> String {
+    "--sk-gradient-via-position".into()
+  }
+  /// ```css
+  /// @property --sk-scale-x {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn scale_x(&self) -> String {
+    "--sk-scale-x".into()
+  }
+  /// ```css
+  /// @property --sk-scale-y {
+  ///   syntax: "<number> | <percentage>";
+  ///   inherits: true;
+  ///   initial-value: 1;
+  /// }
+  /// ```
+  #[inline]
+  pub fn scale_y(&self) -> String {
+    "--sk-scale-y".into()
+  }
+  /// ```css
+  /// @property --sk-translate-x {
+  ///   syntax: "<length-percentage>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn translate_x(&self) -> String {
+    "--sk-translate-x".into()
+  }
+  /// ```css
+  /// @property --sk-translate-y {
+  ///   syntax: "<length-percentage>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn translate_y(&self) -> String {
+    "--sk-translate-y".into()
+  }
+  /// ```css
+  /// @property --sk-skew-x {
+  ///   syntax: "<angle>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn skew_x(&self) -> String {
+    "--sk-skew-x".into()
+  }
+  /// ```css
+  /// @property --sk-skew-y {
+  ///   syntax: "<angle>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn skew_y(&self) -> String {
+    "--sk-skew-y".into()
+  }
+  /// ```css
+  /// @property --sk-rotate {
+  ///   syntax: "<angle>";
+  ///   inherits: true;
+  ///   initial-value: 0;
+  /// }
+  /// ```
+  #[inline]
+  pub fn rotate(&self) -> String {
+    "--sk-rotate".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn ring_offset_shadow(&self) -> String {
+    "--sk-ring-offset-shadow".into()
+  }
+  /// ```css
+  /// @property --sk-ring-offset-color {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(0 0% 100%);
+  /// }
+  /// ```
+  #[inline]
+  pub fn ring_offset_color(&self) -> String {
+    "--sk-ring-offset-color".into()
+  }
+  /// ```css
+  /// @property --sk-ring-color {
+  ///   syntax: "<color>";
+  ///   inherits: true;
+  ///   initial-value: hsl(217.21925 91.21951% 59.80392% / 0.5019608);
+  /// }
+  /// ```
+  #[inline]
+  pub fn ring_color(&self) -> String {
+    "--sk-ring-color".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn ring_inset(&self) -> String {
+    "--sk-ring-inset".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn ring_offset_width(&self) -> String {
+    "--sk-ring-offset-width".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn ring_shadow(&self) -> String {
+    "--sk-ring-shadow".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn shadow(&self) -> String {
+    "--sk-shadow".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn shadow_colored(&self) -> String {
+    "--sk-shadow-colored".into()
+  }
+  /// ```css
+  /// 
+  /// ```
+  #[inline]
+  pub fn shadow_color(&self) -> String {
+    "--sk-shadow-color".into()
+  }
+  /// ```css
+  /// @property --sk-scroll-snap-strictness {
+  ///   syntax: "mandatory | proximity";
+  ///   inherits: true;
+  ///   initial-value: proximity;
+  /// }
+  /// ```
+  #[inline]
+  pub fn scroll_snap_strictness(&self) -> String {
+    "--sk-scroll-snap-strictness".into()
+  }
+}
+#[cfg(not(feature = "skribble-skip-media-queries"))]
+mod media_queries {
+  use super::*;
+  pub struct GeneratedMediaQueryDeviceCategoriesChild(String);
+  impl GeneratedSkribbleValue for GeneratedMediaQueryDeviceCategoriesChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedMediaQueryDeviceCategories: GeneratedSkribbleValue {
+    /// The media query for devices with a screen.
+    ///
+    /// ```css
+    /// @media screen {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn screen(&self) -> GeneratedMediaQueryDeviceCategoriesChild {
+      GeneratedMediaQueryDeviceCategoriesChild::from_ref(self.append("screen"))
+    }
+    #[inline]
+    fn screen_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("screen:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The media query for devices with a printer.
+    ///
+    /// ```css
+    /// @media print {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn print(&self) -> GeneratedMediaQueryDeviceCategoriesChild {
+      GeneratedMediaQueryDeviceCategoriesChild::from_ref(self.append("print"))
+    }
+    #[inline]
+    fn print_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("print:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedMediaQueryBreakpointsChild(String);
+  impl GeneratedSkribbleValue for GeneratedMediaQueryBreakpointsChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedMediaQueryBreakpoints: GeneratedSkribbleValue {
+    /// The breakpoint for devices with screen size greater than tiny.
+    ///
+    /// ```css
+    /// @media (min-width: 640px) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn sm(&self) -> GeneratedMediaQueryBreakpointsChild {
+      GeneratedMediaQueryBreakpointsChild::from_ref(self.append("sm"))
+    }
+    #[inline]
+    fn sm_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("sm:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for devices screen size greater than medium
+    ///
+    /// ```css
+    /// @media (min-width: 768px) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn md(&self) -> GeneratedMediaQueryBreakpointsChild {
+      GeneratedMediaQueryBreakpointsChild::from_ref(self.append("md"))
+    }
+    #[inline]
+    fn md_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("md:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for devices screen size greater than large
+    ///
+    /// ```css
+    /// @media (min-width: 1024px) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn lg(&self) -> GeneratedMediaQueryBreakpointsChild {
+      GeneratedMediaQueryBreakpointsChild::from_ref(self.append("lg"))
+    }
+    #[inline]
+    fn lg_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("lg:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for devices screen size greater than extra large
+    ///
+    /// ```css
+    /// @media (min-width: 1280px) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn xl(&self) -> GeneratedMediaQueryBreakpointsChild {
+      GeneratedMediaQueryBreakpointsChild::from_ref(self.append("xl"))
+    }
+    #[inline]
+    fn xl_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("xl:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for devices screen size greater than xxl
+    ///
+    /// ```css
+    /// @media (min-width: 1536px) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn xxl(&self) -> GeneratedMediaQueryBreakpointsChild {
+      GeneratedMediaQueryBreakpointsChild::from_ref(self.append("xxl"))
+    }
+    #[inline]
+    fn xxl_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("xxl:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedMediaQueryOrientationChild(String);
+  impl GeneratedSkribbleValue for GeneratedMediaQueryOrientationChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedMediaQueryOrientation: GeneratedSkribbleValue {
+    /// The media query for devices with a portrait orientation.
+    ///
+    /// ```css
+    /// @media (orientation: portrait) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn portrait(&self) -> GeneratedMediaQueryOrientationChild {
+      GeneratedMediaQueryOrientationChild::from_ref(self.append("portrait"))
+    }
+    #[inline]
+    fn portrait_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("portrait:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The media query for devices with a landscape orientation.
+    ///
+    /// ```css
+    /// @media (orientation: landscape) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn landscape(&self) -> GeneratedMediaQueryOrientationChild {
+      GeneratedMediaQueryOrientationChild::from_ref(self.append("landscape"))
+    }
+    #[inline]
+    fn landscape_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("landscape:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedMediaQueryMotionChild(String);
+  impl GeneratedSkribbleValue for GeneratedMediaQueryMotionChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedMediaQueryMotion: GeneratedSkribbleValue {
+    /// The media query for devices with a reduced motion preference.
+    ///
+    /// ```css
+    /// @media (prefers-reduced-motion: reduce) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn motion_reduce(&self) -> GeneratedMediaQueryMotionChild {
+      GeneratedMediaQueryMotionChild::from_ref(self.append("motion-reduce"))
+    }
+    #[inline]
+    fn motion_reduce_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("motion-reduce:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The media query for devices with a no preference motion preference.
+    ///
+    /// ```css
+    /// @media (prefers-reduced-motion: no-preference) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn motion_safe(&self) -> GeneratedMediaQueryMotionChild {
+      GeneratedMediaQueryMotionChild::from_ref(self.append("motion-safe"))
+    }
+    #[inline]
+    fn motion_safe_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("motion-safe:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedMediaQueryDarkModeChild(String);
+  impl GeneratedSkribbleValue for GeneratedMediaQueryDarkModeChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedMediaQueryDarkMode: GeneratedSkribbleValue {
+    /// The media query for devices with a dark color scheme.
+    ///
+    /// ```css
+    /// @media (prefers-color-scheme: dark) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn dark(&self) -> GeneratedMediaQueryDarkModeChild {
+      GeneratedMediaQueryDarkModeChild::from_ref(self.append("dark"))
+    }
+    #[inline]
+    fn dark_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("dark:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The media query for devices with a light color scheme.
+    ///
+    /// ```css
+    /// @media (prefers-color-scheme: light) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn light(&self) -> GeneratedMediaQueryDarkModeChild {
+      GeneratedMediaQueryDarkModeChild::from_ref(self.append("light"))
+    }
+    #[inline]
+    fn light_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("light:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedFeatureQuerySupportsChild(String);
+  impl GeneratedSkribbleValue for GeneratedFeatureQuerySupportsChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedFeatureQuerySupports: GeneratedSkribbleValue {
+    /// Apply when the browser supports grid layouts.
+    ///
+    /// ```css
+    /// @supports (display: grid) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn supports_grid(&self) -> GeneratedFeatureQuerySupportsChild {
+      GeneratedFeatureQuerySupportsChild::from_ref(self.append("supports-grid"))
+    }
+    #[inline]
+    fn supports_grid_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("supports-grid:[{}={}]", property.trim(), value.trim()))
+    }
+    /// Apply when the browser supports subgrid layouts.
+    ///
+    /// ```css
+    /// @supports (grid-template-columns: subgrid) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn supports_subgrid(&self) -> GeneratedFeatureQuerySupportsChild {
+      GeneratedFeatureQuerySupportsChild::from_ref(self.append("supports-subgrid"))
+    }
+    #[inline]
+    fn supports_subgrid_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("supports-subgrid:[{}={}]", property.trim(), value.trim()))
+    }
+    /// Apply when the browser supports backdrop filters.
+    ///
+    /// ```css
+    /// @supports ((backdrop-filter: blur(0)) or (-webkit-backdrop-filter: blur(0))) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn supports_backdrop(&self) -> GeneratedFeatureQuerySupportsChild {
+      GeneratedFeatureQuerySupportsChild::from_ref(self.append("supports-backdrop"))
+    }
+    #[inline]
+    fn supports_backdrop_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("supports-backdrop:[{}={}]", property.trim(), value.trim()))
+    }
+    /// Apply when the browser supports container queries.
+    ///
+    /// ```css
+    /// @supports (container-type: inline-size) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn supports_container(&self) -> GeneratedFeatureQuerySupportsChild {
+      GeneratedFeatureQuerySupportsChild::from_ref(self.append("supports-container"))
+    }
+    #[inline]
+    fn supports_container_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("supports-container:[{}={}]", property.trim(), value.trim()))
+    }
+    /// Apply when the browser supports the `:has()` selector.
+    ///
+    /// ```css
+    /// @supports selector(:has(*)) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn supports_has(&self) -> GeneratedFeatureQuerySupportsChild {
+      GeneratedFeatureQuerySupportsChild::from_ref(self.append("supports-has"))
+    }
+    #[inline]
+    fn supports_has_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("supports-has:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedContainerQueryContainerBreakpointsChild(String);
+  impl GeneratedSkribbleValue for GeneratedContainerQueryContainerBreakpointsChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedContainerQueryContainerBreakpoints: GeneratedSkribbleValue {
+    /// The breakpoint for containers wider than 20rem.
+    ///
+    /// ```css
+    /// @container (min-width: 20rem) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn cq_xs(&self) -> GeneratedContainerQueryContainerBreakpointsChild {
+      GeneratedContainerQueryContainerBreakpointsChild::from_ref(self.append("cq-xs"))
+    }
+    #[inline]
+    fn cq_xs_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("cq-xs:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for containers wider than 24rem.
+    ///
+    /// ```css
+    /// @container (min-width: 24rem) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn cq_sm(&self) -> GeneratedContainerQueryContainerBreakpointsChild {
+      GeneratedContainerQueryContainerBreakpointsChild::from_ref(self.append("cq-sm"))
+    }
+    #[inline]
+    fn cq_sm_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("cq-sm:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for containers wider than 28rem.
+    ///
+    /// ```css
+    /// @container (min-width: 28rem) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn cq_md(&self) -> GeneratedContainerQueryContainerBreakpointsChild {
+      GeneratedContainerQueryContainerBreakpointsChild::from_ref(self.append("cq-md"))
+    }
+    #[inline]
+    fn cq_md_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("cq-md:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for containers wider than 32rem.
+    ///
+    /// ```css
+    /// @container (min-width: 32rem) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn cq_lg(&self) -> GeneratedContainerQueryContainerBreakpointsChild {
+      GeneratedContainerQueryContainerBreakpointsChild::from_ref(self.append("cq-lg"))
+    }
+    #[inline]
+    fn cq_lg_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("cq-lg:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for containers wider than 36rem.
+    ///
+    /// ```css
+    /// @container (min-width: 36rem) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn cq_xl(&self) -> GeneratedContainerQueryContainerBreakpointsChild {
+      GeneratedContainerQueryContainerBreakpointsChild::from_ref(self.append("cq-xl"))
+    }
+    #[inline]
+    fn cq_xl_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("cq-xl:[{}={}]", property.trim(), value.trim()))
+    }
+    /// The breakpoint for containers wider than 42rem.
+    ///
+    /// ```css
+    /// @container (min-width: 42rem) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn cq_xxl(&self) -> GeneratedContainerQueryContainerBreakpointsChild {
+      GeneratedContainerQueryContainerBreakpointsChild::from_ref(self.append("cq-xxl"))
+    }
+    #[inline]
+    fn cq_xxl_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("cq-xxl:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  impl GeneratedMediaQueryDeviceCategories for GeneratedSkribbleRoot {}
+  impl GeneratedMediaQueryBreakpoints for GeneratedSkribbleRoot {}
+  impl GeneratedMediaQueryOrientation for GeneratedSkribbleRoot {}
+  impl GeneratedMediaQueryMotion for GeneratedSkribbleRoot {}
+  impl GeneratedMediaQueryDarkMode for GeneratedSkribbleRoot {}
+  impl GeneratedFeatureQuerySupports for GeneratedSkribbleRoot {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedSkribbleRoot {}
+  impl GeneratedMediaQueryBreakpoints for GeneratedMediaQueryDeviceCategoriesChild {}
+  impl GeneratedMediaQueryOrientation for GeneratedMediaQueryDeviceCategoriesChild {}
+  impl GeneratedMediaQueryMotion for GeneratedMediaQueryDeviceCategoriesChild {}
+  impl GeneratedMediaQueryDarkMode for GeneratedMediaQueryDeviceCategoriesChild {}
+  impl GeneratedFeatureQuerySupports for GeneratedMediaQueryDeviceCategoriesChild {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedMediaQueryDeviceCategoriesChild {}
+  impl GeneratedMediaQueryOrientation for GeneratedMediaQueryBreakpointsChild {}
+  impl GeneratedMediaQueryMotion for GeneratedMediaQueryBreakpointsChild {}
+  impl GeneratedMediaQueryDarkMode for GeneratedMediaQueryBreakpointsChild {}
+  impl GeneratedFeatureQuerySupports for GeneratedMediaQueryBreakpointsChild {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedMediaQueryBreakpointsChild {}
+  impl GeneratedMediaQueryMotion for GeneratedMediaQueryOrientationChild {}
+  impl GeneratedMediaQueryDarkMode for GeneratedMediaQueryOrientationChild {}
+  impl GeneratedFeatureQuerySupports for GeneratedMediaQueryOrientationChild {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedMediaQueryOrientationChild {}
+  impl GeneratedMediaQueryDarkMode for GeneratedMediaQueryMotionChild {}
+  impl GeneratedFeatureQuerySupports for GeneratedMediaQueryMotionChild {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedMediaQueryMotionChild {}
+  impl GeneratedFeatureQuerySupports for GeneratedMediaQueryDarkModeChild {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedMediaQueryDarkModeChild {}
+  impl GeneratedContainerQueryContainerBreakpoints for GeneratedFeatureQuerySupportsChild {}
+}
+#[cfg(not(feature = "skribble-skip-media-queries"))]
+pub use media_queries::*;
+#[cfg(not(feature = "skribble-skip-modifiers"))]
+mod modifiers {
+  use super::*;
+  pub struct GeneratedModifierParentGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierParentGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierParentGroup: GeneratedSkribbleValue {
+    /// This class modifier becomes active when when the text direction of any parent nodes is set to right to left.
+    ///
+    /// ```css
+    /// [dir=rtl] & {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn rtl(&self) -> GeneratedModifierParentGroupChild {
+      GeneratedModifierParentGroupChild::from_ref(self.append("rtl"))
+    }
+    #[inline]
+    fn rtl_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("rtl:[{}={}]", property.trim(), value.trim()))
+    }
+    /// This class modifier becomes active when a parent group is hovered.
+    ///
+    /// ```css
+    /// .\$group:hover &, .group:hover &, [role='group']:hover & {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn group_hover(&self) -> GeneratedModifierParentGroupChild {
+      GeneratedModifierParentGroupChild::from_ref(self.append("group-hover"))
+    }
+    #[inline]
+    fn group_hover_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("group-hover:[{}={}]", property.trim(), value.trim()))
+    }
+    /// This class modifier becomes active when a parent group is focused.
+    ///
+    /// ```css
+    /// .\$group:focus &, .group:focus &, [role='group']:focus & {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn group_focus(&self) -> GeneratedModifierParentGroupChild {
+      GeneratedModifierParentGroupChild::from_ref(self.append("group-focus"))
+    }
+    #[inline]
+    fn group_focus_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("group-focus:[{}={}]", property.trim(), value.trim()))
+    }
+    /// This class modifier becomes active when a parent group is active.
+    ///
+    /// ```css
+    /// .\$group:active &, .group:active &, [role='group']:active & {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn group_active(&self) -> GeneratedModifierParentGroupChild {
+      GeneratedModifierParentGroupChild::from_ref(self.append("group-active"))
+    }
+    #[inline]
+    fn group_active_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("group-active:[{}={}]", property.trim(), value.trim()))
+    }
+    /// This class modifier becomes active when a parent group is visited.
+    ///
+    /// ```css
+    /// .\$group:visited &, .group:visited &, [role='group']:visited & {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn group_visited(&self) -> GeneratedModifierParentGroupChild {
+      GeneratedModifierParentGroupChild::from_ref(self.append("group-visited"))
+    }
+    #[inline]
+    fn group_visited_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("group-visited:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierHoverGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierHoverGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierHoverGroup: GeneratedSkribbleValue {
+    /// This class modifier becomes active when the element is hovered.
+    ///
+    /// ```css
+    /// &:hover {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn hover(&self) -> GeneratedModifierHoverGroupChild {
+      GeneratedModifierHoverGroupChild::from_ref(self.append("hover"))
+    }
+    #[inline]
+    fn hover_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("hover:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierActiveGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierActiveGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierActiveGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:active {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn active(&self) -> GeneratedModifierActiveGroupChild {
+      GeneratedModifierActiveGroupChild::from_ref(self.append("active"))
+    }
+    #[inline]
+    fn active_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("active:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierFocusGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierFocusGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierFocusGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:focus {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn focus(&self) -> GeneratedModifierFocusGroupChild {
+      GeneratedModifierFocusGroupChild::from_ref(self.append("focus"))
+    }
+    #[inline]
+    fn focus_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("focus:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierFocusWithinGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierFocusWithinGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierFocusWithinGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:focus-within {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn focus_within(&self) -> GeneratedModifierFocusWithinGroupChild {
+      GeneratedModifierFocusWithinGroupChild::from_ref(self.append("focus-within"))
+    }
+    #[inline]
+    fn focus_within_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("focus-within:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierFocusVisibleGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierFocusVisibleGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierFocusVisibleGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:focus-visible {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn focus_visible(&self) -> GeneratedModifierFocusVisibleGroupChild {
+      GeneratedModifierFocusVisibleGroupChild::from_ref(self.append("focus-visible"))
+    }
+    #[inline]
+    fn focus_visible_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("focus-visible:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierEnabledGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierEnabledGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierEnabledGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &[disabled], &:disabled {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn disabled(&self) -> GeneratedModifierEnabledGroupChild {
+      GeneratedModifierEnabledGroupChild::from_ref(self.append("disabled"))
+    }
+    #[inline]
+    fn disabled_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("disabled:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:disabled {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_disabled(&self) -> GeneratedModifierEnabledGroupChild {
+      GeneratedModifierEnabledGroupChild::from_ref(self.append("not-disabled"))
+    }
+    #[inline]
+    fn not_disabled_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-disabled:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:enabled {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn enabled(&self) -> GeneratedModifierEnabledGroupChild {
+      GeneratedModifierEnabledGroupChild::from_ref(self.append("enabled"))
+    }
+    #[inline]
+    fn enabled_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("enabled:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierEmptyGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierEmptyGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierEmptyGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:empty {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn empty(&self) -> GeneratedModifierEmptyGroupChild {
+      GeneratedModifierEmptyGroupChild::from_ref(self.append("empty"))
+    }
+    #[inline]
+    fn empty_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("empty:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierReadGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierReadGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierReadGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:read-write {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn read_write(&self) -> GeneratedModifierReadGroupChild {
+      GeneratedModifierReadGroupChild::from_ref(self.append("read-write"))
+    }
+    #[inline]
+    fn read_write_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("read-write:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[readonly], &:read-only {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn readonly(&self) -> GeneratedModifierReadGroupChild {
+      GeneratedModifierReadGroupChild::from_ref(self.append("readonly"))
+    }
+    #[inline]
+    fn readonly_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("readonly:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[readonly=false], &:not(:read-only) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_readonly(&self) -> GeneratedModifierReadGroupChild {
+      GeneratedModifierReadGroupChild::from_ref(self.append("not-readonly"))
+    }
+    #[inline]
+    fn not_readonly_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-readonly:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierCheckedGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierCheckedGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierCheckedGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:indeterminate {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn indeterminate(&self) -> GeneratedModifierCheckedGroupChild {
+      GeneratedModifierCheckedGroupChild::from_ref(self.append("indeterminate"))
+    }
+    #[inline]
+    fn indeterminate_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("indeterminate:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:checked {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn checked(&self) -> GeneratedModifierCheckedGroupChild {
+      GeneratedModifierCheckedGroupChild::from_ref(self.append("checked"))
+    }
+    #[inline]
+    fn checked_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("checked:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:checked) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn unchecked(&self) -> GeneratedModifierCheckedGroupChild {
+      GeneratedModifierCheckedGroupChild::from_ref(self.append("unchecked"))
+    }
+    #[inline]
+    fn unchecked_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("unchecked:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierValidGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierValidGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierValidGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:valid {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn valid(&self) -> GeneratedModifierValidGroupChild {
+      GeneratedModifierValidGroupChild::from_ref(self.append("valid"))
+    }
+    #[inline]
+    fn valid_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("valid:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:invalid {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn invalid(&self) -> GeneratedModifierValidGroupChild {
+      GeneratedModifierValidGroupChild::from_ref(self.append("invalid"))
+    }
+    #[inline]
+    fn invalid_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("invalid:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierAutofillGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierAutofillGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierAutofillGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:-webkit-autofill {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn autofill(&self) -> GeneratedModifierAutofillGroupChild {
+      GeneratedModifierAutofillGroupChild::from_ref(self.append("autofill"))
+    }
+    #[inline]
+    fn autofill_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("autofill:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierEvenGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierEvenGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierEvenGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:even {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn even(&self) -> GeneratedModifierEvenGroupChild {
+      GeneratedModifierEvenGroupChild::from_ref(self.append("even"))
+    }
+    #[inline]
+    fn even_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("even:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:odd {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn odd(&self) -> GeneratedModifierEvenGroupChild {
+      GeneratedModifierEvenGroupChild::from_ref(self.append("odd"))
+    }
+    #[inline]
+    fn odd_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("odd:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:nth-of-type(even) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn even_of_type(&self) -> GeneratedModifierEvenGroupChild {
+      GeneratedModifierEvenGroupChild::from_ref(self.append("even-of-type"))
+    }
+    #[inline]
+    fn even_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("even-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:nth-of-type(odd) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn odd_of_type(&self) -> GeneratedModifierEvenGroupChild {
+      GeneratedModifierEvenGroupChild::from_ref(self.append("odd-of-type"))
+    }
+    #[inline]
+    fn odd_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("odd-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierNodePositionGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierNodePositionGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierNodePositionGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:first {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn first(&self) -> GeneratedModifierNodePositionGroupChild {
+      GeneratedModifierNodePositionGroupChild::from_ref(self.append("first"))
+    }
+    #[inline]
+    fn first_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("first:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:first-child) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_first(&self) -> GeneratedModifierNodePositionGroupChild {
+      GeneratedModifierNodePositionGroupChild::from_ref(self.append("not-first"))
+    }
+    #[inline]
+    fn not_first_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-first:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:last {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn last(&self) -> GeneratedModifierNodePositionGroupChild {
+      GeneratedModifierNodePositionGroupChild::from_ref(self.append("last"))
+    }
+    #[inline]
+    fn last_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("last:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:last-child) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_last(&self) -> GeneratedModifierNodePositionGroupChild {
+      GeneratedModifierNodePositionGroupChild::from_ref(self.append("not-last"))
+    }
+    #[inline]
+    fn not_last_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-last:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierNodeOfTypeGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierNodeOfTypeGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierNodeOfTypeGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:first-of-type {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn first_of_type(&self) -> GeneratedModifierNodeOfTypeGroupChild {
+      GeneratedModifierNodeOfTypeGroupChild::from_ref(self.append("first-of-type"))
+    }
+    #[inline]
+    fn first_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("first-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:first-of-type) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_first_of_type(&self) -> GeneratedModifierNodeOfTypeGroupChild {
+      GeneratedModifierNodeOfTypeGroupChild::from_ref(self.append("not-first-of-type"))
+    }
+    #[inline]
+    fn not_first_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-first-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:last-of-type {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn last_of_type(&self) -> GeneratedModifierNodeOfTypeGroupChild {
+      GeneratedModifierNodeOfTypeGroupChild::from_ref(self.append("last-of-type"))
+    }
+    #[inline]
+    fn last_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("last-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:last-of-type) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_last_of_type(&self) -> GeneratedModifierNodeOfTypeGroupChild {
+      GeneratedModifierNodeOfTypeGroupChild::from_ref(self.append("not-last-of-type"))
+    }
+    #[inline]
+    fn not_last_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-last-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierVisitedGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierVisitedGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierVisitedGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:visited {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn visited(&self) -> GeneratedModifierVisitedGroupChild {
+      GeneratedModifierVisitedGroupChild::from_ref(self.append("visited"))
+    }
+    #[inline]
+    fn visited_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("visited:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierOptionalGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierOptionalGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierOptionalGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:optional {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn optional(&self) -> GeneratedModifierOptionalGroupChild {
+      GeneratedModifierOptionalGroupChild::from_ref(self.append("optional"))
+    }
+    #[inline]
+    fn optional_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("optional:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierAriaChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierAriaChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierAria: GeneratedSkribbleValue {
+    /// ```css
+    /// &[aria-current=page] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_current_page(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-current-page"))
+    }
+    #[inline]
+    fn aria_current_page_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-current-page:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-current=location] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_current_location(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-current-location"))
+    }
+    #[inline]
+    fn aria_current_location_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-current-location:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-current=date] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_current_date(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-current-date"))
+    }
+    #[inline]
+    fn aria_current_date_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-current-date:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-current=time] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_current_time(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-current-time"))
+    }
+    #[inline]
+    fn aria_current_time_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-current-time:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-current=step] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_current_step(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-current-step"))
+    }
+    #[inline]
+    fn aria_current_step_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-current-step:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-disabled=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_disabled(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-disabled"))
+    }
+    #[inline]
+    fn aria_disabled_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-disabled:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-disabled=false] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_not_disabled(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-not-disabled"))
+    }
+    #[inline]
+    fn aria_not_disabled_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-not-disabled:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-readonly=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_readonly(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-readonly"))
+    }
+    #[inline]
+    fn aria_readonly_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-readonly:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-readonly=false] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_not_readonly(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-not-readonly"))
+    }
+    #[inline]
+    fn aria_not_readonly_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-not-readonly:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-expanded=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_expanded(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-expanded"))
+    }
+    #[inline]
+    fn aria_expanded_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-expanded:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-checked=mixed] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_indeterminate(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-indeterminate"))
+    }
+    #[inline]
+    fn aria_indeterminate_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-indeterminate:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-checked=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_checked(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-checked"))
+    }
+    #[inline]
+    fn aria_checked_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-checked:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-checked=false] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_unchecked(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-unchecked"))
+    }
+    #[inline]
+    fn aria_unchecked_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-unchecked:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-grabbed=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_grabbed(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-grabbed"))
+    }
+    #[inline]
+    fn aria_grabbed_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-grabbed:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-pressed=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_pressed(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-pressed"))
+    }
+    #[inline]
+    fn aria_pressed_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-pressed:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-invalid=grammar] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_invalid_grammar(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-invalid-grammar"))
+    }
+    #[inline]
+    fn aria_invalid_grammar_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-invalid-grammar:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-invalid=spelling] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_invalid_spelling(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-invalid-spelling"))
+    }
+    #[inline]
+    fn aria_invalid_spelling_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-invalid-spelling:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-invalid=false] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_valid(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-valid"))
+    }
+    #[inline]
+    fn aria_valid_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-valid:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-invalid=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_invalid(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-invalid"))
+    }
+    #[inline]
+    fn aria_invalid_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-invalid:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// [aria-required=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_required(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-required"))
+    }
+    #[inline]
+    fn aria_required_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-required:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// [aria-required=false] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_not_required(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-not-required"))
+    }
+    #[inline]
+    fn aria_not_required_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-not-required:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-busy=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn loading(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("loading"))
+    }
+    #[inline]
+    fn loading_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("loading:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-selected=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_selected(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-selected"))
+    }
+    #[inline]
+    fn aria_selected_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-selected:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-hidden=true] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_hidden(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-hidden"))
+    }
+    #[inline]
+    fn aria_hidden_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-hidden:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &[aria-live=polite] {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn aria_live(&self) -> GeneratedModifierAriaChild {
+      GeneratedModifierAriaChild::from_ref(self.append("aria-live"))
+    }
+    #[inline]
+    fn aria_live_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("aria-live:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierFullScreenGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierFullScreenGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierFullScreenGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:fullscreen {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn full_screen(&self) -> GeneratedModifierFullScreenGroupChild {
+      GeneratedModifierFullScreenGroupChild::from_ref(self.append("full-screen"))
+    }
+    #[inline]
+    fn full_screen_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("full-screen:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierTargetGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierTargetGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierTargetGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:target {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn target(&self) -> GeneratedModifierTargetGroupChild {
+      GeneratedModifierTargetGroupChild::from_ref(self.append("target"))
+    }
+    #[inline]
+    fn target_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("target:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierPlaceholderShownGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierPlaceholderShownGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierPlaceholderShownGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:placeholder-shown {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn placeholder_shown(&self) -> GeneratedModifierPlaceholderShownGroupChild {
+      GeneratedModifierPlaceholderShownGroupChild::from_ref(self.append("placeholder-shown"))
+    }
+    #[inline]
+    fn placeholder_shown_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("placeholder-shown:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierRequiredGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierRequiredGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierRequiredGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:required {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn required(&self) -> GeneratedModifierRequiredGroupChild {
+      GeneratedModifierRequiredGroupChild::from_ref(self.append("required"))
+    }
+    #[inline]
+    fn required_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("required:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:required) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_required(&self) -> GeneratedModifierRequiredGroupChild {
+      GeneratedModifierRequiredGroupChild::from_ref(self.append("not-required"))
+    }
+    #[inline]
+    fn not_required_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-required:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierDefaultGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierDefaultGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierDefaultGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:default {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn default(&self) -> GeneratedModifierDefaultGroupChild {
+      GeneratedModifierDefaultGroupChild::from_ref(self.append("default"))
+    }
+    #[inline]
+    fn default_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("default:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierOnlyChildGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierOnlyChildGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierOnlyChildGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:only-child {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn only_child(&self) -> GeneratedModifierOnlyChildGroupChild {
+      GeneratedModifierOnlyChildGroupChild::from_ref(self.append("only-child"))
+    }
+    #[inline]
+    fn only_child_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("only-child:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:only-child) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_only_child(&self) -> GeneratedModifierOnlyChildGroupChild {
+      GeneratedModifierOnlyChildGroupChild::from_ref(self.append("not-only-child"))
+    }
+    #[inline]
+    fn not_only_child_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-only-child:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierOnlyGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierOnlyGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierOnlyGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:only-of-type {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn only_of_type(&self) -> GeneratedModifierOnlyGroupChild {
+      GeneratedModifierOnlyGroupChild::from_ref(self.append("only-of-type"))
+    }
+    #[inline]
+    fn only_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("only-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &:not(:only-of-type) {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn not_only_of_type(&self) -> GeneratedModifierOnlyGroupChild {
+      GeneratedModifierOnlyGroupChild::from_ref(self.append("not-only-of-type"))
+    }
+    #[inline]
+    fn not_only_of_type_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("not-only-of-type:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierRootGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierRootGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierRootGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:root {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn root(&self) -> GeneratedModifierRootGroupChild {
+      GeneratedModifierRootGroupChild::from_ref(self.append("root"))
+    }
+    #[inline]
+    fn root_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("root:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierLinkGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierLinkGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierLinkGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &:link {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn link(&self) -> GeneratedModifierLinkGroupChild {
+      GeneratedModifierLinkGroupChild::from_ref(self.append("link"))
+    }
+    #[inline]
+    fn link_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("link:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  pub struct GeneratedModifierPseudoGroupChild(String);
+  impl GeneratedSkribbleValue for GeneratedModifierPseudoGroupChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedModifierPseudoGroup: GeneratedSkribbleValue {
+    /// ```css
+    /// &::placeholder {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn placeholder(&self) -> GeneratedModifierPseudoGroupChild {
+      GeneratedModifierPseudoGroupChild::from_ref(self.append("placeholder"))
+    }
+    #[inline]
+    fn placeholder_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("placeholder:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &::selection {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn selection(&self) -> GeneratedModifierPseudoGroupChild {
+      GeneratedModifierPseudoGroupChild::from_ref(self.append("selection"))
+    }
+    #[inline]
+    fn selection_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("selection:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &::first-letter {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn first_letter(&self) -> GeneratedModifierPseudoGroupChild {
+      GeneratedModifierPseudoGroupChild::from_ref(self.append("first-letter"))
+    }
+    #[inline]
+    fn first_letter_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("first-letter:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &::first-line {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn first_line(&self) -> GeneratedModifierPseudoGroupChild {
+      GeneratedModifierPseudoGroupChild::from_ref(self.append("first-line"))
+    }
+    #[inline]
+    fn first_line_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("first-line:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &::before {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn before(&self) -> GeneratedModifierPseudoGroupChild {
+      GeneratedModifierPseudoGroupChild::from_ref(self.append("before"))
+    }
+    #[inline]
+    fn before_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("before:[{}={}]", property.trim(), value.trim()))
+    }
+    /// ```css
+    /// &::after {
+    ///   /* ... */
+    /// }
+    /// ```
+    #[inline]
+    fn after(&self) -> GeneratedModifierPseudoGroupChild {
+      GeneratedModifierPseudoGroupChild::from_ref(self.append("after"))
+    }
+    #[inline]
+    fn after_(&self, property: &'static str, value: &'static str) -> String {
+      self.append(format!("after:[{}={}]", property.trim(), value.trim()))
+    }
+  }
+  impl GeneratedModifierParentGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierHoverGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierActiveGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierFocusGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierFocusWithinGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierFocusVisibleGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierEnabledGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierEmptyGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierReadGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierCheckedGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierValidGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierAutofillGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierEvenGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierNodePositionGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierVisitedGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierOptionalGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierAria for GeneratedSkribbleRoot {}
+  impl GeneratedModifierFullScreenGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierTargetGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierRequiredGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierDefaultGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierOnlyGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierRootGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierLinkGroup for GeneratedSkribbleRoot {}
+  impl GeneratedModifierPseudoGroup for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierParentGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierHoverGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierActiveGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusWithinGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFocusVisibleGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEnabledGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEmptyGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierReadGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierCheckedGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierValidGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAutofillGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierEvenGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodePositionGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierVisitedGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOptionalGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierAria for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierFullScreenGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierTargetGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRequiredGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierDefaultGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyChildGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierOnlyGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierRootGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierLinkGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedModifierPseudoGroup for GeneratedContainerQueryContainerBreakpointsChild {}
+  impl GeneratedModifierHoverGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierActiveGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierFocusGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierFocusWithinGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierFocusVisibleGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierEnabledGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierParentGroupChild {}
+  impl GeneratedModifierActiveGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierFocusGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierFocusWithinGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierFocusVisibleGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierEnabledGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierHoverGroupChild {}
+  impl GeneratedModifierFocusGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierFocusWithinGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierFocusVisibleGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierEnabledGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierActiveGroupChild {}
+  impl GeneratedModifierFocusWithinGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierFocusVisibleGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierEnabledGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierFocusGroupChild {}
+  impl GeneratedModifierFocusVisibleGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierEnabledGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierFocusWithinGroupChild {}
+  impl GeneratedModifierEnabledGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierFocusVisibleGroupChild {}
+  impl GeneratedModifierEmptyGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierEnabledGroupChild {}
+  impl GeneratedModifierReadGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierEmptyGroupChild {}
+  impl GeneratedModifierCheckedGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierReadGroupChild {}
+  impl GeneratedModifierValidGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierCheckedGroupChild {}
+  impl GeneratedModifierAutofillGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierValidGroupChild {}
+  impl GeneratedModifierEvenGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierAutofillGroupChild {}
+  impl GeneratedModifierNodePositionGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierEvenGroupChild {}
+  impl GeneratedModifierNodeOfTypeGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierNodePositionGroupChild {}
+  impl GeneratedModifierVisitedGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierNodeOfTypeGroupChild {}
+  impl GeneratedModifierOptionalGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierVisitedGroupChild {}
+  impl GeneratedModifierAria for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierOptionalGroupChild {}
+  impl GeneratedModifierFullScreenGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierAriaChild {}
+  impl GeneratedModifierTargetGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierFullScreenGroupChild {}
+  impl GeneratedModifierPlaceholderShownGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierTargetGroupChild {}
+  impl GeneratedModifierRequiredGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierPlaceholderShownGroupChild {}
+  impl GeneratedModifierDefaultGroup for GeneratedModifierRequiredGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierRequiredGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierRequiredGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierRequiredGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierRequiredGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierRequiredGroupChild {}
+  impl GeneratedModifierOnlyChildGroup for GeneratedModifierDefaultGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierDefaultGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierDefaultGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierDefaultGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierDefaultGroupChild {}
+  impl GeneratedModifierOnlyGroup for GeneratedModifierOnlyChildGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierOnlyChildGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierOnlyChildGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierOnlyChildGroupChild {}
+  impl GeneratedModifierRootGroup for GeneratedModifierOnlyGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierOnlyGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierOnlyGroupChild {}
+  impl GeneratedModifierLinkGroup for GeneratedModifierRootGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierRootGroupChild {}
+  impl GeneratedModifierPseudoGroup for GeneratedModifierLinkGroupChild {}
+}
+#[cfg(not(feature = "skribble-skip-modifiers"))]
+pub use modifiers::*;
+#[cfg(not(feature = "skribble-skip-transformers"))]
+mod transformers {
+  use super::*;
+  pub struct GeneratedTransformerImportantGroupImportantChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerImportantGroupImportantChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedTransformerImportant: GeneratedSkribbleValue {
+    /// Append `!important` to every property in the transformed atom / named class.
+    ///
+    #[inline]
+    fn important(&self) -> GeneratedTransformerImportantGroupImportantChild {
+      GeneratedTransformerImportantGroupImportantChild::from_ref(self.append_transformer("important", None))
+    }
+  }
+  pub struct GeneratedTransformerAlphaGroupAlphaChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerAlphaGroupAlphaChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerAlphaGroupOpaquifyChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerAlphaGroupOpaquifyChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerAlphaGroupTransparentizeChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerAlphaGroupTransparentizeChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedTransformerAlpha: GeneratedSkribbleValue {
+    /// Sets the alpha value of a color to this value. Should be a value between 0 and 1.
+    ///
+    #[inline]
+    fn alpha(&self, value: &'static str,) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some(value)))
+    }
+    #[inline]
+    fn alpha_000(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=000")))
+    }
+    #[inline]
+    fn alpha_005(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=005")))
+    }
+    #[inline]
+    fn alpha_010(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=010")))
+    }
+    #[inline]
+    fn alpha_015(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=015")))
+    }
+    #[inline]
+    fn alpha_020(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=020")))
+    }
+    #[inline]
+    fn alpha_025(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=025")))
+    }
+    #[inline]
+    fn alpha_030(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=030")))
+    }
+    #[inline]
+    fn alpha_040(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=040")))
+    }
+    #[inline]
+    fn alpha_050(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=050")))
+    }
+    #[inline]
+    fn alpha_060(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=060")))
+    }
+    #[inline]
+    fn alpha_070(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=070")))
+    }
+    #[inline]
+    fn alpha_075(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=075")))
+    }
+    #[inline]
+    fn alpha_080(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=080")))
+    }
+    #[inline]
+    fn alpha_090(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=090")))
+    }
+    #[inline]
+    fn alpha_095(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=095")))
+    }
+    #[inline]
+    fn alpha_100(&self) -> GeneratedTransformerAlphaGroupAlphaChild {
+      GeneratedTransformerAlphaGroupAlphaChild::from_ref(self.append_transformer("alpha", Some("=100")))
+    }
+    /// Increase the opacity of the color.
+    ///
+    #[inline]
+    fn opaquify(&self, value: &'static str,) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some(value)))
+    }
+    #[inline]
+    fn opaquify_001(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=001")))
+    }
+    #[inline]
+    fn opaquify_002(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=002")))
+    }
+    #[inline]
+    fn opaquify_003(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=003")))
+    }
+    #[inline]
+    fn opaquify_004(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=004")))
+    }
+    #[inline]
+    fn opaquify_005(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=005")))
+    }
+    #[inline]
+    fn opaquify_010(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=010")))
+    }
+    #[inline]
+    fn opaquify_015(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=015")))
+    }
+    #[inline]
+    fn opaquify_020(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=020")))
+    }
+    #[inline]
+    fn opaquify_025(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=025")))
+    }
+    #[inline]
+    fn opaquify_030(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=030")))
+    }
+    #[inline]
+    fn opaquify_040(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=040")))
+    }
+    #[inline]
+    fn opaquify_050(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=050")))
+    }
+    #[inline]
+    fn opaquify_060(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=060")))
+    }
+    #[inline]
+    fn opaquify_070(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=070")))
+    }
+    #[inline]
+    fn opaquify_075(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=075")))
+    }
+    #[inline]
+    fn opaquify_080(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=080")))
+    }
+    #[inline]
+    fn opaquify_090(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=090")))
+    }
+    #[inline]
+    fn opaquify_095(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=095")))
+    }
+    #[inline]
+    fn opaquify_100(&self) -> GeneratedTransformerAlphaGroupOpaquifyChild {
+      GeneratedTransformerAlphaGroupOpaquifyChild::from_ref(self.append_transformer("opaquify", Some("=100")))
+    }
+    /// Decrease the opacity of the color.
+    ///
+    #[inline]
+    fn transparentize(&self, value: &'static str,) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some(value)))
+    }
+    #[inline]
+    fn transparentize_001(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=001")))
+    }
+    #[inline]
+    fn transparentize_002(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=002")))
+    }
+    #[inline]
+    fn transparentize_003(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=003")))
+    }
+    #[inline]
+    fn transparentize_004(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=004")))
+    }
+    #[inline]
+    fn transparentize_005(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=005")))
+    }
+    #[inline]
+    fn transparentize_010(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=010")))
+    }
+    #[inline]
+    fn transparentize_015(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=015")))
+    }
+    #[inline]
+    fn transparentize_020(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=020")))
+    }
+    #[inline]
+    fn transparentize_025(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=025")))
+    }
+    #[inline]
+    fn transparentize_030(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=030")))
+    }
+    #[inline]
+    fn transparentize_040(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=040")))
+    }
+    #[inline]
+    fn transparentize_050(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=050")))
+    }
+    #[inline]
+    fn transparentize_060(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=060")))
+    }
+    #[inline]
+    fn transparentize_070(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=070")))
+    }
+    #[inline]
+    fn transparentize_075(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=075")))
+    }
+    #[inline]
+    fn transparentize_080(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=080")))
+    }
+    #[inline]
+    fn transparentize_090(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=090")))
+    }
+    #[inline]
+    fn transparentize_095(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=095")))
+    }
+    #[inline]
+    fn transparentize_100(&self) -> GeneratedTransformerAlphaGroupTransparentizeChild {
+      GeneratedTransformerAlphaGroupTransparentizeChild::from_ref(self.append_transformer("transparentize", Some("=100")))
+    }
+  }
+  pub struct GeneratedTransformerLightnessGroupLightnessChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerLightnessGroupLightnessChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerLightnessGroupLightenChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerLightnessGroupLightenChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerLightnessGroupDarkenChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerLightnessGroupDarkenChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedTransformerLightness: GeneratedSkribbleValue {
+    /// Set the lightness of the color.
+    ///
+    #[inline]
+    fn lightness(&self, value: &'static str,) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some(value)))
+    }
+    #[inline]
+    fn lightness_000(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=000")))
+    }
+    #[inline]
+    fn lightness_001(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=001")))
+    }
+    #[inline]
+    fn lightness_002(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=002")))
+    }
+    #[inline]
+    fn lightness_003(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=003")))
+    }
+    #[inline]
+    fn lightness_004(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=004")))
+    }
+    #[inline]
+    fn lightness_005(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=005")))
+    }
+    #[inline]
+    fn lightness_010(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=010")))
+    }
+    #[inline]
+    fn lightness_015(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=015")))
+    }
+    #[inline]
+    fn lightness_020(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=020")))
+    }
+    #[inline]
+    fn lightness_025(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=025")))
+    }
+    #[inline]
+    fn lightness_030(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=030")))
+    }
+    #[inline]
+    fn lightness_035(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=035")))
+    }
+    #[inline]
+    fn lightness_040(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=040")))
+    }
+    #[inline]
+    fn lightness_045(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=045")))
+    }
+    #[inline]
+    fn lightness_050(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=050")))
+    }
+    #[inline]
+    fn lightness_055(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=055")))
+    }
+    #[inline]
+    fn lightness_060(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=060")))
+    }
+    #[inline]
+    fn lightness_065(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=065")))
+    }
+    #[inline]
+    fn lightness_070(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=070")))
+    }
+    #[inline]
+    fn lightness_075(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=075")))
+    }
+    #[inline]
+    fn lightness_080(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=080")))
+    }
+    #[inline]
+    fn lightness_085(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=085")))
+    }
+    #[inline]
+    fn lightness_090(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=090")))
+    }
+    #[inline]
+    fn lightness_095(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=095")))
+    }
+    #[inline]
+    fn lightness_100(&self) -> GeneratedTransformerLightnessGroupLightnessChild {
+      GeneratedTransformerLightnessGroupLightnessChild::from_ref(self.append_transformer("lightness", Some("=100")))
+    }
+    /// Lighten the color by the provided percentage.
+    ///
+    #[inline]
+    fn lighten(&self, value: &'static str,) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some(value)))
+    }
+    #[inline]
+    fn lighten_001(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=001")))
+    }
+    #[inline]
+    fn lighten_002(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=002")))
+    }
+    #[inline]
+    fn lighten_003(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=003")))
+    }
+    #[inline]
+    fn lighten_004(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=004")))
+    }
+    #[inline]
+    fn lighten_005(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=005")))
+    }
+    #[inline]
+    fn lighten_010(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=010")))
+    }
+    #[inline]
+    fn lighten_015(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=015")))
+    }
+    #[inline]
+    fn lighten_020(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=020")))
+    }
+    #[inline]
+    fn lighten_025(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=025")))
+    }
+    #[inline]
+    fn lighten_030(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=030")))
+    }
+    #[inline]
+    fn lighten_035(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=035")))
+    }
+    #[inline]
+    fn lighten_040(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=040")))
+    }
+    #[inline]
+    fn lighten_045(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=045")))
+    }
+    #[inline]
+    fn lighten_050(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=050")))
+    }
+    #[inline]
+    fn lighten_055(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=055")))
+    }
+    #[inline]
+    fn lighten_060(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=060")))
+    }
+    #[inline]
+    fn lighten_065(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=065")))
+    }
+    #[inline]
+    fn lighten_070(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=070")))
+    }
+    #[inline]
+    fn lighten_075(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=075")))
+    }
+    #[inline]
+    fn lighten_080(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=080")))
+    }
+    #[inline]
+    fn lighten_085(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=085")))
+    }
+    #[inline]
+    fn lighten_090(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=090")))
+    }
+    #[inline]
+    fn lighten_095(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=095")))
+    }
+    #[inline]
+    fn lighten_100(&self) -> GeneratedTransformerLightnessGroupLightenChild {
+      GeneratedTransformerLightnessGroupLightenChild::from_ref(self.append_transformer("lighten", Some("=100")))
+    }
+    /// Darken the color by the provided percentage.
+    ///
+    #[inline]
+    fn darken(&self, value: &'static str,) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some(value)))
+    }
+    #[inline]
+    fn darken_001(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=001")))
+    }
+    #[inline]
+    fn darken_002(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=002")))
+    }
+    #[inline]
+    fn darken_003(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=003")))
+    }
+    #[inline]
+    fn darken_004(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=004")))
+    }
+    #[inline]
+    fn darken_005(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=005")))
+    }
+    #[inline]
+    fn darken_010(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=010")))
+    }
+    #[inline]
+    fn darken_015(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=015")))
+    }
+    #[inline]
+    fn darken_020(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=020")))
+    }
+    #[inline]
+    fn darken_025(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=025")))
+    }
+    #[inline]
+    fn darken_030(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=030")))
+    }
+    #[inline]
+    fn darken_035(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=035")))
+    }
+    #[inline]
+    fn darken_040(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=040")))
+    }
+    #[inline]
+    fn darken_045(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=045")))
+    }
+    #[inline]
+    fn darken_050(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=050")))
+    }
+    #[inline]
+    fn darken_055(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=055")))
+    }
+    #[inline]
+    fn darken_060(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=060")))
+    }
+    #[inline]
+    fn darken_065(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=065")))
+    }
+    #[inline]
+    fn darken_070(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=070")))
+    }
+    #[inline]
+    fn darken_075(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=075")))
+    }
+    #[inline]
+    fn darken_080(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=080")))
+    }
+    #[inline]
+    fn darken_085(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=085")))
+    }
+    #[inline]
+    fn darken_090(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=090")))
+    }
+    #[inline]
+    fn darken_095(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=095")))
+    }
+    #[inline]
+    fn darken_100(&self) -> GeneratedTransformerLightnessGroupDarkenChild {
+      GeneratedTransformerLightnessGroupDarkenChild::from_ref(self.append_transformer("darken", Some("=100")))
+    }
+  }
+  pub struct GeneratedTransformerMixGroupMixChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerMixGroupMixChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerMixGroupTintChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerMixGroupTintChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerMixGroupShadeChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerMixGroupShadeChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedTransformerMix: GeneratedSkribbleValue {
+    /// Mix the color with another color. The value should be in the format `<color>,<amount>`. e.g. `(mix=white,20%)`.
+    ///
+    #[inline]
+    fn mix(&self, value: &'static str,) -> GeneratedTransformerMixGroupMixChild {
+      GeneratedTransformerMixGroupMixChild::from_ref(self.append_transformer("mix", Some(value)))
+    }
+    /// Mix the color with white by the provided percentage.
+    ///
+    #[inline]
+    fn tint(&self, value: &'static str,) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some(value)))
+    }
+    #[inline]
+    fn tint_005(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=005")))
+    }
+    #[inline]
+    fn tint_010(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=010")))
+    }
+    #[inline]
+    fn tint_020(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=020")))
+    }
+    #[inline]
+    fn tint_025(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=025")))
+    }
+    #[inline]
+    fn tint_030(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=030")))
+    }
+    #[inline]
+    fn tint_040(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=040")))
+    }
+    #[inline]
+    fn tint_050(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=050")))
+    }
+    #[inline]
+    fn tint_060(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=060")))
+    }
+    #[inline]
+    fn tint_070(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=070")))
+    }
+    #[inline]
+    fn tint_075(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=075")))
+    }
+    #[inline]
+    fn tint_080(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=080")))
+    }
+    #[inline]
+    fn tint_090(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=090")))
+    }
+    #[inline]
+    fn tint_095(&self) -> GeneratedTransformerMixGroupTintChild {
+      GeneratedTransformerMixGroupTintChild::from_ref(self.append_transformer("tint", Some("=095")))
+    }
+    /// Mix the color with black by the provided percentage.
+    ///
+    #[inline]
+    fn shade(&self, value: &'static str,) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some(value)))
+    }
+    #[inline]
+    fn shade_005(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=005")))
+    }
+    #[inline]
+    fn shade_010(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=010")))
+    }
+    #[inline]
+    fn shade_020(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=020")))
+    }
+    #[inline]
+    fn shade_025(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=025")))
+    }
+    #[inline]
+    fn shade_030(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=030")))
+    }
+    #[inline]
+    fn shade_040(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=040")))
+    }
+    #[inline]
+    fn shade_050(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=050")))
+    }
+    #[inline]
+    fn shade_060(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=060")))
+    }
+    #[inline]
+    fn shade_070(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=070")))
+    }
+    #[inline]
+    fn shade_075(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=075")))
+    }
+    #[inline]
+    fn shade_080(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=080")))
+    }
+    #[inline]
+    fn shade_090(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=090")))
+    }
+    #[inline]
+    fn shade_095(&self) -> GeneratedTransformerMixGroupShadeChild {
+      GeneratedTransformerMixGroupShadeChild::from_ref(self.append_transformer("shade", Some("=095")))
+    }
+  }
+  pub struct GeneratedTransformerOklchGroupOklchLightenChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerOklchGroupOklchLightenChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerOklchGroupOklchDarkenChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerOklchGroupOklchDarkenChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerOklchGroupOklchSaturateChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerOklchGroupOklchSaturateChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerOklchGroupOklchDesaturateChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerOklchGroupOklchDesaturateChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub struct GeneratedTransformerOklchGroupOklchInvertChild(String);
+  impl GeneratedSkribbleValue for GeneratedTransformerOklchGroupOklchInvertChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  pub trait GeneratedTransformerOklch: GeneratedSkribbleValue {
+    /// Increase the OKLCH lightness of the color by the provided percentage.
+    ///
+    #[inline]
+    fn oklch_lighten(&self, value: &'static str,) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some(value)))
+    }
+    #[inline]
+    fn oklch_lighten_001(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=001")))
+    }
+    #[inline]
+    fn oklch_lighten_002(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=002")))
+    }
+    #[inline]
+    fn oklch_lighten_003(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=003")))
+    }
+    #[inline]
+    fn oklch_lighten_004(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=004")))
+    }
+    #[inline]
+    fn oklch_lighten_005(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=005")))
+    }
+    #[inline]
+    fn oklch_lighten_010(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=010")))
+    }
+    #[inline]
+    fn oklch_lighten_015(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=015")))
+    }
+    #[inline]
+    fn oklch_lighten_020(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=020")))
+    }
+    #[inline]
+    fn oklch_lighten_025(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=025")))
+    }
+    #[inline]
+    fn oklch_lighten_030(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=030")))
+    }
+    #[inline]
+    fn oklch_lighten_040(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=040")))
+    }
+    #[inline]
+    fn oklch_lighten_050(&self) -> GeneratedTransformerOklchGroupOklchLightenChild {
+      GeneratedTransformerOklchGroupOklchLightenChild::from_ref(self.append_transformer("oklch-lighten", Some("=050")))
+    }
+    /// Decrease the OKLCH lightness of the color by the provided percentage.
+    ///
+    #[inline]
+    fn oklch_darken(&self, value: &'static str,) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some(value)))
+    }
+    #[inline]
+    fn oklch_darken_001(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=001")))
+    }
+    #[inline]
+    fn oklch_darken_002(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=002")))
+    }
+    #[inline]
+    fn oklch_darken_003(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=003")))
+    }
+    #[inline]
+    fn oklch_darken_004(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=004")))
+    }
+    #[inline]
+    fn oklch_darken_005(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=005")))
+    }
+    #[inline]
+    fn oklch_darken_010(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=010")))
+    }
+    #[inline]
+    fn oklch_darken_015(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=015")))
+    }
+    #[inline]
+    fn oklch_darken_020(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=020")))
+    }
+    #[inline]
+    fn oklch_darken_025(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=025")))
+    }
+    #[inline]
+    fn oklch_darken_030(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=030")))
+    }
+    #[inline]
+    fn oklch_darken_040(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=040")))
+    }
+    #[inline]
+    fn oklch_darken_050(&self) -> GeneratedTransformerOklchGroupOklchDarkenChild {
+      GeneratedTransformerOklchGroupOklchDarkenChild::from_ref(self.append_transformer("oklch-darken", Some("=050")))
+    }
+    /// Increase the OKLCH chroma of the color by the provided percentage.
+    ///
+    #[inline]
+    fn oklch_saturate(&self, value: &'static str,) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some(value)))
+    }
+    #[inline]
+    fn oklch_saturate_001(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=001")))
+    }
+    #[inline]
+    fn oklch_saturate_002(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=002")))
+    }
+    #[inline]
+    fn oklch_saturate_003(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=003")))
+    }
+    #[inline]
+    fn oklch_saturate_004(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=004")))
+    }
+    #[inline]
+    fn oklch_saturate_005(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=005")))
+    }
+    #[inline]
+    fn oklch_saturate_010(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=010")))
+    }
+    #[inline]
+    fn oklch_saturate_015(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=015")))
+    }
+    #[inline]
+    fn oklch_saturate_020(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=020")))
+    }
+    #[inline]
+    fn oklch_saturate_025(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=025")))
+    }
+    #[inline]
+    fn oklch_saturate_030(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=030")))
+    }
+    #[inline]
+    fn oklch_saturate_040(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=040")))
+    }
+    #[inline]
+    fn oklch_saturate_050(&self) -> GeneratedTransformerOklchGroupOklchSaturateChild {
+      GeneratedTransformerOklchGroupOklchSaturateChild::from_ref(self.append_transformer("oklch-saturate", Some("=050")))
+    }
+    /// Decrease the OKLCH chroma of the color by the provided percentage.
+    ///
+    #[inline]
+    fn oklch_desaturate(&self, value: &'static str,) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some(value)))
+    }
+    #[inline]
+    fn oklch_desaturate_001(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=001")))
+    }
+    #[inline]
+    fn oklch_desaturate_002(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=002")))
+    }
+    #[inline]
+    fn oklch_desaturate_003(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=003")))
+    }
+    #[inline]
+    fn oklch_desaturate_004(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=004")))
+    }
+    #[inline]
+    fn oklch_desaturate_005(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=005")))
+    }
+    #[inline]
+    fn oklch_desaturate_010(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=010")))
+    }
+    #[inline]
+    fn oklch_desaturate_015(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=015")))
+    }
+    #[inline]
+    fn oklch_desaturate_020(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=020")))
+    }
+    #[inline]
+    fn oklch_desaturate_025(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=025")))
+    }
+    #[inline]
+    fn oklch_desaturate_030(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=030")))
+    }
+    #[inline]
+    fn oklch_desaturate_040(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=040")))
+    }
+    #[inline]
+    fn oklch_desaturate_050(&self) -> GeneratedTransformerOklchGroupOklchDesaturateChild {
+      GeneratedTransformerOklchGroupOklchDesaturateChild::from_ref(self.append_transformer("oklch-desaturate", Some("=050")))
+    }
+    /// Invert the OKLCH lightness of the color. Useful for dark mode.
+    ///
+    #[inline]
+    fn oklch_invert(&self) -> GeneratedTransformerOklchGroupOklchInvertChild {
+      GeneratedTransformerOklchGroupOklchInvertChild::from_ref(self.append_transformer("oklch-invert", None))
+    }
+  }
+  impl GeneratedTransformerImportant for GeneratedSkribbleRoot {}
+  impl GeneratedTransformerAlpha for GeneratedSkribbleRoot {}
+  impl GeneratedTransformerLightness for GeneratedSkribbleRoot {}
+  impl GeneratedTransformerMix for GeneratedSkribbleRoot {}
+  impl GeneratedTransformerOklch for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerImportant for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerAlpha for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerLightness for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerMix for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedTransformerOklch for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerImportant for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerAlpha for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerLightness for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerMix for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedTransformerOklch for GeneratedModifierPseudoGroupChild {}
+  impl GeneratedTransformerAlpha for GeneratedTransformerImportantGroupImportantChild {}
+  impl GeneratedTransformerLightness for GeneratedTransformerImportantGroupImportantChild {}
+  impl GeneratedTransformerMix for GeneratedTransformerImportantGroupImportantChild {}
+  impl GeneratedTransformerOklch for GeneratedTransformerImportantGroupImportantChild {}
+}
+#[cfg(not(feature = "skribble-skip-transformers"))]
+pub use transformers::*;
+#[cfg(not(feature = "skribble-skip-keyframes"))]
+mod keyframes {
+  use super::*;
+  pub trait GeneratedKeyframeIn: GeneratedSkribbleValue {
+    /// Manages the keyframes for the entry animation
+    ///
+    /// ```css
+    /// @keyframes in {
+    ///   from {
+    ///     opacity: var(--sk-enter-opacity, 1);
+    ///     transform: translate3d(var(--sk-enter-tx, 0), var(--sk-enter-ty, 0), 0) scale3d(var(--sk-enter-sx, 1), var(--sk-enter-sy, 1), 1) rotate(var(--sk-enter-rotate, 0deg));
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn r#in(&self) -> String {
+      self.append_value("in")
+    }
+  }
+  pub trait GeneratedKeyframeOut: GeneratedSkribbleValue {
+    /// Manages the keyframes for the exit animation.
+    ///
+    /// ```css
+    /// @keyframes out {
+    ///   to {
+    ///     opacity: var(--sk-exit-opacity, 1);
+    ///     transform: translate3d(var(--sk-exit-tx, 0), var(--sk-exit-ty, 0), 0) scale3d(var(--sk-exit-sx, 1), var(--sk-exit-sy, 1), 1) rotate(var(--sk-exit-rotate, 0deg));
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn out(&self) -> String {
+      self.append_value("out")
+    }
+  }
+  pub trait GeneratedKeyframeSpin: GeneratedSkribbleValue {
+    /// Manages the keyframes for the spin animation.
+    ///
+    /// ```css
+    /// @keyframes spin {
+    ///   from {
+    ///     transform: rotate(0deg);
+    ///   }
+    ///   to {
+    ///     transform: rotate(360deg);
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn spin(&self) -> String {
+      self.append_value("spin")
+    }
+  }
+  pub trait GeneratedKeyframePing: GeneratedSkribbleValue {
+    /// Manages the keyframes for the ping animation.
+    ///
+    /// ```css
+    /// @keyframes ping {
+    ///   75%, 100% {
+    ///     transform: scale(2);
+    ///     opacity: 0;
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn ping(&self) -> String {
+      self.append_value("ping")
+    }
+  }
+  pub trait GeneratedKeyframePulse: GeneratedSkribbleValue {
+    /// Manages the keyframes for the pulse animation.
+    ///
+    /// ```css
+    /// @keyframes pulse {
+    ///   0%, 100% {
+    ///     opacity: 1;
+    ///   }
+    ///   50% {
+    ///     opacity: 0.5;
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn pulse(&self) -> String {
+      self.append_value("pulse")
+    }
+  }
+  pub trait GeneratedKeyframeBounce: GeneratedSkribbleValue {
+    /// Manages the keyframes for the bounce animation.
+    ///
+    /// ```css
+    /// @keyframes bounce {
+    ///   0%, 100% {
+    ///     transform: translateY(-25%);
+    ///     animationTimingFunction: cubic-bezier(0.8, 0, 1, 1);
+    ///   }
+    ///   50% {
+    ///     transform: translateY(0);
+    ///     animationTimingFunction: cubic-bezier(0, 0, 0.2, 1);
+    ///   }
+    /// }
+    /// ```
+    #[inline]
+    fn bounce(&self) -> String {
+      self.append_value("bounce")
+    }
+  }
+}
+#[cfg(not(feature = "skribble-skip-keyframes"))]
+pub use keyframes::*;
+#[cfg(not(feature = "skribble-skip-palette"))]
+mod palette {
+  use super::*;
+  pub trait GeneratedColorPrimary: GeneratedSkribbleValue {
+    /// The primary color. Useful for primary buttons.
+    ///
+    /// ```css
+    /// @property --sk-p {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(258.89362 94.37751% 51.17647%);
+    /// }
+    /// ```
+    #[inline]
+    fn primary(&self) -> String {
+      self.append_value("primary")
+    }
+  }
+  pub trait GeneratedColorPrimaryContent: GeneratedSkribbleValue {
+    /// The primary content color
+    ///
+    /// ```css
+    /// @property --sk-pc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn primary_content(&self) -> String {
+      self.append_value("primary-content")
+    }
+  }
+  pub trait GeneratedColorSecondary: GeneratedSkribbleValue {
+    /// The secondary color. Useful for secondary buttons.
+    ///
+    /// ```css
+    /// @property --sk-s {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(314 100% 47.058823%);
+    /// }
+    /// ```
+    #[inline]
+    fn secondary(&self) -> String {
+      self.append_value("secondary")
+    }
+  }
+  pub trait GeneratedColorSecondaryContent: GeneratedSkribbleValue {
+    /// The secondary content color. Useful for text within secondary buttons.
+    ///
+    /// ```css
+    /// @property --sk-sc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn secondary_content(&self) -> String {
+      self.append_value("secondary-content")
+    }
+  }
+  pub trait GeneratedColorAccent: GeneratedSkribbleValue {
+    /// Color for accents.
+    ///
+    /// ```css
+    /// @property --sk-a {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(174 60.000008% 50.980396%);
+    /// }
+    /// ```
+    #[inline]
+    fn accent(&self) -> String {
+      self.append_value("accent")
+    }
+  }
+  pub trait GeneratedColorAccentContent: GeneratedSkribbleValue {
+    /// Color for content within accents.
+    ///
+    /// ```css
+    /// @property --sk-ac {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(174.70587 43.589737% 15.294118%);
+    /// }
+    /// ```
+    #[inline]
+    fn accent_content(&self) -> String {
+      self.append_value("accent-content")
+    }
+  }
+  pub trait GeneratedColorNeutral: GeneratedSkribbleValue {
+    /// The neutral color.
+    ///
+    /// ```css
+    /// @property --sk-n {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(218.99998 14.084508% 27.843138%);
+    /// }
+    /// ```
+    #[inline]
+    fn neutral(&self) -> String {
+      self.append_value("neutral")
+    }
+  }
+  pub trait GeneratedColorNeutralContent: GeneratedSkribbleValue {
+    /// The neutral content color.
+    ///
+    /// ```css
+    /// @property --sk-nc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn neutral_content(&self) -> String {
+      self.append_value("neutral-content")
+    }
+  }
+  pub trait GeneratedColorBase100: GeneratedSkribbleValue {
+    /// The base color.
+    ///
+    /// ```css
+    /// @property --sk-b1 {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn base100(&self) -> String {
+      self.append_value("base100")
+    }
+  }
+  pub trait GeneratedColorBase200: GeneratedSkribbleValue {
+    /// The secondary base color.
+    ///
+    /// ```css
+    /// @property --sk-b2 {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 94.90196%);
+    /// }
+    /// ```
+    #[inline]
+    fn base200(&self) -> String {
+      self.append_value("base200")
+    }
+  }
+  pub trait GeneratedColorBase300: GeneratedSkribbleValue {
+    /// The tertiary base color.
+    ///
+    /// ```css
+    /// @property --sk-b3 {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(180 1.9607836% 90%);
+    /// }
+    /// ```
+    #[inline]
+    fn base300(&self) -> String {
+      self.append_value("base300")
+    }
+  }
+  pub trait GeneratedColorBaseContent: GeneratedSkribbleValue {
+    /// The base content color. This is useful for text.
+    ///
+    /// ```css
+    /// @property --sk-bc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(215 27.906975% 16.862745%);
+    /// }
+    /// ```
+    #[inline]
+    fn base_content(&self) -> String {
+      self.append_value("base-content")
+    }
+  }
+  pub trait GeneratedColorInfo: GeneratedSkribbleValue {
+    /// The info color. Useful for info buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-in {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(212.34569 100% 47.64706%);
+    /// }
+    /// ```
+    #[inline]
+    fn info(&self) -> String {
+      self.append_value("info")
+    }
+  }
+  pub trait GeneratedColorInfoContent: GeneratedSkribbleValue {
+    /// The info content color. Useful for text within info buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-inc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn info_content(&self) -> String {
+      self.append_value("info-content")
+    }
+  }
+  pub trait GeneratedColorSuccess: GeneratedSkribbleValue {
+    /// The success color. Useful for success buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-su {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(136.8421 72.1519% 46.47059%);
+    /// }
+    /// ```
+    #[inline]
+    fn success(&self) -> String {
+      self.append_value("success")
+    }
+  }
+  pub trait GeneratedColorSuccessContent: GeneratedSkribbleValue {
+    /// The success content color. Useful for text within success buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-suc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn success_content(&self) -> String {
+      self.append_value("success-content")
+    }
+  }
+  pub trait GeneratedColorWarning: GeneratedSkribbleValue {
+    /// The warning color. Useful for warning buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-wa {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(4.5614033 100% 66.47059%);
+    /// }
+    /// ```
+    #[inline]
+    fn warning(&self) -> String {
+      self.append_value("warning")
+    }
+  }
+  pub trait GeneratedColorWarningContent: GeneratedSkribbleValue {
+    /// The warning content color. Useful for text within warning buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-wac {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn warning_content(&self) -> String {
+      self.append_value("warning-content")
+    }
+  }
+  pub trait GeneratedColorError: GeneratedSkribbleValue {
+    /// The error color. Useful for error buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-er {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(325.05154 77.600006% 49.019608%);
+    /// }
+    /// ```
+    #[inline]
+    fn error(&self) -> String {
+      self.append_value("error")
+    }
+  }
+  pub trait GeneratedColorErrorContent: GeneratedSkribbleValue {
+    /// The error content color. Useful for text within error buttons and alerts.
+    ///
+    /// ```css
+    /// @property --sk-erc {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn error_content(&self) -> String {
+      self.append_value("error-content")
+    }
+  }
+  pub trait GeneratedColorRingOffsetColor: GeneratedSkribbleValue {
+    /// ```css
+    /// @property --sk-ring-offset-color {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(0 0% 100%);
+    /// }
+    /// ```
+    #[inline]
+    fn ring_offset_color(&self) -> String {
+      self.append_value("ring-offset-color")
+    }
+  }
+  pub trait GeneratedColorRingColor: GeneratedSkribbleValue {
+    /// ```css
+    /// @property --sk-ring-color {
+    ///   syntax: "<color>";
+    ///   inherits: true;
+    ///   initial-value: hsl(217.21925 91.21951% 59.80392% / 0.5019608);
+    /// }
+    /// ```
+    #[inline]
+    fn ring_color(&self) -> String {
+      self.append_value("ring-color")
+    }
+  }
+  pub trait GeneratedColorBlack: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 0%);"></span> `hsl(0 0% 0%)`
+    #[inline]
+    fn black(&self) -> String {
+      self.append_value("black")
+    }
+  }
+  pub trait GeneratedColorWhite: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 100%);"></span> `hsl(0 0% 100%)`
+    #[inline]
+    fn white(&self) -> String {
+      self.append_value("white")
+    }
+  }
+  pub trait GeneratedColorSlate50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(210 40% 98.039215%);"></span> `hsl(210 40% 98.039215%)`
+    #[inline]
+    fn slate50(&self) -> String {
+      self.append_value("slate50")
+    }
+  }
+  pub trait GeneratedColorSlate100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(210 40% 96.07843%);"></span> `hsl(210 40% 96.07843%)`
+    #[inline]
+    fn slate100(&self) -> String {
+      self.append_value("slate100")
+    }
+  }
+  pub trait GeneratedColorSlate200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(214.2857 31.818182% 91.37255%);"></span> `hsl(214.2857 31.818182% 91.37255%)`
+    #[inline]
+    fn slate200(&self) -> String {
+      self.append_value("slate200")
+    }
+  }
+  pub trait GeneratedColorSlate300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(212.72726 26.82927% 83.92157%);"></span> `hsl(212.72726 26.82927% 83.92157%)`
+    #[inline]
+    fn slate300(&self) -> String {
+      self.append_value("slate300")
+    }
+  }
+  pub trait GeneratedColorSlate400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(215 20.224718% 65.09804%);"></span> `hsl(215 20.224718% 65.09804%)`
+    #[inline]
+    fn slate400(&self) -> String {
+      self.append_value("slate400")
+    }
+  }
+  pub trait GeneratedColorSlate500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(215.38461 16.317993% 46.862747%);"></span> `hsl(215.38461 16.317993% 46.862747%)`
+    #[inline]
+    fn slate500(&self) -> String {
+      self.append_value("slate500")
+    }
+  }
+  pub trait GeneratedColorSlate600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(215.29413 19.31818% 34.509804%);"></span> `hsl(215.29413 19.31818% 34.509804%)`
+    #[inline]
+    fn slate600(&self) -> String {
+      self.append_value("slate600")
+    }
+  }
+  pub trait GeneratedColorSlate700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(215.29411 25% 26.666668%);"></span> `hsl(215.29411 25% 26.666668%)`
+    #[inline]
+    fn slate700(&self) -> String {
+      self.append_value("slate700")
+    }
+  }
+  pub trait GeneratedColorSlate800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(217.24138 32.584267% 17.450981%);"></span> `hsl(217.24138 32.584267% 17.450981%)`
+    #[inline]
+    fn slate800(&self) -> String {
+      self.append_value("slate800")
+    }
+  }
+  pub trait GeneratedColorSlate900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(222.22221 47.368423% 11.176471%);"></span> `hsl(222.22221 47.368423% 11.176471%)`
+    #[inline]
+    fn slate900(&self) -> String {
+      self.append_value("slate900")
+    }
+  }
+  pub trait GeneratedColorSlate950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(228.57143 84% 4.901961%);"></span> `hsl(228.57143 84% 4.901961%)`
+    #[inline]
+    fn slate950(&self) -> String {
+      self.append_value("slate950")
+    }
+  }
+  pub trait GeneratedColorGray50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(210 20% 98.039215%);"></span> `hsl(210 20% 98.039215%)`
+    #[inline]
+    fn gray50(&self) -> String {
+      self.append_value("gray50")
+    }
+  }
+  pub trait GeneratedColorGray100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(220 14.285726% 95.882355%);"></span> `hsl(220 14.285726% 95.882355%)`
+    #[inline]
+    fn gray100(&self) -> String {
+      self.append_value("gray100")
+    }
+  }
+  pub trait GeneratedColorGray200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(220 13.043478% 90.98039%);"></span> `hsl(220 13.043478% 90.98039%)`
+    #[inline]
+    fn gray200(&self) -> String {
+      self.append_value("gray200")
+    }
+  }
+  pub trait GeneratedColorGray300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(216 12.195122% 83.92157%);"></span> `hsl(216 12.195122% 83.92157%)`
+    #[inline]
+    fn gray300(&self) -> String {
+      self.append_value("gray300")
+    }
+  }
+  pub trait GeneratedColorGray400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(217.89473 10.614524% 64.90196%);"></span> `hsl(217.89473 10.614524% 64.90196%)`
+    #[inline]
+    fn gray400(&self) -> String {
+      self.append_value("gray400")
+    }
+  }
+  pub trait GeneratedColorGray500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(220 8.9361725% 46.07843%);"></span> `hsl(220 8.9361725% 46.07843%)`
+    #[inline]
+    fn gray500(&self) -> String {
+      self.append_value("gray500")
+    }
+  }
+  pub trait GeneratedColorGray600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(215 13.793102% 34.11765%);"></span> `hsl(215 13.793102% 34.11765%)`
+    #[inline]
+    fn gray600(&self) -> String {
+      self.append_value("gray600")
+    }
+  }
+  pub trait GeneratedColorGray700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(216.92308 19.117647% 26.666668%);"></span> `hsl(216.92308 19.117647% 26.666668%)`
+    #[inline]
+    fn gray700(&self) -> String {
+      self.append_value("gray700")
+    }
+  }
+  pub trait GeneratedColorGray800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(215 27.906975% 16.862745%);"></span> `hsl(215 27.906975% 16.862745%)`
+    #[inline]
+    fn gray800(&self) -> String {
+      self.append_value("gray800")
+    }
+  }
+  pub trait GeneratedColorGray900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(220.90909 39.285713% 10.980393%);"></span> `hsl(220.90909 39.285713% 10.980393%)`
+    #[inline]
+    fn gray900(&self) -> String {
+      self.append_value("gray900")
+    }
+  }
+  pub trait GeneratedColorGray950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(224 71.42857% 4.117647%);"></span> `hsl(224 71.42857% 4.117647%)`
+    #[inline]
+    fn gray950(&self) -> String {
+      self.append_value("gray950")
+    }
+  }
+  pub trait GeneratedColorZinc50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 98.039215%);"></span> `hsl(0 0% 98.039215%)`
+    #[inline]
+    fn zinc50(&self) -> String {
+      self.append_value("zinc50")
+    }
+  }
+  pub trait GeneratedColorZinc100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 4.761908% 95.882355%);"></span> `hsl(240 4.761908% 95.882355%)`
+    #[inline]
+    fn zinc100(&self) -> String {
+      self.append_value("zinc100")
+    }
+  }
+  pub trait GeneratedColorZinc200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 5.882351% 90%);"></span> `hsl(240 5.882351% 90%)`
+    #[inline]
+    fn zinc200(&self) -> String {
+      self.append_value("zinc200")
+    }
+  }
+  pub trait GeneratedColorZinc300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 4.8780484% 83.92157%);"></span> `hsl(240 4.8780484% 83.92157%)`
+    #[inline]
+    fn zinc300(&self) -> String {
+      self.append_value("zinc300")
+    }
+  }
+  pub trait GeneratedColorZinc400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 5.0279326% 64.90196%);"></span> `hsl(240 5.0279326% 64.90196%)`
+    #[inline]
+    fn zinc400(&self) -> String {
+      self.append_value("zinc400")
+    }
+  }
+  pub trait GeneratedColorZinc500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 3.829787% 46.07843%);"></span> `hsl(240 3.829787% 46.07843%)`
+    #[inline]
+    fn zinc500(&self) -> String {
+      self.append_value("zinc500")
+    }
+  }
+  pub trait GeneratedColorZinc600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 5.2023115% 33.92157%);"></span> `hsl(240 5.2023115% 33.92157%)`
+    #[inline]
+    fn zinc600(&self) -> String {
+      self.append_value("zinc600")
+    }
+  }
+  pub trait GeneratedColorZinc700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 5.26316% 26.078432%);"></span> `hsl(240 5.26316% 26.078432%)`
+    #[inline]
+    fn zinc700(&self) -> String {
+      self.append_value("zinc700")
+    }
+  }
+  pub trait GeneratedColorZinc800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 3.7037034% 15.882354%);"></span> `hsl(240 3.7037034% 15.882354%)`
+    #[inline]
+    fn zinc800(&self) -> String {
+      self.append_value("zinc800")
+    }
+  }
+  pub trait GeneratedColorZinc900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 5.882353% 10%);"></span> `hsl(240 5.882353% 10%)`
+    #[inline]
+    fn zinc900(&self) -> String {
+      self.append_value("zinc900")
+    }
+  }
+  pub trait GeneratedColorZinc950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(240 9.999999% 3.9215689%);"></span> `hsl(240 9.999999% 3.9215689%)`
+    #[inline]
+    fn zinc950(&self) -> String {
+      self.append_value("zinc950")
+    }
+  }
+  pub trait GeneratedColorNeutral50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 98.039215%);"></span> `hsl(0 0% 98.039215%)`
+    #[inline]
+    fn neutral50(&self) -> String {
+      self.append_value("neutral50")
+    }
+  }
+  pub trait GeneratedColorNeutral100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 96.07843%);"></span> `hsl(0 0% 96.07843%)`
+    #[inline]
+    fn neutral100(&self) -> String {
+      self.append_value("neutral100")
+    }
+  }
+  pub trait GeneratedColorNeutral200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 89.803925%);"></span> `hsl(0 0% 89.803925%)`
+    #[inline]
+    fn neutral200(&self) -> String {
+      self.append_value("neutral200")
+    }
+  }
+  pub trait GeneratedColorNeutral300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 83.13725%);"></span> `hsl(0 0% 83.13725%)`
+    #[inline]
+    fn neutral300(&self) -> String {
+      self.append_value("neutral300")
+    }
+  }
+  pub trait GeneratedColorNeutral400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 63.92157%);"></span> `hsl(0 0% 63.92157%)`
+    #[inline]
+    fn neutral400(&self) -> String {
+      self.append_value("neutral400")
+    }
+  }
+  pub trait GeneratedColorNeutral500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 45.098038%);"></span> `hsl(0 0% 45.098038%)`
+    #[inline]
+    fn neutral500(&self) -> String {
+      self.append_value("neutral500")
+    }
+  }
+  pub trait GeneratedColorNeutral600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 32.156864%);"></span> `hsl(0 0% 32.156864%)`
+    #[inline]
+    fn neutral600(&self) -> String {
+      self.append_value("neutral600")
+    }
+  }
+  pub trait GeneratedColorNeutral700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 25.098042%);"></span> `hsl(0 0% 25.098042%)`
+    #[inline]
+    fn neutral700(&self) -> String {
+      self.append_value("neutral700")
+    }
+  }
+  pub trait GeneratedColorNeutral800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 14.901961%);"></span> `hsl(0 0% 14.901961%)`
+    #[inline]
+    fn neutral800(&self) -> String {
+      self.append_value("neutral800")
+    }
+  }
+  pub trait GeneratedColorNeutral900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 9.0196085%);"></span> `hsl(0 0% 9.0196085%)`
+    #[inline]
+    fn neutral900(&self) -> String {
+      self.append_value("neutral900")
+    }
+  }
+  pub trait GeneratedColorNeutral950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 0% 3.9215689%);"></span> `hsl(0 0% 3.9215689%)`
+    #[inline]
+    fn neutral950(&self) -> String {
+      self.append_value("neutral950")
+    }
+  }
+  pub trait GeneratedColorStone50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(60 9.090897% 97.84313%);"></span> `hsl(60 9.090897% 97.84313%)`
+    #[inline]
+    fn stone50(&self) -> String {
+      self.append_value("stone50")
+    }
+  }
+  pub trait GeneratedColorStone100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(60 4.761908% 95.882355%);"></span> `hsl(60 4.761908% 95.882355%)`
+    #[inline]
+    fn stone100(&self) -> String {
+      self.append_value("stone100")
+    }
+  }
+  pub trait GeneratedColorStone200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(20 5.882351% 90%);"></span> `hsl(20 5.882351% 90%)`
+    #[inline]
+    fn stone200(&self) -> String {
+      self.append_value("stone200")
+    }
+  }
+  pub trait GeneratedColorStone300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(24 5.747125% 82.94118%);"></span> `hsl(24 5.747125% 82.94118%)`
+    #[inline]
+    fn stone300(&self) -> String {
+      self.append_value("stone300")
+    }
+  }
+  pub trait GeneratedColorStone400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(24 5.4347825% 63.92157%);"></span> `hsl(24 5.4347825% 63.92157%)`
+    #[inline]
+    fn stone400(&self) -> String {
+      self.append_value("stone400")
+    }
+  }
+  pub trait GeneratedColorStone500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(25 5.2631574% 44.705883%);"></span> `hsl(25 5.2631574% 44.705883%)`
+    #[inline]
+    fn stone500(&self) -> String {
+      self.append_value("stone500")
+    }
+  }
+  pub trait GeneratedColorStone600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(33.333336 5.454545% 32.352943%);"></span> `hsl(33.333336 5.454545% 32.352943%)`
+    #[inline]
+    fn stone600(&self) -> String {
+      self.append_value("stone600")
+    }
+  }
+  pub trait GeneratedColorStone700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(30.000015 6.2500024% 25.098042%);"></span> `hsl(30.000015 6.2500024% 25.098042%)`
+    #[inline]
+    fn stone700(&self) -> String {
+      self.append_value("stone700")
+    }
+  }
+  pub trait GeneratedColorStone800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(12 6.493506% 15.09804%);"></span> `hsl(12 6.493506% 15.09804%)`
+    #[inline]
+    fn stone800(&self) -> String {
+      self.append_value("stone800")
+    }
+  }
+  pub trait GeneratedColorStone900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(24 9.803921% 10%);"></span> `hsl(24 9.803921% 10%)`
+    #[inline]
+    fn stone900(&self) -> String {
+      self.append_value("stone900")
+    }
+  }
+  pub trait GeneratedColorStone950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(20 14.285713% 4.117647%);"></span> `hsl(20 14.285713% 4.117647%)`
+    #[inline]
+    fn stone950(&self) -> String {
+      self.append_value("stone950")
+    }
+  }
+  pub trait GeneratedColorRed50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 85.71429% 97.254906%);"></span> `hsl(0 85.71429% 97.254906%)`
+    #[inline]
+    fn red50(&self) -> String {
+      self.append_value("red50")
+    }
+  }
+  pub trait GeneratedColorRed100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 93.333336% 94.117645%);"></span> `hsl(0 93.333336% 94.117645%)`
+    #[inline]
+    fn red100(&self) -> String {
+      self.append_value("red100")
+    }
+  }
+  pub trait GeneratedColorRed200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 96.296295% 89.411766%);"></span> `hsl(0 96.296295% 89.411766%)`
+    #[inline]
+    fn red200(&self) -> String {
+      self.append_value("red200")
+    }
+  }
+  pub trait GeneratedColorRed300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 93.54841% 81.76471%);"></span> `hsl(0 93.54841% 81.76471%)`
+    #[inline]
+    fn red300(&self) -> String {
+      self.append_value("red300")
+    }
+  }
+  pub trait GeneratedColorRed400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 90.60403% 70.78431%);"></span> `hsl(0 90.60403% 70.78431%)`
+    #[inline]
+    fn red400(&self) -> String {
+      self.append_value("red400")
+    }
+  }
+  pub trait GeneratedColorRed500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 84.23646% 60.19608%);"></span> `hsl(0 84.23646% 60.19608%)`
+    #[inline]
+    fn red500(&self) -> String {
+      self.append_value("red500")
+    }
+  }
+  pub trait GeneratedColorRed600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 72.22223% 50.588238%);"></span> `hsl(0 72.22223% 50.588238%)`
+    #[inline]
+    fn red600(&self) -> String {
+      self.append_value("red600")
+    }
+  }
+  pub trait GeneratedColorRed700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 73.70892% 41.764706%);"></span> `hsl(0 73.70892% 41.764706%)`
+    #[inline]
+    fn red700(&self) -> String {
+      self.append_value("red700")
+    }
+  }
+  pub trait GeneratedColorRed800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 70.00001% 35.294117%);"></span> `hsl(0 70.00001% 35.294117%)`
+    #[inline]
+    fn red800(&self) -> String {
+      self.append_value("red800")
+    }
+  }
+  pub trait GeneratedColorRed900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 62.82051% 30.588236%);"></span> `hsl(0 62.82051% 30.588236%)`
+    #[inline]
+    fn red900(&self) -> String {
+      self.append_value("red900")
+    }
+  }
+  pub trait GeneratedColorRed950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(0 74.68355% 15.490196%);"></span> `hsl(0 74.68355% 15.490196%)`
+    #[inline]
+    fn red950(&self) -> String {
+      self.append_value("red950")
+    }
+  }
+  pub trait GeneratedColorOrange50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(33.333336 100% 96.47059%);"></span> `hsl(33.333336 100% 96.47059%)`
+    #[inline]
+    fn orange50(&self) -> String {
+      self.append_value("orange50")
+    }
+  }
+  pub trait GeneratedColorOrange100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(34.285717 100% 91.76471%);"></span> `hsl(34.285717 100% 91.76471%)`
+    #[inline]
+    fn orange100(&self) -> String {
+      self.append_value("orange100")
+    }
+  }
+  pub trait GeneratedColorOrange200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(32.142857 97.674416% 83.13725%);"></span> `hsl(32.142857 97.674416% 83.13725%)`
+    #[inline]
+    fn orange200(&self) -> String {
+      self.append_value("orange200")
+    }
+  }
+  pub trait GeneratedColorOrange300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(30.656933 97.163124% 72.35294%);"></span> `hsl(30.656933 97.163124% 72.35294%)`
+    #[inline]
+    fn orange300(&self) -> String {
+      self.append_value("orange300")
+    }
+  }
+  pub trait GeneratedColorOrange400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(27.015709 95.9799% 60.980392%);"></span> `hsl(27.015709 95.9799% 60.980392%)`
+    #[inline]
+    fn orange400(&self) -> String {
+      self.append_value("orange400")
+    }
+  }
+  pub trait GeneratedColorOrange500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(24.581497 94.97908% 53.137253%);"></span> `hsl(24.581497 94.97908% 53.137253%)`
+    #[inline]
+    fn orange500(&self) -> String {
+      self.append_value("orange500")
+    }
+  }
+  pub trait GeneratedColorOrange600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(20.54054 90.243904% 48.235294%);"></span> `hsl(20.54054 90.243904% 48.235294%)`
+    #[inline]
+    fn orange600(&self) -> String {
+      self.append_value("orange600")
+    }
+  }
+  pub trait GeneratedColorOrange700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(17.472528 88.34952% 40.39216%);"></span> `hsl(17.472528 88.34952% 40.39216%)`
+    #[inline]
+    fn orange700(&self) -> String {
+      self.append_value("orange700")
+    }
+  }
+  pub trait GeneratedColorOrange800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(14.999998 79.06977% 33.72549%);"></span> `hsl(14.999998 79.06977% 33.72549%)`
+    #[inline]
+    fn orange800(&self) -> String {
+      self.append_value("orange800")
+    }
+  }
+  pub trait GeneratedColorOrange900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(15.28302 74.64789% 27.843138%);"></span> `hsl(15.28302 74.64789% 27.843138%)`
+    #[inline]
+    fn orange900(&self) -> String {
+      self.append_value("orange900")
+    }
+  }
+  pub trait GeneratedColorOrange950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(13 81.08108% 14.509805%);"></span> `hsl(13 81.08108% 14.509805%)`
+    #[inline]
+    fn orange950(&self) -> String {
+      self.append_value("orange950")
+    }
+  }
+  pub trait GeneratedColorAmber50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(48 100% 96.07843%);"></span> `hsl(48 100% 96.07843%)`
+    #[inline]
+    fn amber50(&self) -> String {
+      self.append_value("amber50")
+    }
+  }
+  pub trait GeneratedColorAmber100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(48 96.49126% 88.82353%);"></span> `hsl(48 96.49126% 88.82353%)`
+    #[inline]
+    fn amber100(&self) -> String {
+      self.append_value("amber100")
+    }
+  }
+  pub trait GeneratedColorAmber200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(48 96.63864% 76.666664%);"></span> `hsl(48 96.63864% 76.666664%)`
+    #[inline]
+    fn amber200(&self) -> String {
+      self.append_value("amber200")
+    }
+  }
+  pub trait GeneratedColorAmber300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(45.942852 96.68508% 64.5098%);"></span> `hsl(45.942852 96.68508% 64.5098%)`
+    #[inline]
+    fn amber300(&self) -> String {
+      self.append_value("amber300")
+    }
+  }
+  pub trait GeneratedColorAmber400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(43.255817 96.41256% 56.27451%);"></span> `hsl(43.255817 96.41256% 56.27451%)`
+    #[inline]
+    fn amber400(&self) -> String {
+      self.append_value("amber400")
+    }
+  }
+  pub trait GeneratedColorAmber500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(37.69231 92.12599% 50.196083%);"></span> `hsl(37.69231 92.12599% 50.196083%)`
+    #[inline]
+    fn amber500(&self) -> String {
+      self.append_value("amber500")
+    }
+  }
+  pub trait GeneratedColorAmber600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(32.132698 94.618835% 43.72549%);"></span> `hsl(32.132698 94.618835% 43.72549%)`
+    #[inline]
+    fn amber600(&self) -> String {
+      self.append_value("amber600")
+    }
+  }
+  pub trait GeneratedColorAmber700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(25.964912 90.47619% 37.058823%);"></span> `hsl(25.964912 90.47619% 37.058823%)`
+    #[inline]
+    fn amber700(&self) -> String {
+      self.append_value("amber700")
+    }
+  }
+  pub trait GeneratedColorAmber800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(22.727274 82.5% 31.372551%);"></span> `hsl(22.727274 82.5% 31.372551%)`
+    #[inline]
+    fn amber800(&self) -> String {
+      self.append_value("amber800")
+    }
+  }
+  pub trait GeneratedColorAmber900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(21.714287 77.77777% 26.470589%);"></span> `hsl(21.714287 77.77777% 26.470589%)`
+    #[inline]
+    fn amber900(&self) -> String {
+      self.append_value("amber900")
+    }
+  }
+  pub trait GeneratedColorAmber950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(20.90909 91.66667% 14.117648%);"></span> `hsl(20.90909 91.66667% 14.117648%)`
+    #[inline]
+    fn amber950(&self) -> String {
+      self.append_value("amber950")
+    }
+  }
+  pub trait GeneratedColorYellow50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(54.545456 91.66667% 95.29412%);"></span> `hsl(54.545456 91.66667% 95.29412%)`
+    #[inline]
+    fn yellow50(&self) -> String {
+      self.append_value("yellow50")
+    }
+  }
+  pub trait GeneratedColorYellow100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(54.915253 96.72134% 88.03922%);"></span> `hsl(54.915253 96.72134% 88.03922%)`
+    #[inline]
+    fn yellow100(&self) -> String {
+      self.append_value("yellow100")
+    }
+  }
+  pub trait GeneratedColorYellow200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(52.75862 98.305084% 76.86275%);"></span> `hsl(52.75862 98.305084% 76.86275%)`
+    #[inline]
+    fn yellow200(&self) -> String {
+      self.append_value("yellow200")
+    }
+  }
+  pub trait GeneratedColorYellow300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(50.439568 97.849464% 63.529415%);"></span> `hsl(50.439568 97.849464% 63.529415%)`
+    #[inline]
+    fn yellow300(&self) -> String {
+      self.append_value("yellow300")
+    }
+  }
+  pub trait GeneratedColorYellow400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(47.947598 95.815895% 53.137253%);"></span> `hsl(47.947598 95.815895% 53.137253%)`
+    #[inline]
+    fn yellow400(&self) -> String {
+      self.append_value("yellow400")
+    }
+  }
+  pub trait GeneratedColorYellow500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(45.398228 93.388435% 47.45098%);"></span> `hsl(45.398228 93.388435% 47.45098%)`
+    #[inline]
+    fn yellow500(&self) -> String {
+      self.append_value("yellow500")
+    }
+  }
+  pub trait GeneratedColorYellow600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(40.606064 96.11651% 40.39216%);"></span> `hsl(40.606064 96.11651% 40.39216%)`
+    #[inline]
+    fn yellow600(&self) -> String {
+      self.append_value("yellow600")
+    }
+  }
+  pub trait GeneratedColorYellow700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(35.454544 91.66667% 32.941177%);"></span> `hsl(35.454544 91.66667% 32.941177%)`
+    #[inline]
+    fn yellow700(&self) -> String {
+      self.append_value("yellow700")
+    }
+  }
+  pub trait GeneratedColorYellow800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(31.764704 80.952385% 28.82353%);"></span> `hsl(31.764704 80.952385% 28.82353%)`
+    #[inline]
+    fn yellow800(&self) -> String {
+      self.append_value("yellow800")
+    }
+  }
+  pub trait GeneratedColorYellow900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(28.42105 72.51908% 25.686275%);"></span> `hsl(28.42105 72.51908% 25.686275%)`
+    #[inline]
+    fn yellow900(&self) -> String {
+      self.append_value("yellow900")
+    }
+  }
+  pub trait GeneratedColorYellow950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(26 83.333336% 14.117648%);"></span> `hsl(26 83.333336% 14.117648%)`
+    #[inline]
+    fn yellow950(&self) -> String {
+      self.append_value("yellow950")
+    }
+  }
+  pub trait GeneratedColorLime50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(78.260864 92.00005% 95.098045%);"></span> `hsl(78.260864 92.00005% 95.098045%)`
+    #[inline]
+    fn lime50(&self) -> String {
+      self.append_value("lime50")
+    }
+  }
+  pub trait GeneratedColorLime100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(79.59184 89.09088% 89.21568%);"></span> `hsl(79.59184 89.09088% 89.21568%)`
+    #[inline]
+    fn lime100(&self) -> String {
+      self.append_value("lime100")
+    }
+  }
+  pub trait GeneratedColorLime200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(80.86956 88.46153% 79.60784%);"></span> `hsl(80.86956 88.46153% 79.60784%)`
+    #[inline]
+    fn lime200(&self) -> String {
+      self.append_value("lime200")
+    }
+  }
+  pub trait GeneratedColorLime300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(81.971825 84.52381% 67.05882%);"></span> `hsl(81.971825 84.52381% 67.05882%)`
+    #[inline]
+    fn lime300(&self) -> String {
+      self.append_value("lime300")
+    }
+  }
+  pub trait GeneratedColorLime400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(82.71186 77.97357% 55.490196%);"></span> `hsl(82.71186 77.97357% 55.490196%)`
+    #[inline]
+    fn lime400(&self) -> String {
+      self.append_value("lime400")
+    }
+  }
+  pub trait GeneratedColorLime500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(83.73626 80.530975% 44.313725%);"></span> `hsl(83.73626 80.530975% 44.313725%)`
+    #[inline]
+    fn lime500(&self) -> String {
+      self.append_value("lime500")
+    }
+  }
+  pub trait GeneratedColorLime600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(84.8 85.22727% 34.509804%);"></span> `hsl(84.8 85.22727% 34.509804%)`
+    #[inline]
+    fn lime600(&self) -> String {
+      self.append_value("lime600")
+    }
+  }
+  pub trait GeneratedColorLime700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(85.87156 78.41727% 27.254904%);"></span> `hsl(85.87156 78.41727% 27.254904%)`
+    #[inline]
+    fn lime700(&self) -> String {
+      self.append_value("lime700")
+    }
+  }
+  pub trait GeneratedColorLime800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(86.25 68.965515% 22.745098%);"></span> `hsl(86.25 68.965515% 22.745098%)`
+    #[inline]
+    fn lime800(&self) -> String {
+      self.append_value("lime800")
+    }
+  }
+  pub trait GeneratedColorLime900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(87.61906 61.165054% 20.19608%);"></span> `hsl(87.61906 61.165054% 20.19608%)`
+    #[inline]
+    fn lime900(&self) -> String {
+      self.append_value("lime900")
+    }
+  }
+  pub trait GeneratedColorLime950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(89.268295 80.39216% 10%);"></span> `hsl(89.268295 80.39216% 10%)`
+    #[inline]
+    fn lime950(&self) -> String {
+      self.append_value("lime950")
+    }
+  }
+  pub trait GeneratedColorGreen50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(138.46153 76.47066% 96.66667%);"></span> `hsl(138.46153 76.47066% 96.66667%)`
+    #[inline]
+    fn green50(&self) -> String {
+      self.append_value("green50")
+    }
+  }
+  pub trait GeneratedColorGreen100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(140.625 84.210526% 92.54902%);"></span> `hsl(140.625 84.210526% 92.54902%)`
+    #[inline]
+    fn green100(&self) -> String {
+      self.append_value("green100")
+    }
+  }
+  pub trait GeneratedColorGreen200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(141 78.94737% 85.09804%);"></span> `hsl(141 78.94737% 85.09804%)`
+    #[inline]
+    fn green200(&self) -> String {
+      self.append_value("green200")
+    }
+  }
+  pub trait GeneratedColorGreen300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(141.7143 76.64235% 73.13726%);"></span> `hsl(141.7143 76.64235% 73.13726%)`
+    #[inline]
+    fn green300(&self) -> String {
+      self.append_value("green300")
+    }
+  }
+  pub trait GeneratedColorGreen400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(141.89189 69.158875% 58.03922%);"></span> `hsl(141.89189 69.158875% 58.03922%)`
+    #[inline]
+    fn green400(&self) -> String {
+      self.append_value("green400")
+    }
+  }
+  pub trait GeneratedColorGreen500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(142.08589 70.562775% 45.294117%);"></span> `hsl(142.08589 70.562775% 45.294117%)`
+    #[inline]
+    fn green500(&self) -> String {
+      self.append_value("green500")
+    }
+  }
+  pub trait GeneratedColorGreen600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(142.12767 76.21622% 36.27451%);"></span> `hsl(142.12767 76.21622% 36.27451%)`
+    #[inline]
+    fn green600(&self) -> String {
+      self.append_value("green600")
+    }
+  }
+  pub trait GeneratedColorGreen700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(142.4299 71.81208% 29.215687%);"></span> `hsl(142.4299 71.81208% 29.215687%)`
+    #[inline]
+    fn green700(&self) -> String {
+      self.append_value("green700")
+    }
+  }
+  pub trait GeneratedColorGreen800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(142.78482 64.227646% 24.117647%);"></span> `hsl(142.78482 64.227646% 24.117647%)`
+    #[inline]
+    fn green800(&self) -> String {
+      self.append_value("green800")
+    }
+  }
+  pub trait GeneratedColorGreen900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(143.80952 61.165054% 20.19608%);"></span> `hsl(143.80952 61.165054% 20.19608%)`
+    #[inline]
+    fn green900(&self) -> String {
+      self.append_value("green900")
+    }
+  }
+  pub trait GeneratedColorGreen950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(144.87805 80.39216% 10%);"></span> `hsl(144.87805 80.39216% 10%)`
+    #[inline]
+    fn green950(&self) -> String {
+      self.append_value("green950")
+    }
+  }
+  pub trait GeneratedColorEmerald50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(151.76471 80.95244% 95.882355%);"></span> `hsl(151.76471 80.95244% 95.882355%)`
+    #[inline]
+    fn emerald50(&self) -> String {
+      self.append_value("emerald50")
+    }
+  }
+  pub trait GeneratedColorEmerald100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(149.2683 80.392136% 90%);"></span> `hsl(149.2683 80.392136% 90%)`
+    #[inline]
+    fn emerald100(&self) -> String {
+      self.append_value("emerald100")
+    }
+  }
+  pub trait GeneratedColorEmerald200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(152.36842 76% 80.39216%);"></span> `hsl(152.36842 76% 80.39216%)`
+    #[inline]
+    fn emerald200(&self) -> String {
+      self.append_value("emerald200")
+    }
+  }
+  pub trait GeneratedColorEmerald300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(156.19835 71.59763% 66.86275%);"></span> `hsl(156.19835 71.59763% 66.86275%)`
+    #[inline]
+    fn emerald300(&self) -> String {
+      self.append_value("emerald300")
+    }
+  }
+  pub trait GeneratedColorEmerald400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(158.1132 64.372475% 51.568626%);"></span> `hsl(158.1132 64.372475% 51.568626%)`
+    #[inline]
+    fn emerald400(&self) -> String {
+      self.append_value("emerald400")
+    }
+  }
+  pub trait GeneratedColorEmerald500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(160.11835 84.079605% 39.411766%);"></span> `hsl(160.11835 84.079605% 39.411766%)`
+    #[inline]
+    fn emerald500(&self) -> String {
+      self.append_value("emerald500")
+    }
+  }
+  pub trait GeneratedColorEmerald600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(161.3793 93.548386% 30.392159%);"></span> `hsl(161.3793 93.548386% 30.392159%)`
+    #[inline]
+    fn emerald600(&self) -> String {
+      self.append_value("emerald600")
+    }
+  }
+  pub trait GeneratedColorEmerald700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(162.93103 93.548386% 24.313726%);"></span> `hsl(162.93103 93.548386% 24.313726%)`
+    #[inline]
+    fn emerald700(&self) -> String {
+      self.append_value("emerald700")
+    }
+  }
+  pub trait GeneratedColorEmerald800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(163.14607 88.11881% 19.803923%);"></span> `hsl(163.14607 88.11881% 19.803923%)`
+    #[inline]
+    fn emerald800(&self) -> String {
+      self.append_value("emerald800")
+    }
+  }
+  pub trait GeneratedColorEmerald900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(164.16667 85.71429% 16.470589%);"></span> `hsl(164.16667 85.71429% 16.470589%)`
+    #[inline]
+    fn emerald900(&self) -> String {
+      self.append_value("emerald900")
+    }
+  }
+  pub trait GeneratedColorEmerald950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(165.71428 91.30435% 9.0196085%);"></span> `hsl(165.71428 91.30435% 9.0196085%)`
+    #[inline]
+    fn emerald950(&self) -> String {
+      self.append_value("emerald950")
+    }
+  }
+  pub trait GeneratedColorTeal50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(166.15385 76.47066% 96.66667%);"></span> `hsl(166.15385 76.47066% 96.66667%)`
+    #[inline]
+    fn teal50(&self) -> String {
+      self.append_value("teal50")
+    }
+  }
+  pub trait GeneratedColorTeal100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(167.23404 85.45452% 89.21568%);"></span> `hsl(167.23404 85.45452% 89.21568%)`
+    #[inline]
+    fn teal100(&self) -> String {
+      self.append_value("teal100")
+    }
+  }
+  pub trait GeneratedColorTeal200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(168.3871 83.78377% 78.23529%);"></span> `hsl(168.3871 83.78377% 78.23529%)`
+    #[inline]
+    fn teal200(&self) -> String {
+      self.append_value("teal200")
+    }
+  }
+  pub trait GeneratedColorTeal300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(170.57143 76.92308% 64.31373%);"></span> `hsl(170.57143 76.92308% 64.31373%)`
+    #[inline]
+    fn teal300(&self) -> String {
+      self.append_value("teal300")
+    }
+  }
+  pub trait GeneratedColorTeal400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(172.45508 66.007904% 50.39216%);"></span> `hsl(172.45508 66.007904% 50.39216%)`
+    #[inline]
+    fn teal400(&self) -> String {
+      self.append_value("teal400")
+    }
+  }
+  pub trait GeneratedColorTeal500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(173.41464 80.39216% 40%);"></span> `hsl(173.41464 80.39216% 40%)`
+    #[inline]
+    fn teal500(&self) -> String {
+      self.append_value("teal500")
+    }
+  }
+  pub trait GeneratedColorTeal600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(174.66667 83.85093% 31.568628%);"></span> `hsl(174.66667 83.85093% 31.568628%)`
+    #[inline]
+    fn teal600(&self) -> String {
+      self.append_value("teal600")
+    }
+  }
+  pub trait GeneratedColorTeal700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(175.33981 77.4436% 26.078432%);"></span> `hsl(175.33981 77.4436% 26.078432%)`
+    #[inline]
+    fn teal700(&self) -> String {
+      self.append_value("teal700")
+    }
+  }
+  pub trait GeneratedColorTeal800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(176.10388 69.36937% 21.764706%);"></span> `hsl(176.10388 69.36937% 21.764706%)`
+    #[inline]
+    fn teal800(&self) -> String {
+      self.append_value("teal800")
+    }
+  }
+  pub trait GeneratedColorTeal900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(175.93219 60.824745% 19.019608%);"></span> `hsl(175.93219 60.824745% 19.019608%)`
+    #[inline]
+    fn teal900(&self) -> String {
+      self.append_value("teal900")
+    }
+  }
+  pub trait GeneratedColorTeal950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(178.60464 84.31373% 10%);"></span> `hsl(178.60464 84.31373% 10%)`
+    #[inline]
+    fn teal950(&self) -> String {
+      self.append_value("teal950")
+    }
+  }
+  pub trait GeneratedColorCyan50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(183.1579 99.99992% 96.274506%);"></span> `hsl(183.1579 99.99992% 96.274506%)`
+    #[inline]
+    fn cyan50(&self) -> String {
+      self.append_value("cyan50")
+    }
+  }
+  pub trait GeneratedColorCyan100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(185.10638 95.918396% 90.39216%);"></span> `hsl(185.10638 95.918396% 90.39216%)`
+    #[inline]
+    fn cyan100(&self) -> String {
+      self.append_value("cyan100")
+    }
+  }
+  pub trait GeneratedColorCyan200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(186.20691 93.54841% 81.76471%);"></span> `hsl(186.20691 93.54841% 81.76471%)`
+    #[inline]
+    fn cyan200(&self) -> String {
+      self.append_value("cyan200")
+    }
+  }
+  pub trait GeneratedColorCyan300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(186.9863 92.40506% 69.01961%);"></span> `hsl(186.9863 92.40506% 69.01961%)`
+    #[inline]
+    fn cyan300(&self) -> String {
+      self.append_value("cyan300")
+    }
+  }
+  pub trait GeneratedColorCyan400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(187.94116 85.714294% 53.333336%);"></span> `hsl(187.94116 85.714294% 53.333336%)`
+    #[inline]
+    fn cyan400(&self) -> String {
+      self.append_value("cyan400")
+    }
+  }
+  pub trait GeneratedColorCyan500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(188.73787 94.495415% 42.7451%);"></span> `hsl(188.73787 94.495415% 42.7451%)`
+    #[inline]
+    fn cyan500(&self) -> String {
+      self.append_value("cyan500")
+    }
+  }
+  pub trait GeneratedColorCyan600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(191.64705 91.39785% 36.47059%);"></span> `hsl(191.64705 91.39785% 36.47059%)`
+    #[inline]
+    fn cyan600(&self) -> String {
+      self.append_value("cyan600")
+    }
+  }
+  pub trait GeneratedColorCyan700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(192.92308 82.27848% 30.980392%);"></span> `hsl(192.92308 82.27848% 30.980392%)`
+    #[inline]
+    fn cyan700(&self) -> String {
+      self.append_value("cyan700")
+    }
+  }
+  pub trait GeneratedColorCyan800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(194.375 69.565216% 27.058825%);"></span> `hsl(194.375 69.565216% 27.058825%)`
+    #[inline]
+    fn cyan800(&self) -> String {
+      self.append_value("cyan800")
+    }
+  }
+  pub trait GeneratedColorCyan900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(196.36363 63.636368% 23.72549%);"></span> `hsl(196.36363 63.636368% 23.72549%)`
+    #[inline]
+    fn cyan900(&self) -> String {
+      self.append_value("cyan900")
+    }
+  }
+  pub trait GeneratedColorCyan950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(197 78.94737% 14.901961%);"></span> `hsl(197 78.94737% 14.901961%)`
+    #[inline]
+    fn cyan950(&self) -> String {
+      self.append_value("cyan950")
+    }
+  }
+  pub trait GeneratedColorSky50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(204 99.9999% 97.05882%);"></span> `hsl(204 99.9999% 97.05882%)`
+    #[inline]
+    fn sky50(&self) -> String {
+      self.append_value("sky50")
+    }
+  }
+  pub trait GeneratedColorSky100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(204 93.75% 93.725494%);"></span> `hsl(204 93.75% 93.725494%)`
+    #[inline]
+    fn sky100(&self) -> String {
+      self.append_value("sky100")
+    }
+  }
+  pub trait GeneratedColorSky200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(200.59702 94.36617% 86.07843%);"></span> `hsl(200.59702 94.36617% 86.07843%)`
+    #[inline]
+    fn sky200(&self) -> String {
+      self.append_value("sky200")
+    }
+  }
+  pub trait GeneratedColorSky300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(199.37007 95.488716% 73.92157%);"></span> `hsl(199.37007 95.488716% 73.92157%)`
+    #[inline]
+    fn sky300(&self) -> String {
+      self.append_value("sky300")
+    }
+  }
+  pub trait GeneratedColorSky400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(198.43748 93.20389% 59.607845%);"></span> `hsl(198.43748 93.20389% 59.607845%)`
+    #[inline]
+    fn sky400(&self) -> String {
+      self.append_value("sky400")
+    }
+  }
+  pub trait GeneratedColorSky500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(198.63014 88.66396% 48.431374%);"></span> `hsl(198.63014 88.66396% 48.431374%)`
+    #[inline]
+    fn sky500(&self) -> String {
+      self.append_value("sky500")
+    }
+  }
+  pub trait GeneratedColorSky600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(200.40608 98.00995% 39.411766%);"></span> `hsl(200.40608 98.00995% 39.411766%)`
+    #[inline]
+    fn sky600(&self) -> String {
+      self.append_value("sky600")
+    }
+  }
+  pub trait GeneratedColorSky700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(201.26582 96.34146% 32.156864%);"></span> `hsl(201.26582 96.34146% 32.156864%)`
+    #[inline]
+    fn sky700(&self) -> String {
+      self.append_value("sky700")
+    }
+  }
+  pub trait GeneratedColorSky800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(200.9524 90% 27.450981%);"></span> `hsl(200.9524 90% 27.450981%)`
+    #[inline]
+    fn sky800(&self) -> String {
+      self.append_value("sky800")
+    }
+  }
+  pub trait GeneratedColorSky900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(202.0408 80.327866% 23.921568%);"></span> `hsl(202.0408 80.327866% 23.921568%)`
+    #[inline]
+    fn sky900(&self) -> String {
+      self.append_value("sky900")
+    }
+  }
+  pub trait GeneratedColorSky950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(204 80.24691% 15.882354%);"></span> `hsl(204 80.24691% 15.882354%)`
+    #[inline]
+    fn sky950(&self) -> String {
+      self.append_value("sky950")
+    }
+  }
+  pub trait GeneratedColorBlue50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(213.75 100% 96.86275%);"></span> `hsl(213.75 100% 96.86275%)`
+    #[inline]
+    fn blue50(&self) -> String {
+      self.append_value("blue50")
+    }
+  }
+  pub trait GeneratedColorBlue100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(214.2857 94.594635% 92.7451%);"></span> `hsl(214.2857 94.594635% 92.7451%)`
+    #[inline]
+    fn blue100(&self) -> String {
+      self.append_value("blue100")
+    }
+  }
+  pub trait GeneratedColorBlue200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(213.33333 96.9231% 87.254906%);"></span> `hsl(213.33333 96.9231% 87.254906%)`
+    #[inline]
+    fn blue200(&self) -> String {
+      self.append_value("blue200")
+    }
+  }
+  pub trait GeneratedColorBlue300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(211.69812 96.36363% 78.43137%);"></span> `hsl(211.69812 96.36363% 78.43137%)`
+    #[inline]
+    fn blue300(&self) -> String {
+      self.append_value("blue300")
+    }
+  }
+  pub trait GeneratedColorBlue400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(213.11688 93.902435% 67.84314%);"></span> `hsl(213.11688 93.902435% 67.84314%)`
+    #[inline]
+    fn blue400(&self) -> String {
+      self.append_value("blue400")
+    }
+  }
+  pub trait GeneratedColorBlue500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(217.21925 91.21951% 59.80392%);"></span> `hsl(217.21925 91.21951% 59.80392%)`
+    #[inline]
+    fn blue500(&self) -> String {
+      self.append_value("blue500")
+    }
+  }
+  pub trait GeneratedColorBlue600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(221.21213 83.19328% 53.333336%);"></span> `hsl(221.21213 83.19328% 53.333336%)`
+    #[inline]
+    fn blue600(&self) -> String {
+      self.append_value("blue600")
+    }
+  }
+  pub trait GeneratedColorBlue700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(224.27808 76.32653% 48.039215%);"></span> `hsl(224.27808 76.32653% 48.039215%)`
+    #[inline]
+    fn blue700(&self) -> String {
+      self.append_value("blue700")
+    }
+  }
+  pub trait GeneratedColorBlue800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(225.93103 70.73171% 40.19608%);"></span> `hsl(225.93103 70.73171% 40.19608%)`
+    #[inline]
+    fn blue800(&self) -> String {
+      self.append_value("blue800")
+    }
+  }
+  pub trait GeneratedColorBlue900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(224.44444 64.28572% 32.941177%);"></span> `hsl(224.44444 64.28572% 32.941177%)`
+    #[inline]
+    fn blue900(&self) -> String {
+      self.append_value("blue900")
+    }
+  }
+  pub trait GeneratedColorBlue950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(226.2295 57.00935% 20.980392%);"></span> `hsl(226.2295 57.00935% 20.980392%)`
+    #[inline]
+    fn blue950(&self) -> String {
+      self.append_value("blue950")
+    }
+  }
+  pub trait GeneratedColorIndigo50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(225.88235 100% 96.66667%);"></span> `hsl(225.88235 100% 96.66667%)`
+    #[inline]
+    fn indigo50(&self) -> String {
+      self.append_value("indigo50")
+    }
+  }
+  pub trait GeneratedColorIndigo100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(226.45161 99.999954% 93.92157%);"></span> `hsl(226.45161 99.999954% 93.92157%)`
+    #[inline]
+    fn indigo100(&self) -> String {
+      self.append_value("indigo100")
+    }
+  }
+  pub trait GeneratedColorIndigo200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(228 96.49126% 88.82353%);"></span> `hsl(228 96.49126% 88.82353%)`
+    #[inline]
+    fn indigo200(&self) -> String {
+      self.append_value("indigo200")
+    }
+  }
+  pub trait GeneratedColorIndigo300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(229.65517 93.54841% 81.76471%);"></span> `hsl(229.65517 93.54841% 81.76471%)`
+    #[inline]
+    fn indigo300(&self) -> String {
+      self.append_value("indigo300")
+    }
+  }
+  pub trait GeneratedColorIndigo400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(234.45378 89.473694% 73.92157%);"></span> `hsl(234.45378 89.473694% 73.92157%)`
+    #[inline]
+    fn indigo400(&self) -> String {
+      self.append_value("indigo400")
+    }
+  }
+  pub trait GeneratedColorIndigo500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(238.73239 83.52941% 66.66667%);"></span> `hsl(238.73239 83.52941% 66.66667%)`
+    #[inline]
+    fn indigo500(&self) -> String {
+      self.append_value("indigo500")
+    }
+  }
+  pub trait GeneratedColorIndigo600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(243.39624 75.35545% 58.62745%);"></span> `hsl(243.39624 75.35545% 58.62745%)`
+    #[inline]
+    fn indigo600(&self) -> String {
+      self.append_value("indigo600")
+    }
+  }
+  pub trait GeneratedColorIndigo700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(244.52055 57.936512% 50.588238%);"></span> `hsl(244.52055 57.936512% 50.588238%)`
+    #[inline]
+    fn indigo700(&self) -> String {
+      self.append_value("indigo700")
+    }
+  }
+  pub trait GeneratedColorIndigo800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(243.65218 54.502373% 41.37255%);"></span> `hsl(243.65218 54.502373% 41.37255%)`
+    #[inline]
+    fn indigo800(&self) -> String {
+      self.append_value("indigo800")
+    }
+  }
+  pub trait GeneratedColorIndigo900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(242.16869 47.428574% 34.31373%);"></span> `hsl(242.16869 47.428574% 34.31373%)`
+    #[inline]
+    fn indigo900(&self) -> String {
+      self.append_value("indigo900")
+    }
+  }
+  pub trait GeneratedColorIndigo950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(243.75 47.058826% 20%);"></span> `hsl(243.75 47.058826% 20%)`
+    #[inline]
+    fn indigo950(&self) -> String {
+      self.append_value("indigo950")
+    }
+  }
+  pub trait GeneratedColorViolet50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(249.99998 100% 97.64706%);"></span> `hsl(249.99998 100% 97.64706%)`
+    #[inline]
+    fn violet50(&self) -> String {
+      self.append_value("violet50")
+    }
+  }
+  pub trait GeneratedColorViolet100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(251.42859 91.30429% 95.4902%);"></span> `hsl(251.42859 91.30429% 95.4902%)`
+    #[inline]
+    fn violet100(&self) -> String {
+      self.append_value("violet100")
+    }
+  }
+  pub trait GeneratedColorViolet200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(250.50002 95.2381% 91.76471%);"></span> `hsl(250.50002 95.2381% 91.76471%)`
+    #[inline]
+    fn violet200(&self) -> String {
+      self.append_value("violet200")
+    }
+  }
+  pub trait GeneratedColorViolet300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(252.50002 94.73685% 85.09804%);"></span> `hsl(252.50002 94.73685% 85.09804%)`
+    #[inline]
+    fn violet300(&self) -> String {
+      self.append_value("violet300")
+    }
+  }
+  pub trait GeneratedColorViolet400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(255.13513 91.73555% 76.27451%);"></span> `hsl(255.13513 91.73555% 76.27451%)`
+    #[inline]
+    fn violet400(&self) -> String {
+      self.append_value("violet400")
+    }
+  }
+  pub trait GeneratedColorViolet500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(258.3117 89.53488% 66.27451%);"></span> `hsl(258.3117 89.53488% 66.27451%)`
+    #[inline]
+    fn violet500(&self) -> String {
+      self.append_value("violet500")
+    }
+  }
+  pub trait GeneratedColorViolet600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(262.12292 83.25581% 57.843136%);"></span> `hsl(262.12292 83.25581% 57.843136%)`
+    #[inline]
+    fn violet600(&self) -> String {
+      self.append_value("violet600")
+    }
+  }
+  pub trait GeneratedColorViolet700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(263.38983 69.96047% 50.39216%);"></span> `hsl(263.38983 69.96047% 50.39216%)`
+    #[inline]
+    fn violet700(&self) -> String {
+      self.append_value("violet700")
+    }
+  }
+  pub trait GeneratedColorViolet800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(263.3557 69.30233% 42.156864%);"></span> `hsl(263.3557 69.30233% 42.156864%)`
+    #[inline]
+    fn violet800(&self) -> String {
+      self.append_value("violet800")
+    }
+  }
+  pub trait GeneratedColorViolet900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(263.5 67.41573% 34.901962%);"></span> `hsl(263.5 67.41573% 34.901962%)`
+    #[inline]
+    fn violet900(&self) -> String {
+      self.append_value("violet900")
+    }
+  }
+  pub trait GeneratedColorViolet950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(261.17645 72.649574% 22.941177%);"></span> `hsl(261.17645 72.649574% 22.941177%)`
+    #[inline]
+    fn violet950(&self) -> String {
+      self.append_value("violet950")
+    }
+  }
+  pub trait GeneratedColorPurple50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(270 100% 98.039215%);"></span> `hsl(270 100% 98.039215%)`
+    #[inline]
+    fn purple50(&self) -> String {
+      self.append_value("purple50")
+    }
+  }
+  pub trait GeneratedColorPurple100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(268.69565 99.99993% 95.4902%);"></span> `hsl(268.69565 99.99993% 95.4902%)`
+    #[inline]
+    fn purple100(&self) -> String {
+      self.append_value("purple100")
+    }
+  }
+  pub trait GeneratedColorPurple200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(268.57144 100% 91.76471%);"></span> `hsl(268.57144 100% 91.76471%)`
+    #[inline]
+    fn purple200(&self) -> String {
+      self.append_value("purple200")
+    }
+  }
+  pub trait GeneratedColorPurple300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(269.18918 97.368416% 85.09804%);"></span> `hsl(269.18918 97.368416% 85.09804%)`
+    #[inline]
+    fn purple300(&self) -> String {
+      self.append_value("purple300")
+    }
+  }
+  pub trait GeneratedColorPurple400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(270 95.2381% 75.29412%);"></span> `hsl(270 95.2381% 75.29412%)`
+    #[inline]
+    fn purple400(&self) -> String {
+      self.append_value("purple400")
+    }
+  }
+  pub trait GeneratedColorPurple500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(270.74075 91.01124% 65.09804%);"></span> `hsl(270.74075 91.01124% 65.09804%)`
+    #[inline]
+    fn purple500(&self) -> String {
+      self.append_value("purple500")
+    }
+  }
+  pub trait GeneratedColorPurple600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(271.4754 81.333336% 55.88235%);"></span> `hsl(271.4754 81.333336% 55.88235%)`
+    #[inline]
+    fn purple600(&self) -> String {
+      self.append_value("purple600")
+    }
+  }
+  pub trait GeneratedColorPurple700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(272.09302 71.66667% 47.058823%);"></span> `hsl(272.09302 71.66667% 47.058823%)`
+    #[inline]
+    fn purple700(&self) -> String {
+      self.append_value("purple700")
+    }
+  }
+  pub trait GeneratedColorPurple800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(272.8889 67.164185% 39.411766%);"></span> `hsl(272.8889 67.164185% 39.411766%)`
+    #[inline]
+    fn purple800(&self) -> String {
+      self.append_value("purple800")
+    }
+  }
+  pub trait GeneratedColorPurple900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(273.64487 65.64417% 31.960785%);"></span> `hsl(273.64487 65.64417% 31.960785%)`
+    #[inline]
+    fn purple900(&self) -> String {
+      self.append_value("purple900")
+    }
+  }
+  pub trait GeneratedColorPurple950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(273.54837 86.915886% 20.980392%);"></span> `hsl(273.54837 86.915886% 20.980392%)`
+    #[inline]
+    fn purple950(&self) -> String {
+      self.append_value("purple950")
+    }
+  }
+  pub trait GeneratedColorFuchsia50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(289.0909 99.99986% 97.84313%);"></span> `hsl(289.0909 99.99986% 97.84313%)`
+    #[inline]
+    fn fuchsia50(&self) -> String {
+      self.append_value("fuchsia50")
+    }
+  }
+  pub trait GeneratedColorFuchsia100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(286.9565 99.99993% 95.4902%);"></span> `hsl(286.9565 99.99993% 95.4902%)`
+    #[inline]
+    fn fuchsia100(&self) -> String {
+      self.append_value("fuchsia100")
+    }
+  }
+  pub trait GeneratedColorFuchsia200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(288.26086 95.83333% 90.588234%);"></span> `hsl(288.26086 95.83333% 90.588234%)`
+    #[inline]
+    fn fuchsia200(&self) -> String {
+      self.append_value("fuchsia200")
+    }
+  }
+  pub trait GeneratedColorFuchsia300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(291.1111 93.10343% 82.94118%);"></span> `hsl(291.1111 93.10343% 82.94118%)`
+    #[inline]
+    fn fuchsia300(&self) -> String {
+      self.append_value("fuchsia300")
+    }
+  }
+  pub trait GeneratedColorFuchsia400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(292.03125 91.42857% 72.54902%);"></span> `hsl(292.03125 91.42857% 72.54902%)`
+    #[inline]
+    fn fuchsia400(&self) -> String {
+      self.append_value("fuchsia400")
+    }
+  }
+  pub trait GeneratedColorFuchsia500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(292.18936 84.079605% 60.588234%);"></span> `hsl(292.18936 84.079605% 60.588234%)`
+    #[inline]
+    fn fuchsia500(&self) -> String {
+      self.append_value("fuchsia500")
+    }
+  }
+  pub trait GeneratedColorFuchsia600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(293.4104 69.47791% 48.82353%);"></span> `hsl(293.4104 69.47791% 48.82353%)`
+    #[inline]
+    fn fuchsia600(&self) -> String {
+      self.append_value("fuchsia600")
+    }
+  }
+  pub trait GeneratedColorFuchsia700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(294.69388 72.413795% 39.80392%);"></span> `hsl(294.69388 72.413795% 39.80392%)`
+    #[inline]
+    fn fuchsia700(&self) -> String {
+      self.append_value("fuchsia700")
+    }
+  }
+  pub trait GeneratedColorFuchsia800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(295.42374 70.2381% 32.941177%);"></span> `hsl(295.42374 70.2381% 32.941177%)`
+    #[inline]
+    fn fuchsia800(&self) -> String {
+      self.append_value("fuchsia800")
+    }
+  }
+  pub trait GeneratedColorFuchsia900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(296.7033 63.636364% 28.039217%);"></span> `hsl(296.7033 63.636364% 28.039217%)`
+    #[inline]
+    fn fuchsia900(&self) -> String {
+      self.append_value("fuchsia900")
+    }
+  }
+  pub trait GeneratedColorFuchsia950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(296.75674 90.243904% 16.078432%);"></span> `hsl(296.75674 90.243904% 16.078432%)`
+    #[inline]
+    fn fuchsia950(&self) -> String {
+      self.append_value("fuchsia950")
+    }
+  }
+  pub trait GeneratedColorPink50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(327.27274 73.33326% 97.05882%);"></span> `hsl(327.27274 73.33326% 97.05882%)`
+    #[inline]
+    fn pink50(&self) -> String {
+      self.append_value("pink50")
+    }
+  }
+  pub trait GeneratedColorPink100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(325.7143 77.77773% 94.70588%);"></span> `hsl(325.7143 77.77773% 94.70588%)`
+    #[inline]
+    fn pink100(&self) -> String {
+      self.append_value("pink100")
+    }
+  }
+  pub trait GeneratedColorPink200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(325.9091 84.61539% 89.803925%);"></span> `hsl(325.9091 84.61539% 89.803925%)`
+    #[inline]
+    fn pink200(&self) -> String {
+      self.append_value("pink200")
+    }
+  }
+  pub trait GeneratedColorPink300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(327.4074 87.09679% 81.76471%);"></span> `hsl(327.4074 87.09679% 81.76471%)`
+    #[inline]
+    fn pink300(&self) -> String {
+      self.append_value("pink300")
+    }
+  }
+  pub trait GeneratedColorPink400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(328.6154 85.52631% 70.19608%);"></span> `hsl(328.6154 85.52631% 70.19608%)`
+    #[inline]
+    fn pink400(&self) -> String {
+      self.append_value("pink400")
+    }
+  }
+  pub trait GeneratedColorPink500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(330.36584 81.18812% 60.39216%);"></span> `hsl(330.36584 81.18812% 60.39216%)`
+    #[inline]
+    fn pink500(&self) -> String {
+      self.append_value("pink500")
+    }
+  }
+  pub trait GeneratedColorPink600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(333.33334 71.42858% 50.588238%);"></span> `hsl(333.33334 71.42858% 50.588238%)`
+    #[inline]
+    fn pink600(&self) -> String {
+      self.append_value("pink600")
+    }
+  }
+  pub trait GeneratedColorPink700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(335.06024 77.57009% 41.960785%);"></span> `hsl(335.06024 77.57009% 41.960785%)`
+    #[inline]
+    fn pink700(&self) -> String {
+      self.append_value("pink700")
+    }
+  }
+  pub trait GeneratedColorPink800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(335.8209 74.44445% 35.294117%);"></span> `hsl(335.8209 74.44445% 35.294117%)`
+    #[inline]
+    fn pink800(&self) -> String {
+      self.append_value("pink800")
+    }
+  }
+  pub trait GeneratedColorPink900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(335.88785 69.032265% 30.392159%);"></span> `hsl(335.88785 69.032265% 30.392159%)`
+    #[inline]
+    fn pink900(&self) -> String {
+      self.append_value("pink900")
+    }
+  }
+  pub trait GeneratedColorPink950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(336.16437 83.90804% 17.058825%);"></span> `hsl(336.16437 83.90804% 17.058825%)`
+    #[inline]
+    fn pink950(&self) -> String {
+      self.append_value("pink950")
+    }
+  }
+  pub trait GeneratedColorRose50: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(355.7143 100% 97.254906%);"></span> `hsl(355.7143 100% 97.254906%)`
+    #[inline]
+    fn rose50(&self) -> String {
+      self.append_value("rose50")
+    }
+  }
+  pub trait GeneratedColorRose100: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(355.55554 99.99995% 94.70588%);"></span> `hsl(355.55554 99.99995% 94.70588%)`
+    #[inline]
+    fn rose100(&self) -> String {
+      self.append_value("rose100")
+    }
+  }
+  pub trait GeneratedColorRose200: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(352.65308 96.0784% 90%);"></span> `hsl(352.65308 96.0784% 90%)`
+    #[inline]
+    fn rose200(&self) -> String {
+      self.append_value("rose200")
+    }
+  }
+  pub trait GeneratedColorRose300: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(352.58426 95.698944% 81.76471%);"></span> `hsl(352.58426 95.698944% 81.76471%)`
+    #[inline]
+    fn rose300(&self) -> String {
+      self.append_value("rose300")
+    }
+  }
+  pub trait GeneratedColorRose400: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(351.30435 94.520546% 71.37255%);"></span> `hsl(351.30435 94.520546% 71.37255%)`
+    #[inline]
+    fn rose400(&self) -> String {
+      self.append_value("rose400")
+    }
+  }
+  pub trait GeneratedColorRose500: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(349.72375 89.16257% 60.19608%);"></span> `hsl(349.72375 89.16257% 60.19608%)`
+    #[inline]
+    fn rose500(&self) -> String {
+      self.append_value("rose500")
+    }
+  }
+  pub trait GeneratedColorRose600: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(346.83673 77.16535% 49.80392%);"></span> `hsl(346.83673 77.16535% 49.80392%)`
+    #[inline]
+    fn rose600(&self) -> String {
+      self.append_value("rose600")
+    }
+  }
+  pub trait GeneratedColorRose700: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(345.34885 82.69231% 40.784313%);"></span> `hsl(345.34885 82.69231% 40.784313%)`
+    #[inline]
+    fn rose700(&self) -> String {
+      self.append_value("rose700")
+    }
+  }
+  pub trait GeneratedColorRose800: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(343.40427 79.66102% 34.705883%);"></span> `hsl(343.40427 79.66102% 34.705883%)`
+    #[inline]
+    fn rose800(&self) -> String {
+      self.append_value("rose800")
+    }
+  }
+  pub trait GeneratedColorRose900: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(341.53845 75.48387% 30.392159%);"></span> `hsl(341.53845 75.48387% 30.392159%)`
+    #[inline]
+    fn rose900(&self) -> String {
+      self.append_value("rose900")
+    }
+  }
+  pub trait GeneratedColorRose950: GeneratedSkribbleValue {
+    /// <span style="display: inline-block; width: 1em; height: 1em; border: 1px solid #8888; border-radius: 2px; background-color: hsl(343.0986 87.65432% 15.882354%);"></span> `hsl(343.0986 87.65432% 15.882354%)`
+    #[inline]
+    fn rose950(&self) -> String {
+      self.append_value("rose950")
+    }
+  }
+  pub trait GeneratedColorInherit: GeneratedSkribbleValue {
+    #[inline]
+    fn inherit(&self) -> String {
+      self.append_value("inherit")
+    }
+  }
+  pub trait GeneratedColorTransparent: GeneratedSkribbleValue {
+    #[inline]
+    fn transparent(&self) -> String {
+      self.append_value("transparent")
+    }
+  }
+  pub trait GeneratedColorCurrent: GeneratedSkribbleValue {
+    #[inline]
+    fn current(&self) -> String {
+      self.append_value("current")
+    }
+  }
+}
+#[cfg(not(feature = "skribble-skip-palette"))]
+pub use palette::*;
+#[cfg(not(feature = "skribble-skip-value-sets"))]
+mod value_sets {
+  use super::*;
+  pub trait GeneratedValueSetScreenReaderOnly: GeneratedSkribbleValue {
+    /// The `only` value of the `screen-reader` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn only(&self) -> String {
+      self.append_value("only")
+    }
+  }
+  pub trait GeneratedValueSetScreenReaderExclude: GeneratedSkribbleValue {
+    /// The `exclude` value of the `screen-reader` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn exclude(&self) -> String {
+      self.append_value("exclude")
+    }
+  }
+  pub trait GeneratedValueSetTransitionMain: GeneratedSkribbleValue {
+    /// The `main` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn main(&self) -> String {
+      self.append_value("main")
+    }
+  }
+  pub trait GeneratedValueSetTransitionNone: GeneratedSkribbleValue {
+    /// The `none` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetTransitionAll: GeneratedSkribbleValue {
+    /// The `all` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn all(&self) -> String {
+      self.append_value("all")
+    }
+  }
+  pub trait GeneratedValueSetTransitionColors: GeneratedSkribbleValue {
+    /// The `colors` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn colors(&self) -> String {
+      self.append_value("colors")
+    }
+  }
+  pub trait GeneratedValueSetTransitionOpacity: GeneratedSkribbleValue {
+    /// The `opacity` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn opacity(&self) -> String {
+      self.append_value("opacity")
+    }
+  }
+  pub trait GeneratedValueSetTransitionShadow: GeneratedSkribbleValue {
+    /// The `shadow` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn shadow(&self) -> String {
+      self.append_value("shadow")
+    }
+  }
+  pub trait GeneratedValueSetTransitionTransform: GeneratedSkribbleValue {
+    /// The `transform` value of the `transition` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn transform(&self) -> String {
+      self.append_value("transform")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesMost: GeneratedSkribbleValue {
+    /// The `most` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn most(&self) -> String {
+      self.append_value("most")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesNone: GeneratedSkribbleValue {
+    /// The `none` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesAll: GeneratedSkribbleValue {
+    /// The `all` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn all(&self) -> String {
+      self.append_value("all")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesColors: GeneratedSkribbleValue {
+    /// The `colors` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn colors(&self) -> String {
+      self.append_value("colors")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesOpacity: GeneratedSkribbleValue {
+    /// The `opacity` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn opacity(&self) -> String {
+      self.append_value("opacity")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesShadow: GeneratedSkribbleValue {
+    /// The `shadow` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn shadow(&self) -> String {
+      self.append_value("shadow")
+    }
+  }
+  pub trait GeneratedValueSetTransitionPropertiesTransform: GeneratedSkribbleValue {
+    /// The `transform` value of the `transition-properties` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn transform(&self) -> String {
+      self.append_value("transform")
+    }
+  }
+  pub trait GeneratedValueSetDurationN0: GeneratedSkribbleValue {
+    /// The `0` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetDurationN75: GeneratedSkribbleValue {
+    /// The `75` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75")
+    }
+  }
+  pub trait GeneratedValueSetDurationN100: GeneratedSkribbleValue {
+    /// The `100` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetDurationN150: GeneratedSkribbleValue {
+    /// The `150` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n150(&self) -> String {
+      self.append_value("150")
+    }
+  }
+  pub trait GeneratedValueSetDurationN200: GeneratedSkribbleValue {
+    /// The `200` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n200(&self) -> String {
+      self.append_value("200")
+    }
+  }
+  pub trait GeneratedValueSetDurationN300: GeneratedSkribbleValue {
+    /// The `300` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n300(&self) -> String {
+      self.append_value("300")
+    }
+  }
+  pub trait GeneratedValueSetDurationN500: GeneratedSkribbleValue {
+    /// The `500` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n500(&self) -> String {
+      self.append_value("500")
+    }
+  }
+  pub trait GeneratedValueSetDurationN700: GeneratedSkribbleValue {
+    /// The `700` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n700(&self) -> String {
+      self.append_value("700")
+    }
+  }
+  pub trait GeneratedValueSetDurationN1000: GeneratedSkribbleValue {
+    /// The `1000` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1000(&self) -> String {
+      self.append_value("1000")
+    }
+  }
+  pub trait GeneratedValueSetDurationN1500: GeneratedSkribbleValue {
+    /// The `1500` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1500(&self) -> String {
+      self.append_value("1500")
+    }
+  }
+  pub trait GeneratedValueSetDurationN2000: GeneratedSkribbleValue {
+    /// The `2000` value of the `duration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2000(&self) -> String {
+      self.append_value("2000")
+    }
+  }
+  pub trait GeneratedValueSetEasingLinear: GeneratedSkribbleValue {
+    /// The `linear` value of the `easing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn linear(&self) -> String {
+      self.append_value("linear")
+    }
+  }
+  pub trait GeneratedValueSetEasingRIn: GeneratedSkribbleValue {
+    /// The `in` value of the `easing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn r#in(&self) -> String {
+      self.append_value("in")
+    }
+  }
+  pub trait GeneratedValueSetEasingOut: GeneratedSkribbleValue {
+    /// The `out` value of the `easing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn out(&self) -> String {
+      self.append_value("out")
+    }
+  }
+  pub trait GeneratedValueSetEasingInOut: GeneratedSkribbleValue {
+    /// The `inOut` value of the `easing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn in_out(&self) -> String {
+      self.append_value("inOut")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsN0: GeneratedSkribbleValue {
+    /// The `0` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsN05: GeneratedSkribbleValue {
+    /// The `0.5` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0_5(&self) -> String {
+      self.append_value("0.5")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsN1: GeneratedSkribbleValue {
+    /// The `1` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsN2: GeneratedSkribbleValue {
+    /// The `2` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsN3: GeneratedSkribbleValue {
+    /// The `3` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsInfinite: GeneratedSkribbleValue {
+    /// The `infinite` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn infinite(&self) -> String {
+      self.append_value("infinite")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsRIn: GeneratedSkribbleValue {
+    /// The `in` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn r#in(&self) -> String {
+      self.append_value("in")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsOut: GeneratedSkribbleValue {
+    /// The `out` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn out(&self) -> String {
+      self.append_value("out")
+    }
+  }
+  pub trait GeneratedValueSetAnimationRepetitionsInOut: GeneratedSkribbleValue {
+    /// The `inOut` value of the `animation-repetitions` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn in_out(&self) -> String {
+      self.append_value("inOut")
+    }
+  }
+  pub trait GeneratedValueSetAnimationDirectionNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `animation-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetAnimationDirectionReverse: GeneratedSkribbleValue {
+    /// The `reverse` value of the `animation-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn reverse(&self) -> String {
+      self.append_value("reverse")
+    }
+  }
+  pub trait GeneratedValueSetAnimationDirectionAlt: GeneratedSkribbleValue {
+    /// The `alt` value of the `animation-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn alt(&self) -> String {
+      self.append_value("alt")
+    }
+  }
+  pub trait GeneratedValueSetAnimationDirectionAltReverse: GeneratedSkribbleValue {
+    /// The `altReverse` value of the `animation-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn alt_reverse(&self) -> String {
+      self.append_value("altReverse")
+    }
+  }
+  pub trait GeneratedValueSetAnimationFillModeNone: GeneratedSkribbleValue {
+    /// The `none` value of the `animation-fill-mode` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetAnimationFillModeForwards: GeneratedSkribbleValue {
+    /// The `forwards` value of the `animation-fill-mode` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn forwards(&self) -> String {
+      self.append_value("forwards")
+    }
+  }
+  pub trait GeneratedValueSetAnimationFillModeBackwards: GeneratedSkribbleValue {
+    /// The `backwards` value of the `animation-fill-mode` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn backwards(&self) -> String {
+      self.append_value("backwards")
+    }
+  }
+  pub trait GeneratedValueSetAnimationFillModeBoth: GeneratedSkribbleValue {
+    /// The `both` value of the `animation-fill-mode` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn both(&self) -> String {
+      self.append_value("both")
+    }
+  }
+  pub trait GeneratedValueSetAnimationStateRunning: GeneratedSkribbleValue {
+    /// The `running` value of the `animation-state` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn running(&self) -> String {
+      self.append_value("running")
+    }
+  }
+  pub trait GeneratedValueSetAnimationStatePaused: GeneratedSkribbleValue {
+    /// The `paused` value of the `animation-state` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn paused(&self) -> String {
+      self.append_value("paused")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN0: GeneratedSkribbleValue {
+    /// The `0` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN5: GeneratedSkribbleValue {
+    /// The `5` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN10: GeneratedSkribbleValue {
+    /// The `10` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN20: GeneratedSkribbleValue {
+    /// The `20` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN30: GeneratedSkribbleValue {
+    /// The `30` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n30(&self) -> String {
+      self.append_value("30")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN40: GeneratedSkribbleValue {
+    /// The `40` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN50: GeneratedSkribbleValue {
+    /// The `50` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN60: GeneratedSkribbleValue {
+    /// The `60` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN70: GeneratedSkribbleValue {
+    /// The `70` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n70(&self) -> String {
+      self.append_value("70")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN75: GeneratedSkribbleValue {
+    /// The `75` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN80: GeneratedSkribbleValue {
+    /// The `80` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n80(&self) -> String {
+      self.append_value("80")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN90: GeneratedSkribbleValue {
+    /// The `90` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN95: GeneratedSkribbleValue {
+    /// The `95` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n95(&self) -> String {
+      self.append_value("95")
+    }
+  }
+  pub trait GeneratedValueSetOpacityN100: GeneratedSkribbleValue {
+    /// The `100` value of the `opacity` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN0: GeneratedSkribbleValue {
+    /// The `0` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN1: GeneratedSkribbleValue {
+    /// The `1` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN2: GeneratedSkribbleValue {
+    /// The `2` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN3: GeneratedSkribbleValue {
+    /// The `3` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN4: GeneratedSkribbleValue {
+    /// The `4` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN5: GeneratedSkribbleValue {
+    /// The `5` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN6: GeneratedSkribbleValue {
+    /// The `6` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN7: GeneratedSkribbleValue {
+    /// The `7` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN8: GeneratedSkribbleValue {
+    /// The `8` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN9: GeneratedSkribbleValue {
+    /// The `9` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN10: GeneratedSkribbleValue {
+    /// The `10` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN11: GeneratedSkribbleValue {
+    /// The `11` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN12: GeneratedSkribbleValue {
+    /// The `12` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN14: GeneratedSkribbleValue {
+    /// The `14` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n14(&self) -> String {
+      self.append_value("14")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN16: GeneratedSkribbleValue {
+    /// The `16` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n16(&self) -> String {
+      self.append_value("16")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN20: GeneratedSkribbleValue {
+    /// The `20` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN24: GeneratedSkribbleValue {
+    /// The `24` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n24(&self) -> String {
+      self.append_value("24")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN28: GeneratedSkribbleValue {
+    /// The `28` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n28(&self) -> String {
+      self.append_value("28")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN32: GeneratedSkribbleValue {
+    /// The `32` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n32(&self) -> String {
+      self.append_value("32")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN36: GeneratedSkribbleValue {
+    /// The `36` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n36(&self) -> String {
+      self.append_value("36")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN40: GeneratedSkribbleValue {
+    /// The `40` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN44: GeneratedSkribbleValue {
+    /// The `44` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n44(&self) -> String {
+      self.append_value("44")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN48: GeneratedSkribbleValue {
+    /// The `48` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n48(&self) -> String {
+      self.append_value("48")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN52: GeneratedSkribbleValue {
+    /// The `52` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n52(&self) -> String {
+      self.append_value("52")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN56: GeneratedSkribbleValue {
+    /// The `56` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n56(&self) -> String {
+      self.append_value("56")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN60: GeneratedSkribbleValue {
+    /// The `60` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN64: GeneratedSkribbleValue {
+    /// The `64` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n64(&self) -> String {
+      self.append_value("64")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN72: GeneratedSkribbleValue {
+    /// The `72` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n72(&self) -> String {
+      self.append_value("72")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN80: GeneratedSkribbleValue {
+    /// The `80` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n80(&self) -> String {
+      self.append_value("80")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN96: GeneratedSkribbleValue {
+    /// The `96` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n96(&self) -> String {
+      self.append_value("96")
+    }
+  }
+  pub trait GeneratedValueSetSpacingPx: GeneratedSkribbleValue {
+    /// The `px` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn px(&self) -> String {
+      self.append_value("px")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN05: GeneratedSkribbleValue {
+    /// The `0.5` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0_5(&self) -> String {
+      self.append_value("0.5")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN15: GeneratedSkribbleValue {
+    /// The `1.5` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_5(&self) -> String {
+      self.append_value("1.5")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN25: GeneratedSkribbleValue {
+    /// The `2.5` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_5(&self) -> String {
+      self.append_value("2.5")
+    }
+  }
+  pub trait GeneratedValueSetSpacingN35: GeneratedSkribbleValue {
+    /// The `3.5` value of the `spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_5(&self) -> String {
+      self.append_value("3.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM1: GeneratedSkribbleValue {
+    /// The `-1` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1(&self) -> String {
+      self.append_value("-1")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM2: GeneratedSkribbleValue {
+    /// The `-2` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m2(&self) -> String {
+      self.append_value("-2")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM3: GeneratedSkribbleValue {
+    /// The `-3` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m3(&self) -> String {
+      self.append_value("-3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM4: GeneratedSkribbleValue {
+    /// The `-4` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m4(&self) -> String {
+      self.append_value("-4")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM5: GeneratedSkribbleValue {
+    /// The `-5` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m5(&self) -> String {
+      self.append_value("-5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM6: GeneratedSkribbleValue {
+    /// The `-6` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m6(&self) -> String {
+      self.append_value("-6")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM7: GeneratedSkribbleValue {
+    /// The `-7` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m7(&self) -> String {
+      self.append_value("-7")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM8: GeneratedSkribbleValue {
+    /// The `-8` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m8(&self) -> String {
+      self.append_value("-8")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM9: GeneratedSkribbleValue {
+    /// The `-9` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m9(&self) -> String {
+      self.append_value("-9")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM10: GeneratedSkribbleValue {
+    /// The `-10` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m10(&self) -> String {
+      self.append_value("-10")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM11: GeneratedSkribbleValue {
+    /// The `-11` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m11(&self) -> String {
+      self.append_value("-11")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM12: GeneratedSkribbleValue {
+    /// The `-12` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m12(&self) -> String {
+      self.append_value("-12")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM14: GeneratedSkribbleValue {
+    /// The `-14` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m14(&self) -> String {
+      self.append_value("-14")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM16: GeneratedSkribbleValue {
+    /// The `-16` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m16(&self) -> String {
+      self.append_value("-16")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM20: GeneratedSkribbleValue {
+    /// The `-20` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m20(&self) -> String {
+      self.append_value("-20")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM24: GeneratedSkribbleValue {
+    /// The `-24` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m24(&self) -> String {
+      self.append_value("-24")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM28: GeneratedSkribbleValue {
+    /// The `-28` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m28(&self) -> String {
+      self.append_value("-28")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM32: GeneratedSkribbleValue {
+    /// The `-32` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m32(&self) -> String {
+      self.append_value("-32")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM36: GeneratedSkribbleValue {
+    /// The `-36` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m36(&self) -> String {
+      self.append_value("-36")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM40: GeneratedSkribbleValue {
+    /// The `-40` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m40(&self) -> String {
+      self.append_value("-40")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM44: GeneratedSkribbleValue {
+    /// The `-44` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m44(&self) -> String {
+      self.append_value("-44")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM48: GeneratedSkribbleValue {
+    /// The `-48` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m48(&self) -> String {
+      self.append_value("-48")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM52: GeneratedSkribbleValue {
+    /// The `-52` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m52(&self) -> String {
+      self.append_value("-52")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM56: GeneratedSkribbleValue {
+    /// The `-56` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m56(&self) -> String {
+      self.append_value("-56")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM60: GeneratedSkribbleValue {
+    /// The `-60` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m60(&self) -> String {
+      self.append_value("-60")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM64: GeneratedSkribbleValue {
+    /// The `-64` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m64(&self) -> String {
+      self.append_value("-64")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM72: GeneratedSkribbleValue {
+    /// The `-72` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m72(&self) -> String {
+      self.append_value("-72")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM80: GeneratedSkribbleValue {
+    /// The `-80` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m80(&self) -> String {
+      self.append_value("-80")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM96: GeneratedSkribbleValue {
+    /// The `-96` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m96(&self) -> String {
+      self.append_value("-96")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingMpx: GeneratedSkribbleValue {
+    /// The `-px` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn mpx(&self) -> String {
+      self.append_value("-px")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM05: GeneratedSkribbleValue {
+    /// The `-0.5` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m0_5(&self) -> String {
+      self.append_value("-0.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM15: GeneratedSkribbleValue {
+    /// The `-1.5` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1_5(&self) -> String {
+      self.append_value("-1.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM25: GeneratedSkribbleValue {
+    /// The `-2.5` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m2_5(&self) -> String {
+      self.append_value("-2.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeSpacingM35: GeneratedSkribbleValue {
+    /// The `-3.5` value of the `negative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m3_5(&self) -> String {
+      self.append_value("-3.5")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingHalf: GeneratedSkribbleValue {
+    /// The `half` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn half(&self) -> String {
+      self.append_value("half")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN1Of3: GeneratedSkribbleValue {
+    /// The `1-of-3` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_3(&self) -> String {
+      self.append_value("1-of-3")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN2Of3: GeneratedSkribbleValue {
+    /// The `2-of-3` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_3(&self) -> String {
+      self.append_value("2-of-3")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN1Of4: GeneratedSkribbleValue {
+    /// The `1-of-4` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_4(&self) -> String {
+      self.append_value("1-of-4")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN3Of4: GeneratedSkribbleValue {
+    /// The `3-of-4` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_4(&self) -> String {
+      self.append_value("3-of-4")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN1Of5: GeneratedSkribbleValue {
+    /// The `1-of-5` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_5(&self) -> String {
+      self.append_value("1-of-5")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN2Of5: GeneratedSkribbleValue {
+    /// The `2-of-5` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_5(&self) -> String {
+      self.append_value("2-of-5")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN3Of5: GeneratedSkribbleValue {
+    /// The `3-of-5` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_5(&self) -> String {
+      self.append_value("3-of-5")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN4Of5: GeneratedSkribbleValue {
+    /// The `4-of-5` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4_of_5(&self) -> String {
+      self.append_value("4-of-5")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN1Of6: GeneratedSkribbleValue {
+    /// The `1-of-6` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_6(&self) -> String {
+      self.append_value("1-of-6")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN5Of6: GeneratedSkribbleValue {
+    /// The `5-of-6` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5_of_6(&self) -> String {
+      self.append_value("5-of-6")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN1Of12: GeneratedSkribbleValue {
+    /// The `1-of-12` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_12(&self) -> String {
+      self.append_value("1-of-12")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN5Of12: GeneratedSkribbleValue {
+    /// The `5-of-12` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5_of_12(&self) -> String {
+      self.append_value("5-of-12")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN7Of12: GeneratedSkribbleValue {
+    /// The `7-of-12` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7_of_12(&self) -> String {
+      self.append_value("7-of-12")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingN11Of12: GeneratedSkribbleValue {
+    /// The `11-of-12` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11_of_12(&self) -> String {
+      self.append_value("11-of-12")
+    }
+  }
+  pub trait GeneratedValueSetRelativeSpacingFull: GeneratedSkribbleValue {
+    /// The `full` value of the `relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn full(&self) -> String {
+      self.append_value("full")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingMhalf: GeneratedSkribbleValue {
+    /// The `-half` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn mhalf(&self) -> String {
+      self.append_value("-half")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM1Of3: GeneratedSkribbleValue {
+    /// The `-1-of-3` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1_of_3(&self) -> String {
+      self.append_value("-1-of-3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM2Of3: GeneratedSkribbleValue {
+    /// The `-2-of-3` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m2_of_3(&self) -> String {
+      self.append_value("-2-of-3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM1Of4: GeneratedSkribbleValue {
+    /// The `-1-of-4` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1_of_4(&self) -> String {
+      self.append_value("-1-of-4")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM3Of4: GeneratedSkribbleValue {
+    /// The `-3-of-4` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m3_of_4(&self) -> String {
+      self.append_value("-3-of-4")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM1Of5: GeneratedSkribbleValue {
+    /// The `-1-of-5` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1_of_5(&self) -> String {
+      self.append_value("-1-of-5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM2Of5: GeneratedSkribbleValue {
+    /// The `-2-of-5` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m2_of_5(&self) -> String {
+      self.append_value("-2-of-5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM3Of5: GeneratedSkribbleValue {
+    /// The `-3-of-5` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m3_of_5(&self) -> String {
+      self.append_value("-3-of-5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM4Of5: GeneratedSkribbleValue {
+    /// The `-4-of-5` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m4_of_5(&self) -> String {
+      self.append_value("-4-of-5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM1Of6: GeneratedSkribbleValue {
+    /// The `-1-of-6` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1_of_6(&self) -> String {
+      self.append_value("-1-of-6")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM5Of6: GeneratedSkribbleValue {
+    /// The `-5-of-6` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m5_of_6(&self) -> String {
+      self.append_value("-5-of-6")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM1Of12: GeneratedSkribbleValue {
+    /// The `-1-of-12` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1_of_12(&self) -> String {
+      self.append_value("-1-of-12")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM5Of12: GeneratedSkribbleValue {
+    /// The `-5-of-12` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m5_of_12(&self) -> String {
+      self.append_value("-5-of-12")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM7Of12: GeneratedSkribbleValue {
+    /// The `-7-of-12` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m7_of_12(&self) -> String {
+      self.append_value("-7-of-12")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingM11Of12: GeneratedSkribbleValue {
+    /// The `-11-of-12` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m11_of_12(&self) -> String {
+      self.append_value("-11-of-12")
+    }
+  }
+  pub trait GeneratedValueSetNegativeRelativeSpacingMfull: GeneratedSkribbleValue {
+    /// The `-full` value of the `negative-relative-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn mfull(&self) -> String {
+      self.append_value("-full")
+    }
+  }
+  pub trait GeneratedValueSetContentFitMin: GeneratedSkribbleValue {
+    /// The `min` value of the `content-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn min(&self) -> String {
+      self.append_value("min")
+    }
+  }
+  pub trait GeneratedValueSetContentFitMax: GeneratedSkribbleValue {
+    /// The `max` value of the `content-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn max(&self) -> String {
+      self.append_value("max")
+    }
+  }
+  pub trait GeneratedValueSetContentFitFit: GeneratedSkribbleValue {
+    /// The `fit` value of the `content-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn fit(&self) -> String {
+      self.append_value("fit")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthScreen: GeneratedSkribbleValue {
+    /// The `screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen(&self) -> String {
+      self.append_value("screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthHalfScreen: GeneratedSkribbleValue {
+    /// The `half-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn half_screen(&self) -> String {
+      self.append_value("half-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN1Of3Screen: GeneratedSkribbleValue {
+    /// The `1-of-3-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_3_screen(&self) -> String {
+      self.append_value("1-of-3-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN2Of3Screen: GeneratedSkribbleValue {
+    /// The `2-of-3-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_3_screen(&self) -> String {
+      self.append_value("2-of-3-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN1Of4Screen: GeneratedSkribbleValue {
+    /// The `1-of-4-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_4_screen(&self) -> String {
+      self.append_value("1-of-4-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN3Of4Screen: GeneratedSkribbleValue {
+    /// The `3-of-4-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_4_screen(&self) -> String {
+      self.append_value("3-of-4-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN1Of5Screen: GeneratedSkribbleValue {
+    /// The `1-of-5-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_5_screen(&self) -> String {
+      self.append_value("1-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN2Of5Screen: GeneratedSkribbleValue {
+    /// The `2-of-5-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_5_screen(&self) -> String {
+      self.append_value("2-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN3Of5Screen: GeneratedSkribbleValue {
+    /// The `3-of-5-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_5_screen(&self) -> String {
+      self.append_value("3-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN4Of5Screen: GeneratedSkribbleValue {
+    /// The `4-of-5-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4_of_5_screen(&self) -> String {
+      self.append_value("4-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN1Of6Screen: GeneratedSkribbleValue {
+    /// The `1-of-6-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_6_screen(&self) -> String {
+      self.append_value("1-of-6-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN5Of6Screen: GeneratedSkribbleValue {
+    /// The `5-of-6-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5_of_6_screen(&self) -> String {
+      self.append_value("5-of-6-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN1Of12Screen: GeneratedSkribbleValue {
+    /// The `1-of-12-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_12_screen(&self) -> String {
+      self.append_value("1-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN5Of12Screen: GeneratedSkribbleValue {
+    /// The `5-of-12-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5_of_12_screen(&self) -> String {
+      self.append_value("5-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN7Of12Screen: GeneratedSkribbleValue {
+    /// The `7-of-12-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7_of_12_screen(&self) -> String {
+      self.append_value("7-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenWidthN11Of12Screen: GeneratedSkribbleValue {
+    /// The `11-of-12-screen` value of the `screen-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11_of_12_screen(&self) -> String {
+      self.append_value("11-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightScreen: GeneratedSkribbleValue {
+    /// The `screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen(&self) -> String {
+      self.append_value("screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightHalfScreen: GeneratedSkribbleValue {
+    /// The `half-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn half_screen(&self) -> String {
+      self.append_value("half-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN1Of3Screen: GeneratedSkribbleValue {
+    /// The `1-of-3-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_3_screen(&self) -> String {
+      self.append_value("1-of-3-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN2Of3Screen: GeneratedSkribbleValue {
+    /// The `2-of-3-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_3_screen(&self) -> String {
+      self.append_value("2-of-3-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN1Of4Screen: GeneratedSkribbleValue {
+    /// The `1-of-4-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_4_screen(&self) -> String {
+      self.append_value("1-of-4-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN3Of4Screen: GeneratedSkribbleValue {
+    /// The `3-of-4-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_4_screen(&self) -> String {
+      self.append_value("3-of-4-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN1Of5Screen: GeneratedSkribbleValue {
+    /// The `1-of-5-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_5_screen(&self) -> String {
+      self.append_value("1-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN2Of5Screen: GeneratedSkribbleValue {
+    /// The `2-of-5-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_5_screen(&self) -> String {
+      self.append_value("2-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN3Of5Screen: GeneratedSkribbleValue {
+    /// The `3-of-5-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_5_screen(&self) -> String {
+      self.append_value("3-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN4Of5Screen: GeneratedSkribbleValue {
+    /// The `4-of-5-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4_of_5_screen(&self) -> String {
+      self.append_value("4-of-5-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN1Of6Screen: GeneratedSkribbleValue {
+    /// The `1-of-6-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_6_screen(&self) -> String {
+      self.append_value("1-of-6-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN5Of6Screen: GeneratedSkribbleValue {
+    /// The `5-of-6-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5_of_6_screen(&self) -> String {
+      self.append_value("5-of-6-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN1Of12Screen: GeneratedSkribbleValue {
+    /// The `1-of-12-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_12_screen(&self) -> String {
+      self.append_value("1-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN5Of12Screen: GeneratedSkribbleValue {
+    /// The `5-of-12-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5_of_12_screen(&self) -> String {
+      self.append_value("5-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN7Of12Screen: GeneratedSkribbleValue {
+    /// The `7-of-12-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7_of_12_screen(&self) -> String {
+      self.append_value("7-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetScreenHeightN11Of12Screen: GeneratedSkribbleValue {
+    /// The `11-of-12-screen` value of the `screen-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11_of_12_screen(&self) -> String {
+      self.append_value("11-of-12-screen")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN0: GeneratedSkribbleValue {
+    /// The `0` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthNone: GeneratedSkribbleValue {
+    /// The `none` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthXs: GeneratedSkribbleValue {
+    /// The `xs` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xs(&self) -> String {
+      self.append_value("xs")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthMd: GeneratedSkribbleValue {
+    /// The `md` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn md(&self) -> String {
+      self.append_value("md")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN3xl: GeneratedSkribbleValue {
+    /// The `3xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3xl(&self) -> String {
+      self.append_value("3xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN4xl: GeneratedSkribbleValue {
+    /// The `4xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4xl(&self) -> String {
+      self.append_value("4xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN5xl: GeneratedSkribbleValue {
+    /// The `5xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5xl(&self) -> String {
+      self.append_value("5xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN6xl: GeneratedSkribbleValue {
+    /// The `6xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6xl(&self) -> String {
+      self.append_value("6xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthN7xl: GeneratedSkribbleValue {
+    /// The `7xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7xl(&self) -> String {
+      self.append_value("7xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthFull: GeneratedSkribbleValue {
+    /// The `full` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn full(&self) -> String {
+      self.append_value("full")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthMin: GeneratedSkribbleValue {
+    /// The `min` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn min(&self) -> String {
+      self.append_value("min")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthMax: GeneratedSkribbleValue {
+    /// The `max` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn max(&self) -> String {
+      self.append_value("max")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthFit: GeneratedSkribbleValue {
+    /// The `fit` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn fit(&self) -> String {
+      self.append_value("fit")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthProse: GeneratedSkribbleValue {
+    /// The `prose` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn prose(&self) -> String {
+      self.append_value("prose")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthScreenSm: GeneratedSkribbleValue {
+    /// The `screen-sm` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen_sm(&self) -> String {
+      self.append_value("screen-sm")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthScreenMd: GeneratedSkribbleValue {
+    /// The `screen-md` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen_md(&self) -> String {
+      self.append_value("screen-md")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthScreenLg: GeneratedSkribbleValue {
+    /// The `screen-lg` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen_lg(&self) -> String {
+      self.append_value("screen-lg")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthScreenXl: GeneratedSkribbleValue {
+    /// The `screen-xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen_xl(&self) -> String {
+      self.append_value("screen-xl")
+    }
+  }
+  pub trait GeneratedValueSetMaxWidthScreen2xl: GeneratedSkribbleValue {
+    /// The `screen-2xl` value of the `max-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen_2xl(&self) -> String {
+      self.append_value("screen-2xl")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN0: GeneratedSkribbleValue {
+    /// The `0` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN1: GeneratedSkribbleValue {
+    /// The `1` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN2: GeneratedSkribbleValue {
+    /// The `2` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN3: GeneratedSkribbleValue {
+    /// The `3` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN4: GeneratedSkribbleValue {
+    /// The `4` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN5: GeneratedSkribbleValue {
+    /// The `5` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN10: GeneratedSkribbleValue {
+    /// The `10` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN20: GeneratedSkribbleValue {
+    /// The `20` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN30: GeneratedSkribbleValue {
+    /// The `30` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n30(&self) -> String {
+      self.append_value("30")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN40: GeneratedSkribbleValue {
+    /// The `40` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN50: GeneratedSkribbleValue {
+    /// The `50` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetZIndexN60: GeneratedSkribbleValue {
+    /// The `60` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetZIndexAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM1: GeneratedSkribbleValue {
+    /// The `-1` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1(&self) -> String {
+      self.append_value("-1")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM2: GeneratedSkribbleValue {
+    /// The `-2` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m2(&self) -> String {
+      self.append_value("-2")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM3: GeneratedSkribbleValue {
+    /// The `-3` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m3(&self) -> String {
+      self.append_value("-3")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM5: GeneratedSkribbleValue {
+    /// The `-5` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m5(&self) -> String {
+      self.append_value("-5")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM10: GeneratedSkribbleValue {
+    /// The `-10` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m10(&self) -> String {
+      self.append_value("-10")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM20: GeneratedSkribbleValue {
+    /// The `-20` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m20(&self) -> String {
+      self.append_value("-20")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM30: GeneratedSkribbleValue {
+    /// The `-30` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m30(&self) -> String {
+      self.append_value("-30")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM40: GeneratedSkribbleValue {
+    /// The `-40` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m40(&self) -> String {
+      self.append_value("-40")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM50: GeneratedSkribbleValue {
+    /// The `-50` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m50(&self) -> String {
+      self.append_value("-50")
+    }
+  }
+  pub trait GeneratedValueSetZIndexM60: GeneratedSkribbleValue {
+    /// The `-60` value of the `z-index` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m60(&self) -> String {
+      self.append_value("-60")
+    }
+  }
+  pub trait GeneratedValueSetDirectionLtr: GeneratedSkribbleValue {
+    /// The `ltr` value of the `direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn ltr(&self) -> String {
+      self.append_value("ltr")
+    }
+  }
+  pub trait GeneratedValueSetDirectionRtl: GeneratedSkribbleValue {
+    /// The `rtl` value of the `direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn rtl(&self) -> String {
+      self.append_value("rtl")
+    }
+  }
+  pub trait GeneratedValueSetZoomN0: GeneratedSkribbleValue {
+    /// The `0` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetZoomN50: GeneratedSkribbleValue {
+    /// The `50` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetZoomN75: GeneratedSkribbleValue {
+    /// The `75` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75")
+    }
+  }
+  pub trait GeneratedValueSetZoomN90: GeneratedSkribbleValue {
+    /// The `90` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90")
+    }
+  }
+  pub trait GeneratedValueSetZoomN95: GeneratedSkribbleValue {
+    /// The `95` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n95(&self) -> String {
+      self.append_value("95")
+    }
+  }
+  pub trait GeneratedValueSetZoomN100: GeneratedSkribbleValue {
+    /// The `100` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetZoomN105: GeneratedSkribbleValue {
+    /// The `105` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n105(&self) -> String {
+      self.append_value("105")
+    }
+  }
+  pub trait GeneratedValueSetZoomN110: GeneratedSkribbleValue {
+    /// The `110` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n110(&self) -> String {
+      self.append_value("110")
+    }
+  }
+  pub trait GeneratedValueSetZoomN125: GeneratedSkribbleValue {
+    /// The `125` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n125(&self) -> String {
+      self.append_value("125")
+    }
+  }
+  pub trait GeneratedValueSetZoomN150: GeneratedSkribbleValue {
+    /// The `150` value of the `zoom` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n150(&self) -> String {
+      self.append_value("150")
+    }
+  }
+  pub trait GeneratedValueSetRotationN0: GeneratedSkribbleValue {
+    /// The `0` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetRotationN1: GeneratedSkribbleValue {
+    /// The `1` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetRotationN2: GeneratedSkribbleValue {
+    /// The `2` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetRotationN3: GeneratedSkribbleValue {
+    /// The `3` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetRotationN6: GeneratedSkribbleValue {
+    /// The `6` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetRotationN12: GeneratedSkribbleValue {
+    /// The `12` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetRotationN30: GeneratedSkribbleValue {
+    /// The `30` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n30(&self) -> String {
+      self.append_value("30")
+    }
+  }
+  pub trait GeneratedValueSetRotationN45: GeneratedSkribbleValue {
+    /// The `45` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n45(&self) -> String {
+      self.append_value("45")
+    }
+  }
+  pub trait GeneratedValueSetRotationN90: GeneratedSkribbleValue {
+    /// The `90` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90")
+    }
+  }
+  pub trait GeneratedValueSetRotationN180: GeneratedSkribbleValue {
+    /// The `180` value of the `rotation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n180(&self) -> String {
+      self.append_value("180")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationFull: GeneratedSkribbleValue {
+    /// The `full` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn full(&self) -> String {
+      self.append_value("full")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN0: GeneratedSkribbleValue {
+    /// The `0` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationPx: GeneratedSkribbleValue {
+    /// The `px` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn px(&self) -> String {
+      self.append_value("px")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN05: GeneratedSkribbleValue {
+    /// The `0.5` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0_5(&self) -> String {
+      self.append_value("0.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN1: GeneratedSkribbleValue {
+    /// The `1` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN15: GeneratedSkribbleValue {
+    /// The `1.5` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_5(&self) -> String {
+      self.append_value("1.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN2: GeneratedSkribbleValue {
+    /// The `2` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN25: GeneratedSkribbleValue {
+    /// The `2.5` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_5(&self) -> String {
+      self.append_value("2.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN3: GeneratedSkribbleValue {
+    /// The `3` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN35: GeneratedSkribbleValue {
+    /// The `3.5` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_5(&self) -> String {
+      self.append_value("3.5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN4: GeneratedSkribbleValue {
+    /// The `4` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN5: GeneratedSkribbleValue {
+    /// The `5` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN6: GeneratedSkribbleValue {
+    /// The `6` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN7: GeneratedSkribbleValue {
+    /// The `7` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN8: GeneratedSkribbleValue {
+    /// The `8` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN9: GeneratedSkribbleValue {
+    /// The `9` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN10: GeneratedSkribbleValue {
+    /// The `10` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN11: GeneratedSkribbleValue {
+    /// The `11` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN12: GeneratedSkribbleValue {
+    /// The `12` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN14: GeneratedSkribbleValue {
+    /// The `14` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n14(&self) -> String {
+      self.append_value("14")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN16: GeneratedSkribbleValue {
+    /// The `16` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n16(&self) -> String {
+      self.append_value("16")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN20: GeneratedSkribbleValue {
+    /// The `20` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN24: GeneratedSkribbleValue {
+    /// The `24` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n24(&self) -> String {
+      self.append_value("24")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN28: GeneratedSkribbleValue {
+    /// The `28` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n28(&self) -> String {
+      self.append_value("28")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN32: GeneratedSkribbleValue {
+    /// The `32` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n32(&self) -> String {
+      self.append_value("32")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN36: GeneratedSkribbleValue {
+    /// The `36` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n36(&self) -> String {
+      self.append_value("36")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN40: GeneratedSkribbleValue {
+    /// The `40` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN44: GeneratedSkribbleValue {
+    /// The `44` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n44(&self) -> String {
+      self.append_value("44")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN48: GeneratedSkribbleValue {
+    /// The `48` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n48(&self) -> String {
+      self.append_value("48")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN52: GeneratedSkribbleValue {
+    /// The `52` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n52(&self) -> String {
+      self.append_value("52")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN56: GeneratedSkribbleValue {
+    /// The `56` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n56(&self) -> String {
+      self.append_value("56")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN60: GeneratedSkribbleValue {
+    /// The `60` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN64: GeneratedSkribbleValue {
+    /// The `64` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n64(&self) -> String {
+      self.append_value("64")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN72: GeneratedSkribbleValue {
+    /// The `72` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n72(&self) -> String {
+      self.append_value("72")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN80: GeneratedSkribbleValue {
+    /// The `80` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n80(&self) -> String {
+      self.append_value("80")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN96: GeneratedSkribbleValue {
+    /// The `96` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n96(&self) -> String {
+      self.append_value("96")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN1Of3: GeneratedSkribbleValue {
+    /// The `1-of-3` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_3(&self) -> String {
+      self.append_value("1-of-3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN2Of3: GeneratedSkribbleValue {
+    /// The `2-of-3` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_3(&self) -> String {
+      self.append_value("2-of-3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN1Of4: GeneratedSkribbleValue {
+    /// The `1-of-4` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_4(&self) -> String {
+      self.append_value("1-of-4")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationHalf: GeneratedSkribbleValue {
+    /// The `half` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn half(&self) -> String {
+      self.append_value("half")
+    }
+  }
+  pub trait GeneratedValueSetNegativeTranslationN3Of4: GeneratedSkribbleValue {
+    /// The `3-of-4` value of the `negative-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_4(&self) -> String {
+      self.append_value("3-of-4")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationFull: GeneratedSkribbleValue {
+    /// The `full` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn full(&self) -> String {
+      self.append_value("full")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN0: GeneratedSkribbleValue {
+    /// The `0` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationPx: GeneratedSkribbleValue {
+    /// The `px` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn px(&self) -> String {
+      self.append_value("px")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN05: GeneratedSkribbleValue {
+    /// The `0.5` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0_5(&self) -> String {
+      self.append_value("0.5")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN1: GeneratedSkribbleValue {
+    /// The `1` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN15: GeneratedSkribbleValue {
+    /// The `1.5` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_5(&self) -> String {
+      self.append_value("1.5")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN2: GeneratedSkribbleValue {
+    /// The `2` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN25: GeneratedSkribbleValue {
+    /// The `2.5` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_5(&self) -> String {
+      self.append_value("2.5")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN3: GeneratedSkribbleValue {
+    /// The `3` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN35: GeneratedSkribbleValue {
+    /// The `3.5` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_5(&self) -> String {
+      self.append_value("3.5")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN4: GeneratedSkribbleValue {
+    /// The `4` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN5: GeneratedSkribbleValue {
+    /// The `5` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN6: GeneratedSkribbleValue {
+    /// The `6` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN7: GeneratedSkribbleValue {
+    /// The `7` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN8: GeneratedSkribbleValue {
+    /// The `8` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN9: GeneratedSkribbleValue {
+    /// The `9` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN10: GeneratedSkribbleValue {
+    /// The `10` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN11: GeneratedSkribbleValue {
+    /// The `11` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN12: GeneratedSkribbleValue {
+    /// The `12` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN14: GeneratedSkribbleValue {
+    /// The `14` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n14(&self) -> String {
+      self.append_value("14")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN16: GeneratedSkribbleValue {
+    /// The `16` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n16(&self) -> String {
+      self.append_value("16")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN20: GeneratedSkribbleValue {
+    /// The `20` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN24: GeneratedSkribbleValue {
+    /// The `24` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n24(&self) -> String {
+      self.append_value("24")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN28: GeneratedSkribbleValue {
+    /// The `28` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n28(&self) -> String {
+      self.append_value("28")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN32: GeneratedSkribbleValue {
+    /// The `32` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n32(&self) -> String {
+      self.append_value("32")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN36: GeneratedSkribbleValue {
+    /// The `36` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n36(&self) -> String {
+      self.append_value("36")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN40: GeneratedSkribbleValue {
+    /// The `40` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN44: GeneratedSkribbleValue {
+    /// The `44` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n44(&self) -> String {
+      self.append_value("44")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN48: GeneratedSkribbleValue {
+    /// The `48` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n48(&self) -> String {
+      self.append_value("48")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN52: GeneratedSkribbleValue {
+    /// The `52` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n52(&self) -> String {
+      self.append_value("52")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN56: GeneratedSkribbleValue {
+    /// The `56` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n56(&self) -> String {
+      self.append_value("56")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN60: GeneratedSkribbleValue {
+    /// The `60` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN64: GeneratedSkribbleValue {
+    /// The `64` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n64(&self) -> String {
+      self.append_value("64")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN72: GeneratedSkribbleValue {
+    /// The `72` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n72(&self) -> String {
+      self.append_value("72")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN80: GeneratedSkribbleValue {
+    /// The `80` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n80(&self) -> String {
+      self.append_value("80")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN96: GeneratedSkribbleValue {
+    /// The `96` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n96(&self) -> String {
+      self.append_value("96")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN1Of3: GeneratedSkribbleValue {
+    /// The `1-of-3` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_3(&self) -> String {
+      self.append_value("1-of-3")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN2Of3: GeneratedSkribbleValue {
+    /// The `2-of-3` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_of_3(&self) -> String {
+      self.append_value("2-of-3")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN1Of4: GeneratedSkribbleValue {
+    /// The `1-of-4` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_of_4(&self) -> String {
+      self.append_value("1-of-4")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationHalf: GeneratedSkribbleValue {
+    /// The `half` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn half(&self) -> String {
+      self.append_value("half")
+    }
+  }
+  pub trait GeneratedValueSetPositiveTranslationN3Of4: GeneratedSkribbleValue {
+    /// The `3-of-4` value of the `positive-translation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_of_4(&self) -> String {
+      self.append_value("3-of-4")
+    }
+  }
+  pub trait GeneratedValueSetFontFamilySans: GeneratedSkribbleValue {
+    /// The `sans` value of the `font-family` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sans(&self) -> String {
+      self.append_value("sans")
+    }
+  }
+  pub trait GeneratedValueSetFontFamilySerif: GeneratedSkribbleValue {
+    /// The `serif` value of the `font-family` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn serif(&self) -> String {
+      self.append_value("serif")
+    }
+  }
+  pub trait GeneratedValueSetFontFamilyMono: GeneratedSkribbleValue {
+    /// The `mono` value of the `font-family` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn mono(&self) -> String {
+      self.append_value("mono")
+    }
+  }
+  pub trait GeneratedValueSetRatioAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `ratio` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetRatioSquare: GeneratedSkribbleValue {
+    /// The `square` value of the `ratio` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn square(&self) -> String {
+      self.append_value("square")
+    }
+  }
+  pub trait GeneratedValueSetRatioVideo: GeneratedSkribbleValue {
+    /// The `video` value of the `ratio` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn video(&self) -> String {
+      self.append_value("video")
+    }
+  }
+  pub trait GeneratedValueSetRatioPortrait: GeneratedSkribbleValue {
+    /// The `portrait` value of the `ratio` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn portrait(&self) -> String {
+      self.append_value("portrait")
+    }
+  }
+  pub trait GeneratedValueSetRatioRBox: GeneratedSkribbleValue {
+    /// The `box` value of the `ratio` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn r#box(&self) -> String {
+      self.append_value("box")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN1: GeneratedSkribbleValue {
+    /// The `1` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN2: GeneratedSkribbleValue {
+    /// The `2` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN3: GeneratedSkribbleValue {
+    /// The `3` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN4: GeneratedSkribbleValue {
+    /// The `4` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN5: GeneratedSkribbleValue {
+    /// The `5` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN6: GeneratedSkribbleValue {
+    /// The `6` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN7: GeneratedSkribbleValue {
+    /// The `7` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN8: GeneratedSkribbleValue {
+    /// The `8` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN9: GeneratedSkribbleValue {
+    /// The `9` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN10: GeneratedSkribbleValue {
+    /// The `10` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN11: GeneratedSkribbleValue {
+    /// The `11` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetGridCountN12: GeneratedSkribbleValue {
+    /// The `12` value of the `grid-count` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN3xs: GeneratedSkribbleValue {
+    /// The `3xs` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3xs(&self) -> String {
+      self.append_value("3xs")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN2xs: GeneratedSkribbleValue {
+    /// The `2xs` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xs(&self) -> String {
+      self.append_value("2xs")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeXs: GeneratedSkribbleValue {
+    /// The `xs` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xs(&self) -> String {
+      self.append_value("xs")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeMd: GeneratedSkribbleValue {
+    /// The `md` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn md(&self) -> String {
+      self.append_value("md")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN3xl: GeneratedSkribbleValue {
+    /// The `3xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3xl(&self) -> String {
+      self.append_value("3xl")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN4xl: GeneratedSkribbleValue {
+    /// The `4xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4xl(&self) -> String {
+      self.append_value("4xl")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN5xl: GeneratedSkribbleValue {
+    /// The `5xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5xl(&self) -> String {
+      self.append_value("5xl")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN6xl: GeneratedSkribbleValue {
+    /// The `6xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6xl(&self) -> String {
+      self.append_value("6xl")
+    }
+  }
+  pub trait GeneratedValueSetGridSizeN7xl: GeneratedSkribbleValue {
+    /// The `7xl` value of the `grid-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7xl(&self) -> String {
+      self.append_value("7xl")
+    }
+  }
+  pub trait GeneratedValueSetBreakAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetBreakAvoid: GeneratedSkribbleValue {
+    /// The `avoid` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn avoid(&self) -> String {
+      self.append_value("avoid")
+    }
+  }
+  pub trait GeneratedValueSetBreakAll: GeneratedSkribbleValue {
+    /// The `all` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn all(&self) -> String {
+      self.append_value("all")
+    }
+  }
+  pub trait GeneratedValueSetBreakAvoidPage: GeneratedSkribbleValue {
+    /// The `avoid-page` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn avoid_page(&self) -> String {
+      self.append_value("avoid-page")
+    }
+  }
+  pub trait GeneratedValueSetBreakPage: GeneratedSkribbleValue {
+    /// The `page` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn page(&self) -> String {
+      self.append_value("page")
+    }
+  }
+  pub trait GeneratedValueSetBreakLeft: GeneratedSkribbleValue {
+    /// The `left` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left(&self) -> String {
+      self.append_value("left")
+    }
+  }
+  pub trait GeneratedValueSetBreakRight: GeneratedSkribbleValue {
+    /// The `right` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right(&self) -> String {
+      self.append_value("right")
+    }
+  }
+  pub trait GeneratedValueSetBreakColumn: GeneratedSkribbleValue {
+    /// The `column` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn column(&self) -> String {
+      self.append_value("column")
+    }
+  }
+  pub trait GeneratedValueSetBreakNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetBreakWords: GeneratedSkribbleValue {
+    /// The `words` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn words(&self) -> String {
+      self.append_value("words")
+    }
+  }
+  pub trait GeneratedValueSetBreakKeep: GeneratedSkribbleValue {
+    /// The `keep` value of the `break` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn keep(&self) -> String {
+      self.append_value("keep")
+    }
+  }
+  pub trait GeneratedValueSetBreakInsideAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `break-inside` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetBreakInsideAvoid: GeneratedSkribbleValue {
+    /// The `avoid` value of the `break-inside` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn avoid(&self) -> String {
+      self.append_value("avoid")
+    }
+  }
+  pub trait GeneratedValueSetBreakInsideAvoidPage: GeneratedSkribbleValue {
+    /// The `avoid-page` value of the `break-inside` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn avoid_page(&self) -> String {
+      self.append_value("avoid-page")
+    }
+  }
+  pub trait GeneratedValueSetBreakInsideAvoidColumn: GeneratedSkribbleValue {
+    /// The `avoid-column` value of the `break-inside` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn avoid_column(&self) -> String {
+      self.append_value("avoid-column")
+    }
+  }
+  pub trait GeneratedValueSetBoxDecorationClone: GeneratedSkribbleValue {
+    /// The `clone` value of the `box-decoration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn clone(&self) -> String {
+      self.append_value("clone")
+    }
+  }
+  pub trait GeneratedValueSetBoxDecorationSlice: GeneratedSkribbleValue {
+    /// The `slice` value of the `box-decoration` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn slice(&self) -> String {
+      self.append_value("slice")
+    }
+  }
+  pub trait GeneratedValueSetBoxBorder: GeneratedSkribbleValue {
+    /// The `border` value of the `box` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn border(&self) -> String {
+      self.append_value("border")
+    }
+  }
+  pub trait GeneratedValueSetBoxContent: GeneratedSkribbleValue {
+    /// The `content` value of the `box` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn content(&self) -> String {
+      self.append_value("content")
+    }
+  }
+  pub trait GeneratedValueSetDisplayBlock: GeneratedSkribbleValue {
+    /// The `block` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn block(&self) -> String {
+      self.append_value("block")
+    }
+  }
+  pub trait GeneratedValueSetDisplayInlineBlock: GeneratedSkribbleValue {
+    /// The `inline-block` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inline_block(&self) -> String {
+      self.append_value("inline-block")
+    }
+  }
+  pub trait GeneratedValueSetDisplayInline: GeneratedSkribbleValue {
+    /// The `inline` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inline(&self) -> String {
+      self.append_value("inline")
+    }
+  }
+  pub trait GeneratedValueSetDisplayFlex: GeneratedSkribbleValue {
+    /// The `flex` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn flex(&self) -> String {
+      self.append_value("flex")
+    }
+  }
+  pub trait GeneratedValueSetDisplayInlineFlex: GeneratedSkribbleValue {
+    /// The `inline-flex` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inline_flex(&self) -> String {
+      self.append_value("inline-flex")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTable: GeneratedSkribbleValue {
+    /// The `table` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table(&self) -> String {
+      self.append_value("table")
+    }
+  }
+  pub trait GeneratedValueSetDisplayInlineTable: GeneratedSkribbleValue {
+    /// The `inline-table` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inline_table(&self) -> String {
+      self.append_value("inline-table")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableCaption: GeneratedSkribbleValue {
+    /// The `table-caption` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_caption(&self) -> String {
+      self.append_value("table-caption")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableCell: GeneratedSkribbleValue {
+    /// The `table-cell` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_cell(&self) -> String {
+      self.append_value("table-cell")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableColumn: GeneratedSkribbleValue {
+    /// The `table-column` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_column(&self) -> String {
+      self.append_value("table-column")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableColumnGroup: GeneratedSkribbleValue {
+    /// The `table-column-group` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_column_group(&self) -> String {
+      self.append_value("table-column-group")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableFooterGroup: GeneratedSkribbleValue {
+    /// The `table-footer-group` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_footer_group(&self) -> String {
+      self.append_value("table-footer-group")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableHeaderGroup: GeneratedSkribbleValue {
+    /// The `table-header-group` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_header_group(&self) -> String {
+      self.append_value("table-header-group")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableRowGroup: GeneratedSkribbleValue {
+    /// The `table-row-group` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_row_group(&self) -> String {
+      self.append_value("table-row-group")
+    }
+  }
+  pub trait GeneratedValueSetDisplayTableRow: GeneratedSkribbleValue {
+    /// The `table-row` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn table_row(&self) -> String {
+      self.append_value("table-row")
+    }
+  }
+  pub trait GeneratedValueSetDisplayFlowRoot: GeneratedSkribbleValue {
+    /// The `flow-root` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn flow_root(&self) -> String {
+      self.append_value("flow-root")
+    }
+  }
+  pub trait GeneratedValueSetDisplayGrid: GeneratedSkribbleValue {
+    /// The `grid` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grid(&self) -> String {
+      self.append_value("grid")
+    }
+  }
+  pub trait GeneratedValueSetDisplayInlineGrid: GeneratedSkribbleValue {
+    /// The `inline-grid` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inline_grid(&self) -> String {
+      self.append_value("inline-grid")
+    }
+  }
+  pub trait GeneratedValueSetDisplayContents: GeneratedSkribbleValue {
+    /// The `contents` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn contents(&self) -> String {
+      self.append_value("contents")
+    }
+  }
+  pub trait GeneratedValueSetDisplayListItem: GeneratedSkribbleValue {
+    /// The `list-item` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn list_item(&self) -> String {
+      self.append_value("list-item")
+    }
+  }
+  pub trait GeneratedValueSetDisplayHidden: GeneratedSkribbleValue {
+    /// The `hidden` value of the `display` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn hidden(&self) -> String {
+      self.append_value("hidden")
+    }
+  }
+  pub trait GeneratedValueSetVisibilityVisible: GeneratedSkribbleValue {
+    /// The `visible` value of the `visibility` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn visible(&self) -> String {
+      self.append_value("visible")
+    }
+  }
+  pub trait GeneratedValueSetVisibilityInvisible: GeneratedSkribbleValue {
+    /// The `invisible` value of the `visibility` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn invisible(&self) -> String {
+      self.append_value("invisible")
+    }
+  }
+  pub trait GeneratedValueSetVisibilityCollapse: GeneratedSkribbleValue {
+    /// The `collapse` value of the `visibility` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn collapse(&self) -> String {
+      self.append_value("collapse")
+    }
+  }
+  pub trait GeneratedValueSetFloatRight: GeneratedSkribbleValue {
+    /// The `right` value of the `float` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right(&self) -> String {
+      self.append_value("right")
+    }
+  }
+  pub trait GeneratedValueSetFloatLeft: GeneratedSkribbleValue {
+    /// The `left` value of the `float` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left(&self) -> String {
+      self.append_value("left")
+    }
+  }
+  pub trait GeneratedValueSetFloatNone: GeneratedSkribbleValue {
+    /// The `none` value of the `float` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetClearBoth: GeneratedSkribbleValue {
+    /// The `both` value of the `clear` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn both(&self) -> String {
+      self.append_value("both")
+    }
+  }
+  pub trait GeneratedValueSetIsolationAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `isolation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetIsolationIsolate: GeneratedSkribbleValue {
+    /// The `isolate` value of the `isolation` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn isolate(&self) -> String {
+      self.append_value("isolate")
+    }
+  }
+  pub trait GeneratedValueSetObjectFitContain: GeneratedSkribbleValue {
+    /// The `contain` value of the `object-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn contain(&self) -> String {
+      self.append_value("contain")
+    }
+  }
+  pub trait GeneratedValueSetObjectFitCover: GeneratedSkribbleValue {
+    /// The `cover` value of the `object-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn cover(&self) -> String {
+      self.append_value("cover")
+    }
+  }
+  pub trait GeneratedValueSetObjectFitFill: GeneratedSkribbleValue {
+    /// The `fill` value of the `object-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn fill(&self) -> String {
+      self.append_value("fill")
+    }
+  }
+  pub trait GeneratedValueSetObjectFitNone: GeneratedSkribbleValue {
+    /// The `none` value of the `object-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetObjectFitScaleDown: GeneratedSkribbleValue {
+    /// The `scale-down` value of the `object-fit` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn scale_down(&self) -> String {
+      self.append_value("scale-down")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionBottom: GeneratedSkribbleValue {
+    /// The `bottom` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bottom(&self) -> String {
+      self.append_value("bottom")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionLeft: GeneratedSkribbleValue {
+    /// The `left` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left(&self) -> String {
+      self.append_value("left")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionLeftBottom: GeneratedSkribbleValue {
+    /// The `left bottom` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left_bottom(&self) -> String {
+      self.append_value("left bottom")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionLeftTop: GeneratedSkribbleValue {
+    /// The `left top` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left_top(&self) -> String {
+      self.append_value("left top")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionRight: GeneratedSkribbleValue {
+    /// The `right` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right(&self) -> String {
+      self.append_value("right")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionRightBottom: GeneratedSkribbleValue {
+    /// The `right bottom` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right_bottom(&self) -> String {
+      self.append_value("right bottom")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionRightTop: GeneratedSkribbleValue {
+    /// The `right top` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right_top(&self) -> String {
+      self.append_value("right top")
+    }
+  }
+  pub trait GeneratedValueSetObjectPositionTop: GeneratedSkribbleValue {
+    /// The `top` value of the `object-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn top(&self) -> String {
+      self.append_value("top")
+    }
+  }
+  pub trait GeneratedValueSetOverflowAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetOverflowHidden: GeneratedSkribbleValue {
+    /// The `hidden` value of the `overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn hidden(&self) -> String {
+      self.append_value("hidden")
+    }
+  }
+  pub trait GeneratedValueSetOverflowClip: GeneratedSkribbleValue {
+    /// The `clip` value of the `overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn clip(&self) -> String {
+      self.append_value("clip")
+    }
+  }
+  pub trait GeneratedValueSetOverflowVisible: GeneratedSkribbleValue {
+    /// The `visible` value of the `overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn visible(&self) -> String {
+      self.append_value("visible")
+    }
+  }
+  pub trait GeneratedValueSetOverflowScroll: GeneratedSkribbleValue {
+    /// The `scroll` value of the `overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn scroll(&self) -> String {
+      self.append_value("scroll")
+    }
+  }
+  pub trait GeneratedValueSetContainerTypeNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `container-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetContainerTypeSize: GeneratedSkribbleValue {
+    /// The `size` value of the `container-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn size(&self) -> String {
+      self.append_value("size")
+    }
+  }
+  pub trait GeneratedValueSetContainerTypeInlineSize: GeneratedSkribbleValue {
+    /// The `inline-size` value of the `container-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inline_size(&self) -> String {
+      self.append_value("inline-size")
+    }
+  }
+  pub trait GeneratedValueSetOverscrollAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `overscroll` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetOverscrollContain: GeneratedSkribbleValue {
+    /// The `contain` value of the `overscroll` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn contain(&self) -> String {
+      self.append_value("contain")
+    }
+  }
+  pub trait GeneratedValueSetOverscrollNone: GeneratedSkribbleValue {
+    /// The `none` value of the `overscroll` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetPositionRStatic: GeneratedSkribbleValue {
+    /// The `static` value of the `position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn r#static(&self) -> String {
+      self.append_value("static")
+    }
+  }
+  pub trait GeneratedValueSetPositionFixed: GeneratedSkribbleValue {
+    /// The `fixed` value of the `position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn fixed(&self) -> String {
+      self.append_value("fixed")
+    }
+  }
+  pub trait GeneratedValueSetPositionAbsolute: GeneratedSkribbleValue {
+    /// The `absolute` value of the `position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn absolute(&self) -> String {
+      self.append_value("absolute")
+    }
+  }
+  pub trait GeneratedValueSetPositionRelative: GeneratedSkribbleValue {
+    /// The `relative` value of the `position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn relative(&self) -> String {
+      self.append_value("relative")
+    }
+  }
+  pub trait GeneratedValueSetPositionSticky: GeneratedSkribbleValue {
+    /// The `sticky` value of the `position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sticky(&self) -> String {
+      self.append_value("sticky")
+    }
+  }
+  pub trait GeneratedValueSetBlurDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetBlurNone: GeneratedSkribbleValue {
+    /// The `none` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetBlurSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetBlurMd: GeneratedSkribbleValue {
+    /// The `md` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn md(&self) -> String {
+      self.append_value("md")
+    }
+  }
+  pub trait GeneratedValueSetBlurLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetBlurXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetBlurN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetBlurN3xl: GeneratedSkribbleValue {
+    /// The `3xl` value of the `blur` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3xl(&self) -> String {
+      self.append_value("3xl")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN0: GeneratedSkribbleValue {
+    /// The `0` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN50: GeneratedSkribbleValue {
+    /// The `50` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN75: GeneratedSkribbleValue {
+    /// The `75` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN90: GeneratedSkribbleValue {
+    /// The `90` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN95: GeneratedSkribbleValue {
+    /// The `95` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n95(&self) -> String {
+      self.append_value("95")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN100: GeneratedSkribbleValue {
+    /// The `100` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN105: GeneratedSkribbleValue {
+    /// The `105` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n105(&self) -> String {
+      self.append_value("105")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN110: GeneratedSkribbleValue {
+    /// The `110` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n110(&self) -> String {
+      self.append_value("110")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN125: GeneratedSkribbleValue {
+    /// The `125` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n125(&self) -> String {
+      self.append_value("125")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN150: GeneratedSkribbleValue {
+    /// The `150` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n150(&self) -> String {
+      self.append_value("150")
+    }
+  }
+  pub trait GeneratedValueSetBrightnessN200: GeneratedSkribbleValue {
+    /// The `200` value of the `brightness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n200(&self) -> String {
+      self.append_value("200")
+    }
+  }
+  pub trait GeneratedValueSetContrastN0: GeneratedSkribbleValue {
+    /// The `0` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetContrastN50: GeneratedSkribbleValue {
+    /// The `50` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetContrastN75: GeneratedSkribbleValue {
+    /// The `75` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75")
+    }
+  }
+  pub trait GeneratedValueSetContrastN100: GeneratedSkribbleValue {
+    /// The `100` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetContrastN125: GeneratedSkribbleValue {
+    /// The `125` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n125(&self) -> String {
+      self.append_value("125")
+    }
+  }
+  pub trait GeneratedValueSetContrastN150: GeneratedSkribbleValue {
+    /// The `150` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n150(&self) -> String {
+      self.append_value("150")
+    }
+  }
+  pub trait GeneratedValueSetContrastN200: GeneratedSkribbleValue {
+    /// The `200` value of the `contrast` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n200(&self) -> String {
+      self.append_value("200")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowMd: GeneratedSkribbleValue {
+    /// The `md` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn md(&self) -> String {
+      self.append_value("md")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetDropShadowNone: GeneratedSkribbleValue {
+    /// The `none` value of the `drop-shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetGrayscaleDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `grayscale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetGrayscaleN0: GeneratedSkribbleValue {
+    /// The `0` value of the `grayscale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetInvertDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `invert` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetInvertN0: GeneratedSkribbleValue {
+    /// The `0` value of the `invert` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetSepiaDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `sepia` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetSepiaN0: GeneratedSkribbleValue {
+    /// The `0` value of the `sepia` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetHueRotateN0: GeneratedSkribbleValue {
+    /// The `0` value of the `hue-rotate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetHueRotateN15: GeneratedSkribbleValue {
+    /// The `15` value of the `hue-rotate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n15(&self) -> String {
+      self.append_value("15")
+    }
+  }
+  pub trait GeneratedValueSetHueRotateN30: GeneratedSkribbleValue {
+    /// The `30` value of the `hue-rotate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n30(&self) -> String {
+      self.append_value("30")
+    }
+  }
+  pub trait GeneratedValueSetHueRotateN60: GeneratedSkribbleValue {
+    /// The `60` value of the `hue-rotate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetHueRotateN90: GeneratedSkribbleValue {
+    /// The `90` value of the `hue-rotate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90")
+    }
+  }
+  pub trait GeneratedValueSetHueRotateN180: GeneratedSkribbleValue {
+    /// The `180` value of the `hue-rotate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n180(&self) -> String {
+      self.append_value("180")
+    }
+  }
+  pub trait GeneratedValueSetSaturateN0: GeneratedSkribbleValue {
+    /// The `0` value of the `saturate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetSaturateN50: GeneratedSkribbleValue {
+    /// The `50` value of the `saturate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetSaturateN100: GeneratedSkribbleValue {
+    /// The `100` value of the `saturate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetSaturateN150: GeneratedSkribbleValue {
+    /// The `150` value of the `saturate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n150(&self) -> String {
+      self.append_value("150")
+    }
+  }
+  pub trait GeneratedValueSetSaturateN200: GeneratedSkribbleValue {
+    /// The `200` value of the `saturate` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n200(&self) -> String {
+      self.append_value("200")
+    }
+  }
+  pub trait GeneratedValueSetFilterSvg: GeneratedSkribbleValue {
+    /// The `svg` value of the `filter` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn svg(&self) -> String {
+      self.append_value("svg")
+    }
+  }
+  pub trait GeneratedValueSetBackdropFilterSvg: GeneratedSkribbleValue {
+    /// The `svg` value of the `backdrop-filter` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn svg(&self) -> String {
+      self.append_value("svg")
+    }
+  }
+  pub trait GeneratedValueSetFlexDirectionRow: GeneratedSkribbleValue {
+    /// The `row` value of the `flex-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn row(&self) -> String {
+      self.append_value("row")
+    }
+  }
+  pub trait GeneratedValueSetFlexDirectionRowReverse: GeneratedSkribbleValue {
+    /// The `row-reverse` value of the `flex-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn row_reverse(&self) -> String {
+      self.append_value("row-reverse")
+    }
+  }
+  pub trait GeneratedValueSetFlexDirectionCol: GeneratedSkribbleValue {
+    /// The `col` value of the `flex-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn col(&self) -> String {
+      self.append_value("col")
+    }
+  }
+  pub trait GeneratedValueSetFlexDirectionColReverse: GeneratedSkribbleValue {
+    /// The `col-reverse` value of the `flex-direction` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn col_reverse(&self) -> String {
+      self.append_value("col-reverse")
+    }
+  }
+  pub trait GeneratedValueSetFlexWrapWrap: GeneratedSkribbleValue {
+    /// The `wrap` value of the `flex-wrap` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn wrap(&self) -> String {
+      self.append_value("wrap")
+    }
+  }
+  pub trait GeneratedValueSetFlexWrapWrapReverse: GeneratedSkribbleValue {
+    /// The `wrap-reverse` value of the `flex-wrap` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn wrap_reverse(&self) -> String {
+      self.append_value("wrap-reverse")
+    }
+  }
+  pub trait GeneratedValueSetFlexWrapNowrap: GeneratedSkribbleValue {
+    /// The `nowrap` value of the `flex-wrap` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn nowrap(&self) -> String {
+      self.append_value("nowrap")
+    }
+  }
+  pub trait GeneratedValueSetFlexN1: GeneratedSkribbleValue {
+    /// The `1` value of the `flex` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetFlexAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `flex` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetFlexInitial: GeneratedSkribbleValue {
+    /// The `initial` value of the `flex` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn initial(&self) -> String {
+      self.append_value("initial")
+    }
+  }
+  pub trait GeneratedValueSetFlexNone: GeneratedSkribbleValue {
+    /// The `none` value of the `flex` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetFlexGrowN1: GeneratedSkribbleValue {
+    /// The `1` value of the `flex-grow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetFlexGrowN0: GeneratedSkribbleValue {
+    /// The `0` value of the `flex-grow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetFlexShrinkN1: GeneratedSkribbleValue {
+    /// The `1` value of the `flex-shrink` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetFlexShrinkN0: GeneratedSkribbleValue {
+    /// The `0` value of the `flex-shrink` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetOrderN1: GeneratedSkribbleValue {
+    /// The `1` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetOrderN2: GeneratedSkribbleValue {
+    /// The `2` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetOrderN3: GeneratedSkribbleValue {
+    /// The `3` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetOrderN4: GeneratedSkribbleValue {
+    /// The `4` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetOrderN5: GeneratedSkribbleValue {
+    /// The `5` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetOrderN6: GeneratedSkribbleValue {
+    /// The `6` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetOrderN7: GeneratedSkribbleValue {
+    /// The `7` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetOrderN8: GeneratedSkribbleValue {
+    /// The `8` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetOrderN9: GeneratedSkribbleValue {
+    /// The `9` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetOrderN10: GeneratedSkribbleValue {
+    /// The `10` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetOrderN11: GeneratedSkribbleValue {
+    /// The `11` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetOrderN12: GeneratedSkribbleValue {
+    /// The `12` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetOrderFirst: GeneratedSkribbleValue {
+    /// The `first` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn first(&self) -> String {
+      self.append_value("first")
+    }
+  }
+  pub trait GeneratedValueSetOrderLast: GeneratedSkribbleValue {
+    /// The `last` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn last(&self) -> String {
+      self.append_value("last")
+    }
+  }
+  pub trait GeneratedValueSetOrderNone: GeneratedSkribbleValue {
+    /// The `none` value of the `order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM1: GeneratedSkribbleValue {
+    /// The `-1` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m1(&self) -> String {
+      self.append_value("-1")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM2: GeneratedSkribbleValue {
+    /// The `-2` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m2(&self) -> String {
+      self.append_value("-2")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM3: GeneratedSkribbleValue {
+    /// The `-3` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m3(&self) -> String {
+      self.append_value("-3")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM4: GeneratedSkribbleValue {
+    /// The `-4` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m4(&self) -> String {
+      self.append_value("-4")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM5: GeneratedSkribbleValue {
+    /// The `-5` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m5(&self) -> String {
+      self.append_value("-5")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM6: GeneratedSkribbleValue {
+    /// The `-6` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m6(&self) -> String {
+      self.append_value("-6")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM7: GeneratedSkribbleValue {
+    /// The `-7` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m7(&self) -> String {
+      self.append_value("-7")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM8: GeneratedSkribbleValue {
+    /// The `-8` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m8(&self) -> String {
+      self.append_value("-8")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM9: GeneratedSkribbleValue {
+    /// The `-9` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m9(&self) -> String {
+      self.append_value("-9")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM10: GeneratedSkribbleValue {
+    /// The `-10` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m10(&self) -> String {
+      self.append_value("-10")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM11: GeneratedSkribbleValue {
+    /// The `-11` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m11(&self) -> String {
+      self.append_value("-11")
+    }
+  }
+  pub trait GeneratedValueSetNegativeOrderM12: GeneratedSkribbleValue {
+    /// The `-12` value of the `negative-order` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn m12(&self) -> String {
+      self.append_value("-12")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN1: GeneratedSkribbleValue {
+    /// The `1` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN2: GeneratedSkribbleValue {
+    /// The `2` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN3: GeneratedSkribbleValue {
+    /// The `3` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN4: GeneratedSkribbleValue {
+    /// The `4` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN5: GeneratedSkribbleValue {
+    /// The `5` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN6: GeneratedSkribbleValue {
+    /// The `6` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN7: GeneratedSkribbleValue {
+    /// The `7` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN8: GeneratedSkribbleValue {
+    /// The `8` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN9: GeneratedSkribbleValue {
+    /// The `9` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN10: GeneratedSkribbleValue {
+    /// The `10` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN11: GeneratedSkribbleValue {
+    /// The `11` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateN12: GeneratedSkribbleValue {
+    /// The `12` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetGridTemplateNone: GeneratedSkribbleValue {
+    /// The `none` value of the `grid-template` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN1: GeneratedSkribbleValue {
+    /// The `1` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN2: GeneratedSkribbleValue {
+    /// The `2` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN3: GeneratedSkribbleValue {
+    /// The `3` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN4: GeneratedSkribbleValue {
+    /// The `4` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN5: GeneratedSkribbleValue {
+    /// The `5` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN6: GeneratedSkribbleValue {
+    /// The `6` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN7: GeneratedSkribbleValue {
+    /// The `7` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN8: GeneratedSkribbleValue {
+    /// The `8` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN9: GeneratedSkribbleValue {
+    /// The `9` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN10: GeneratedSkribbleValue {
+    /// The `10` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN11: GeneratedSkribbleValue {
+    /// The `11` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanN12: GeneratedSkribbleValue {
+    /// The `12` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetGridSpanFull: GeneratedSkribbleValue {
+    /// The `full` value of the `grid-span` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn full(&self) -> String {
+      self.append_value("full")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN1: GeneratedSkribbleValue {
+    /// The `1` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN2: GeneratedSkribbleValue {
+    /// The `2` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN3: GeneratedSkribbleValue {
+    /// The `3` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN4: GeneratedSkribbleValue {
+    /// The `4` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN5: GeneratedSkribbleValue {
+    /// The `5` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN6: GeneratedSkribbleValue {
+    /// The `6` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN7: GeneratedSkribbleValue {
+    /// The `7` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN8: GeneratedSkribbleValue {
+    /// The `8` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN9: GeneratedSkribbleValue {
+    /// The `9` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN10: GeneratedSkribbleValue {
+    /// The `10` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN11: GeneratedSkribbleValue {
+    /// The `11` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN12: GeneratedSkribbleValue {
+    /// The `12` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndN13: GeneratedSkribbleValue {
+    /// The `13` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n13(&self) -> String {
+      self.append_value("13")
+    }
+  }
+  pub trait GeneratedValueSetGridStartEndAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `grid-start-end` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetAutoFlowGridFlowRow: GeneratedSkribbleValue {
+    /// The `grid-flow-row` value of the `auto-flow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grid_flow_row(&self) -> String {
+      self.append_value("grid-flow-row")
+    }
+  }
+  pub trait GeneratedValueSetAutoFlowGridFlowCol: GeneratedSkribbleValue {
+    /// The `grid-flow-col` value of the `auto-flow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grid_flow_col(&self) -> String {
+      self.append_value("grid-flow-col")
+    }
+  }
+  pub trait GeneratedValueSetAutoFlowGridFlowDense: GeneratedSkribbleValue {
+    /// The `grid-flow-dense` value of the `auto-flow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grid_flow_dense(&self) -> String {
+      self.append_value("grid-flow-dense")
+    }
+  }
+  pub trait GeneratedValueSetAutoFlowGridFlowRowDense: GeneratedSkribbleValue {
+    /// The `grid-flow-row-dense` value of the `auto-flow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grid_flow_row_dense(&self) -> String {
+      self.append_value("grid-flow-row-dense")
+    }
+  }
+  pub trait GeneratedValueSetAutoFlowGridFlowColDense: GeneratedSkribbleValue {
+    /// The `grid-flow-col-dense` value of the `auto-flow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grid_flow_col_dense(&self) -> String {
+      self.append_value("grid-flow-col-dense")
+    }
+  }
+  pub trait GeneratedValueSetGridAutoAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `grid-auto` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetGridAutoMin: GeneratedSkribbleValue {
+    /// The `min` value of the `grid-auto` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn min(&self) -> String {
+      self.append_value("min")
+    }
+  }
+  pub trait GeneratedValueSetGridAutoMax: GeneratedSkribbleValue {
+    /// The `max` value of the `grid-auto` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn max(&self) -> String {
+      self.append_value("max")
+    }
+  }
+  pub trait GeneratedValueSetGridAutoFr: GeneratedSkribbleValue {
+    /// The `fr` value of the `grid-auto` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn fr(&self) -> String {
+      self.append_value("fr")
+    }
+  }
+  pub trait GeneratedValueSetJustifyNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetJustifyStart: GeneratedSkribbleValue {
+    /// The `start` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetJustifyEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetJustifyCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetJustifyBetween: GeneratedSkribbleValue {
+    /// The `between` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn between(&self) -> String {
+      self.append_value("between")
+    }
+  }
+  pub trait GeneratedValueSetJustifyAround: GeneratedSkribbleValue {
+    /// The `around` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn around(&self) -> String {
+      self.append_value("around")
+    }
+  }
+  pub trait GeneratedValueSetJustifyEvenly: GeneratedSkribbleValue {
+    /// The `evenly` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn evenly(&self) -> String {
+      self.append_value("evenly")
+    }
+  }
+  pub trait GeneratedValueSetJustifyStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `justify` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetJustifyItemsStart: GeneratedSkribbleValue {
+    /// The `start` value of the `justify-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetJustifyItemsEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `justify-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetJustifyItemsCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `justify-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetJustifyItemsStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `justify-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetAutoAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `auto` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetNoneNone: GeneratedSkribbleValue {
+    /// The `none` value of the `none` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentStart: GeneratedSkribbleValue {
+    /// The `start` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentBetween: GeneratedSkribbleValue {
+    /// The `between` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn between(&self) -> String {
+      self.append_value("between")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentAround: GeneratedSkribbleValue {
+    /// The `around` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn around(&self) -> String {
+      self.append_value("around")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentEvenly: GeneratedSkribbleValue {
+    /// The `evenly` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn evenly(&self) -> String {
+      self.append_value("evenly")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetAlignContentStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `align-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetAlignItemsCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `align-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetAlignItemsStart: GeneratedSkribbleValue {
+    /// The `start` value of the `align-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetAlignItemsEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `align-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetAlignItemsBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `align-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetAlignItemsStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `align-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetAlignSelfAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `align-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetAlignSelfStart: GeneratedSkribbleValue {
+    /// The `start` value of the `align-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetAlignSelfEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `align-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetAlignSelfCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `align-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetAlignSelfStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `align-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetAlignSelfBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `align-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentStart: GeneratedSkribbleValue {
+    /// The `start` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentBetween: GeneratedSkribbleValue {
+    /// The `between` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn between(&self) -> String {
+      self.append_value("between")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentAround: GeneratedSkribbleValue {
+    /// The `around` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn around(&self) -> String {
+      self.append_value("around")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentEvenly: GeneratedSkribbleValue {
+    /// The `evenly` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn evenly(&self) -> String {
+      self.append_value("evenly")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetPlaceContentStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `place-content` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetPlaceItemsCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `place-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetPlaceItemsStart: GeneratedSkribbleValue {
+    /// The `start` value of the `place-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetPlaceItemsEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `place-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetPlaceItemsBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `place-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetPlaceItemsStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `place-items` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfStart: GeneratedSkribbleValue {
+    /// The `start` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfBetween: GeneratedSkribbleValue {
+    /// The `between` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn between(&self) -> String {
+      self.append_value("between")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfAround: GeneratedSkribbleValue {
+    /// The `around` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn around(&self) -> String {
+      self.append_value("around")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfEvenly: GeneratedSkribbleValue {
+    /// The `evenly` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn evenly(&self) -> String {
+      self.append_value("evenly")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetPlaceSelfStretch: GeneratedSkribbleValue {
+    /// The `stretch` value of the `place-self` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stretch(&self) -> String {
+      self.append_value("stretch")
+    }
+  }
+  pub trait GeneratedValueSetStrokeWidthN0: GeneratedSkribbleValue {
+    /// The `0` value of the `stroke-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetStrokeWidthN1: GeneratedSkribbleValue {
+    /// The `1` value of the `stroke-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetStrokeWidthN2: GeneratedSkribbleValue {
+    /// The `2` value of the `stroke-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetStrokeWidthN3: GeneratedSkribbleValue {
+    /// The `3` value of the `stroke-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetStrokeWidthN4: GeneratedSkribbleValue {
+    /// The `4` value of the `stroke-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeXs: GeneratedSkribbleValue {
+    /// The `xs` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xs(&self) -> String {
+      self.append_value("xs")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeBase: GeneratedSkribbleValue {
+    /// The `base` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn base(&self) -> String {
+      self.append_value("base")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN3xl: GeneratedSkribbleValue {
+    /// The `3xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3xl(&self) -> String {
+      self.append_value("3xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN4xl: GeneratedSkribbleValue {
+    /// The `4xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4xl(&self) -> String {
+      self.append_value("4xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN5xl: GeneratedSkribbleValue {
+    /// The `5xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5xl(&self) -> String {
+      self.append_value("5xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN6xl: GeneratedSkribbleValue {
+    /// The `6xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6xl(&self) -> String {
+      self.append_value("6xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN7xl: GeneratedSkribbleValue {
+    /// The `7xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7xl(&self) -> String {
+      self.append_value("7xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN8xl: GeneratedSkribbleValue {
+    /// The `8xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8xl(&self) -> String {
+      self.append_value("8xl")
+    }
+  }
+  pub trait GeneratedValueSetFontSizeN9xl: GeneratedSkribbleValue {
+    /// The `9xl` value of the `font-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9xl(&self) -> String {
+      self.append_value("9xl")
+    }
+  }
+  pub trait GeneratedValueSetSmoothingAntialiased: GeneratedSkribbleValue {
+    /// The `antialiased` value of the `smoothing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn antialiased(&self) -> String {
+      self.append_value("antialiased")
+    }
+  }
+  pub trait GeneratedValueSetSmoothingSubpixel: GeneratedSkribbleValue {
+    /// The `subpixel` value of the `smoothing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn subpixel(&self) -> String {
+      self.append_value("subpixel")
+    }
+  }
+  pub trait GeneratedValueSetFontStyleItalic: GeneratedSkribbleValue {
+    /// The `italic` value of the `font-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn italic(&self) -> String {
+      self.append_value("italic")
+    }
+  }
+  pub trait GeneratedValueSetFontStyleNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `font-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightThin: GeneratedSkribbleValue {
+    /// The `thin` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn thin(&self) -> String {
+      self.append_value("thin")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightExtralight: GeneratedSkribbleValue {
+    /// The `extralight` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn extralight(&self) -> String {
+      self.append_value("extralight")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightLight: GeneratedSkribbleValue {
+    /// The `light` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn light(&self) -> String {
+      self.append_value("light")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightMedium: GeneratedSkribbleValue {
+    /// The `medium` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn medium(&self) -> String {
+      self.append_value("medium")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightSemibold: GeneratedSkribbleValue {
+    /// The `semibold` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn semibold(&self) -> String {
+      self.append_value("semibold")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightBold: GeneratedSkribbleValue {
+    /// The `bold` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bold(&self) -> String {
+      self.append_value("bold")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightExtrabold: GeneratedSkribbleValue {
+    /// The `extrabold` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn extrabold(&self) -> String {
+      self.append_value("extrabold")
+    }
+  }
+  pub trait GeneratedValueSetFontWeightBlack: GeneratedSkribbleValue {
+    /// The `black` value of the `font-weight` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn black(&self) -> String {
+      self.append_value("black")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericOrdinal: GeneratedSkribbleValue {
+    /// The `ordinal` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn ordinal(&self) -> String {
+      self.append_value("ordinal")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericSlashed: GeneratedSkribbleValue {
+    /// The `slashed` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn slashed(&self) -> String {
+      self.append_value("slashed")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericLining: GeneratedSkribbleValue {
+    /// The `lining` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lining(&self) -> String {
+      self.append_value("lining")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericOldstyle: GeneratedSkribbleValue {
+    /// The `oldstyle` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn oldstyle(&self) -> String {
+      self.append_value("oldstyle")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericProportional: GeneratedSkribbleValue {
+    /// The `proportional` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn proportional(&self) -> String {
+      self.append_value("proportional")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericTabular: GeneratedSkribbleValue {
+    /// The `tabular` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn tabular(&self) -> String {
+      self.append_value("tabular")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericDiagonal: GeneratedSkribbleValue {
+    /// The `diagonal` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn diagonal(&self) -> String {
+      self.append_value("diagonal")
+    }
+  }
+  pub trait GeneratedValueSetFontVariantNumericStacked: GeneratedSkribbleValue {
+    /// The `stacked` value of the `font-variant-numeric` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn stacked(&self) -> String {
+      self.append_value("stacked")
+    }
+  }
+  pub trait GeneratedValueSetLetterSpacingTighter: GeneratedSkribbleValue {
+    /// The `tighter` value of the `letter-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn tighter(&self) -> String {
+      self.append_value("tighter")
+    }
+  }
+  pub trait GeneratedValueSetLetterSpacingTight: GeneratedSkribbleValue {
+    /// The `tight` value of the `letter-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn tight(&self) -> String {
+      self.append_value("tight")
+    }
+  }
+  pub trait GeneratedValueSetLetterSpacingNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `letter-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetLetterSpacingWide: GeneratedSkribbleValue {
+    /// The `wide` value of the `letter-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn wide(&self) -> String {
+      self.append_value("wide")
+    }
+  }
+  pub trait GeneratedValueSetLetterSpacingWider: GeneratedSkribbleValue {
+    /// The `wider` value of the `letter-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn wider(&self) -> String {
+      self.append_value("wider")
+    }
+  }
+  pub trait GeneratedValueSetLetterSpacingWidest: GeneratedSkribbleValue {
+    /// The `widest` value of the `letter-spacing` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn widest(&self) -> String {
+      self.append_value("widest")
+    }
+  }
+  pub trait GeneratedValueSetLineClampN1: GeneratedSkribbleValue {
+    /// The `1` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetLineClampN2: GeneratedSkribbleValue {
+    /// The `2` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetLineClampN3: GeneratedSkribbleValue {
+    /// The `3` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetLineClampN4: GeneratedSkribbleValue {
+    /// The `4` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetLineClampN5: GeneratedSkribbleValue {
+    /// The `5` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetLineClampN6: GeneratedSkribbleValue {
+    /// The `6` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetLineClampNone: GeneratedSkribbleValue {
+    /// The `none` value of the `line-clamp` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN3: GeneratedSkribbleValue {
+    /// The `3` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN4: GeneratedSkribbleValue {
+    /// The `4` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN5: GeneratedSkribbleValue {
+    /// The `5` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN6: GeneratedSkribbleValue {
+    /// The `6` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN7: GeneratedSkribbleValue {
+    /// The `7` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN8: GeneratedSkribbleValue {
+    /// The `8` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN9: GeneratedSkribbleValue {
+    /// The `9` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightN10: GeneratedSkribbleValue {
+    /// The `10` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightNone: GeneratedSkribbleValue {
+    /// The `none` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightTight: GeneratedSkribbleValue {
+    /// The `tight` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn tight(&self) -> String {
+      self.append_value("tight")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightSnug: GeneratedSkribbleValue {
+    /// The `snug` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn snug(&self) -> String {
+      self.append_value("snug")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightRelaxed: GeneratedSkribbleValue {
+    /// The `relaxed` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn relaxed(&self) -> String {
+      self.append_value("relaxed")
+    }
+  }
+  pub trait GeneratedValueSetLineHeightLoose: GeneratedSkribbleValue {
+    /// The `loose` value of the `line-height` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn loose(&self) -> String {
+      self.append_value("loose")
+    }
+  }
+  pub trait GeneratedValueSetListStylePositionInside: GeneratedSkribbleValue {
+    /// The `inside` value of the `list-style-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inside(&self) -> String {
+      self.append_value("inside")
+    }
+  }
+  pub trait GeneratedValueSetListStylePositionOutside: GeneratedSkribbleValue {
+    /// The `outside` value of the `list-style-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn outside(&self) -> String {
+      self.append_value("outside")
+    }
+  }
+  pub trait GeneratedValueSetListStyleTypeDisc: GeneratedSkribbleValue {
+    /// The `disc` value of the `list-style-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn disc(&self) -> String {
+      self.append_value("disc")
+    }
+  }
+  pub trait GeneratedValueSetListStyleTypeDecimal: GeneratedSkribbleValue {
+    /// The `decimal` value of the `list-style-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn decimal(&self) -> String {
+      self.append_value("decimal")
+    }
+  }
+  pub trait GeneratedValueSetTextAlignLeft: GeneratedSkribbleValue {
+    /// The `left` value of the `text-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left(&self) -> String {
+      self.append_value("left")
+    }
+  }
+  pub trait GeneratedValueSetTextAlignCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `text-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetTextAlignRight: GeneratedSkribbleValue {
+    /// The `right` value of the `text-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right(&self) -> String {
+      self.append_value("right")
+    }
+  }
+  pub trait GeneratedValueSetTextAlignJustify: GeneratedSkribbleValue {
+    /// The `justify` value of the `text-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn justify(&self) -> String {
+      self.append_value("justify")
+    }
+  }
+  pub trait GeneratedValueSetTextAlignStart: GeneratedSkribbleValue {
+    /// The `start` value of the `text-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetTextAlignEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `text-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationLineUnderline: GeneratedSkribbleValue {
+    /// The `underline` value of the `text-decoration-line` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn underline(&self) -> String {
+      self.append_value("underline")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationLineOverline: GeneratedSkribbleValue {
+    /// The `overline` value of the `text-decoration-line` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn overline(&self) -> String {
+      self.append_value("overline")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationLineThrough: GeneratedSkribbleValue {
+    /// The `through` value of the `text-decoration-line` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn through(&self) -> String {
+      self.append_value("through")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationStyleSolid: GeneratedSkribbleValue {
+    /// The `solid` value of the `text-decoration-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn solid(&self) -> String {
+      self.append_value("solid")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationStyleDouble: GeneratedSkribbleValue {
+    /// The `double` value of the `text-decoration-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn double(&self) -> String {
+      self.append_value("double")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationStyleDotted: GeneratedSkribbleValue {
+    /// The `dotted` value of the `text-decoration-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dotted(&self) -> String {
+      self.append_value("dotted")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationStyleDashed: GeneratedSkribbleValue {
+    /// The `dashed` value of the `text-decoration-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dashed(&self) -> String {
+      self.append_value("dashed")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationStyleWavy: GeneratedSkribbleValue {
+    /// The `wavy` value of the `text-decoration-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn wavy(&self) -> String {
+      self.append_value("wavy")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessFromFont: GeneratedSkribbleValue {
+    /// The `from-font` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn from_font(&self) -> String {
+      self.append_value("from-font")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessN0: GeneratedSkribbleValue {
+    /// The `0` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessN1: GeneratedSkribbleValue {
+    /// The `1` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessN2: GeneratedSkribbleValue {
+    /// The `2` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessN4: GeneratedSkribbleValue {
+    /// The `4` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetTextDecorationThicknessN8: GeneratedSkribbleValue {
+    /// The `8` value of the `text-decoration-thickness` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetTextUnderlineOffsetAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `text-underline-offset` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetTextUnderlineOffsetN0: GeneratedSkribbleValue {
+    /// The `0` value of the `text-underline-offset` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetTextUnderlineOffsetN1: GeneratedSkribbleValue {
+    /// The `1` value of the `text-underline-offset` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetTextUnderlineOffsetN2: GeneratedSkribbleValue {
+    /// The `2` value of the `text-underline-offset` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetTextUnderlineOffsetN4: GeneratedSkribbleValue {
+    /// The `4` value of the `text-underline-offset` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetTextUnderlineOffsetN8: GeneratedSkribbleValue {
+    /// The `8` value of the `text-underline-offset` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetTextTransformUppercase: GeneratedSkribbleValue {
+    /// The `uppercase` value of the `text-transform` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn uppercase(&self) -> String {
+      self.append_value("uppercase")
+    }
+  }
+  pub trait GeneratedValueSetTextTransformLowercase: GeneratedSkribbleValue {
+    /// The `lowercase` value of the `text-transform` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lowercase(&self) -> String {
+      self.append_value("lowercase")
+    }
+  }
+  pub trait GeneratedValueSetTextTransformCapitalize: GeneratedSkribbleValue {
+    /// The `capitalize` value of the `text-transform` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn capitalize(&self) -> String {
+      self.append_value("capitalize")
+    }
+  }
+  pub trait GeneratedValueSetTextOverflowEllipsis: GeneratedSkribbleValue {
+    /// The `ellipsis` value of the `text-overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn ellipsis(&self) -> String {
+      self.append_value("ellipsis")
+    }
+  }
+  pub trait GeneratedValueSetTextOverflowClip: GeneratedSkribbleValue {
+    /// The `clip` value of the `text-overflow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn clip(&self) -> String {
+      self.append_value("clip")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN0: GeneratedSkribbleValue {
+    /// The `0` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentPx: GeneratedSkribbleValue {
+    /// The `px` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn px(&self) -> String {
+      self.append_value("px")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN05: GeneratedSkribbleValue {
+    /// The `0.5` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0_5(&self) -> String {
+      self.append_value("0.5")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN1: GeneratedSkribbleValue {
+    /// The `1` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN15: GeneratedSkribbleValue {
+    /// The `1.5` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1_5(&self) -> String {
+      self.append_value("1.5")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN2: GeneratedSkribbleValue {
+    /// The `2` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN25: GeneratedSkribbleValue {
+    /// The `2.5` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2_5(&self) -> String {
+      self.append_value("2.5")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN3: GeneratedSkribbleValue {
+    /// The `3` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN35: GeneratedSkribbleValue {
+    /// The `3.5` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3_5(&self) -> String {
+      self.append_value("3.5")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN4: GeneratedSkribbleValue {
+    /// The `4` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN5: GeneratedSkribbleValue {
+    /// The `5` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN6: GeneratedSkribbleValue {
+    /// The `6` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN7: GeneratedSkribbleValue {
+    /// The `7` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n7(&self) -> String {
+      self.append_value("7")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN8: GeneratedSkribbleValue {
+    /// The `8` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN9: GeneratedSkribbleValue {
+    /// The `9` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n9(&self) -> String {
+      self.append_value("9")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN10: GeneratedSkribbleValue {
+    /// The `10` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN11: GeneratedSkribbleValue {
+    /// The `11` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n11(&self) -> String {
+      self.append_value("11")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN12: GeneratedSkribbleValue {
+    /// The `12` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN14: GeneratedSkribbleValue {
+    /// The `14` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n14(&self) -> String {
+      self.append_value("14")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN16: GeneratedSkribbleValue {
+    /// The `16` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n16(&self) -> String {
+      self.append_value("16")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN20: GeneratedSkribbleValue {
+    /// The `20` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN24: GeneratedSkribbleValue {
+    /// The `24` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n24(&self) -> String {
+      self.append_value("24")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN28: GeneratedSkribbleValue {
+    /// The `28` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n28(&self) -> String {
+      self.append_value("28")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN32: GeneratedSkribbleValue {
+    /// The `32` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n32(&self) -> String {
+      self.append_value("32")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN36: GeneratedSkribbleValue {
+    /// The `36` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n36(&self) -> String {
+      self.append_value("36")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN40: GeneratedSkribbleValue {
+    /// The `40` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN44: GeneratedSkribbleValue {
+    /// The `44` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n44(&self) -> String {
+      self.append_value("44")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN48: GeneratedSkribbleValue {
+    /// The `48` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n48(&self) -> String {
+      self.append_value("48")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN52: GeneratedSkribbleValue {
+    /// The `52` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n52(&self) -> String {
+      self.append_value("52")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN56: GeneratedSkribbleValue {
+    /// The `56` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n56(&self) -> String {
+      self.append_value("56")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN60: GeneratedSkribbleValue {
+    /// The `60` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN64: GeneratedSkribbleValue {
+    /// The `64` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n64(&self) -> String {
+      self.append_value("64")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN72: GeneratedSkribbleValue {
+    /// The `72` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n72(&self) -> String {
+      self.append_value("72")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN80: GeneratedSkribbleValue {
+    /// The `80` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n80(&self) -> String {
+      self.append_value("80")
+    }
+  }
+  pub trait GeneratedValueSetTextIndentN96: GeneratedSkribbleValue {
+    /// The `96` value of the `text-indent` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n96(&self) -> String {
+      self.append_value("96")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignBaseline: GeneratedSkribbleValue {
+    /// The `baseline` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn baseline(&self) -> String {
+      self.append_value("baseline")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignTop: GeneratedSkribbleValue {
+    /// The `top` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn top(&self) -> String {
+      self.append_value("top")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignMiddle: GeneratedSkribbleValue {
+    /// The `middle` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn middle(&self) -> String {
+      self.append_value("middle")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignBottom: GeneratedSkribbleValue {
+    /// The `bottom` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bottom(&self) -> String {
+      self.append_value("bottom")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignTextTop: GeneratedSkribbleValue {
+    /// The `text-top` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn text_top(&self) -> String {
+      self.append_value("text-top")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignTextBottom: GeneratedSkribbleValue {
+    /// The `text-bottom` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn text_bottom(&self) -> String {
+      self.append_value("text-bottom")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignSub: GeneratedSkribbleValue {
+    /// The `sub` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sub(&self) -> String {
+      self.append_value("sub")
+    }
+  }
+  pub trait GeneratedValueSetVertialAlignSupR: GeneratedSkribbleValue {
+    /// The `super` value of the `vertial-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sup_r(&self) -> String {
+      self.append_value("super")
+    }
+  }
+  pub trait GeneratedValueSetWhitespaceNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `whitespace` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetWhitespaceNowrap: GeneratedSkribbleValue {
+    /// The `nowrap` value of the `whitespace` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn nowrap(&self) -> String {
+      self.append_value("nowrap")
+    }
+  }
+  pub trait GeneratedValueSetWhitespacePre: GeneratedSkribbleValue {
+    /// The `pre` value of the `whitespace` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pre(&self) -> String {
+      self.append_value("pre")
+    }
+  }
+  pub trait GeneratedValueSetWhitespacePreLine: GeneratedSkribbleValue {
+    /// The `pre-line` value of the `whitespace` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pre_line(&self) -> String {
+      self.append_value("pre-line")
+    }
+  }
+  pub trait GeneratedValueSetWhitespacePreWrap: GeneratedSkribbleValue {
+    /// The `pre-wrap` value of the `whitespace` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pre_wrap(&self) -> String {
+      self.append_value("pre-wrap")
+    }
+  }
+  pub trait GeneratedValueSetWhitespaceBreakSpaces: GeneratedSkribbleValue {
+    /// The `break-spaces` value of the `whitespace` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn break_spaces(&self) -> String {
+      self.append_value("break-spaces")
+    }
+  }
+  pub trait GeneratedValueSetHyphensManual: GeneratedSkribbleValue {
+    /// The `manual` value of the `hyphens` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn manual(&self) -> String {
+      self.append_value("manual")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundAttachmentFixed: GeneratedSkribbleValue {
+    /// The `fixed` value of the `background-attachment` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn fixed(&self) -> String {
+      self.append_value("fixed")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundAttachmentLocal: GeneratedSkribbleValue {
+    /// The `local` value of the `background-attachment` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn local(&self) -> String {
+      self.append_value("local")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundAttachmentScroll: GeneratedSkribbleValue {
+    /// The `scroll` value of the `background-attachment` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn scroll(&self) -> String {
+      self.append_value("scroll")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundOriginBorder: GeneratedSkribbleValue {
+    /// The `border` value of the `background-origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn border(&self) -> String {
+      self.append_value("border")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundOriginPadding: GeneratedSkribbleValue {
+    /// The `padding` value of the `background-origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn padding(&self) -> String {
+      self.append_value("padding")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundOriginContent: GeneratedSkribbleValue {
+    /// The `content` value of the `background-origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn content(&self) -> String {
+      self.append_value("content")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundTextText: GeneratedSkribbleValue {
+    /// The `text` value of the `background-text` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn text(&self) -> String {
+      self.append_value("text")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionBottom: GeneratedSkribbleValue {
+    /// The `bottom` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bottom(&self) -> String {
+      self.append_value("bottom")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionLeft: GeneratedSkribbleValue {
+    /// The `left` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left(&self) -> String {
+      self.append_value("left")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionLeftBottom: GeneratedSkribbleValue {
+    /// The `left-bottom` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left_bottom(&self) -> String {
+      self.append_value("left-bottom")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionLeftTop: GeneratedSkribbleValue {
+    /// The `left-top` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left_top(&self) -> String {
+      self.append_value("left-top")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionRight: GeneratedSkribbleValue {
+    /// The `right` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right(&self) -> String {
+      self.append_value("right")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionRightBottom: GeneratedSkribbleValue {
+    /// The `right-bottom` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right_bottom(&self) -> String {
+      self.append_value("right-bottom")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionRightTop: GeneratedSkribbleValue {
+    /// The `right-top` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right_top(&self) -> String {
+      self.append_value("right-top")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundPositionTop: GeneratedSkribbleValue {
+    /// The `top` value of the `background-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn top(&self) -> String {
+      self.append_value("top")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundRepeatDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `background-repeat` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundRepeatNone: GeneratedSkribbleValue {
+    /// The `none` value of the `background-repeat` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundRepeatX: GeneratedSkribbleValue {
+    /// The `x` value of the `background-repeat` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn x(&self) -> String {
+      self.append_value("x")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundRepeatY: GeneratedSkribbleValue {
+    /// The `y` value of the `background-repeat` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn y(&self) -> String {
+      self.append_value("y")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundRepeatRound: GeneratedSkribbleValue {
+    /// The `round` value of the `background-repeat` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn round(&self) -> String {
+      self.append_value("round")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundRepeatSpace: GeneratedSkribbleValue {
+    /// The `space` value of the `background-repeat` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn space(&self) -> String {
+      self.append_value("space")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundSizeAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `background-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundSizeCover: GeneratedSkribbleValue {
+    /// The `cover` value of the `background-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn cover(&self) -> String {
+      self.append_value("cover")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundSizeContain: GeneratedSkribbleValue {
+    /// The `contain` value of the `background-size` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn contain(&self) -> String {
+      self.append_value("contain")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToTop: GeneratedSkribbleValue {
+    /// The `to-top` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_top(&self) -> String {
+      self.append_value("to-top")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToTopRight: GeneratedSkribbleValue {
+    /// The `to-top-right` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_top_right(&self) -> String {
+      self.append_value("to-top-right")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToRight: GeneratedSkribbleValue {
+    /// The `to-right` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_right(&self) -> String {
+      self.append_value("to-right")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToBottomRight: GeneratedSkribbleValue {
+    /// The `to-bottom-right` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_bottom_right(&self) -> String {
+      self.append_value("to-bottom-right")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToBottom: GeneratedSkribbleValue {
+    /// The `to-bottom` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_bottom(&self) -> String {
+      self.append_value("to-bottom")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToBottomLeft: GeneratedSkribbleValue {
+    /// The `to-bottom-left` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_bottom_left(&self) -> String {
+      self.append_value("to-bottom-left")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToLeft: GeneratedSkribbleValue {
+    /// The `to-left` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_left(&self) -> String {
+      self.append_value("to-left")
+    }
+  }
+  pub trait GeneratedValueSetBackgroundGradientToTopLeft: GeneratedSkribbleValue {
+    /// The `to-top-left` value of the `background-gradient` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn to_top_left(&self) -> String {
+      self.append_value("to-top-left")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN0: GeneratedSkribbleValue {
+    /// The `0%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN5: GeneratedSkribbleValue {
+    /// The `5%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n5(&self) -> String {
+      self.append_value("5%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN10: GeneratedSkribbleValue {
+    /// The `10%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n10(&self) -> String {
+      self.append_value("10%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN15: GeneratedSkribbleValue {
+    /// The `15%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n15(&self) -> String {
+      self.append_value("15%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN20: GeneratedSkribbleValue {
+    /// The `20%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n20(&self) -> String {
+      self.append_value("20%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN25: GeneratedSkribbleValue {
+    /// The `25%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n25(&self) -> String {
+      self.append_value("25%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN30: GeneratedSkribbleValue {
+    /// The `30%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n30(&self) -> String {
+      self.append_value("30%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN35: GeneratedSkribbleValue {
+    /// The `35%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n35(&self) -> String {
+      self.append_value("35%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN40: GeneratedSkribbleValue {
+    /// The `40%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n40(&self) -> String {
+      self.append_value("40%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN45: GeneratedSkribbleValue {
+    /// The `45%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n45(&self) -> String {
+      self.append_value("45%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN50: GeneratedSkribbleValue {
+    /// The `50%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN55: GeneratedSkribbleValue {
+    /// The `55%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n55(&self) -> String {
+      self.append_value("55%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN60: GeneratedSkribbleValue {
+    /// The `60%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n60(&self) -> String {
+      self.append_value("60%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN65: GeneratedSkribbleValue {
+    /// The `65%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n65(&self) -> String {
+      self.append_value("65%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN70: GeneratedSkribbleValue {
+    /// The `70%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n70(&self) -> String {
+      self.append_value("70%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN75: GeneratedSkribbleValue {
+    /// The `75%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN80: GeneratedSkribbleValue {
+    /// The `80%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n80(&self) -> String {
+      self.append_value("80%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN85: GeneratedSkribbleValue {
+    /// The `85%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n85(&self) -> String {
+      self.append_value("85%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN90: GeneratedSkribbleValue {
+    /// The `90%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN95: GeneratedSkribbleValue {
+    /// The `95%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n95(&self) -> String {
+      self.append_value("95%")
+    }
+  }
+  pub trait GeneratedValueSetGradientPositionN100: GeneratedSkribbleValue {
+    /// The `100%` value of the `gradient-position` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100%")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusNone: GeneratedSkribbleValue {
+    /// The `none` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusMd: GeneratedSkribbleValue {
+    /// The `md` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn md(&self) -> String {
+      self.append_value("md")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusN3xl: GeneratedSkribbleValue {
+    /// The `3xl` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3xl(&self) -> String {
+      self.append_value("3xl")
+    }
+  }
+  pub trait GeneratedValueSetBorderRadiusFull: GeneratedSkribbleValue {
+    /// The `full` value of the `border-radius` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn full(&self) -> String {
+      self.append_value("full")
+    }
+  }
+  pub trait GeneratedValueSetBorderWidthN0: GeneratedSkribbleValue {
+    /// The `0` value of the `border-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetBorderWidthDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `border-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetBorderWidthN2: GeneratedSkribbleValue {
+    /// The `2` value of the `border-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetBorderWidthN4: GeneratedSkribbleValue {
+    /// The `4` value of the `border-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetBorderWidthN8: GeneratedSkribbleValue {
+    /// The `8` value of the `border-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetBorderStyleSolid: GeneratedSkribbleValue {
+    /// The `solid` value of the `border-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn solid(&self) -> String {
+      self.append_value("solid")
+    }
+  }
+  pub trait GeneratedValueSetBorderStyleDashed: GeneratedSkribbleValue {
+    /// The `dashed` value of the `border-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dashed(&self) -> String {
+      self.append_value("dashed")
+    }
+  }
+  pub trait GeneratedValueSetBorderStyleDotted: GeneratedSkribbleValue {
+    /// The `dotted` value of the `border-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dotted(&self) -> String {
+      self.append_value("dotted")
+    }
+  }
+  pub trait GeneratedValueSetBorderStyleDouble: GeneratedSkribbleValue {
+    /// The `double` value of the `border-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn double(&self) -> String {
+      self.append_value("double")
+    }
+  }
+  pub trait GeneratedValueSetBorderStyleHidden: GeneratedSkribbleValue {
+    /// The `hidden` value of the `border-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn hidden(&self) -> String {
+      self.append_value("hidden")
+    }
+  }
+  pub trait GeneratedValueSetBorderStyleNone: GeneratedSkribbleValue {
+    /// The `none` value of the `border-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetScaleN0: GeneratedSkribbleValue {
+    /// The `0` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetScaleN50: GeneratedSkribbleValue {
+    /// The `50` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n50(&self) -> String {
+      self.append_value("50")
+    }
+  }
+  pub trait GeneratedValueSetScaleN75: GeneratedSkribbleValue {
+    /// The `75` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n75(&self) -> String {
+      self.append_value("75")
+    }
+  }
+  pub trait GeneratedValueSetScaleN90: GeneratedSkribbleValue {
+    /// The `90` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n90(&self) -> String {
+      self.append_value("90")
+    }
+  }
+  pub trait GeneratedValueSetScaleN95: GeneratedSkribbleValue {
+    /// The `95` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n95(&self) -> String {
+      self.append_value("95")
+    }
+  }
+  pub trait GeneratedValueSetScaleN100: GeneratedSkribbleValue {
+    /// The `100` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n100(&self) -> String {
+      self.append_value("100")
+    }
+  }
+  pub trait GeneratedValueSetScaleN105: GeneratedSkribbleValue {
+    /// The `105` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n105(&self) -> String {
+      self.append_value("105")
+    }
+  }
+  pub trait GeneratedValueSetScaleN110: GeneratedSkribbleValue {
+    /// The `110` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n110(&self) -> String {
+      self.append_value("110")
+    }
+  }
+  pub trait GeneratedValueSetScaleN125: GeneratedSkribbleValue {
+    /// The `125` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n125(&self) -> String {
+      self.append_value("125")
+    }
+  }
+  pub trait GeneratedValueSetScaleN150: GeneratedSkribbleValue {
+    /// The `150` value of the `scale` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n150(&self) -> String {
+      self.append_value("150")
+    }
+  }
+  pub trait GeneratedValueSetSkewN0: GeneratedSkribbleValue {
+    /// The `0` value of the `skew` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetSkewN1: GeneratedSkribbleValue {
+    /// The `1` value of the `skew` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetSkewN2: GeneratedSkribbleValue {
+    /// The `2` value of the `skew` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetSkewN3: GeneratedSkribbleValue {
+    /// The `3` value of the `skew` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n3(&self) -> String {
+      self.append_value("3")
+    }
+  }
+  pub trait GeneratedValueSetSkewN6: GeneratedSkribbleValue {
+    /// The `6` value of the `skew` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n6(&self) -> String {
+      self.append_value("6")
+    }
+  }
+  pub trait GeneratedValueSetSkewN12: GeneratedSkribbleValue {
+    /// The `12` value of the `skew` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n12(&self) -> String {
+      self.append_value("12")
+    }
+  }
+  pub trait GeneratedValueSetOriginCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetOriginTop: GeneratedSkribbleValue {
+    /// The `top` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn top(&self) -> String {
+      self.append_value("top")
+    }
+  }
+  pub trait GeneratedValueSetOriginTopRight: GeneratedSkribbleValue {
+    /// The `top-right` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn top_right(&self) -> String {
+      self.append_value("top-right")
+    }
+  }
+  pub trait GeneratedValueSetOriginRight: GeneratedSkribbleValue {
+    /// The `right` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn right(&self) -> String {
+      self.append_value("right")
+    }
+  }
+  pub trait GeneratedValueSetOriginBottomRight: GeneratedSkribbleValue {
+    /// The `bottom-right` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bottom_right(&self) -> String {
+      self.append_value("bottom-right")
+    }
+  }
+  pub trait GeneratedValueSetOriginBottom: GeneratedSkribbleValue {
+    /// The `bottom` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bottom(&self) -> String {
+      self.append_value("bottom")
+    }
+  }
+  pub trait GeneratedValueSetOriginBottomLeft: GeneratedSkribbleValue {
+    /// The `bottom-left` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn bottom_left(&self) -> String {
+      self.append_value("bottom-left")
+    }
+  }
+  pub trait GeneratedValueSetOriginLeft: GeneratedSkribbleValue {
+    /// The `left` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn left(&self) -> String {
+      self.append_value("left")
+    }
+  }
+  pub trait GeneratedValueSetOriginTopLeft: GeneratedSkribbleValue {
+    /// The `top-left` value of the `origin` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn top_left(&self) -> String {
+      self.append_value("top-left")
+    }
+  }
+  pub trait GeneratedValueSetDivideXN0: GeneratedSkribbleValue {
+    /// The `0` value of the `divide-x` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetDivideXN2: GeneratedSkribbleValue {
+    /// The `2` value of the `divide-x` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetDivideXN4: GeneratedSkribbleValue {
+    /// The `4` value of the `divide-x` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetDivideXN8: GeneratedSkribbleValue {
+    /// The `8` value of the `divide-x` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetDivideXDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `divide-x` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetDivideYN0: GeneratedSkribbleValue {
+    /// The `0` value of the `divide-y` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetDivideYN2: GeneratedSkribbleValue {
+    /// The `2` value of the `divide-y` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetDivideYN4: GeneratedSkribbleValue {
+    /// The `4` value of the `divide-y` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetDivideYN8: GeneratedSkribbleValue {
+    /// The `8` value of the `divide-y` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetDivideYDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `divide-y` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetDivideStyleSolid: GeneratedSkribbleValue {
+    /// The `solid` value of the `divide-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn solid(&self) -> String {
+      self.append_value("solid")
+    }
+  }
+  pub trait GeneratedValueSetDivideStyleDashed: GeneratedSkribbleValue {
+    /// The `dashed` value of the `divide-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dashed(&self) -> String {
+      self.append_value("dashed")
+    }
+  }
+  pub trait GeneratedValueSetDivideStyleDotted: GeneratedSkribbleValue {
+    /// The `dotted` value of the `divide-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dotted(&self) -> String {
+      self.append_value("dotted")
+    }
+  }
+  pub trait GeneratedValueSetDivideStyleDouble: GeneratedSkribbleValue {
+    /// The `double` value of the `divide-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn double(&self) -> String {
+      self.append_value("double")
+    }
+  }
+  pub trait GeneratedValueSetDivideStyleNone: GeneratedSkribbleValue {
+    /// The `none` value of the `divide-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetOutlineWidthN0: GeneratedSkribbleValue {
+    /// The `0` value of the `outline-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetOutlineWidthN1: GeneratedSkribbleValue {
+    /// The `1` value of the `outline-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetOutlineWidthN2: GeneratedSkribbleValue {
+    /// The `2` value of the `outline-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetOutlineWidthN4: GeneratedSkribbleValue {
+    /// The `4` value of the `outline-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetOutlineWidthN8: GeneratedSkribbleValue {
+    /// The `8` value of the `outline-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetRingWidthN0: GeneratedSkribbleValue {
+    /// The `0` value of the `ring-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n0(&self) -> String {
+      self.append_value("0")
+    }
+  }
+  pub trait GeneratedValueSetRingWidthN1: GeneratedSkribbleValue {
+    /// The `1` value of the `ring-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n1(&self) -> String {
+      self.append_value("1")
+    }
+  }
+  pub trait GeneratedValueSetRingWidthN2: GeneratedSkribbleValue {
+    /// The `2` value of the `ring-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2(&self) -> String {
+      self.append_value("2")
+    }
+  }
+  pub trait GeneratedValueSetRingWidthN4: GeneratedSkribbleValue {
+    /// The `4` value of the `ring-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n4(&self) -> String {
+      self.append_value("4")
+    }
+  }
+  pub trait GeneratedValueSetRingWidthN8: GeneratedSkribbleValue {
+    /// The `8` value of the `ring-width` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n8(&self) -> String {
+      self.append_value("8")
+    }
+  }
+  pub trait GeneratedValueSetOutlineStyleDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `outline-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetOutlineStyleDashed: GeneratedSkribbleValue {
+    /// The `dashed` value of the `outline-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dashed(&self) -> String {
+      self.append_value("dashed")
+    }
+  }
+  pub trait GeneratedValueSetOutlineStyleDotted: GeneratedSkribbleValue {
+    /// The `dotted` value of the `outline-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn dotted(&self) -> String {
+      self.append_value("dotted")
+    }
+  }
+  pub trait GeneratedValueSetOutlineStyleDouble: GeneratedSkribbleValue {
+    /// The `double` value of the `outline-style` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn double(&self) -> String {
+      self.append_value("double")
+    }
+  }
+  pub trait GeneratedValueSetShadowSm: GeneratedSkribbleValue {
+    /// The `sm` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sm(&self) -> String {
+      self.append_value("sm")
+    }
+  }
+  pub trait GeneratedValueSetShadowDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetShadowMd: GeneratedSkribbleValue {
+    /// The `md` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn md(&self) -> String {
+      self.append_value("md")
+    }
+  }
+  pub trait GeneratedValueSetShadowLg: GeneratedSkribbleValue {
+    /// The `lg` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lg(&self) -> String {
+      self.append_value("lg")
+    }
+  }
+  pub trait GeneratedValueSetShadowXl: GeneratedSkribbleValue {
+    /// The `xl` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn xl(&self) -> String {
+      self.append_value("xl")
+    }
+  }
+  pub trait GeneratedValueSetShadowN2xl: GeneratedSkribbleValue {
+    /// The `2xl` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n2xl(&self) -> String {
+      self.append_value("2xl")
+    }
+  }
+  pub trait GeneratedValueSetShadowInner: GeneratedSkribbleValue {
+    /// The `inner` value of the `shadow` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn inner(&self) -> String {
+      self.append_value("inner")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendMultiply: GeneratedSkribbleValue {
+    /// The `multiply` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn multiply(&self) -> String {
+      self.append_value("multiply")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendScreen: GeneratedSkribbleValue {
+    /// The `screen` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn screen(&self) -> String {
+      self.append_value("screen")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendOverlay: GeneratedSkribbleValue {
+    /// The `overlay` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn overlay(&self) -> String {
+      self.append_value("overlay")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendDarken: GeneratedSkribbleValue {
+    /// The `darken` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn darken(&self) -> String {
+      self.append_value("darken")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendLighten: GeneratedSkribbleValue {
+    /// The `lighten` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn lighten(&self) -> String {
+      self.append_value("lighten")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendColorDodge: GeneratedSkribbleValue {
+    /// The `color-dodge` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn color_dodge(&self) -> String {
+      self.append_value("color-dodge")
+    }
+  }
+  pub trait GeneratedValueSetMixBlendColorBurn: GeneratedSkribbleValue {
+    /// The `color-burn` value of the `mix-blend` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn color_burn(&self) -> String {
+      self.append_value("color-burn")
+    }
+  }
+  pub trait GeneratedValueSetCursorAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetCursorDefault: GeneratedSkribbleValue {
+    /// The `default` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn default(&self) -> String {
+      self.append_value("default")
+    }
+  }
+  pub trait GeneratedValueSetCursorPointer: GeneratedSkribbleValue {
+    /// The `pointer` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pointer(&self) -> String {
+      self.append_value("pointer")
+    }
+  }
+  pub trait GeneratedValueSetCursorWait: GeneratedSkribbleValue {
+    /// The `wait` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn wait(&self) -> String {
+      self.append_value("wait")
+    }
+  }
+  pub trait GeneratedValueSetCursorText: GeneratedSkribbleValue {
+    /// The `text` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn text(&self) -> String {
+      self.append_value("text")
+    }
+  }
+  pub trait GeneratedValueSetCursorRMove: GeneratedSkribbleValue {
+    /// The `move` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn r#move(&self) -> String {
+      self.append_value("move")
+    }
+  }
+  pub trait GeneratedValueSetCursorHelp: GeneratedSkribbleValue {
+    /// The `help` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn help(&self) -> String {
+      self.append_value("help")
+    }
+  }
+  pub trait GeneratedValueSetCursorNotAllowed: GeneratedSkribbleValue {
+    /// The `not-allowed` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn not_allowed(&self) -> String {
+      self.append_value("not-allowed")
+    }
+  }
+  pub trait GeneratedValueSetCursorNone: GeneratedSkribbleValue {
+    /// The `none` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetCursorContextMenu: GeneratedSkribbleValue {
+    /// The `context-menu` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn context_menu(&self) -> String {
+      self.append_value("context-menu")
+    }
+  }
+  pub trait GeneratedValueSetCursorProgress: GeneratedSkribbleValue {
+    /// The `progress` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn progress(&self) -> String {
+      self.append_value("progress")
+    }
+  }
+  pub trait GeneratedValueSetCursorCell: GeneratedSkribbleValue {
+    /// The `cell` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn cell(&self) -> String {
+      self.append_value("cell")
+    }
+  }
+  pub trait GeneratedValueSetCursorCrosshair: GeneratedSkribbleValue {
+    /// The `crosshair` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn crosshair(&self) -> String {
+      self.append_value("crosshair")
+    }
+  }
+  pub trait GeneratedValueSetCursorVerticalText: GeneratedSkribbleValue {
+    /// The `vertical-text` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn vertical_text(&self) -> String {
+      self.append_value("vertical-text")
+    }
+  }
+  pub trait GeneratedValueSetCursorAlias: GeneratedSkribbleValue {
+    /// The `alias` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn alias(&self) -> String {
+      self.append_value("alias")
+    }
+  }
+  pub trait GeneratedValueSetCursorCopy: GeneratedSkribbleValue {
+    /// The `copy` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn copy(&self) -> String {
+      self.append_value("copy")
+    }
+  }
+  pub trait GeneratedValueSetCursorNoDrop: GeneratedSkribbleValue {
+    /// The `no-drop` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn no_drop(&self) -> String {
+      self.append_value("no-drop")
+    }
+  }
+  pub trait GeneratedValueSetCursorGrab: GeneratedSkribbleValue {
+    /// The `grab` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grab(&self) -> String {
+      self.append_value("grab")
+    }
+  }
+  pub trait GeneratedValueSetCursorGrabbing: GeneratedSkribbleValue {
+    /// The `grabbing` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn grabbing(&self) -> String {
+      self.append_value("grabbing")
+    }
+  }
+  pub trait GeneratedValueSetCursorAllScroll: GeneratedSkribbleValue {
+    /// The `all-scroll` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn all_scroll(&self) -> String {
+      self.append_value("all-scroll")
+    }
+  }
+  pub trait GeneratedValueSetCursorColResize: GeneratedSkribbleValue {
+    /// The `col-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn col_resize(&self) -> String {
+      self.append_value("col-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorRowResize: GeneratedSkribbleValue {
+    /// The `row-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn row_resize(&self) -> String {
+      self.append_value("row-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorNResize: GeneratedSkribbleValue {
+    /// The `n-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn n_resize(&self) -> String {
+      self.append_value("n-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorEResize: GeneratedSkribbleValue {
+    /// The `e-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn e_resize(&self) -> String {
+      self.append_value("e-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorSResize: GeneratedSkribbleValue {
+    /// The `s-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn s_resize(&self) -> String {
+      self.append_value("s-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorWResize: GeneratedSkribbleValue {
+    /// The `w-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn w_resize(&self) -> String {
+      self.append_value("w-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorNeResize: GeneratedSkribbleValue {
+    /// The `ne-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn ne_resize(&self) -> String {
+      self.append_value("ne-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorNwResize: GeneratedSkribbleValue {
+    /// The `nw-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn nw_resize(&self) -> String {
+      self.append_value("nw-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorSeResize: GeneratedSkribbleValue {
+    /// The `se-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn se_resize(&self) -> String {
+      self.append_value("se-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorSwResize: GeneratedSkribbleValue {
+    /// The `sw-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn sw_resize(&self) -> String {
+      self.append_value("sw-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorEwResize: GeneratedSkribbleValue {
+    /// The `ew-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn ew_resize(&self) -> String {
+      self.append_value("ew-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorNsResize: GeneratedSkribbleValue {
+    /// The `ns-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn ns_resize(&self) -> String {
+      self.append_value("ns-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorNeswResize: GeneratedSkribbleValue {
+    /// The `nesw-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn nesw_resize(&self) -> String {
+      self.append_value("nesw-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorNwseResize: GeneratedSkribbleValue {
+    /// The `nwse-resize` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn nwse_resize(&self) -> String {
+      self.append_value("nwse-resize")
+    }
+  }
+  pub trait GeneratedValueSetCursorZoomIn: GeneratedSkribbleValue {
+    /// The `zoom-in` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn zoom_in(&self) -> String {
+      self.append_value("zoom-in")
+    }
+  }
+  pub trait GeneratedValueSetCursorZoomOut: GeneratedSkribbleValue {
+    /// The `zoom-out` value of the `cursor` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn zoom_out(&self) -> String {
+      self.append_value("zoom-out")
+    }
+  }
+  pub trait GeneratedValueSetPointerEventsNone: GeneratedSkribbleValue {
+    /// The `none` value of the `pointer-events` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetPointerEventsAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `pointer-events` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetResizeNone: GeneratedSkribbleValue {
+    /// The `none` value of the `resize` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetResizeY: GeneratedSkribbleValue {
+    /// The `y` value of the `resize` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn y(&self) -> String {
+      self.append_value("y")
+    }
+  }
+  pub trait GeneratedValueSetResizeX: GeneratedSkribbleValue {
+    /// The `x` value of the `resize` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn x(&self) -> String {
+      self.append_value("x")
+    }
+  }
+  pub trait GeneratedValueSetResizeBoth: GeneratedSkribbleValue {
+    /// The `both` value of the `resize` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn both(&self) -> String {
+      self.append_value("both")
+    }
+  }
+  pub trait GeneratedValueSetScrollBehaviorAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `scroll-behavior` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetScrollBehaviorSmooth: GeneratedSkribbleValue {
+    /// The `smooth` value of the `scroll-behavior` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn smooth(&self) -> String {
+      self.append_value("smooth")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapAlignStart: GeneratedSkribbleValue {
+    /// The `start` value of the `scroll-snap-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn start(&self) -> String {
+      self.append_value("start")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapAlignEnd: GeneratedSkribbleValue {
+    /// The `end` value of the `scroll-snap-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn end(&self) -> String {
+      self.append_value("end")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapAlignCenter: GeneratedSkribbleValue {
+    /// The `center` value of the `scroll-snap-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn center(&self) -> String {
+      self.append_value("center")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapAlignNone: GeneratedSkribbleValue {
+    /// The `none` value of the `scroll-snap-align` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapStopNormal: GeneratedSkribbleValue {
+    /// The `normal` value of the `scroll-snap-stop` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn normal(&self) -> String {
+      self.append_value("normal")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapStopAlways: GeneratedSkribbleValue {
+    /// The `always` value of the `scroll-snap-stop` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn always(&self) -> String {
+      self.append_value("always")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapTypeNone: GeneratedSkribbleValue {
+    /// The `none` value of the `scroll-snap-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapTypeX: GeneratedSkribbleValue {
+    /// The `x` value of the `scroll-snap-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn x(&self) -> String {
+      self.append_value("x")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapTypeY: GeneratedSkribbleValue {
+    /// The `y` value of the `scroll-snap-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn y(&self) -> String {
+      self.append_value("y")
+    }
+  }
+  pub trait GeneratedValueSetScrollSnapTypeBoth: GeneratedSkribbleValue {
+    /// The `both` value of the `scroll-snap-type` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn both(&self) -> String {
+      self.append_value("both")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionNone: GeneratedSkribbleValue {
+    /// The `none` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPanX: GeneratedSkribbleValue {
+    /// The `pan-x` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pan_x(&self) -> String {
+      self.append_value("pan-x")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPanLeft: GeneratedSkribbleValue {
+    /// The `pan-left` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pan_left(&self) -> String {
+      self.append_value("pan-left")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPanRight: GeneratedSkribbleValue {
+    /// The `pan-right` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pan_right(&self) -> String {
+      self.append_value("pan-right")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPanY: GeneratedSkribbleValue {
+    /// The `pan-y` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pan_y(&self) -> String {
+      self.append_value("pan-y")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPanUp: GeneratedSkribbleValue {
+    /// The `pan-up` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pan_up(&self) -> String {
+      self.append_value("pan-up")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPanDown: GeneratedSkribbleValue {
+    /// The `pan-down` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pan_down(&self) -> String {
+      self.append_value("pan-down")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionPinchZoom: GeneratedSkribbleValue {
+    /// The `pinch-zoom` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn pinch_zoom(&self) -> String {
+      self.append_value("pinch-zoom")
+    }
+  }
+  pub trait GeneratedValueSetTouchActionManipulation: GeneratedSkribbleValue {
+    /// The `manipulation` value of the `touch-action` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn manipulation(&self) -> String {
+      self.append_value("manipulation")
+    }
+  }
+  pub trait GeneratedValueSetUserSelectNone: GeneratedSkribbleValue {
+    /// The `none` value of the `user-select` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn none(&self) -> String {
+      self.append_value("none")
+    }
+  }
+  pub trait GeneratedValueSetUserSelectText: GeneratedSkribbleValue {
+    /// The `text` value of the `user-select` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn text(&self) -> String {
+      self.append_value("text")
+    }
+  }
+  pub trait GeneratedValueSetUserSelectAll: GeneratedSkribbleValue {
+    /// The `all` value of the `user-select` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn all(&self) -> String {
+      self.append_value("all")
+    }
+  }
+  pub trait GeneratedValueSetUserSelectAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `user-select` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetWillChangeAuto: GeneratedSkribbleValue {
+    /// The `auto` value of the `will-change` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn auto(&self) -> String {
+      self.append_value("auto")
+    }
+  }
+  pub trait GeneratedValueSetWillChangeScroll: GeneratedSkribbleValue {
+    /// The `scroll` value of the `will-change` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn scroll(&self) -> String {
+      self.append_value("scroll")
+    }
+  }
+  pub trait GeneratedValueSetWillChangeContents: GeneratedSkribbleValue {
+    /// The `contents` value of the `will-change` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn contents(&self) -> String {
+      self.append_value("contents")
+    }
+  }
+  pub trait GeneratedValueSetWillChangeTransform: GeneratedSkribbleValue {
+    /// The `transform` value of the `will-change` value set. The declarations which each atom produces are documented on its implementation of this trait.
+    #[inline]
+    fn transform(&self) -> String {
+      self.append_value("transform")
+    }
+  }
+}
+#[cfg(not(feature = "skribble-skip-value-sets"))]
+pub use value_sets::*;
+#[cfg(not(feature = "skribble-skip-atom-p"))]
+mod atom_p {
+  use super::*;
+  pub struct GeneratedAtomPChild(String);
+  impl GeneratedSkribbleValue for GeneratedAtomPChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  /// `p:$0`: `padding: 0px;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN0 for GeneratedAtomPChild {}
+  /// `p:$1`: `padding: 0.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN1 for GeneratedAtomPChild {}
+  /// `p:$2`: `padding: 0.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN2 for GeneratedAtomPChild {}
+  /// `p:$3`: `padding: 0.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN3 for GeneratedAtomPChild {}
+  /// `p:$4`: `padding: 1rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN4 for GeneratedAtomPChild {}
+  /// `p:$5`: `padding: 1.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN5 for GeneratedAtomPChild {}
+  /// `p:$6`: `padding: 1.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN6 for GeneratedAtomPChild {}
+  /// `p:$7`: `padding: 1.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN7 for GeneratedAtomPChild {}
+  /// `p:$8`: `padding: 2rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN8 for GeneratedAtomPChild {}
+  /// `p:$9`: `padding: 2.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN9 for GeneratedAtomPChild {}
+  /// `p:$10`: `padding: 2.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN10 for GeneratedAtomPChild {}
+  /// `p:$11`: `padding: 2.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN11 for GeneratedAtomPChild {}
+  /// `p:$12`: `padding: 3rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN12 for GeneratedAtomPChild {}
+  /// `p:$14`: `padding: 3.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN14 for GeneratedAtomPChild {}
+  /// `p:$16`: `padding: 4rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN16 for GeneratedAtomPChild {}
+  /// `p:$20`: `padding: 5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN20 for GeneratedAtomPChild {}
+  /// `p:$24`: `padding: 6rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN24 for GeneratedAtomPChild {}
+  /// `p:$28`: `padding: 7rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN28 for GeneratedAtomPChild {}
+  /// `p:$32`: `padding: 8rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN32 for GeneratedAtomPChild {}
+  /// `p:$36`: `padding: 9rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN36 for GeneratedAtomPChild {}
+  /// `p:$40`: `padding: 10rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN40 for GeneratedAtomPChild {}
+  /// `p:$44`: `padding: 11rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN44 for GeneratedAtomPChild {}
+  /// `p:$48`: `padding: 12rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN48 for GeneratedAtomPChild {}
+  /// `p:$52`: `padding: 13rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN52 for GeneratedAtomPChild {}
+  /// `p:$56`: `padding: 14rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN56 for GeneratedAtomPChild {}
+  /// `p:$60`: `padding: 15rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN60 for GeneratedAtomPChild {}
+  /// `p:$64`: `padding: 16rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN64 for GeneratedAtomPChild {}
+  /// `p:$72`: `padding: 18rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN72 for GeneratedAtomPChild {}
+  /// `p:$80`: `padding: 20rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN80 for GeneratedAtomPChild {}
+  /// `p:$96`: `padding: 24rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN96 for GeneratedAtomPChild {}
+  /// `p:$px`: `padding: 1px;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingPx for GeneratedAtomPChild {}
+  /// `p:$0.5`: `padding: 0.125rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN05 for GeneratedAtomPChild {}
+  /// `p:$1.5`: `padding: 0.375rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN15 for GeneratedAtomPChild {}
+  /// `p:$2.5`: `padding: 0.625rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN25 for GeneratedAtomPChild {}
+  /// `p:$3.5`: `padding: 0.875rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN35 for GeneratedAtomPChild {}
+  /// `p:$-1`: `padding: -0.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM1 for GeneratedAtomPChild {}
+  /// `p:$-2`: `padding: -0.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM2 for GeneratedAtomPChild {}
+  /// `p:$-3`: `padding: -0.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM3 for GeneratedAtomPChild {}
+  /// `p:$-4`: `padding: -1rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM4 for GeneratedAtomPChild {}
+  /// `p:$-5`: `padding: -1.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM5 for GeneratedAtomPChild {}
+  /// `p:$-6`: `padding: -1.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM6 for GeneratedAtomPChild {}
+  /// `p:$-7`: `padding: -1.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM7 for GeneratedAtomPChild {}
+  /// `p:$-8`: `padding: -2rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM8 for GeneratedAtomPChild {}
+  /// `p:$-9`: `padding: -2.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM9 for GeneratedAtomPChild {}
+  /// `p:$-10`: `padding: -2.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM10 for GeneratedAtomPChild {}
+  /// `p:$-11`: `padding: -2.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM11 for GeneratedAtomPChild {}
+  /// `p:$-12`: `padding: -3rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM12 for GeneratedAtomPChild {}
+  /// `p:$-14`: `padding: -3.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM14 for GeneratedAtomPChild {}
+  /// `p:$-16`: `padding: -4rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM16 for GeneratedAtomPChild {}
+  /// `p:$-20`: `padding: -5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM20 for GeneratedAtomPChild {}
+  /// `p:$-24`: `padding: -6rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM24 for GeneratedAtomPChild {}
+  /// `p:$-28`: `padding: -7rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM28 for GeneratedAtomPChild {}
+  /// `p:$-32`: `padding: -8rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM32 for GeneratedAtomPChild {}
+  /// `p:$-36`: `padding: -9rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM36 for GeneratedAtomPChild {}
+  /// `p:$-40`: `padding: -10rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM40 for GeneratedAtomPChild {}
+  /// `p:$-44`: `padding: -11rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM44 for GeneratedAtomPChild {}
+  /// `p:$-48`: `padding: -12rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM48 for GeneratedAtomPChild {}
+  /// `p:$-52`: `padding: -13rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM52 for GeneratedAtomPChild {}
+  /// `p:$-56`: `padding: -14rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM56 for GeneratedAtomPChild {}
+  /// `p:$-60`: `padding: -15rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM60 for GeneratedAtomPChild {}
+  /// `p:$-64`: `padding: -16rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM64 for GeneratedAtomPChild {}
+  /// `p:$-72`: `padding: -18rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM72 for GeneratedAtomPChild {}
+  /// `p:$-80`: `padding: -20rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM80 for GeneratedAtomPChild {}
+  /// `p:$-96`: `padding: -24rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM96 for GeneratedAtomPChild {}
+  /// `p:$-px`: `padding: -1px;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingMpx for GeneratedAtomPChild {}
+  /// `p:$-0.5`: `padding: -0.125rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM05 for GeneratedAtomPChild {}
+  /// `p:$-1.5`: `padding: -0.375rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM15 for GeneratedAtomPChild {}
+  /// `p:$-2.5`: `padding: -0.625rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM25 for GeneratedAtomPChild {}
+  /// `p:$-3.5`: `padding: -0.875rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM35 for GeneratedAtomPChild {}
+  pub trait GeneratedAtomP: GeneratedSkribbleValue {
+    #[inline]
+    fn p(&self) -> GeneratedAtomPChild {
+      GeneratedAtomPChild::from_ref(self.append("p"))
+    }
+    #[inline]
+    fn p_(&self, value: &'static str) -> String {
+      self.append(format!("p:[{}]", value.trim()))
+    }
+  }
+  impl GeneratedAtomP for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomP for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomP for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomP for GeneratedTransformerImportantGroupImportantChild {}
+}
+#[cfg(not(feature = "skribble-skip-atom-p"))]
+pub use atom_p::*;
+#[cfg(not(feature = "skribble-skip-atom-pt"))]
+mod atom_pt {
+  use super::*;
+  pub struct GeneratedAtomPtChild(String);
+  impl GeneratedSkribbleValue for GeneratedAtomPtChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  /// `pt:$0`: `padding-top: 0px;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN0 for GeneratedAtomPtChild {}
+  /// `pt:$1`: `padding-top: 0.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN1 for GeneratedAtomPtChild {}
+  /// `pt:$2`: `padding-top: 0.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN2 for GeneratedAtomPtChild {}
+  /// `pt:$3`: `padding-top: 0.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN3 for GeneratedAtomPtChild {}
+  /// `pt:$4`: `padding-top: 1rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN4 for GeneratedAtomPtChild {}
+  /// `pt:$5`: `padding-top: 1.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN5 for GeneratedAtomPtChild {}
+  /// `pt:$6`: `padding-top: 1.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN6 for GeneratedAtomPtChild {}
+  /// `pt:$7`: `padding-top: 1.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN7 for GeneratedAtomPtChild {}
+  /// `pt:$8`: `padding-top: 2rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN8 for GeneratedAtomPtChild {}
+  /// `pt:$9`: `padding-top: 2.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN9 for GeneratedAtomPtChild {}
+  /// `pt:$10`: `padding-top: 2.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN10 for GeneratedAtomPtChild {}
+  /// `pt:$11`: `padding-top: 2.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN11 for GeneratedAtomPtChild {}
+  /// `pt:$12`: `padding-top: 3rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN12 for GeneratedAtomPtChild {}
+  /// `pt:$14`: `padding-top: 3.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN14 for GeneratedAtomPtChild {}
+  /// `pt:$16`: `padding-top: 4rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN16 for GeneratedAtomPtChild {}
+  /// `pt:$20`: `padding-top: 5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN20 for GeneratedAtomPtChild {}
+  /// `pt:$24`: `padding-top: 6rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN24 for GeneratedAtomPtChild {}
+  /// `pt:$28`: `padding-top: 7rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN28 for GeneratedAtomPtChild {}
+  /// `pt:$32`: `padding-top: 8rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN32 for GeneratedAtomPtChild {}
+  /// `pt:$36`: `padding-top: 9rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN36 for GeneratedAtomPtChild {}
+  /// `pt:$40`: `padding-top: 10rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN40 for GeneratedAtomPtChild {}
+  /// `pt:$44`: `padding-top: 11rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN44 for GeneratedAtomPtChild {}
+  /// `pt:$48`: `padding-top: 12rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN48 for GeneratedAtomPtChild {}
+  /// `pt:$52`: `padding-top: 13rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN52 for GeneratedAtomPtChild {}
+  /// `pt:$56`: `padding-top: 14rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN56 for GeneratedAtomPtChild {}
+  /// `pt:$60`: `padding-top: 15rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN60 for GeneratedAtomPtChild {}
+  /// `pt:$64`: `padding-top: 16rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN64 for GeneratedAtomPtChild {}
+  /// `pt:$72`: `padding-top: 18rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN72 for GeneratedAtomPtChild {}
+  /// `pt:$80`: `padding-top: 20rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN80 for GeneratedAtomPtChild {}
+  /// `pt:$96`: `padding-top: 24rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN96 for GeneratedAtomPtChild {}
+  /// `pt:$px`: `padding-top: 1px;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingPx for GeneratedAtomPtChild {}
+  /// `pt:$0.5`: `padding-top: 0.125rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN05 for GeneratedAtomPtChild {}
+  /// `pt:$1.5`: `padding-top: 0.375rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN15 for GeneratedAtomPtChild {}
+  /// `pt:$2.5`: `padding-top: 0.625rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN25 for GeneratedAtomPtChild {}
+  /// `pt:$3.5`: `padding-top: 0.875rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetSpacingN35 for GeneratedAtomPtChild {}
+  /// `pt:$-1`: `padding-top: -0.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM1 for GeneratedAtomPtChild {}
+  /// `pt:$-2`: `padding-top: -0.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM2 for GeneratedAtomPtChild {}
+  /// `pt:$-3`: `padding-top: -0.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM3 for GeneratedAtomPtChild {}
+  /// `pt:$-4`: `padding-top: -1rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM4 for GeneratedAtomPtChild {}
+  /// `pt:$-5`: `padding-top: -1.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM5 for GeneratedAtomPtChild {}
+  /// `pt:$-6`: `padding-top: -1.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM6 for GeneratedAtomPtChild {}
+  /// `pt:$-7`: `padding-top: -1.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM7 for GeneratedAtomPtChild {}
+  /// `pt:$-8`: `padding-top: -2rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM8 for GeneratedAtomPtChild {}
+  /// `pt:$-9`: `padding-top: -2.25rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM9 for GeneratedAtomPtChild {}
+  /// `pt:$-10`: `padding-top: -2.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM10 for GeneratedAtomPtChild {}
+  /// `pt:$-11`: `padding-top: -2.75rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM11 for GeneratedAtomPtChild {}
+  /// `pt:$-12`: `padding-top: -3rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM12 for GeneratedAtomPtChild {}
+  /// `pt:$-14`: `padding-top: -3.5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM14 for GeneratedAtomPtChild {}
+  /// `pt:$-16`: `padding-top: -4rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM16 for GeneratedAtomPtChild {}
+  /// `pt:$-20`: `padding-top: -5rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM20 for GeneratedAtomPtChild {}
+  /// `pt:$-24`: `padding-top: -6rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM24 for GeneratedAtomPtChild {}
+  /// `pt:$-28`: `padding-top: -7rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM28 for GeneratedAtomPtChild {}
+  /// `pt:$-32`: `padding-top: -8rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM32 for GeneratedAtomPtChild {}
+  /// `pt:$-36`: `padding-top: -9rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM36 for GeneratedAtomPtChild {}
+  /// `pt:$-40`: `padding-top: -10rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM40 for GeneratedAtomPtChild {}
+  /// `pt:$-44`: `padding-top: -11rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM44 for GeneratedAtomPtChild {}
+  /// `pt:$-48`: `padding-top: -12rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM48 for GeneratedAtomPtChild {}
+  /// `pt:$-52`: `padding-top: -13rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM52 for GeneratedAtomPtChild {}
+  /// `pt:$-56`: `padding-top: -14rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM56 for GeneratedAtomPtChild {}
+  /// `pt:$-60`: `padding-top: -15rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM60 for GeneratedAtomPtChild {}
+  /// `pt:$-64`: `padding-top: -16rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM64 for GeneratedAtomPtChild {}
+  /// `pt:$-72`: `padding-top: -18rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM72 for GeneratedAtomPtChild {}
+  /// `pt:$-80`: `padding-top: -20rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM80 for GeneratedAtomPtChild {}
+  /// `pt:$-96`: `padding-top: -24rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM96 for GeneratedAtomPtChild {}
+  /// `pt:$-px`: `padding-top: -1px;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingMpx for GeneratedAtomPtChild {}
+  /// `pt:$-0.5`: `padding-top: -0.125rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM05 for GeneratedAtomPtChild {}
+  /// `pt:$-1.5`: `padding-top: -0.375rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM15 for GeneratedAtomPtChild {}
+  /// `pt:$-2.5`: `padding-top: -0.625rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM25 for GeneratedAtomPtChild {}
+  /// `pt:$-3.5`: `padding-top: -0.875rem;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetNegativeSpacingM35 for GeneratedAtomPtChild {}
+  pub trait GeneratedAtomPt: GeneratedSkribbleValue {
+    #[inline]
+    fn pt(&self) -> GeneratedAtomPtChild {
+      GeneratedAtomPtChild::from_ref(self.append("pt"))
+    }
+    #[inline]
+    fn pt_(&self, value: &'static str) -> String {
+      self.append(format!("pt:[{}]", value.trim()))
+    }
+  }
+  impl GeneratedAtomPt for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomPt for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomPt for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomPt for GeneratedTransformerImportantGroupImportantChild {}
+}
+#[cfg(not(feature = "skribble-skip-atom-pt"))]
+pub use atom_pt::*;
+#[cfg(not(feature = "skribble-skip-atom-aspect"))]
+mod atom_aspect {
+  use super::*;
+  pub struct GeneratedAtomAspectChild(String);
+  impl GeneratedSkribbleValue for GeneratedAtomAspectChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  /// `aspect:$auto`: `aspect-ratio: auto;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetRatioAuto for GeneratedAtomAspectChild {}
+  /// `aspect:$square`: `aspect-ratio: 1;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetRatioSquare for GeneratedAtomAspectChild {}
+  /// `aspect:$video`: `aspect-ratio: 16 / 9;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetRatioVideo for GeneratedAtomAspectChild {}
+  /// `aspect:$portrait`: `aspect-ratio: 9 / 16;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetRatioPortrait for GeneratedAtomAspectChild {}
+  /// `aspect:$box`: `aspect-ratio: 4 / 3;`
+  #[cfg(not(feature = "skribble-skip-value-sets"))]
+  impl GeneratedValueSetRatioRBox for GeneratedAtomAspectChild {}
+  pub trait GeneratedAtomAspect: GeneratedSkribbleValue {
+    /// Control the aspect ratio of an element.
+    #[inline]
+    fn aspect(&self) -> GeneratedAtomAspectChild {
+      GeneratedAtomAspectChild::from_ref(self.append("aspect"))
+    }
+    #[inline]
+    fn aspect_(&self, value: &'static str) -> String {
+      self.append(format!("aspect:[{}]", value.trim()))
+    }
+  }
+  impl GeneratedAtomAspect for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomAspect for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomAspect for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomAspect for GeneratedTransformerImportantGroupImportantChild {}
+}
+#[cfg(not(feature = "skribble-skip-atom-aspect"))]
+pub use atom_aspect::*;
+#[cfg(not(feature = "skribble-skip-atom-text"))]
+mod atom_text {
+  use super::*;
+  pub struct GeneratedAtomTextChild(String);
+  impl GeneratedSkribbleValue for GeneratedAtomTextChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  /// `text:$primary`: `color: var(--sk-p, #570df8);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPrimary for GeneratedAtomTextChild {}
+  /// `text:$primary-content`: `color: var(--sk-pc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPrimaryContent for GeneratedAtomTextChild {}
+  /// `text:$secondary`: `color: var(--sk-s, #f000b8);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSecondary for GeneratedAtomTextChild {}
+  /// `text:$secondary-content`: `color: var(--sk-sc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSecondaryContent for GeneratedAtomTextChild {}
+  /// `text:$accent`: `color: var(--sk-a, #37cdbe);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAccent for GeneratedAtomTextChild {}
+  /// `text:$accent-content`: `color: var(--sk-ac, #163835);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAccentContent for GeneratedAtomTextChild {}
+  /// `text:$neutral`: `color: var(--sk-n, #3d4451);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral for GeneratedAtomTextChild {}
+  /// `text:$neutral-content`: `color: var(--sk-nc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutralContent for GeneratedAtomTextChild {}
+  /// `text:$base100`: `color: var(--sk-b1, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBase100 for GeneratedAtomTextChild {}
+  /// `text:$base200`: `color: var(--sk-b2, #F2F2F2);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBase200 for GeneratedAtomTextChild {}
+  /// `text:$base300`: `color: var(--sk-b3, #E5E6E6);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBase300 for GeneratedAtomTextChild {}
+  /// `text:$base-content`: `color: var(--sk-bc, #1f2937);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBaseContent for GeneratedAtomTextChild {}
+  /// `text:$info`: `color: var(--sk-in, #0070F3);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorInfo for GeneratedAtomTextChild {}
+  /// `text:$info-content`: `color: var(--sk-inc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorInfoContent for GeneratedAtomTextChild {}
+  /// `text:$success`: `color: var(--sk-su, #21CC51);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSuccess for GeneratedAtomTextChild {}
+  /// `text:$success-content`: `color: var(--sk-suc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSuccessContent for GeneratedAtomTextChild {}
+  /// `text:$warning`: `color: var(--sk-wa, #FF6154);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorWarning for GeneratedAtomTextChild {}
+  /// `text:$warning-content`: `color: var(--sk-wac, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorWarningContent for GeneratedAtomTextChild {}
+  /// `text:$error`: `color: var(--sk-er, #DE1C8D);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorError for GeneratedAtomTextChild {}
+  /// `text:$error-content`: `color: var(--sk-erc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorErrorContent for GeneratedAtomTextChild {}
+  /// `text:$ring-offset-color`: `color: var(--sk-ring-offset-color, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRingOffsetColor for GeneratedAtomTextChild {}
+  /// `text:$ring-color`: `color: var(--sk-ring-color, #3b82f680);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRingColor for GeneratedAtomTextChild {}
+  /// `text:$black`: `color: hsl(0 0% 0%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlack for GeneratedAtomTextChild {}
+  /// `text:$white`: `color: hsl(0 0% 100%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorWhite for GeneratedAtomTextChild {}
+  /// `text:$slate50`: `color: hsl(210 40% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate50 for GeneratedAtomTextChild {}
+  /// `text:$slate100`: `color: hsl(210 40% 96.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate100 for GeneratedAtomTextChild {}
+  /// `text:$slate200`: `color: hsl(214.2857 31.818182% 91.37255%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate200 for GeneratedAtomTextChild {}
+  /// `text:$slate300`: `color: hsl(212.72726 26.82927% 83.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate300 for GeneratedAtomTextChild {}
+  /// `text:$slate400`: `color: hsl(215 20.224718% 65.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate400 for GeneratedAtomTextChild {}
+  /// `text:$slate500`: `color: hsl(215.38461 16.317993% 46.862747%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate500 for GeneratedAtomTextChild {}
+  /// `text:$slate600`: `color: hsl(215.29413 19.31818% 34.509804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate600 for GeneratedAtomTextChild {}
+  /// `text:$slate700`: `color: hsl(215.29411 25% 26.666668%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate700 for GeneratedAtomTextChild {}
+  /// `text:$slate800`: `color: hsl(217.24138 32.584267% 17.450981%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate800 for GeneratedAtomTextChild {}
+  /// `text:$slate900`: `color: hsl(222.22221 47.368423% 11.176471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate900 for GeneratedAtomTextChild {}
+  /// `text:$slate950`: `color: hsl(228.57143 84% 4.901961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate950 for GeneratedAtomTextChild {}
+  /// `text:$gray50`: `color: hsl(210 20% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray50 for GeneratedAtomTextChild {}
+  /// `text:$gray100`: `color: hsl(220 14.285726% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray100 for GeneratedAtomTextChild {}
+  /// `text:$gray200`: `color: hsl(220 13.043478% 90.98039%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray200 for GeneratedAtomTextChild {}
+  /// `text:$gray300`: `color: hsl(216 12.195122% 83.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray300 for GeneratedAtomTextChild {}
+  /// `text:$gray400`: `color: hsl(217.89473 10.614524% 64.90196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray400 for GeneratedAtomTextChild {}
+  /// `text:$gray500`: `color: hsl(220 8.9361725% 46.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray500 for GeneratedAtomTextChild {}
+  /// `text:$gray600`: `color: hsl(215 13.793102% 34.11765%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray600 for GeneratedAtomTextChild {}
+  /// `text:$gray700`: `color: hsl(216.92308 19.117647% 26.666668%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray700 for GeneratedAtomTextChild {}
+  /// `text:$gray800`: `color: hsl(215 27.906975% 16.862745%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray800 for GeneratedAtomTextChild {}
+  /// `text:$gray900`: `color: hsl(220.90909 39.285713% 10.980393%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray900 for GeneratedAtomTextChild {}
+  /// `text:$gray950`: `color: hsl(224 71.42857% 4.117647%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray950 for GeneratedAtomTextChild {}
+  /// `text:$zinc50`: `color: hsl(0 0% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc50 for GeneratedAtomTextChild {}
+  /// `text:$zinc100`: `color: hsl(240 4.761908% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc100 for GeneratedAtomTextChild {}
+  /// `text:$zinc200`: `color: hsl(240 5.882351% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc200 for GeneratedAtomTextChild {}
+  /// `text:$zinc300`: `color: hsl(240 4.8780484% 83.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc300 for GeneratedAtomTextChild {}
+  /// `text:$zinc400`: `color: hsl(240 5.0279326% 64.90196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc400 for GeneratedAtomTextChild {}
+  /// `text:$zinc500`: `color: hsl(240 3.829787% 46.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc500 for GeneratedAtomTextChild {}
+  /// `text:$zinc600`: `color: hsl(240 5.2023115% 33.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc600 for GeneratedAtomTextChild {}
+  /// `text:$zinc700`: `color: hsl(240 5.26316% 26.078432%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc700 for GeneratedAtomTextChild {}
+  /// `text:$zinc800`: `color: hsl(240 3.7037034% 15.882354%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc800 for GeneratedAtomTextChild {}
+  /// `text:$zinc900`: `color: hsl(240 5.882353% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc900 for GeneratedAtomTextChild {}
+  /// `text:$zinc950`: `color: hsl(240 9.999999% 3.9215689%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc950 for GeneratedAtomTextChild {}
+  /// `text:$neutral50`: `color: hsl(0 0% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral50 for GeneratedAtomTextChild {}
+  /// `text:$neutral100`: `color: hsl(0 0% 96.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral100 for GeneratedAtomTextChild {}
+  /// `text:$neutral200`: `color: hsl(0 0% 89.803925%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral200 for GeneratedAtomTextChild {}
+  /// `text:$neutral300`: `color: hsl(0 0% 83.13725%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral300 for GeneratedAtomTextChild {}
+  /// `text:$neutral400`: `color: hsl(0 0% 63.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral400 for GeneratedAtomTextChild {}
+  /// `text:$neutral500`: `color: hsl(0 0% 45.098038%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral500 for GeneratedAtomTextChild {}
+  /// `text:$neutral600`: `color: hsl(0 0% 32.156864%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral600 for GeneratedAtomTextChild {}
+  /// `text:$neutral700`: `color: hsl(0 0% 25.098042%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral700 for GeneratedAtomTextChild {}
+  /// `text:$neutral800`: `color: hsl(0 0% 14.901961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral800 for GeneratedAtomTextChild {}
+  /// `text:$neutral900`: `color: hsl(0 0% 9.0196085%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral900 for GeneratedAtomTextChild {}
+  /// `text:$neutral950`: `color: hsl(0 0% 3.9215689%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral950 for GeneratedAtomTextChild {}
+  /// `text:$stone50`: `color: hsl(60 9.090897% 97.84313%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone50 for GeneratedAtomTextChild {}
+  /// `text:$stone100`: `color: hsl(60 4.761908% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone100 for GeneratedAtomTextChild {}
+  /// `text:$stone200`: `color: hsl(20 5.882351% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone200 for GeneratedAtomTextChild {}
+  /// `text:$stone300`: `color: hsl(24 5.747125% 82.94118%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone300 for GeneratedAtomTextChild {}
+  /// `text:$stone400`: `color: hsl(24 5.4347825% 63.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone400 for GeneratedAtomTextChild {}
+  /// `text:$stone500`: `color: hsl(25 5.2631574% 44.705883%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone500 for GeneratedAtomTextChild {}
+  /// `text:$stone600`: `color: hsl(33.333336 5.454545% 32.352943%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone600 for GeneratedAtomTextChild {}
+  /// `text:$stone700`: `color: hsl(30.000015 6.2500024% 25.098042%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone700 for GeneratedAtomTextChild {}
+  /// `text:$stone800`: `color: hsl(12 6.493506% 15.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone800 for GeneratedAtomTextChild {}
+  /// `text:$stone900`: `color: hsl(24 9.803921% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone900 for GeneratedAtomTextChild {}
+  /// `text:$stone950`: `color: hsl(20 14.285713% 4.117647%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone950 for GeneratedAtomTextChild {}
+  /// `text:$red50`: `color: hsl(0 85.71429% 97.254906%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed50 for GeneratedAtomTextChild {}
+  /// `text:$red100`: `color: hsl(0 93.333336% 94.117645%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed100 for GeneratedAtomTextChild {}
+  /// `text:$red200`: `color: hsl(0 96.296295% 89.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed200 for GeneratedAtomTextChild {}
+  /// `text:$red300`: `color: hsl(0 93.54841% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed300 for GeneratedAtomTextChild {}
+  /// `text:$red400`: `color: hsl(0 90.60403% 70.78431%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed400 for GeneratedAtomTextChild {}
+  /// `text:$red500`: `color: hsl(0 84.23646% 60.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed500 for GeneratedAtomTextChild {}
+  /// `text:$red600`: `color: hsl(0 72.22223% 50.588238%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed600 for GeneratedAtomTextChild {}
+  /// `text:$red700`: `color: hsl(0 73.70892% 41.764706%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed700 for GeneratedAtomTextChild {}
+  /// `text:$red800`: `color: hsl(0 70.00001% 35.294117%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed800 for GeneratedAtomTextChild {}
+  /// `text:$red900`: `color: hsl(0 62.82051% 30.588236%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed900 for GeneratedAtomTextChild {}
+  /// `text:$red950`: `color: hsl(0 74.68355% 15.490196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed950 for GeneratedAtomTextChild {}
+  /// `text:$orange50`: `color: hsl(33.333336 100% 96.47059%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange50 for GeneratedAtomTextChild {}
+  /// `text:$orange100`: `color: hsl(34.285717 100% 91.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange100 for GeneratedAtomTextChild {}
+  /// `text:$orange200`: `color: hsl(32.142857 97.674416% 83.13725%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange200 for GeneratedAtomTextChild {}
+  /// `text:$orange300`: `color: hsl(30.656933 97.163124% 72.35294%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange300 for GeneratedAtomTextChild {}
+  /// `text:$orange400`: `color: hsl(27.015709 95.9799% 60.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange400 for GeneratedAtomTextChild {}
+  /// `text:$orange500`: `color: hsl(24.581497 94.97908% 53.137253%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange500 for GeneratedAtomTextChild {}
+  /// `text:$orange600`: `color: hsl(20.54054 90.243904% 48.235294%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange600 for GeneratedAtomTextChild {}
+  /// `text:$orange700`: `color: hsl(17.472528 88.34952% 40.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange700 for GeneratedAtomTextChild {}
+  /// `text:$orange800`: `color: hsl(14.999998 79.06977% 33.72549%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange800 for GeneratedAtomTextChild {}
+  /// `text:$orange900`: `color: hsl(15.28302 74.64789% 27.843138%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange900 for GeneratedAtomTextChild {}
+  /// `text:$orange950`: `color: hsl(13 81.08108% 14.509805%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange950 for GeneratedAtomTextChild {}
+  /// `text:$amber50`: `color: hsl(48 100% 96.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber50 for GeneratedAtomTextChild {}
+  /// `text:$amber100`: `color: hsl(48 96.49126% 88.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber100 for GeneratedAtomTextChild {}
+  /// `text:$amber200`: `color: hsl(48 96.63864% 76.666664%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber200 for GeneratedAtomTextChild {}
+  /// `text:$amber300`: `color: hsl(45.942852 96.68508% 64.5098%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber300 for GeneratedAtomTextChild {}
+  /// `text:$amber400`: `color: hsl(43.255817 96.41256% 56.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber400 for GeneratedAtomTextChild {}
+  /// `text:$amber500`: `color: hsl(37.69231 92.12599% 50.196083%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber500 for GeneratedAtomTextChild {}
+  /// `text:$amber600`: `color: hsl(32.132698 94.618835% 43.72549%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber600 for GeneratedAtomTextChild {}
+  /// `text:$amber700`: `color: hsl(25.964912 90.47619% 37.058823%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber700 for GeneratedAtomTextChild {}
+  /// `text:$amber800`: `color: hsl(22.727274 82.5% 31.372551%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber800 for GeneratedAtomTextChild {}
+  /// `text:$amber900`: `color: hsl(21.714287 77.77777% 26.470589%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber900 for GeneratedAtomTextChild {}
+  /// `text:$amber950`: `color: hsl(20.90909 91.66667% 14.117648%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber950 for GeneratedAtomTextChild {}
+  /// `text:$yellow50`: `color: hsl(54.545456 91.66667% 95.29412%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow50 for GeneratedAtomTextChild {}
+  /// `text:$yellow100`: `color: hsl(54.915253 96.72134% 88.03922%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow100 for GeneratedAtomTextChild {}
+  /// `text:$yellow200`: `color: hsl(52.75862 98.305084% 76.86275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow200 for GeneratedAtomTextChild {}
+  /// `text:$yellow300`: `color: hsl(50.439568 97.849464% 63.529415%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow300 for GeneratedAtomTextChild {}
+  /// `text:$yellow400`: `color: hsl(47.947598 95.815895% 53.137253%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow400 for GeneratedAtomTextChild {}
+  /// `text:$yellow500`: `color: hsl(45.398228 93.388435% 47.45098%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow500 for GeneratedAtomTextChild {}
+  /// `text:$yellow600`: `color: hsl(40.606064 96.11651% 40.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow600 for GeneratedAtomTextChild {}
+  /// `text:$yellow700`: `color: hsl(35.454544 91.66667% 32.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow700 for GeneratedAtomTextChild {}
+  /// `text:$yellow800`: `color: hsl(31.764704 80.952385% 28.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow800 for GeneratedAtomTextChild {}
+  /// `text:$yellow900`: `color: hsl(28.42105 72.51908% 25.686275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow900 for GeneratedAtomTextChild {}
+  /// `text:$yellow950`: `color: hsl(26 83.333336% 14.117648%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow950 for GeneratedAtomTextChild {}
+  /// `text:$lime50`: `color: hsl(78.260864 92.00005% 95.098045%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime50 for GeneratedAtomTextChild {}
+  /// `text:$lime100`: `color: hsl(79.59184 89.09088% 89.21568%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime100 for GeneratedAtomTextChild {}
+  /// `text:$lime200`: `color: hsl(80.86956 88.46153% 79.60784%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime200 for GeneratedAtomTextChild {}
+  /// `text:$lime300`: `color: hsl(81.971825 84.52381% 67.05882%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime300 for GeneratedAtomTextChild {}
+  /// `text:$lime400`: `color: hsl(82.71186 77.97357% 55.490196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime400 for GeneratedAtomTextChild {}
+  /// `text:$lime500`: `color: hsl(83.73626 80.530975% 44.313725%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime500 for GeneratedAtomTextChild {}
+  /// `text:$lime600`: `color: hsl(84.8 85.22727% 34.509804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime600 for GeneratedAtomTextChild {}
+  /// `text:$lime700`: `color: hsl(85.87156 78.41727% 27.254904%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime700 for GeneratedAtomTextChild {}
+  /// `text:$lime800`: `color: hsl(86.25 68.965515% 22.745098%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime800 for GeneratedAtomTextChild {}
+  /// `text:$lime900`: `color: hsl(87.61906 61.165054% 20.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime900 for GeneratedAtomTextChild {}
+  /// `text:$lime950`: `color: hsl(89.268295 80.39216% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime950 for GeneratedAtomTextChild {}
+  /// `text:$green50`: `color: hsl(138.46153 76.47066% 96.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen50 for GeneratedAtomTextChild {}
+  /// `text:$green100`: `color: hsl(140.625 84.210526% 92.54902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen100 for GeneratedAtomTextChild {}
+  /// `text:$green200`: `color: hsl(141 78.94737% 85.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen200 for GeneratedAtomTextChild {}
+  /// `text:$green300`: `color: hsl(141.7143 76.64235% 73.13726%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen300 for GeneratedAtomTextChild {}
+  /// `text:$green400`: `color: hsl(141.89189 69.158875% 58.03922%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen400 for GeneratedAtomTextChild {}
+  /// `text:$green500`: `color: hsl(142.08589 70.562775% 45.294117%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen500 for GeneratedAtomTextChild {}
+  /// `text:$green600`: `color: hsl(142.12767 76.21622% 36.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen600 for GeneratedAtomTextChild {}
+  /// `text:$green700`: `color: hsl(142.4299 71.81208% 29.215687%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen700 for GeneratedAtomTextChild {}
+  /// `text:$green800`: `color: hsl(142.78482 64.227646% 24.117647%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen800 for GeneratedAtomTextChild {}
+  /// `text:$green900`: `color: hsl(143.80952 61.165054% 20.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen900 for GeneratedAtomTextChild {}
+  /// `text:$green950`: `color: hsl(144.87805 80.39216% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen950 for GeneratedAtomTextChild {}
+  /// `text:$emerald50`: `color: hsl(151.76471 80.95244% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald50 for GeneratedAtomTextChild {}
+  /// `text:$emerald100`: `color: hsl(149.2683 80.392136% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald100 for GeneratedAtomTextChild {}
+  /// `text:$emerald200`: `color: hsl(152.36842 76% 80.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald200 for GeneratedAtomTextChild {}
+  /// `text:$emerald300`: `color: hsl(156.19835 71.59763% 66.86275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald300 for GeneratedAtomTextChild {}
+  /// `text:$emerald400`: `color: hsl(158.1132 64.372475% 51.568626%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald400 for GeneratedAtomTextChild {}
+  /// `text:$emerald500`: `color: hsl(160.11835 84.079605% 39.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald500 for GeneratedAtomTextChild {}
+  /// `text:$emerald600`: `color: hsl(161.3793 93.548386% 30.392159%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald600 for GeneratedAtomTextChild {}
+  /// `text:$emerald700`: `color: hsl(162.93103 93.548386% 24.313726%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald700 for GeneratedAtomTextChild {}
+  /// `text:$emerald800`: `color: hsl(163.14607 88.11881% 19.803923%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald800 for GeneratedAtomTextChild {}
+  /// `text:$emerald900`: `color: hsl(164.16667 85.71429% 16.470589%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald900 for GeneratedAtomTextChild {}
+  /// `text:$emerald950`: `color: hsl(165.71428 91.30435% 9.0196085%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald950 for GeneratedAtomTextChild {}
+  /// `text:$teal50`: `color: hsl(166.15385 76.47066% 96.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal50 for GeneratedAtomTextChild {}
+  /// `text:$teal100`: `color: hsl(167.23404 85.45452% 89.21568%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal100 for GeneratedAtomTextChild {}
+  /// `text:$teal200`: `color: hsl(168.3871 83.78377% 78.23529%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal200 for GeneratedAtomTextChild {}
+  /// `text:$teal300`: `color: hsl(170.57143 76.92308% 64.31373%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal300 for GeneratedAtomTextChild {}
+  /// `text:$teal400`: `color: hsl(172.45508 66.007904% 50.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal400 for GeneratedAtomTextChild {}
+  /// `text:$teal500`: `color: hsl(173.41464 80.39216% 40%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal500 for GeneratedAtomTextChild {}
+  /// `text:$teal600`: `color: hsl(174.66667 83.85093% 31.568628%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal600 for GeneratedAtomTextChild {}
+  /// `text:$teal700`: `color: hsl(175.33981 77.4436% 26.078432%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal700 for GeneratedAtomTextChild {}
+  /// `text:$teal800`: `color: hsl(176.10388 69.36937% 21.764706%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal800 for GeneratedAtomTextChild {}
+  /// `text:$teal900`: `color: hsl(175.93219 60.824745% 19.019608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal900 for GeneratedAtomTextChild {}
+  /// `text:$teal950`: `color: hsl(178.60464 84.31373% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal950 for GeneratedAtomTextChild {}
+  /// `text:$cyan50`: `color: hsl(183.1579 99.99992% 96.274506%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan50 for GeneratedAtomTextChild {}
+  /// `text:$cyan100`: `color: hsl(185.10638 95.918396% 90.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan100 for GeneratedAtomTextChild {}
+  /// `text:$cyan200`: `color: hsl(186.20691 93.54841% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan200 for GeneratedAtomTextChild {}
+  /// `text:$cyan300`: `color: hsl(186.9863 92.40506% 69.01961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan300 for GeneratedAtomTextChild {}
+  /// `text:$cyan400`: `color: hsl(187.94116 85.714294% 53.333336%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan400 for GeneratedAtomTextChild {}
+  /// `text:$cyan500`: `color: hsl(188.73787 94.495415% 42.7451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan500 for GeneratedAtomTextChild {}
+  /// `text:$cyan600`: `color: hsl(191.64705 91.39785% 36.47059%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan600 for GeneratedAtomTextChild {}
+  /// `text:$cyan700`: `color: hsl(192.92308 82.27848% 30.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan700 for GeneratedAtomTextChild {}
+  /// `text:$cyan800`: `color: hsl(194.375 69.565216% 27.058825%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan800 for GeneratedAtomTextChild {}
+  /// `text:$cyan900`: `color: hsl(196.36363 63.636368% 23.72549%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan900 for GeneratedAtomTextChild {}
+  /// `text:$cyan950`: `color: hsl(197 78.94737% 14.901961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan950 for GeneratedAtomTextChild {}
+  /// `text:$sky50`: `color: hsl(204 99.9999% 97.05882%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky50 for GeneratedAtomTextChild {}
+  /// `text:$sky100`: `color: hsl(204 93.75% 93.725494%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky100 for GeneratedAtomTextChild {}
+  /// `text:$sky200`: `color: hsl(200.59702 94.36617% 86.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky200 for GeneratedAtomTextChild {}
+  /// `text:$sky300`: `color: hsl(199.37007 95.488716% 73.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky300 for GeneratedAtomTextChild {}
+  /// `text:$sky400`: `color: hsl(198.43748 93.20389% 59.607845%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky400 for GeneratedAtomTextChild {}
+  /// `text:$sky500`: `color: hsl(198.63014 88.66396% 48.431374%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky500 for GeneratedAtomTextChild {}
+  /// `text:$sky600`: `color: hsl(200.40608 98.00995% 39.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky600 for GeneratedAtomTextChild {}
+  /// `text:$sky700`: `color: hsl(201.26582 96.34146% 32.156864%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky700 for GeneratedAtomTextChild {}
+  /// `text:$sky800`: `color: hsl(200.9524 90% 27.450981%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky800 for GeneratedAtomTextChild {}
+  /// `text:$sky900`: `color: hsl(202.0408 80.327866% 23.921568%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky900 for GeneratedAtomTextChild {}
+  /// `text:$sky950`: `color: hsl(204 80.24691% 15.882354%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky950 for GeneratedAtomTextChild {}
+  /// `text:$blue50`: `color: hsl(213.75 100% 96.86275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue50 for GeneratedAtomTextChild {}
+  /// `text:$blue100`: `color: hsl(214.2857 94.594635% 92.7451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue100 for GeneratedAtomTextChild {}
+  /// `text:$blue200`: `color: hsl(213.33333 96.9231% 87.254906%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue200 for GeneratedAtomTextChild {}
+  /// `text:$blue300`: `color: hsl(211.69812 96.36363% 78.43137%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue300 for GeneratedAtomTextChild {}
+  /// `text:$blue400`: `color: hsl(213.11688 93.902435% 67.84314%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue400 for GeneratedAtomTextChild {}
+  /// `text:$blue500`: `color: hsl(217.21925 91.21951% 59.80392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue500 for GeneratedAtomTextChild {}
+  /// `text:$blue600`: `color: hsl(221.21213 83.19328% 53.333336%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue600 for GeneratedAtomTextChild {}
+  /// `text:$blue700`: `color: hsl(224.27808 76.32653% 48.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue700 for GeneratedAtomTextChild {}
+  /// `text:$blue800`: `color: hsl(225.93103 70.73171% 40.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue800 for GeneratedAtomTextChild {}
+  /// `text:$blue900`: `color: hsl(224.44444 64.28572% 32.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue900 for GeneratedAtomTextChild {}
+  /// `text:$blue950`: `color: hsl(226.2295 57.00935% 20.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue950 for GeneratedAtomTextChild {}
+  /// `text:$indigo50`: `color: hsl(225.88235 100% 96.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo50 for GeneratedAtomTextChild {}
+  /// `text:$indigo100`: `color: hsl(226.45161 99.999954% 93.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo100 for GeneratedAtomTextChild {}
+  /// `text:$indigo200`: `color: hsl(228 96.49126% 88.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo200 for GeneratedAtomTextChild {}
+  /// `text:$indigo300`: `color: hsl(229.65517 93.54841% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo300 for GeneratedAtomTextChild {}
+  /// `text:$indigo400`: `color: hsl(234.45378 89.473694% 73.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo400 for GeneratedAtomTextChild {}
+  /// `text:$indigo500`: `color: hsl(238.73239 83.52941% 66.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo500 for GeneratedAtomTextChild {}
+  /// `text:$indigo600`: `color: hsl(243.39624 75.35545% 58.62745%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo600 for GeneratedAtomTextChild {}
+  /// `text:$indigo700`: `color: hsl(244.52055 57.936512% 50.588238%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo700 for GeneratedAtomTextChild {}
+  /// `text:$indigo800`: `color: hsl(243.65218 54.502373% 41.37255%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo800 for GeneratedAtomTextChild {}
+  /// `text:$indigo900`: `color: hsl(242.16869 47.428574% 34.31373%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo900 for GeneratedAtomTextChild {}
+  /// `text:$indigo950`: `color: hsl(243.75 47.058826% 20%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo950 for GeneratedAtomTextChild {}
+  /// `text:$violet50`: `color: hsl(249.99998 100% 97.64706%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet50 for GeneratedAtomTextChild {}
+  /// `text:$violet100`: `color: hsl(251.42859 91.30429% 95.4902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet100 for GeneratedAtomTextChild {}
+  /// `text:$violet200`: `color: hsl(250.50002 95.2381% 91.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet200 for GeneratedAtomTextChild {}
+  /// `text:$violet300`: `color: hsl(252.50002 94.73685% 85.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet300 for GeneratedAtomTextChild {}
+  /// `text:$violet400`: `color: hsl(255.13513 91.73555% 76.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet400 for GeneratedAtomTextChild {}
+  /// `text:$violet500`: `color: hsl(258.3117 89.53488% 66.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet500 for GeneratedAtomTextChild {}
+  /// `text:$violet600`: `color: hsl(262.12292 83.25581% 57.843136%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet600 for GeneratedAtomTextChild {}
+  /// `text:$violet700`: `color: hsl(263.38983 69.96047% 50.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet700 for GeneratedAtomTextChild {}
+  /// `text:$violet800`: `color: hsl(263.3557 69.30233% 42.156864%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet800 for GeneratedAtomTextChild {}
+  /// `text:$violet900`: `color: hsl(263.5 67.41573% 34.901962%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet900 for GeneratedAtomTextChild {}
+  /// `text:$violet950`: `color: hsl(261.17645 72.649574% 22.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet950 for GeneratedAtomTextChild {}
+  /// `text:$purple50`: `color: hsl(270 100% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple50 for GeneratedAtomTextChild {}
+  /// `text:$purple100`: `color: hsl(268.69565 99.99993% 95.4902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple100 for GeneratedAtomTextChild {}
+  /// `text:$purple200`: `color: hsl(268.57144 100% 91.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple200 for GeneratedAtomTextChild {}
+  /// `text:$purple300`: `color: hsl(269.18918 97.368416% 85.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple300 for GeneratedAtomTextChild {}
+  /// `text:$purple400`: `color: hsl(270 95.2381% 75.29412%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple400 for GeneratedAtomTextChild {}
+  /// `text:$purple500`: `color: hsl(270.74075 91.01124% 65.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple500 for GeneratedAtomTextChild {}
+  /// `text:$purple600`: `color: hsl(271.4754 81.333336% 55.88235%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple600 for GeneratedAtomTextChild {}
+  /// `text:$purple700`: `color: hsl(272.09302 71.66667% 47.058823%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple700 for GeneratedAtomTextChild {}
+  /// `text:$purple800`: `color: hsl(272.8889 67.164185% 39.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple800 for GeneratedAtomTextChild {}
+  /// `text:$purple900`: `color: hsl(273.64487 65.64417% 31.960785%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple900 for GeneratedAtomTextChild {}
+  /// `text:$purple950`: `color: hsl(273.54837 86.915886% 20.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple950 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia50`: `color: hsl(289.0909 99.99986% 97.84313%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia50 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia100`: `color: hsl(286.9565 99.99993% 95.4902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia100 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia200`: `color: hsl(288.26086 95.83333% 90.588234%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia200 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia300`: `color: hsl(291.1111 93.10343% 82.94118%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia300 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia400`: `color: hsl(292.03125 91.42857% 72.54902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia400 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia500`: `color: hsl(292.18936 84.079605% 60.588234%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia500 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia600`: `color: hsl(293.4104 69.47791% 48.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia600 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia700`: `color: hsl(294.69388 72.413795% 39.80392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia700 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia800`: `color: hsl(295.42374 70.2381% 32.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia800 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia900`: `color: hsl(296.7033 63.636364% 28.039217%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia900 for GeneratedAtomTextChild {}
+  /// `text:$fuchsia950`: `color: hsl(296.75674 90.243904% 16.078432%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia950 for GeneratedAtomTextChild {}
+  /// `text:$pink50`: `color: hsl(327.27274 73.33326% 97.05882%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink50 for GeneratedAtomTextChild {}
+  /// `text:$pink100`: `color: hsl(325.7143 77.77773% 94.70588%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink100 for GeneratedAtomTextChild {}
+  /// `text:$pink200`: `color: hsl(325.9091 84.61539% 89.803925%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink200 for GeneratedAtomTextChild {}
+  /// `text:$pink300`: `color: hsl(327.4074 87.09679% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink300 for GeneratedAtomTextChild {}
+  /// `text:$pink400`: `color: hsl(328.6154 85.52631% 70.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink400 for GeneratedAtomTextChild {}
+  /// `text:$pink500`: `color: hsl(330.36584 81.18812% 60.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink500 for GeneratedAtomTextChild {}
+  /// `text:$pink600`: `color: hsl(333.33334 71.42858% 50.588238%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink600 for GeneratedAtomTextChild {}
+  /// `text:$pink700`: `color: hsl(335.06024 77.57009% 41.960785%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink700 for GeneratedAtomTextChild {}
+  /// `text:$pink800`: `color: hsl(335.8209 74.44445% 35.294117%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink800 for GeneratedAtomTextChild {}
+  /// `text:$pink900`: `color: hsl(335.88785 69.032265% 30.392159%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink900 for GeneratedAtomTextChild {}
+  /// `text:$pink950`: `color: hsl(336.16437 83.90804% 17.058825%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink950 for GeneratedAtomTextChild {}
+  /// `text:$rose50`: `color: hsl(355.7143 100% 97.254906%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose50 for GeneratedAtomTextChild {}
+  /// `text:$rose100`: `color: hsl(355.55554 99.99995% 94.70588%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose100 for GeneratedAtomTextChild {}
+  /// `text:$rose200`: `color: hsl(352.65308 96.0784% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose200 for GeneratedAtomTextChild {}
+  /// `text:$rose300`: `color: hsl(352.58426 95.698944% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose300 for GeneratedAtomTextChild {}
+  /// `text:$rose400`: `color: hsl(351.30435 94.520546% 71.37255%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose400 for GeneratedAtomTextChild {}
+  /// `text:$rose500`: `color: hsl(349.72375 89.16257% 60.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose500 for GeneratedAtomTextChild {}
+  /// `text:$rose600`: `color: hsl(346.83673 77.16535% 49.80392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose600 for GeneratedAtomTextChild {}
+  /// `text:$rose700`: `color: hsl(345.34885 82.69231% 40.784313%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose700 for GeneratedAtomTextChild {}
+  /// `text:$rose800`: `color: hsl(343.40427 79.66102% 34.705883%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose800 for GeneratedAtomTextChild {}
+  /// `text:$rose900`: `color: hsl(341.53845 75.48387% 30.392159%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose900 for GeneratedAtomTextChild {}
+  /// `text:$rose950`: `color: hsl(343.0986 87.65432% 15.882354%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose950 for GeneratedAtomTextChild {}
+  /// `text:$inherit`: `color: inherit;`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorInherit for GeneratedAtomTextChild {}
+  /// `text:$transparent`: `color: transparent;`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTransparent for GeneratedAtomTextChild {}
+  /// `text:$current`: `color: currentColor;`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCurrent for GeneratedAtomTextChild {}
+  pub trait GeneratedAtomText: GeneratedSkribbleValue {
+    #[inline]
+    fn text(&self) -> GeneratedAtomTextChild {
+      GeneratedAtomTextChild::from_ref(self.append("text"))
+    }
+    #[inline]
+    fn text_(&self, value: &'static str) -> String {
+      self.append(format!("text:[{}]", value.trim()))
+    }
+  }
+  impl GeneratedAtomText for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomText for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomText for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerImportantGroupImportantChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerAlphaGroupAlphaChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerAlphaGroupOpaquifyChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerAlphaGroupTransparentizeChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerLightnessGroupLightnessChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerLightnessGroupLightenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerLightnessGroupDarkenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerMixGroupMixChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerMixGroupTintChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerMixGroupShadeChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerOklchGroupOklchLightenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerOklchGroupOklchDarkenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerOklchGroupOklchSaturateChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerOklchGroupOklchDesaturateChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomText for GeneratedTransformerOklchGroupOklchInvertChild {}
+}
+#[cfg(not(feature = "skribble-skip-atom-text"))]
+pub use atom_text::*;
+#[cfg(not(feature = "skribble-skip-atom-bg"))]
+mod atom_bg {
+  use super::*;
+  pub struct GeneratedAtomBgChild(String);
+  impl GeneratedSkribbleValue for GeneratedAtomBgChild {
+    #[inline]
+    fn from_ref(value: impl AsRef<str>) -> Self {
+      Self(value.as_ref().to_string())
+    }
+    #[inline]
+    fn get_skribble_value(&self) -> &String {
+      &self.0
+    }
+  }
+  /// `bg:$primary`: `background-color: var(--sk-p, #570df8);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPrimary for GeneratedAtomBgChild {}
+  /// `bg:$primary-content`: `background-color: var(--sk-pc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPrimaryContent for GeneratedAtomBgChild {}
+  /// `bg:$secondary`: `background-color: var(--sk-s, #f000b8);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSecondary for GeneratedAtomBgChild {}
+  /// `bg:$secondary-content`: `background-color: var(--sk-sc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSecondaryContent for GeneratedAtomBgChild {}
+  /// `bg:$accent`: `background-color: var(--sk-a, #37cdbe);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAccent for GeneratedAtomBgChild {}
+  /// `bg:$accent-content`: `background-color: var(--sk-ac, #163835);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAccentContent for GeneratedAtomBgChild {}
+  /// `bg:$neutral`: `background-color: var(--sk-n, #3d4451);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral for GeneratedAtomBgChild {}
+  /// `bg:$neutral-content`: `background-color: var(--sk-nc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutralContent for GeneratedAtomBgChild {}
+  /// `bg:$base100`: `background-color: var(--sk-b1, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBase100 for GeneratedAtomBgChild {}
+  /// `bg:$base200`: `background-color: var(--sk-b2, #F2F2F2);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBase200 for GeneratedAtomBgChild {}
+  /// `bg:$base300`: `background-color: var(--sk-b3, #E5E6E6);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBase300 for GeneratedAtomBgChild {}
+  /// `bg:$base-content`: `background-color: var(--sk-bc, #1f2937);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBaseContent for GeneratedAtomBgChild {}
+  /// `bg:$info`: `background-color: var(--sk-in, #0070F3);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorInfo for GeneratedAtomBgChild {}
+  /// `bg:$info-content`: `background-color: var(--sk-inc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorInfoContent for GeneratedAtomBgChild {}
+  /// `bg:$success`: `background-color: var(--sk-su, #21CC51);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSuccess for GeneratedAtomBgChild {}
+  /// `bg:$success-content`: `background-color: var(--sk-suc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSuccessContent for GeneratedAtomBgChild {}
+  /// `bg:$warning`: `background-color: var(--sk-wa, #FF6154);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorWarning for GeneratedAtomBgChild {}
+  /// `bg:$warning-content`: `background-color: var(--sk-wac, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorWarningContent for GeneratedAtomBgChild {}
+  /// `bg:$error`: `background-color: var(--sk-er, #DE1C8D);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorError for GeneratedAtomBgChild {}
+  /// `bg:$error-content`: `background-color: var(--sk-erc, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorErrorContent for GeneratedAtomBgChild {}
+  /// `bg:$ring-offset-color`: `background-color: var(--sk-ring-offset-color, #ffffff);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRingOffsetColor for GeneratedAtomBgChild {}
+  /// `bg:$ring-color`: `background-color: var(--sk-ring-color, #3b82f680);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRingColor for GeneratedAtomBgChild {}
+  /// `bg:$black`: `background-color: hsl(0 0% 0%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlack for GeneratedAtomBgChild {}
+  /// `bg:$white`: `background-color: hsl(0 0% 100%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorWhite for GeneratedAtomBgChild {}
+  /// `bg:$slate50`: `background-color: hsl(210 40% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate50 for GeneratedAtomBgChild {}
+  /// `bg:$slate100`: `background-color: hsl(210 40% 96.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate100 for GeneratedAtomBgChild {}
+  /// `bg:$slate200`: `background-color: hsl(214.2857 31.818182% 91.37255%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate200 for GeneratedAtomBgChild {}
+  /// `bg:$slate300`: `background-color: hsl(212.72726 26.82927% 83.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate300 for GeneratedAtomBgChild {}
+  /// `bg:$slate400`: `background-color: hsl(215 20.224718% 65.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate400 for GeneratedAtomBgChild {}
+  /// `bg:$slate500`: `background-color: hsl(215.38461 16.317993% 46.862747%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate500 for GeneratedAtomBgChild {}
+  /// `bg:$slate600`: `background-color: hsl(215.29413 19.31818% 34.509804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate600 for GeneratedAtomBgChild {}
+  /// `bg:$slate700`: `background-color: hsl(215.29411 25% 26.666668%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate700 for GeneratedAtomBgChild {}
+  /// `bg:$slate800`: `background-color: hsl(217.24138 32.584267% 17.450981%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate800 for GeneratedAtomBgChild {}
+  /// `bg:$slate900`: `background-color: hsl(222.22221 47.368423% 11.176471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate900 for GeneratedAtomBgChild {}
+  /// `bg:$slate950`: `background-color: hsl(228.57143 84% 4.901961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSlate950 for GeneratedAtomBgChild {}
+  /// `bg:$gray50`: `background-color: hsl(210 20% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray50 for GeneratedAtomBgChild {}
+  /// `bg:$gray100`: `background-color: hsl(220 14.285726% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray100 for GeneratedAtomBgChild {}
+  /// `bg:$gray200`: `background-color: hsl(220 13.043478% 90.98039%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray200 for GeneratedAtomBgChild {}
+  /// `bg:$gray300`: `background-color: hsl(216 12.195122% 83.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray300 for GeneratedAtomBgChild {}
+  /// `bg:$gray400`: `background-color: hsl(217.89473 10.614524% 64.90196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray400 for GeneratedAtomBgChild {}
+  /// `bg:$gray500`: `background-color: hsl(220 8.9361725% 46.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray500 for GeneratedAtomBgChild {}
+  /// `bg:$gray600`: `background-color: hsl(215 13.793102% 34.11765%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray600 for GeneratedAtomBgChild {}
+  /// `bg:$gray700`: `background-color: hsl(216.92308 19.117647% 26.666668%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray700 for GeneratedAtomBgChild {}
+  /// `bg:$gray800`: `background-color: hsl(215 27.906975% 16.862745%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray800 for GeneratedAtomBgChild {}
+  /// `bg:$gray900`: `background-color: hsl(220.90909 39.285713% 10.980393%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray900 for GeneratedAtomBgChild {}
+  /// `bg:$gray950`: `background-color: hsl(224 71.42857% 4.117647%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGray950 for GeneratedAtomBgChild {}
+  /// `bg:$zinc50`: `background-color: hsl(0 0% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc50 for GeneratedAtomBgChild {}
+  /// `bg:$zinc100`: `background-color: hsl(240 4.761908% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc100 for GeneratedAtomBgChild {}
+  /// `bg:$zinc200`: `background-color: hsl(240 5.882351% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc200 for GeneratedAtomBgChild {}
+  /// `bg:$zinc300`: `background-color: hsl(240 4.8780484% 83.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc300 for GeneratedAtomBgChild {}
+  /// `bg:$zinc400`: `background-color: hsl(240 5.0279326% 64.90196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc400 for GeneratedAtomBgChild {}
+  /// `bg:$zinc500`: `background-color: hsl(240 3.829787% 46.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc500 for GeneratedAtomBgChild {}
+  /// `bg:$zinc600`: `background-color: hsl(240 5.2023115% 33.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc600 for GeneratedAtomBgChild {}
+  /// `bg:$zinc700`: `background-color: hsl(240 5.26316% 26.078432%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc700 for GeneratedAtomBgChild {}
+  /// `bg:$zinc800`: `background-color: hsl(240 3.7037034% 15.882354%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc800 for GeneratedAtomBgChild {}
+  /// `bg:$zinc900`: `background-color: hsl(240 5.882353% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc900 for GeneratedAtomBgChild {}
+  /// `bg:$zinc950`: `background-color: hsl(240 9.999999% 3.9215689%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorZinc950 for GeneratedAtomBgChild {}
+  /// `bg:$neutral50`: `background-color: hsl(0 0% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral50 for GeneratedAtomBgChild {}
+  /// `bg:$neutral100`: `background-color: hsl(0 0% 96.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral100 for GeneratedAtomBgChild {}
+  /// `bg:$neutral200`: `background-color: hsl(0 0% 89.803925%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral200 for GeneratedAtomBgChild {}
+  /// `bg:$neutral300`: `background-color: hsl(0 0% 83.13725%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral300 for GeneratedAtomBgChild {}
+  /// `bg:$neutral400`: `background-color: hsl(0 0% 63.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral400 for GeneratedAtomBgChild {}
+  /// `bg:$neutral500`: `background-color: hsl(0 0% 45.098038%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral500 for GeneratedAtomBgChild {}
+  /// `bg:$neutral600`: `background-color: hsl(0 0% 32.156864%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral600 for GeneratedAtomBgChild {}
+  /// `bg:$neutral700`: `background-color: hsl(0 0% 25.098042%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral700 for GeneratedAtomBgChild {}
+  /// `bg:$neutral800`: `background-color: hsl(0 0% 14.901961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral800 for GeneratedAtomBgChild {}
+  /// `bg:$neutral900`: `background-color: hsl(0 0% 9.0196085%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral900 for GeneratedAtomBgChild {}
+  /// `bg:$neutral950`: `background-color: hsl(0 0% 3.9215689%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorNeutral950 for GeneratedAtomBgChild {}
+  /// `bg:$stone50`: `background-color: hsl(60 9.090897% 97.84313%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone50 for GeneratedAtomBgChild {}
+  /// `bg:$stone100`: `background-color: hsl(60 4.761908% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone100 for GeneratedAtomBgChild {}
+  /// `bg:$stone200`: `background-color: hsl(20 5.882351% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone200 for GeneratedAtomBgChild {}
+  /// `bg:$stone300`: `background-color: hsl(24 5.747125% 82.94118%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone300 for GeneratedAtomBgChild {}
+  /// `bg:$stone400`: `background-color: hsl(24 5.4347825% 63.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone400 for GeneratedAtomBgChild {}
+  /// `bg:$stone500`: `background-color: hsl(25 5.2631574% 44.705883%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone500 for GeneratedAtomBgChild {}
+  /// `bg:$stone600`: `background-color: hsl(33.333336 5.454545% 32.352943%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone600 for GeneratedAtomBgChild {}
+  /// `bg:$stone700`: `background-color: hsl(30.000015 6.2500024% 25.098042%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone700 for GeneratedAtomBgChild {}
+  /// `bg:$stone800`: `background-color: hsl(12 6.493506% 15.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone800 for GeneratedAtomBgChild {}
+  /// `bg:$stone900`: `background-color: hsl(24 9.803921% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone900 for GeneratedAtomBgChild {}
+  /// `bg:$stone950`: `background-color: hsl(20 14.285713% 4.117647%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorStone950 for GeneratedAtomBgChild {}
+  /// `bg:$red50`: `background-color: hsl(0 85.71429% 97.254906%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed50 for GeneratedAtomBgChild {}
+  /// `bg:$red100`: `background-color: hsl(0 93.333336% 94.117645%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed100 for GeneratedAtomBgChild {}
+  /// `bg:$red200`: `background-color: hsl(0 96.296295% 89.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed200 for GeneratedAtomBgChild {}
+  /// `bg:$red300`: `background-color: hsl(0 93.54841% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed300 for GeneratedAtomBgChild {}
+  /// `bg:$red400`: `background-color: hsl(0 90.60403% 70.78431%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed400 for GeneratedAtomBgChild {}
+  /// `bg:$red500`: `background-color: hsl(0 84.23646% 60.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed500 for GeneratedAtomBgChild {}
+  /// `bg:$red600`: `background-color: hsl(0 72.22223% 50.588238%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed600 for GeneratedAtomBgChild {}
+  /// `bg:$red700`: `background-color: hsl(0 73.70892% 41.764706%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed700 for GeneratedAtomBgChild {}
+  /// `bg:$red800`: `background-color: hsl(0 70.00001% 35.294117%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed800 for GeneratedAtomBgChild {}
+  /// `bg:$red900`: `background-color: hsl(0 62.82051% 30.588236%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed900 for GeneratedAtomBgChild {}
+  /// `bg:$red950`: `background-color: hsl(0 74.68355% 15.490196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRed950 for GeneratedAtomBgChild {}
+  /// `bg:$orange50`: `background-color: hsl(33.333336 100% 96.47059%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange50 for GeneratedAtomBgChild {}
+  /// `bg:$orange100`: `background-color: hsl(34.285717 100% 91.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange100 for GeneratedAtomBgChild {}
+  /// `bg:$orange200`: `background-color: hsl(32.142857 97.674416% 83.13725%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange200 for GeneratedAtomBgChild {}
+  /// `bg:$orange300`: `background-color: hsl(30.656933 97.163124% 72.35294%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange300 for GeneratedAtomBgChild {}
+  /// `bg:$orange400`: `background-color: hsl(27.015709 95.9799% 60.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange400 for GeneratedAtomBgChild {}
+  /// `bg:$orange500`: `background-color: hsl(24.581497 94.97908% 53.137253%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange500 for GeneratedAtomBgChild {}
+  /// `bg:$orange600`: `background-color: hsl(20.54054 90.243904% 48.235294%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange600 for GeneratedAtomBgChild {}
+  /// `bg:$orange700`: `background-color: hsl(17.472528 88.34952% 40.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange700 for GeneratedAtomBgChild {}
+  /// `bg:$orange800`: `background-color: hsl(14.999998 79.06977% 33.72549%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange800 for GeneratedAtomBgChild {}
+  /// `bg:$orange900`: `background-color: hsl(15.28302 74.64789% 27.843138%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange900 for GeneratedAtomBgChild {}
+  /// `bg:$orange950`: `background-color: hsl(13 81.08108% 14.509805%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorOrange950 for GeneratedAtomBgChild {}
+  /// `bg:$amber50`: `background-color: hsl(48 100% 96.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber50 for GeneratedAtomBgChild {}
+  /// `bg:$amber100`: `background-color: hsl(48 96.49126% 88.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber100 for GeneratedAtomBgChild {}
+  /// `bg:$amber200`: `background-color: hsl(48 96.63864% 76.666664%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber200 for GeneratedAtomBgChild {}
+  /// `bg:$amber300`: `background-color: hsl(45.942852 96.68508% 64.5098%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber300 for GeneratedAtomBgChild {}
+  /// `bg:$amber400`: `background-color: hsl(43.255817 96.41256% 56.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber400 for GeneratedAtomBgChild {}
+  /// `bg:$amber500`: `background-color: hsl(37.69231 92.12599% 50.196083%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber500 for GeneratedAtomBgChild {}
+  /// `bg:$amber600`: `background-color: hsl(32.132698 94.618835% 43.72549%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber600 for GeneratedAtomBgChild {}
+  /// `bg:$amber700`: `background-color: hsl(25.964912 90.47619% 37.058823%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber700 for GeneratedAtomBgChild {}
+  /// `bg:$amber800`: `background-color: hsl(22.727274 82.5% 31.372551%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber800 for GeneratedAtomBgChild {}
+  /// `bg:$amber900`: `background-color: hsl(21.714287 77.77777% 26.470589%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber900 for GeneratedAtomBgChild {}
+  /// `bg:$amber950`: `background-color: hsl(20.90909 91.66667% 14.117648%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorAmber950 for GeneratedAtomBgChild {}
+  /// `bg:$yellow50`: `background-color: hsl(54.545456 91.66667% 95.29412%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow50 for GeneratedAtomBgChild {}
+  /// `bg:$yellow100`: `background-color: hsl(54.915253 96.72134% 88.03922%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow100 for GeneratedAtomBgChild {}
+  /// `bg:$yellow200`: `background-color: hsl(52.75862 98.305084% 76.86275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow200 for GeneratedAtomBgChild {}
+  /// `bg:$yellow300`: `background-color: hsl(50.439568 97.849464% 63.529415%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow300 for GeneratedAtomBgChild {}
+  /// `bg:$yellow400`: `background-color: hsl(47.947598 95.815895% 53.137253%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow400 for GeneratedAtomBgChild {}
+  /// `bg:$yellow500`: `background-color: hsl(45.398228 93.388435% 47.45098%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow500 for GeneratedAtomBgChild {}
+  /// `bg:$yellow600`: `background-color: hsl(40.606064 96.11651% 40.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow600 for GeneratedAtomBgChild {}
+  /// `bg:$yellow700`: `background-color: hsl(35.454544 91.66667% 32.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow700 for GeneratedAtomBgChild {}
+  /// `bg:$yellow800`: `background-color: hsl(31.764704 80.952385% 28.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow800 for GeneratedAtomBgChild {}
+  /// `bg:$yellow900`: `background-color: hsl(28.42105 72.51908% 25.686275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow900 for GeneratedAtomBgChild {}
+  /// `bg:$yellow950`: `background-color: hsl(26 83.333336% 14.117648%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorYellow950 for GeneratedAtomBgChild {}
+  /// `bg:$lime50`: `background-color: hsl(78.260864 92.00005% 95.098045%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime50 for GeneratedAtomBgChild {}
+  /// `bg:$lime100`: `background-color: hsl(79.59184 89.09088% 89.21568%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime100 for GeneratedAtomBgChild {}
+  /// `bg:$lime200`: `background-color: hsl(80.86956 88.46153% 79.60784%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime200 for GeneratedAtomBgChild {}
+  /// `bg:$lime300`: `background-color: hsl(81.971825 84.52381% 67.05882%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime300 for GeneratedAtomBgChild {}
+  /// `bg:$lime400`: `background-color: hsl(82.71186 77.97357% 55.490196%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime400 for GeneratedAtomBgChild {}
+  /// `bg:$lime500`: `background-color: hsl(83.73626 80.530975% 44.313725%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime500 for GeneratedAtomBgChild {}
+  /// `bg:$lime600`: `background-color: hsl(84.8 85.22727% 34.509804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime600 for GeneratedAtomBgChild {}
+  /// `bg:$lime700`: `background-color: hsl(85.87156 78.41727% 27.254904%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime700 for GeneratedAtomBgChild {}
+  /// `bg:$lime800`: `background-color: hsl(86.25 68.965515% 22.745098%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime800 for GeneratedAtomBgChild {}
+  /// `bg:$lime900`: `background-color: hsl(87.61906 61.165054% 20.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime900 for GeneratedAtomBgChild {}
+  /// `bg:$lime950`: `background-color: hsl(89.268295 80.39216% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorLime950 for GeneratedAtomBgChild {}
+  /// `bg:$green50`: `background-color: hsl(138.46153 76.47066% 96.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen50 for GeneratedAtomBgChild {}
+  /// `bg:$green100`: `background-color: hsl(140.625 84.210526% 92.54902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen100 for GeneratedAtomBgChild {}
+  /// `bg:$green200`: `background-color: hsl(141 78.94737% 85.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen200 for GeneratedAtomBgChild {}
+  /// `bg:$green300`: `background-color: hsl(141.7143 76.64235% 73.13726%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen300 for GeneratedAtomBgChild {}
+  /// `bg:$green400`: `background-color: hsl(141.89189 69.158875% 58.03922%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen400 for GeneratedAtomBgChild {}
+  /// `bg:$green500`: `background-color: hsl(142.08589 70.562775% 45.294117%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen500 for GeneratedAtomBgChild {}
+  /// `bg:$green600`: `background-color: hsl(142.12767 76.21622% 36.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen600 for GeneratedAtomBgChild {}
+  /// `bg:$green700`: `background-color: hsl(142.4299 71.81208% 29.215687%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen700 for GeneratedAtomBgChild {}
+  /// `bg:$green800`: `background-color: hsl(142.78482 64.227646% 24.117647%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen800 for GeneratedAtomBgChild {}
+  /// `bg:$green900`: `background-color: hsl(143.80952 61.165054% 20.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen900 for GeneratedAtomBgChild {}
+  /// `bg:$green950`: `background-color: hsl(144.87805 80.39216% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorGreen950 for GeneratedAtomBgChild {}
+  /// `bg:$emerald50`: `background-color: hsl(151.76471 80.95244% 95.882355%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald50 for GeneratedAtomBgChild {}
+  /// `bg:$emerald100`: `background-color: hsl(149.2683 80.392136% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald100 for GeneratedAtomBgChild {}
+  /// `bg:$emerald200`: `background-color: hsl(152.36842 76% 80.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald200 for GeneratedAtomBgChild {}
+  /// `bg:$emerald300`: `background-color: hsl(156.19835 71.59763% 66.86275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald300 for GeneratedAtomBgChild {}
+  /// `bg:$emerald400`: `background-color: hsl(158.1132 64.372475% 51.568626%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald400 for GeneratedAtomBgChild {}
+  /// `bg:$emerald500`: `background-color: hsl(160.11835 84.079605% 39.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald500 for GeneratedAtomBgChild {}
+  /// `bg:$emerald600`: `background-color: hsl(161.3793 93.548386% 30.392159%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald600 for GeneratedAtomBgChild {}
+  /// `bg:$emerald700`: `background-color: hsl(162.93103 93.548386% 24.313726%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald700 for GeneratedAtomBgChild {}
+  /// `bg:$emerald800`: `background-color: hsl(163.14607 88.11881% 19.803923%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald800 for GeneratedAtomBgChild {}
+  /// `bg:$emerald900`: `background-color: hsl(164.16667 85.71429% 16.470589%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald900 for GeneratedAtomBgChild {}
+  /// `bg:$emerald950`: `background-color: hsl(165.71428 91.30435% 9.0196085%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorEmerald950 for GeneratedAtomBgChild {}
+  /// `bg:$teal50`: `background-color: hsl(166.15385 76.47066% 96.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal50 for GeneratedAtomBgChild {}
+  /// `bg:$teal100`: `background-color: hsl(167.23404 85.45452% 89.21568%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal100 for GeneratedAtomBgChild {}
+  /// `bg:$teal200`: `background-color: hsl(168.3871 83.78377% 78.23529%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal200 for GeneratedAtomBgChild {}
+  /// `bg:$teal300`: `background-color: hsl(170.57143 76.92308% 64.31373%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal300 for GeneratedAtomBgChild {}
+  /// `bg:$teal400`: `background-color: hsl(172.45508 66.007904% 50.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal400 for GeneratedAtomBgChild {}
+  /// `bg:$teal500`: `background-color: hsl(173.41464 80.39216% 40%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal500 for GeneratedAtomBgChild {}
+  /// `bg:$teal600`: `background-color: hsl(174.66667 83.85093% 31.568628%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal600 for GeneratedAtomBgChild {}
+  /// `bg:$teal700`: `background-color: hsl(175.33981 77.4436% 26.078432%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal700 for GeneratedAtomBgChild {}
+  /// `bg:$teal800`: `background-color: hsl(176.10388 69.36937% 21.764706%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal800 for GeneratedAtomBgChild {}
+  /// `bg:$teal900`: `background-color: hsl(175.93219 60.824745% 19.019608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal900 for GeneratedAtomBgChild {}
+  /// `bg:$teal950`: `background-color: hsl(178.60464 84.31373% 10%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTeal950 for GeneratedAtomBgChild {}
+  /// `bg:$cyan50`: `background-color: hsl(183.1579 99.99992% 96.274506%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan50 for GeneratedAtomBgChild {}
+  /// `bg:$cyan100`: `background-color: hsl(185.10638 95.918396% 90.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan100 for GeneratedAtomBgChild {}
+  /// `bg:$cyan200`: `background-color: hsl(186.20691 93.54841% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan200 for GeneratedAtomBgChild {}
+  /// `bg:$cyan300`: `background-color: hsl(186.9863 92.40506% 69.01961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan300 for GeneratedAtomBgChild {}
+  /// `bg:$cyan400`: `background-color: hsl(187.94116 85.714294% 53.333336%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan400 for GeneratedAtomBgChild {}
+  /// `bg:$cyan500`: `background-color: hsl(188.73787 94.495415% 42.7451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan500 for GeneratedAtomBgChild {}
+  /// `bg:$cyan600`: `background-color: hsl(191.64705 91.39785% 36.47059%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan600 for GeneratedAtomBgChild {}
+  /// `bg:$cyan700`: `background-color: hsl(192.92308 82.27848% 30.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan700 for GeneratedAtomBgChild {}
+  /// `bg:$cyan800`: `background-color: hsl(194.375 69.565216% 27.058825%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan800 for GeneratedAtomBgChild {}
+  /// `bg:$cyan900`: `background-color: hsl(196.36363 63.636368% 23.72549%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan900 for GeneratedAtomBgChild {}
+  /// `bg:$cyan950`: `background-color: hsl(197 78.94737% 14.901961%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCyan950 for GeneratedAtomBgChild {}
+  /// `bg:$sky50`: `background-color: hsl(204 99.9999% 97.05882%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky50 for GeneratedAtomBgChild {}
+  /// `bg:$sky100`: `background-color: hsl(204 93.75% 93.725494%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky100 for GeneratedAtomBgChild {}
+  /// `bg:$sky200`: `background-color: hsl(200.59702 94.36617% 86.07843%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky200 for GeneratedAtomBgChild {}
+  /// `bg:$sky300`: `background-color: hsl(199.37007 95.488716% 73.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky300 for GeneratedAtomBgChild {}
+  /// `bg:$sky400`: `background-color: hsl(198.43748 93.20389% 59.607845%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky400 for GeneratedAtomBgChild {}
+  /// `bg:$sky500`: `background-color: hsl(198.63014 88.66396% 48.431374%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky500 for GeneratedAtomBgChild {}
+  /// `bg:$sky600`: `background-color: hsl(200.40608 98.00995% 39.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky600 for GeneratedAtomBgChild {}
+  /// `bg:$sky700`: `background-color: hsl(201.26582 96.34146% 32.156864%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky700 for GeneratedAtomBgChild {}
+  /// `bg:$sky800`: `background-color: hsl(200.9524 90% 27.450981%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky800 for GeneratedAtomBgChild {}
+  /// `bg:$sky900`: `background-color: hsl(202.0408 80.327866% 23.921568%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky900 for GeneratedAtomBgChild {}
+  /// `bg:$sky950`: `background-color: hsl(204 80.24691% 15.882354%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorSky950 for GeneratedAtomBgChild {}
+  /// `bg:$blue50`: `background-color: hsl(213.75 100% 96.86275%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue50 for GeneratedAtomBgChild {}
+  /// `bg:$blue100`: `background-color: hsl(214.2857 94.594635% 92.7451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue100 for GeneratedAtomBgChild {}
+  /// `bg:$blue200`: `background-color: hsl(213.33333 96.9231% 87.254906%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue200 for GeneratedAtomBgChild {}
+  /// `bg:$blue300`: `background-color: hsl(211.69812 96.36363% 78.43137%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue300 for GeneratedAtomBgChild {}
+  /// `bg:$blue400`: `background-color: hsl(213.11688 93.902435% 67.84314%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue400 for GeneratedAtomBgChild {}
+  /// `bg:$blue500`: `background-color: hsl(217.21925 91.21951% 59.80392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue500 for GeneratedAtomBgChild {}
+  /// `bg:$blue600`: `background-color: hsl(221.21213 83.19328% 53.333336%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue600 for GeneratedAtomBgChild {}
+  /// `bg:$blue700`: `background-color: hsl(224.27808 76.32653% 48.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue700 for GeneratedAtomBgChild {}
+  /// `bg:$blue800`: `background-color: hsl(225.93103 70.73171% 40.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue800 for GeneratedAtomBgChild {}
+  /// `bg:$blue900`: `background-color: hsl(224.44444 64.28572% 32.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue900 for GeneratedAtomBgChild {}
+  /// `bg:$blue950`: `background-color: hsl(226.2295 57.00935% 20.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorBlue950 for GeneratedAtomBgChild {}
+  /// `bg:$indigo50`: `background-color: hsl(225.88235 100% 96.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo50 for GeneratedAtomBgChild {}
+  /// `bg:$indigo100`: `background-color: hsl(226.45161 99.999954% 93.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo100 for GeneratedAtomBgChild {}
+  /// `bg:$indigo200`: `background-color: hsl(228 96.49126% 88.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo200 for GeneratedAtomBgChild {}
+  /// `bg:$indigo300`: `background-color: hsl(229.65517 93.54841% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo300 for GeneratedAtomBgChild {}
+  /// `bg:$indigo400`: `background-color: hsl(234.45378 89.473694% 73.92157%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo400 for GeneratedAtomBgChild {}
+  /// `bg:$indigo500`: `background-color: hsl(238.73239 83.52941% 66.66667%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo500 for GeneratedAtomBgChild {}
+  /// `bg:$indigo600`: `background-color: hsl(243.39624 75.35545% 58.62745%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo600 for GeneratedAtomBgChild {}
+  /// `bg:$indigo700`: `background-color: hsl(244.52055 57.936512% 50.588238%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo700 for GeneratedAtomBgChild {}
+  /// `bg:$indigo800`: `background-color: hsl(243.65218 54.502373% 41.37255%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo800 for GeneratedAtomBgChild {}
+  /// `bg:$indigo900`: `background-color: hsl(242.16869 47.428574% 34.31373%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo900 for GeneratedAtomBgChild {}
+  /// `bg:$indigo950`: `background-color: hsl(243.75 47.058826% 20%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorIndigo950 for GeneratedAtomBgChild {}
+  /// `bg:$violet50`: `background-color: hsl(249.99998 100% 97.64706%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet50 for GeneratedAtomBgChild {}
+  /// `bg:$violet100`: `background-color: hsl(251.42859 91.30429% 95.4902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet100 for GeneratedAtomBgChild {}
+  /// `bg:$violet200`: `background-color: hsl(250.50002 95.2381% 91.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet200 for GeneratedAtomBgChild {}
+  /// `bg:$violet300`: `background-color: hsl(252.50002 94.73685% 85.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet300 for GeneratedAtomBgChild {}
+  /// `bg:$violet400`: `background-color: hsl(255.13513 91.73555% 76.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet400 for GeneratedAtomBgChild {}
+  /// `bg:$violet500`: `background-color: hsl(258.3117 89.53488% 66.27451%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet500 for GeneratedAtomBgChild {}
+  /// `bg:$violet600`: `background-color: hsl(262.12292 83.25581% 57.843136%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet600 for GeneratedAtomBgChild {}
+  /// `bg:$violet700`: `background-color: hsl(263.38983 69.96047% 50.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet700 for GeneratedAtomBgChild {}
+  /// `bg:$violet800`: `background-color: hsl(263.3557 69.30233% 42.156864%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet800 for GeneratedAtomBgChild {}
+  /// `bg:$violet900`: `background-color: hsl(263.5 67.41573% 34.901962%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet900 for GeneratedAtomBgChild {}
+  /// `bg:$violet950`: `background-color: hsl(261.17645 72.649574% 22.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorViolet950 for GeneratedAtomBgChild {}
+  /// `bg:$purple50`: `background-color: hsl(270 100% 98.039215%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple50 for GeneratedAtomBgChild {}
+  /// `bg:$purple100`: `background-color: hsl(268.69565 99.99993% 95.4902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple100 for GeneratedAtomBgChild {}
+  /// `bg:$purple200`: `background-color: hsl(268.57144 100% 91.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple200 for GeneratedAtomBgChild {}
+  /// `bg:$purple300`: `background-color: hsl(269.18918 97.368416% 85.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple300 for GeneratedAtomBgChild {}
+  /// `bg:$purple400`: `background-color: hsl(270 95.2381% 75.29412%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple400 for GeneratedAtomBgChild {}
+  /// `bg:$purple500`: `background-color: hsl(270.74075 91.01124% 65.09804%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple500 for GeneratedAtomBgChild {}
+  /// `bg:$purple600`: `background-color: hsl(271.4754 81.333336% 55.88235%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple600 for GeneratedAtomBgChild {}
+  /// `bg:$purple700`: `background-color: hsl(272.09302 71.66667% 47.058823%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple700 for GeneratedAtomBgChild {}
+  /// `bg:$purple800`: `background-color: hsl(272.8889 67.164185% 39.411766%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple800 for GeneratedAtomBgChild {}
+  /// `bg:$purple900`: `background-color: hsl(273.64487 65.64417% 31.960785%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple900 for GeneratedAtomBgChild {}
+  /// `bg:$purple950`: `background-color: hsl(273.54837 86.915886% 20.980392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPurple950 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia50`: `background-color: hsl(289.0909 99.99986% 97.84313%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia50 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia100`: `background-color: hsl(286.9565 99.99993% 95.4902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia100 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia200`: `background-color: hsl(288.26086 95.83333% 90.588234%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia200 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia300`: `background-color: hsl(291.1111 93.10343% 82.94118%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia300 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia400`: `background-color: hsl(292.03125 91.42857% 72.54902%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia400 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia500`: `background-color: hsl(292.18936 84.079605% 60.588234%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia500 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia600`: `background-color: hsl(293.4104 69.47791% 48.82353%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia600 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia700`: `background-color: hsl(294.69388 72.413795% 39.80392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia700 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia800`: `background-color: hsl(295.42374 70.2381% 32.941177%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia800 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia900`: `background-color: hsl(296.7033 63.636364% 28.039217%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia900 for GeneratedAtomBgChild {}
+  /// `bg:$fuchsia950`: `background-color: hsl(296.75674 90.243904% 16.078432%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorFuchsia950 for GeneratedAtomBgChild {}
+  /// `bg:$pink50`: `background-color: hsl(327.27274 73.33326% 97.05882%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink50 for GeneratedAtomBgChild {}
+  /// `bg:$pink100`: `background-color: hsl(325.7143 77.77773% 94.70588%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink100 for GeneratedAtomBgChild {}
+  /// `bg:$pink200`: `background-color: hsl(325.9091 84.61539% 89.803925%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink200 for GeneratedAtomBgChild {}
+  /// `bg:$pink300`: `background-color: hsl(327.4074 87.09679% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink300 for GeneratedAtomBgChild {}
+  /// `bg:$pink400`: `background-color: hsl(328.6154 85.52631% 70.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink400 for GeneratedAtomBgChild {}
+  /// `bg:$pink500`: `background-color: hsl(330.36584 81.18812% 60.39216%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink500 for GeneratedAtomBgChild {}
+  /// `bg:$pink600`: `background-color: hsl(333.33334 71.42858% 50.588238%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink600 for GeneratedAtomBgChild {}
+  /// `bg:$pink700`: `background-color: hsl(335.06024 77.57009% 41.960785%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink700 for GeneratedAtomBgChild {}
+  /// `bg:$pink800`: `background-color: hsl(335.8209 74.44445% 35.294117%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink800 for GeneratedAtomBgChild {}
+  /// `bg:$pink900`: `background-color: hsl(335.88785 69.032265% 30.392159%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink900 for GeneratedAtomBgChild {}
+  /// `bg:$pink950`: `background-color: hsl(336.16437 83.90804% 17.058825%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorPink950 for GeneratedAtomBgChild {}
+  /// `bg:$rose50`: `background-color: hsl(355.7143 100% 97.254906%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose50 for GeneratedAtomBgChild {}
+  /// `bg:$rose100`: `background-color: hsl(355.55554 99.99995% 94.70588%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose100 for GeneratedAtomBgChild {}
+  /// `bg:$rose200`: `background-color: hsl(352.65308 96.0784% 90%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose200 for GeneratedAtomBgChild {}
+  /// `bg:$rose300`: `background-color: hsl(352.58426 95.698944% 81.76471%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose300 for GeneratedAtomBgChild {}
+  /// `bg:$rose400`: `background-color: hsl(351.30435 94.520546% 71.37255%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose400 for GeneratedAtomBgChild {}
+  /// `bg:$rose500`: `background-color: hsl(349.72375 89.16257% 60.19608%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose500 for GeneratedAtomBgChild {}
+  /// `bg:$rose600`: `background-color: hsl(346.83673 77.16535% 49.80392%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose600 for GeneratedAtomBgChild {}
+  /// `bg:$rose700`: `background-color: hsl(345.34885 82.69231% 40.784313%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose700 for GeneratedAtomBgChild {}
+  /// `bg:$rose800`: `background-color: hsl(343.40427 79.66102% 34.705883%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose800 for GeneratedAtomBgChild {}
+  /// `bg:$rose900`: `background-color: hsl(341.53845 75.48387% 30.392159%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose900 for GeneratedAtomBgChild {}
+  /// `bg:$rose950`: `background-color: hsl(343.0986 87.65432% 15.882354%);`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorRose950 for GeneratedAtomBgChild {}
+  /// `bg:$inherit`: `background-color: inherit;`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorInherit for GeneratedAtomBgChild {}
+  /// `bg:$transparent`: `background-color: transparent;`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorTransparent for GeneratedAtomBgChild {}
+  /// `bg:$current`: `background-color: currentColor;`
+  #[cfg(not(feature = "skribble-skip-palette"))]
+  impl GeneratedColorCurrent for GeneratedAtomBgChild {}
+  pub trait GeneratedAtomBg: GeneratedSkribbleValue {
+    #[inline]
+    fn bg(&self) -> GeneratedAtomBgChild {
+      GeneratedAtomBgChild::from_ref(self.append("bg"))
+    }
+    #[inline]
+    fn bg_(&self, value: &'static str) -> String {
+      self.append(format!("bg:[{}]", value.trim()))
+    }
+  }
+  impl GeneratedAtomBg for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAtomBg for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAtomBg for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerImportantGroupImportantChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerAlphaGroupAlphaChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerAlphaGroupOpaquifyChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerAlphaGroupTransparentizeChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerLightnessGroupLightnessChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerLightnessGroupLightenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerLightnessGroupDarkenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerMixGroupMixChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerMixGroupTintChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerMixGroupShadeChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerOklchGroupOklchLightenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerOklchGroupOklchDarkenChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerOklchGroupOklchSaturateChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerOklchGroupOklchDesaturateChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAtomBg for GeneratedTransformerOklchGroupOklchInvertChild {}
+}
+#[cfg(not(feature = "skribble-skip-atom-bg"))]
+pub use atom_bg::*;
+#[cfg(not(feature = "skribble-skip-classes"))]
+mod classes {
+  use super::*;
+  pub trait GeneratedNamedClasses: GeneratedSkribbleValue {
+    #[inline]
+    fn group(&self) -> String {
+      self.append_value("group")
+    }
+    /// ```css
+    /// width: 100%;
+    /// max-width: var(--sk-cmw);
+    /// ```
+    #[inline]
+    fn contained(&self) -> String {
+      self.append_value("contained")
+    }
+    /// ```css
+    /// font-style: italic;
+    /// ```
+    #[inline]
+    fn italic(&self) -> String {
+      self.append_value("italic")
+    }
+    /// ```css
+    /// font-style: normal;
+    /// ```
+    #[inline]
+    fn non_italic(&self) -> String {
+      self.append_value("non-italic")
+    }
+    /// ```css
+    /// font-style: oblique -10deg;
+    /// ```
+    #[inline]
+    fn oblique(&self) -> String {
+      self.append_value("oblique")
+    }
+    /// ```css
+    /// --sk-invalid-css-variable: --sk-invalid-css-variable;
+    /// transform: translate3d(var(--sk-translate-x), var(--sk-translate-y), 0) rotate(var(--sk-rotate) skewX(var(--sk-skew-x)) skewY(var(--sk-skew-y)) scaleX(var(--sk-scale-x)) scaleY(var(--sk-scale-y));
+    /// ```
+    #[inline]
+    fn transform_gpu(&self) -> String {
+      self.append_value("transform-gpu")
+    }
+    /// ```css
+    /// --sk-invalid-css-variable: --sk-invalid-css-variable;
+    /// transform: translate(var(--sk-translate-x), var(--sk-translate-y)) rotate(var(--sk-rotate)) skewX(var(--sk-skew-x)) skewY(var(--sk-skew-y)) scaleX(var(--sk-scale-x)) scaleY(var(--sk-scale-y));
+    /// ```
+    #[inline]
+    fn transform_cpu(&self) -> String {
+      self.append_value("transform-cpu")
+    }
+    /// ```css
+    /// --sk-space-x-reverse: 1;
+    /// ```
+    #[inline]
+    fn space_x_reverse(&self) -> String {
+      self.append_value("space-x-reverse")
+    }
+    /// ```css
+    /// --sk-space-y-reverse: 1;
+    /// ```
+    #[inline]
+    fn space_y_reverse(&self) -> String {
+      self.append_value("space-y-reverse")
+    }
+    /// ```css
+    /// position: absolute;
+    /// width: 1px;
+    /// height: 1px;
+    /// padding: 0;
+    /// margin: -1px;
+    /// overflow: hidden;
+    /// clip: rect(0, 0, 0, 0);
+    /// white-space: nowrap;
+    /// border-width: 0;
+    /// ```
+    #[inline]
+    fn sr_only(&self) -> String {
+      self.append_value("sr-only")
+    }
+    /// ```css
+    /// position: static;
+    /// width: auto;
+    /// height: auto;
+    /// padding: 0;
+    /// margin: 0;
+    /// overflow: visible;
+    /// clip: auto;
+    /// white-space: normal;
+    /// ```
+    #[inline]
+    fn sr_exclude(&self) -> String {
+      self.append_value("sr-exclude")
+    }
+    /// ```css
+    /// outline: 2px solid transparent;
+    /// outline-offset: 2px;
+    /// ```
+    #[inline]
+    fn outline_none(&self) -> String {
+      self.append_value("outline-none")
+    }
+    /// ```css
+    /// box-shadow: 0 0 #000;
+    /// ```
+    #[inline]
+    fn shadow_none(&self) -> String {
+      self.append_value("shadow-none")
+    }
+    /// ```css
+    /// filter: none;
+    /// ```
+    #[inline]
+    fn filter_none(&self) -> String {
+      self.append_value("filter-none")
+    }
+    /// ```css
+    /// backdrop-filter: none;
+    /// ```
+    #[inline]
+    fn backdrop_filter_none(&self) -> String {
+      self.append_value("backdrop-filter-none")
+    }
+    /// Suppress native form control styling.
+    ///
+    /// ```css
+    /// appearance: none;
+    /// ```
+    #[inline]
+    fn appearance_none(&self) -> String {
+      self.append_value("appearance-none")
+    }
+    /// Use the `snap-mandatory` utility to force a snap container to always come to rest on a snap point.
+    ///
+    /// ```css
+    /// --sk-scroll-snap-strictness: mandatory;
+    /// ```
+    #[inline]
+    fn snap_mandatory(&self) -> String {
+      self.append_value("snap-mandatory")
+    }
+    /// Use the `snap-proximity` utility to make a snap container come to rest on snap points that are close in proximity. This is the browser default.
+    ///
+    /// ```css
+    /// --sk-scroll-snap-strictness: proximity;
+    /// ```
+    #[inline]
+    fn snap_proximity(&self) -> String {
+      self.append_value("snap-proximity")
+    }
+  }
+  pub trait GeneratedAliases: GeneratedSkribbleValue {
+    #[inline]
+    fn block(&self) -> String {
+      ["display:$block"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn inline_block(&self) -> String {
+      ["display:$inline-block"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn inline(&self) -> String {
+      ["display:$inline"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_1(&self) -> String {
+      ["display:$flex"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn inline_flex(&self) -> String {
+      ["display:$inline-flex"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table(&self) -> String {
+      ["display:$table"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn inline_table(&self) -> String {
+      ["display:$inline-table"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_caption(&self) -> String {
+      ["display:$table-caption"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_cell(&self) -> String {
+      ["display:$table-cell"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_column(&self) -> String {
+      ["display:$table-column"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_column_group(&self) -> String {
+      ["display:$table-column-group"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_footer_group(&self) -> String {
+      ["display:$table-footer-group"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_header_group(&self) -> String {
+      ["display:$table-header-group"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_row_group(&self) -> String {
+      ["display:$table-row-group"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn table_row(&self) -> String {
+      ["display:$table-row"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flow_root(&self) -> String {
+      ["display:$flow-root"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn grid(&self) -> String {
+      ["display:$grid"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn inline_grid(&self) -> String {
+      ["display:$inline-grid"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn contents(&self) -> String {
+      ["display:$contents"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn list_item(&self) -> String {
+      ["display:$list-item"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn hidden(&self) -> String {
+      ["display:$none"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn visible(&self) -> String {
+      ["visibility:$visible"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn invisible(&self) -> String {
+      ["visibility:$hidden"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn collapse(&self) -> String {
+      ["visibility:$collapse"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn isolate_1(&self) -> String {
+      ["isolate:$isolate"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn r#static(&self) -> String {
+      ["position:$static"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn fixed(&self) -> String {
+      ["position:$fixed"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn absolute(&self) -> String {
+      ["position:$absolute"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn relative(&self) -> String {
+      ["position:$relative"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn sticky(&self) -> String {
+      ["position:$sticky"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn blur_1(&self) -> String {
+      ["blur:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn drop_shadow_1(&self) -> String {
+      ["drop-shadow:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn grayscale_1(&self) -> String {
+      ["grayscale:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn invert_1(&self) -> String {
+      ["invert:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn sepia_1(&self) -> String {
+      ["sepia:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn filter_none_1(&self) -> String {
+      ["filter:$none"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn backdrop_blur_1(&self) -> String {
+      ["backdrop-blur:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn backdrop_drop_shadow_1(&self) -> String {
+      ["backdrop-drop-shadow:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn backdrop_grayscale_1(&self) -> String {
+      ["backdrop-grayscale:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn backdrop_invert_1(&self) -> String {
+      ["backdrop-invert:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn backdrop_sepia_1(&self) -> String {
+      ["backdrop-sepia:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn backdrop_filter_none_1(&self) -> String {
+      ["backdrop-filter:$none"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_row(&self) -> String {
+      ["flex-direction:$row"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_row_reverse(&self) -> String {
+      ["flex-direction:$row-reverse"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_col(&self) -> String {
+      ["flex-direction:$col"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_col_reverse(&self) -> String {
+      ["flex-direction:$col-reverse"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_wrap_1(&self) -> String {
+      ["flex-direction:$wrap"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_wrap_reverse(&self) -> String {
+      ["flex-direction:$wrap-reverse"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn flex_nowrap(&self) -> String {
+      ["flex-direction:$nowrap"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn grow(&self) -> String {
+      ["flex-grow:$1"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn grow_0(&self) -> String {
+      ["flex-grow:$0"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn shrink(&self) -> String {
+      ["flex-shrink:$1"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn shrink_0(&self) -> String {
+      ["flex-shrink:$0"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_sans(&self) -> String {
+      ["font-family:$sans"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_xs(&self) -> String {
+      ["font-size:$xs"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_sm(&self) -> String {
+      ["font-size:$sm"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_base(&self) -> String {
+      ["font-size:$base"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_lg(&self) -> String {
+      ["font-size:$lg"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_xl(&self) -> String {
+      ["font-size:$xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_2xl(&self) -> String {
+      ["font-size:$2xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_3xl(&self) -> String {
+      ["font-size:$3xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_4xl(&self) -> String {
+      ["font-size:$4xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_5xl(&self) -> String {
+      ["font-size:$5xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_6xl(&self) -> String {
+      ["font-size:$6xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_7xl(&self) -> String {
+      ["font-size:$7xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_8xl(&self) -> String {
+      ["font-size:$8xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_9xl(&self) -> String {
+      ["font-size:$9xl"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn antialiased(&self) -> String {
+      ["smoothing:$antialiased"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn subpixel_antialiased(&self) -> String {
+      ["smoothing:$subpixel"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn italic_1(&self) -> String {
+      ["font-style:$italic"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn non_italic_1(&self) -> String {
+      ["font-style:$normal"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_thin(&self) -> String {
+      ["font-weight:$thin"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_extralight(&self) -> String {
+      ["font-weight:$extralight"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_light(&self) -> String {
+      ["font-weight:$light"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_normal(&self) -> String {
+      ["font-weight:$normal"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_medium(&self) -> String {
+      ["font-weight:$medium"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_semibold(&self) -> String {
+      ["font-weight:$semibold"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_bold(&self) -> String {
+      ["font-weight:$bold"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_extrabold(&self) -> String {
+      ["font-weight:$extrabold"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn font_black(&self) -> String {
+      ["font-weight:$black"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn slashed_zero(&self) -> String {
+      ["font-numeric:$slashed"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn lining_nums(&self) -> String {
+      ["font-numeric:$lining"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn oldstyle_nums(&self) -> String {
+      ["font-numeric:$oldstyle"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn proportional_nums(&self) -> String {
+      ["font-numeric:$proportional"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn tabular_nums(&self) -> String {
+      ["font-numeric:$tabular"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn diagonal_fractions(&self) -> String {
+      ["font-numeric:$diagonal"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn stacked_fractions(&self) -> String {
+      ["font-numeric:$stacked"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn list_inside(&self) -> String {
+      ["list-position:$inside"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn list_outside(&self) -> String {
+      ["list-position:$outside"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_left(&self) -> String {
+      ["text-align:$left"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_center(&self) -> String {
+      ["text-align:$center"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_right(&self) -> String {
+      ["text-align:$right"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_justify(&self) -> String {
+      ["text-align:$justify"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_start(&self) -> String {
+      ["text-align:$start"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_end(&self) -> String {
+      ["text-align:$end"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn underline(&self) -> String {
+      ["text-decoration:$underline"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn overline(&self) -> String {
+      ["text-decoration:$overline"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn line_through(&self) -> String {
+      ["text-decoration:$through"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn strikethrough(&self) -> String {
+      ["text-decoration:$through"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn no_underline(&self) -> String {
+      ["text-decoration:$none"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_solid(&self) -> String {
+      ["decoration-style:$solid"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_double(&self) -> String {
+      ["decoration-style:$double"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_dotted(&self) -> String {
+      ["decoration-style:$dotted"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_dashed(&self) -> String {
+      ["decoration-style:$dashed"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_wavy(&self) -> String {
+      ["decoration-style:$wavy"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_auto(&self) -> String {
+      ["decoration-thickness:$auto"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_from_font(&self) -> String {
+      ["decoration-thickness:$from-font"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_0(&self) -> String {
+      ["decoration-thickness:$0"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_1(&self) -> String {
+      ["decoration-thickness:$1"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_2(&self) -> String {
+      ["decoration-thickness:$2"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_4(&self) -> String {
+      ["decoration-thickness:$4"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn decoration_8(&self) -> String {
+      ["decoration-thickness:$8"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn uppercase(&self) -> String {
+      ["text-transform:$uppercase"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn lowercase(&self) -> String {
+      ["text-transform:$lowercase"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn capitalize(&self) -> String {
+      ["text-transform:$capitalize"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_ellipsis(&self) -> String {
+      ["text-overflow:$ellipsis"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn text_clip(&self) -> String {
+      ["text-overflow:$clip"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn truncate(&self) -> String {
+      ["visibility:$hidden", "text-overflow:$ellipsis", "whitespace:$nowrap"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_fixed(&self) -> String {
+      ["bg-attachment:$fixed"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_local(&self) -> String {
+      ["bg-attachment:$local"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_scroll(&self) -> String {
+      ["bg-attachment:$scroll"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_bottom(&self) -> String {
+      ["bg-position:$bottom"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_center(&self) -> String {
+      ["bg-position:$center"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_left(&self) -> String {
+      ["bg-position:$left"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_left_bottom(&self) -> String {
+      ["bg-position:$left-bottom"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_left_top(&self) -> String {
+      ["bg-position:$left-top"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_right(&self) -> String {
+      ["bg-position:$right"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_right_bottom(&self) -> String {
+      ["bg-position:$right-bottom"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_right_top(&self) -> String {
+      ["bg-position:$right-top"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_top(&self) -> String {
+      ["bg-position:$top"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_repeat_1(&self) -> String {
+      ["bg-repeat:$default"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_no_repeat(&self) -> String {
+      ["bg-repeat:$none"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_repeat_x(&self) -> String {
+      ["bg-repeat:$x"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_repeat_y(&self) -> String {
+      ["bg-repeat:$y"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_repeat_round(&self) -> String {
+      ["bg-repeat:$round"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_repeat_space(&self) -> String {
+      ["bg-repeat:$space"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_auto(&self) -> String {
+      ["bg-size:$auto"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_cover(&self) -> String {
+      ["bg-size:$cover"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn bg_contain(&self) -> String {
+      ["bg-size:$contain"].map(|class| self.append(class)).join(" ")
+    }
+    #[inline]
+    fn ring_1(&self) -> String {
+      ["ring:$default"].map(|class| self.append(class)).join(" ")
+    }
+  }
+  impl GeneratedNamedClasses for GeneratedSkribbleRoot {}
+  impl GeneratedAliases for GeneratedSkribbleRoot {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedMediaQueryDeviceCategoriesChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedMediaQueryBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedMediaQueryOrientationChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedMediaQueryMotionChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedMediaQueryDarkModeChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedFeatureQuerySupportsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedNamedClasses for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-media-queries"))]
+  impl GeneratedAliases for GeneratedContainerQueryContainerBreakpointsChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierParentGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierHoverGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierActiveGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierFocusGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierFocusWithinGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierFocusVisibleGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierEnabledGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierEmptyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierReadGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierCheckedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierValidGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierAutofillGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierEvenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierNodePositionGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierNodeOfTypeGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierVisitedGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierOptionalGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierAriaChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierFullScreenGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierTargetGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierPlaceholderShownGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierRequiredGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierDefaultGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierOnlyChildGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierOnlyGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierRootGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierLinkGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedNamedClasses for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-modifiers"))]
+  impl GeneratedAliases for GeneratedModifierPseudoGroupChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedNamedClasses for GeneratedTransformerImportantGroupImportantChild {}
+  #[cfg(not(feature = "skribble-skip-transformers"))]
+  impl GeneratedAliases for GeneratedTransformerImportantGroupImportantChild {}
+}
+#[cfg(not(feature = "skribble-skip-classes"))]
+pub use classes::*;
\ No newline at end of file
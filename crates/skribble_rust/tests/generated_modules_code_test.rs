@@ -0,0 +1,12 @@
+mod cases;
+mod generated_modules_code;
+use generated_modules_code::*;
+use rstest::rstest;
+pub use rstest_reuse;
+use rstest_reuse::apply;
+use similar_asserts::assert_eq;
+
+#[apply(cases::generated_class_name_cases)]
+fn generated_class_names(#[case] input: impl AsRef<str>, #[case] expected: &str) {
+	assert_eq!(input.as_ref(), expected);
+}
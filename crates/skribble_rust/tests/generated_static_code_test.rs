@@ -0,0 +1,43 @@
+mod cases;
+mod generated_static_code;
+use generated_static_code::*;
+use rstest::rstest;
+pub use rstest_reuse;
+use rstest_reuse::apply;
+use similar_asserts::assert_eq;
+
+#[apply(cases::generated_class_name_cases)]
+#[case::media_query_padding(sk().dark().p().px(), "dark:p:$px")]
+#[case::media_query_padding(sk().dark().p().n1(), "dark:p:$1")]
+#[case::chained_media_query_key_value_argument(sk().screen().md_("padding", "1px"), "screen:md:[padding=1px]")]
+#[case::container_query_padding(sk().cq_md().p().n4(), "cq-md:p:$4")]
+#[case::padding_argument(sk().p_("101px"), "p:[101px]")]
+#[case::background_palette(sk().bg().red100(), "bg:$red100")]
+#[case::columns(sk().columns().n4(), "columns:$4")]
+#[case::aliases(sk().md().block(), "md:display:$block")]
+#[case::root_alias(sk().block(), "display:$block")]
+#[case::important_atom(sk().hover().important().inline_block(), "hover:(important):display:$inline-block")]
+#[case::root_named_class(sk().sr_only(), "$sr-only")]
+#[case::transformer_alpha(sk().md().alpha_050().bg().red100(), "md:(alpha==050):bg:$red100")]
+#[case::transformer_color_args(sk().md().alpha_("50%", sk().bg().red100()), "md:(alpha=50%):bg:$red100")]
+#[case::transformer_mix(sk().mix_("white,20%", sk().bg().blue500()), "(mix=white,20%):bg:$blue500")]
+#[case::transformer_tint(sk().tint_020().bg().red500(), "(tint==020):bg:$red500")]
+#[case::gradients(
+  &[sk().bg_gradient().to_right(), sk().from_color().cyan500(), sk().to_color().blue500()].join(" "),
+  "bg-gradient:$to-right from-color:$cyan500 to-color:$blue500"
+)]
+fn generated_static_class_names(#[case] input: impl AsRef<str>, #[case] expected: &str) {
+	assert_eq!(input.as_ref(), expected);
+}
+
+#[test]
+fn static_class_names_are_borrowed() {
+	let class_name: &'static str = sk().md().hover().bg().red500();
+	assert_eq!(class_name, "md:hover:bg:$red500");
+}
+
+#[test]
+fn static_variables_are_const() {
+	const PRIMARY: &str = vars().primary();
+	assert_eq!(PRIMARY, "--sk-p");
+}
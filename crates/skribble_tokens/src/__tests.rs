@@ -0,0 +1,329 @@
+use std::path::PathBuf;
+
+use indexmap::indexmap;
+use indoc::indoc;
+use rstest::rstest;
+use skribble_core::*;
+use skribble_test::set_snapshot_suffix;
+
+use super::*;
+
+const TOKENS: &str = indoc! {r##"
+	{
+		"color": {
+			"$type": "color",
+			"brand": {
+				"100": { "$value": "#dbeafe" },
+				"500": { "$value": "#3b82f6", "$description": "The main brand color." },
+				"900": { "$value": { "colorSpace": "oklch", "components": [0.38, 0.14, 265], "alpha": 0.9 } }
+			},
+			"primary": { "$value": "{color.brand.500}", "$description": "The primary color." },
+			"action": { "$value": "{color.primary}" },
+			"muted": { "$value": "rgb(from {color.brand.900} r g b / 50%)" }
+		},
+		"spacing": {
+			"$type": "dimension",
+			"sm": { "$value": "0.5rem" },
+			"md": { "$value": { "value": 1, "unit": "rem" } },
+			"lg": { "$value": "calc({spacing.md} * 2)" }
+		},
+		"fontFamily": {
+			"$type": "fontFamily",
+			"sans": { "$value": ["Inter", "Helvetica Neue", "sans-serif"] }
+		},
+		"fontWeight": {
+			"bold": { "$type": "fontWeight", "$value": 700 }
+		},
+		"easing": {
+			"standard": { "$type": "cubicBezier", "$value": [0.4, 0, 0.2, 1] }
+		},
+		"shadow": {
+			"raised": {
+				"$type": "shadow",
+				"$value": {
+					"color": "{color.primary}",
+					"offsetX": "0px",
+					"offsetY": "2px",
+					"blur": "4px",
+					"spread": "0px"
+				}
+			}
+		},
+		"typography": {
+			"heading": {
+				"$type": "typography",
+				"$value": {
+					"fontFamily": "{fontFamily.sans}",
+					"fontSize": "2rem",
+					"fontWeight": "{fontWeight.bold}",
+					"lineHeight": 1.2
+				}
+			}
+		},
+		"breakpoints": {
+			"$type": "dimension",
+			"tablet": { "$value": "768px", "$description": "Tablets and larger." },
+			"desktop": { "$value": "1280px" }
+		},
+		"keyframes": {
+			"$type": "keyframes",
+			"fade": {
+				"$value": {
+					"from": { "opacity": "0" },
+					"to": { "opacity": "1", "backgroundColor": "{color.action}" }
+				}
+			}
+		},
+		"size": {
+			"icon": { "value": "24px", "type": "dimension", "comment": "Style Dictionary format." }
+		}
+	}
+"##};
+
+#[test]
+fn can_import_design_tokens() -> AnyEmptyResult {
+	let mut plugin = TokensPlugin::builder()
+		.source(TokenSource::json(TOKENS))
+		.build();
+	let mut config = PluginConfig::default();
+	plugin.mutate_config(&mut config, &Options::default())?;
+
+	insta::assert_json_snapshot!(config);
+
+	Ok(())
+}
+
+#[test]
+fn can_read_design_tokens_file() -> AnyEmptyResult {
+	let directory = tempfile::tempdir()?;
+	std::fs::write(directory.path().join("tokens.json"), TOKENS)?;
+
+	let plugin = TokensPlugin::builder()
+		.source(TokenSource::file("tokens.json"))
+		.build();
+	let config = StyleConfig::builder()
+		.plugins(vec![PluginContainer::from(plugin)])
+		.build();
+	let mut runner = SkribbleRunner::new(config, directory.path(), None);
+	let runner_config = runner.initialize()?;
+
+	assert_eq!(
+		runner_config.palette.get("brand-500").map(String::as_str),
+		Some("#3b82f6")
+	);
+	assert_eq!(
+		runner_config
+			.css_variables
+			.get("action")
+			.map(|variable| variable.get_variable(runner_config.options())),
+		Some("--sk-action".into())
+	);
+	assert!(runner_config.get_media_query("tablet").is_some());
+
+	Ok(())
+}
+
+#[test]
+fn palette_aliases_are_css_variables() -> AnyEmptyResult {
+	let content = r##"{
+		"color": {
+			"$type": "color",
+			"blue": { "500": { "$value": "#3b82f6" } },
+			"primary": { "$value": "{color.blue.500}" }
+		}
+	}"##;
+	let plugin = TokensPlugin::builder()
+		.source(TokenSource::json(content))
+		.build();
+	let config = StyleConfig::builder()
+		.plugins(vec![PluginContainer::from(plugin)])
+		.build();
+	let mut runner = SkribbleRunner::try_new(config)?;
+	let runner_config = runner.initialize()?;
+	let variable = runner_config.css_variables.get("color-blue-500");
+
+	assert_eq!(
+		runner_config.palette.get("blue-500").map(String::as_str),
+		Some("#3b82f6")
+	);
+	assert_eq!(
+		runner_config
+			.css_variables
+			.get("primary")
+			.map(|variable| &variable.value),
+		Some(&Placeholder::wrapped_variable("color-blue-500", None))
+	);
+	assert_eq!(
+		variable.map(|variable| &variable.value),
+		Some(&Placeholder::palette("blue-500"))
+	);
+	assert_eq!(
+		variable.map(|variable| variable.get_variable(runner_config.options())),
+		Some("--sk-color-blue-500".into())
+	);
+
+	Ok(())
+}
+
+#[rstest]
+#[case("unresolved", r#"{ "a": { "$value": "{b}" } }"#)]
+#[case(
+	"circular",
+	r#"{ "a": { "$value": "{b}" }, "b": { "$value": "{a}" } }"#
+)]
+#[case(
+	"circular_color",
+	r#"{ "color": { "$type": "color", "a": { "$value": "{color.b}" }, "b": { "$value": "{color.a}" } } }"#
+)]
+#[case(
+	"invalid_dimension",
+	r#"{ "a": { "$type": "dimension", "$value": { "unit": "px" } } }"#
+)]
+#[case("invalid_color", r#"{ "a": { "$type": "color", "$value": { "colorSpace": "cmyk", "components": [0, 0, 0, 1] } } }"#)]
+#[case("invalid_root", r#"[]"#)]
+#[case("invalid_json", r#"{ "a": "#)]
+fn invalid_design_tokens(#[case] id: &str, #[case] content: &str) {
+	set_snapshot_suffix!("{id}");
+
+	let mut plugin = TokensPlugin::builder()
+		.source(TokenSource::json(content))
+		.build();
+	let mut config = PluginConfig::default();
+	let error = plugin
+		.mutate_config(&mut config, &Options::default())
+		.unwrap_err();
+
+	insta::assert_display_snapshot!(error);
+}
+
+#[rstest]
+#[case::dtcg(TokenFormat::Dtcg)]
+#[case::figma(TokenFormat::Figma)]
+#[case::scss(TokenFormat::Scss)]
+#[case::less(TokenFormat::Less)]
+fn can_export_design_tokens(#[case] format: TokenFormat) -> AnyEmptyResult {
+	set_snapshot_suffix!("{}", format.file_name());
+
+	let plugin = TokenExportPlugin::builder().formats(vec![format]).build();
+	let mut runner = SkribbleRunner::try_new(create_export_config(plugin))?;
+	let _ = runner.initialize()?;
+	let result = runner.generate()?;
+	let GeneratedFile { path, content } = result.first().ok_or(Error::Unknown)?;
+
+	assert_eq!(path, &PathBuf::from("./tokens").join(format.file_name()));
+	insta::assert_display_snapshot!(content);
+
+	Ok(())
+}
+
+#[test]
+fn exported_tokens_can_be_imported() -> AnyEmptyResult {
+	let mut runner =
+		SkribbleRunner::try_new(create_export_config(TokenExportPlugin::builder().build()))?;
+	let exported = runner.initialize()?.clone();
+	let content = TokenExporter::new(&exported).export(TokenFormat::Dtcg)?;
+
+	let plugin = TokensPlugin::builder()
+		.source(TokenSource::json(content))
+		.build();
+	let config = StyleConfig::builder()
+		.plugins(vec![PluginContainer::from(plugin)])
+		.build();
+	let mut runner = SkribbleRunner::try_new(config)?;
+	let imported = runner.initialize()?;
+
+	for (name, value) in exported.palette.iter() {
+		assert_eq!(imported.palette.get(name), Some(value));
+	}
+
+	assert_eq!(
+		imported
+			.css_variables
+			.get("brand")
+			.and_then(|v| v.media_queries.get(&Placeholder::media_query("dark")))
+			.and_then(|selectors| selectors.get("")),
+		Some(&Placeholder::wrapped_variable("color-blue-500", None))
+	);
+	assert_eq!(
+		imported.css_variables.get("primary").map(|v| &v.value),
+		Some(&Placeholder::wrapped_variable("brand", None))
+	);
+	assert!(imported.get_media_query("dark").is_some());
+	assert_eq!(
+		imported.value_sets.get("spacing").map(|v| &v.values),
+		exported.value_sets.get("spacing").map(|v| &v.values)
+	);
+	assert_eq!(
+		imported.get_media_query("tablet").map(|m| &m.query),
+		Some(&"(min-width: 768px)".to_string())
+	);
+
+	Ok(())
+}
+
+fn create_export_config(plugin: TokenExportPlugin) -> StyleConfig {
+	StyleConfig::builder()
+		.palette(indexmap! { "blue-500" => "#3b82f6", "white" => "#ffffff" })
+		.variables(vec![
+			CssVariable::builder()
+				.name("brand")
+				.variable("--brand")
+				.value("#570df8")
+				.description("The brand color.")
+				.syntax(PropertySyntaxValue::Color)
+				.media_queries(indexmap! {
+					Placeholder::media_query("dark") => indexmap! { "" => Placeholder::palette("blue-500") },
+				})
+				.build(),
+			CssVariable::builder()
+				.name("primary")
+				.variable("--primary")
+				.value(Placeholder::wrapped_variable("brand", None))
+				.syntax(PropertySyntaxValue::Color)
+				.build(),
+			CssVariable::builder()
+				.name("radius")
+				.variable("--radius")
+				.value("0.25rem")
+				.build(),
+		])
+		.value_sets(vec![
+			ValueSet::builder()
+				.name("spacing")
+				.values(indexmap! { "0.5" => "0.125rem", "1" => "0.25rem", "px" => "1px" })
+				.build(),
+			ValueSet::builder()
+				.name("heading")
+				.values(indexmap! {
+					"lg" => indexmap! { "font-size" => "2rem", "line-height" => "1.2" },
+				})
+				.build(),
+		])
+		.media_queries(vec![
+			Group::builder()
+				.name("breakpoints")
+				.items(vec![
+					MediaQuery::builder()
+						.name("tablet")
+						.query("(min-width: 768px)")
+						.description("Tablets and larger.")
+						.build(),
+					MediaQuery::builder()
+						.name("dark")
+						.query("(prefers-color-scheme: dark)")
+						.build(),
+				])
+				.build(),
+		])
+		.keyframes(vec![
+			Keyframe::builder()
+				.name("fade")
+				.rules(indexmap! {
+					"from" => indexmap! { "opacity" => "0" },
+					"to" => indexmap! { "opacity" => "1" },
+				})
+				.build(),
+		])
+		.plugins(vec![PluginContainer::from(plugin)])
+		.build()
+}
@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+
+pub type TokensResult<T> = core::result::Result<T, TokensError>;
+
+#[non_exhaustive]
+#[derive(thiserror::Error, Debug)]
+pub enum TokensError {
+	#[error("could not read the design tokens file: `{path}`")]
+	ReadFile {
+		path: PathBuf,
+		#[source]
+		source: std::io::Error,
+	},
+	#[error("the design tokens are not valid json")]
+	InvalidJson(#[source] serde_json::Error),
+	#[error("the design tokens must be a json object")]
+	InvalidRoot,
+	#[error("the token `{0}` references a token which does not exist: `{1}`")]
+	UnresolvedAlias(String, String),
+	#[error("the token `{0}` has a circular reference")]
+	CircularAlias(String),
+	#[error("the token `{0}` has an invalid value for the `{1}` type")]
+	InvalidValue(String, String),
+}
@@ -0,0 +1,659 @@
+use heck::ToLowerCamelCase;
+use indexmap::indexmap;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use skribble_core::skribble_color::Color;
+use skribble_core::*;
+
+use crate::SKRIBBLE_EXTENSION;
+
+const DEFAULT_MODE: &str = "default";
+const ROOT_FONT_SIZE: f64 = 16.0;
+
+/// The formats which the design tokens can be exported to.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenFormat {
+	/// The [W3C Design Tokens Community Group](https://tr.designtokens.org/format/) JSON format.
+	Dtcg,
+	/// A JSON file with the same shape as the Figma variables REST API.
+	Figma,
+	/// SCSS variables.
+	Scss,
+	/// Less variables.
+	Less,
+}
+
+impl TokenFormat {
+	/// The name of the file generated for this format.
+	pub fn file_name(&self) -> &'static str {
+		match self {
+			Self::Dtcg => "tokens.json",
+			Self::Figma => "figma.json",
+			Self::Scss => "_tokens.scss",
+			Self::Less => "tokens.less",
+		}
+	}
+}
+
+/// Serializes the resolved configuration to design tokens.
+pub struct TokenExporter<'a> {
+	config: &'a RunnerConfig,
+}
+
+impl<'a> TokenExporter<'a> {
+	pub fn new(config: &'a RunnerConfig) -> Self {
+		Self { config }
+	}
+
+	/// Export the tokens to the provided format.
+	pub fn export(&self, format: TokenFormat) -> AnyResult<String> {
+		let content = match format {
+			TokenFormat::Dtcg => serde_json::to_string_pretty(&self.to_dtcg())?,
+			TokenFormat::Figma => serde_json::to_string_pretty(&self.to_figma())?,
+			TokenFormat::Scss => self.to_variables("$", |value| format!("\"{value}\"")),
+			TokenFormat::Less => self.to_variables("@", |value| format!("~\"{value}\"")),
+		};
+
+		Ok(content)
+	}
+
+	/// Create the W3C design tokens.
+	///
+	/// Palette entries and color css variables are added to the `color` group
+	/// and references between them are kept as token aliases. Value sets are
+	/// added to groups with the same name.
+	pub fn to_dtcg(&self) -> Value {
+		let mut groups = IndexMap::<String, Map<String, Value>>::new();
+		let mut colors = group_with_type("color");
+		let mut variables = Map::new();
+
+		for (name, value) in self.config.palette.iter() {
+			colors.insert(name.clone(), json!({ "$value": self.to_dtcg_value(value) }));
+		}
+
+		for (name, variable) in self.config.css_variables.iter() {
+			let mut token = Map::new();
+			token.insert("$value".into(), self.to_dtcg_value(&variable.value).into());
+
+			if let Some(ref description) = variable.description {
+				token.insert("$description".into(), description.clone().into());
+			}
+
+			if !variable.is_color() {
+				insert_inferred_type(&mut token, &variable.value);
+			}
+
+			if !variable.media_queries.is_empty() {
+				let media_queries = variable
+					.media_queries
+					.iter()
+					.map(|(query, selectors)| {
+						let selectors = selectors
+							.iter()
+							.map(|(selector, value)| {
+								(selector.clone(), Value::from(self.to_dtcg_value(value)))
+							})
+							.collect::<Map<_, _>>();
+
+						(get_mode_name(query), Value::Object(selectors))
+					})
+					.collect::<Map<_, _>>();
+
+				token.insert(
+					"$extensions".into(),
+					json!({ SKRIBBLE_EXTENSION: { "mediaQueries": media_queries } }),
+				);
+			}
+
+			if variable.is_color() {
+				colors.insert(name.clone(), token.into());
+			} else {
+				variables.insert(name.clone(), token.into());
+			}
+		}
+
+		groups.insert("color".into(), colors);
+
+		if !variables.is_empty() {
+			groups.insert("variables".into(), variables);
+		}
+
+		for (name, value_set) in self.config.value_sets.iter() {
+			let group = groups.entry(name.clone()).or_default();
+
+			for (key, value) in value_set.values.iter() {
+				let mut token = Map::new();
+
+				match value {
+					CssValue::Value(value) => {
+						token.insert("$value".into(), self.to_dtcg_value(value).into());
+						insert_inferred_type(&mut token, value);
+					}
+					CssValue::Object(properties) => {
+						let is_typography = properties.keys().all(|property| {
+							property.starts_with("font-")
+								|| matches!(property.as_str(), "line-height" | "letter-spacing")
+						});
+						let properties = properties
+							.iter()
+							.map(|(property, value)| {
+								(
+									property.to_lower_camel_case(),
+									Value::from(self.to_dtcg_value(value)),
+								)
+							})
+							.collect::<Map<_, _>>();
+
+						token.insert("$value".into(), properties.into());
+
+						if is_typography {
+							token.insert("$type".into(), "typography".into());
+						}
+					}
+				}
+
+				group.insert(key.clone(), token.into());
+			}
+		}
+
+		for media_query in self.config.get_media_queries() {
+			let query = Placeholder::normalize(&media_query.query, self.config);
+			let (group, mut token) = match get_min_width(&query) {
+				Some(width) => {
+					(
+						"breakpoints",
+						json!({ "$type": "dimension", "$value": width }),
+					)
+				}
+				None => ("mediaQueries", json!({ "$value": query })),
+			};
+
+			if let (Some(ref description), Value::Object(ref mut token)) =
+				(&media_query.description, &mut token)
+			{
+				token.insert("$description".into(), description.clone().into());
+			}
+
+			groups
+				.entry(group.to_string())
+				.or_default()
+				.insert(media_query.name.clone(), token);
+		}
+
+		if !self.config.keyframes.is_empty() {
+			let mut keyframes = group_with_type("keyframes");
+
+			for (name, keyframe) in self.config.keyframes.iter() {
+				let rules = keyframe
+					.rules
+					.iter()
+					.map(|(offset, properties)| {
+						let properties = properties
+							.iter()
+							.map(|(property, value)| {
+								(
+									Placeholder::normalize(property, self.config),
+									Value::from(self.to_dtcg_value(value)),
+								)
+							})
+							.collect::<Map<_, _>>();
+
+						(offset.clone(), Value::Object(properties))
+					})
+					.collect::<Map<_, _>>();
+
+				let mut token = Map::new();
+				token.insert("$value".into(), rules.into());
+
+				if let Some(ref description) = keyframe.description {
+					token.insert("$description".into(), description.clone().into());
+				}
+
+				keyframes.insert(name.clone(), token.into());
+			}
+
+			groups.insert("keyframes".into(), keyframes);
+		}
+
+		groups
+			.into_iter()
+			.map(|(name, group)| (name, Value::Object(group)))
+			.collect::<Map<_, _>>()
+			.into()
+	}
+
+	/// Create a JSON file which matches the shape of the [Figma variables
+	/// REST API](https://www.figma.com/developers/api#variables).
+	///
+	/// The palette, css variables, value sets and breakpoints are each placed
+	/// in their own collection. The media queries used by the css variables
+	/// become modes of the `variables` collection.
+	pub fn to_figma(&self) -> Value {
+		let mut collections = vec![];
+		let mut variables = vec![];
+
+		let palette = self
+			.config
+			.palette
+			.iter()
+			.map(|(name, value)| {
+				let values = indexmap! { DEFAULT_MODE.to_string() => value.clone() };
+				(name.clone(), None, values)
+			})
+			.collect::<Vec<_>>();
+		self.add_figma_collection("palette", palette, &mut collections, &mut variables);
+
+		let css_variables = self
+			.config
+			.css_variables
+			.iter()
+			.map(|(name, variable)| {
+				let mut values = indexmap! { DEFAULT_MODE.to_string() => variable.value.clone() };
+
+				for (query, selectors) in variable.media_queries.iter() {
+					if let Some(value) = selectors.get("") {
+						values.insert(get_mode_name(query), value.clone());
+					}
+				}
+
+				(name.clone(), variable.description.clone(), values)
+			})
+			.collect::<Vec<_>>();
+		self.add_figma_collection("variables", css_variables, &mut collections, &mut variables);
+
+		let values = self
+			.config
+			.value_sets
+			.iter()
+			.flat_map(|(name, value_set)| {
+				value_set.values.iter().filter_map(move |(key, value)| {
+					let CssValue::Value(value) = value else {
+						return None;
+					};
+
+					let values = indexmap! { DEFAULT_MODE.to_string() => value.clone() };
+					Some((format!("{name}/{key}"), None, values))
+				})
+			})
+			.collect::<Vec<_>>();
+		self.add_figma_collection("values", values, &mut collections, &mut variables);
+
+		let breakpoints = self
+			.config
+			.get_media_queries()
+			.into_iter()
+			.filter_map(|media_query| {
+				let query = Placeholder::normalize(&media_query.query, self.config);
+				let width = get_min_width(&query)?;
+				let values = indexmap! { DEFAULT_MODE.to_string() => width.to_string() };
+
+				Some((
+					media_query.name.clone(),
+					media_query.description.clone(),
+					values,
+				))
+			})
+			.collect::<Vec<_>>();
+		self.add_figma_collection("breakpoints", breakpoints, &mut collections, &mut variables);
+
+		json!({
+			"variableCollections": collections,
+			"variables": variables,
+		})
+	}
+
+	/// Create a file with a variable for each token. The `prefix` is `$` for
+	/// SCSS and `@` for Less.
+	///
+	/// Colors are resolved to their default values so that they can be used
+	/// with color functions.
+	pub fn to_variables(&self, prefix: &str, quote: impl Fn(&str) -> String) -> String {
+		let mut lines =
+			vec!["// This file was generated by skribble. Do not edit it manually.".to_string()];
+		let mut push = |name: String, value: String| {
+			lines.push(format!("{prefix}{}: {value};", get_variable_name(name)));
+		};
+
+		for (name, value) in self.config.palette.iter() {
+			push(format!("color-{name}"), self.to_static_color(value));
+		}
+
+		for (name, variable) in self.config.css_variables.iter() {
+			if variable.is_color() {
+				push(
+					format!("color-{name}"),
+					self.to_static_color(&variable.value),
+				);
+			} else if !variable.value.is_empty() {
+				push(
+					name.clone(),
+					Placeholder::normalize(&variable.value, self.config),
+				);
+			}
+		}
+
+		for (name, value_set) in self.config.value_sets.iter() {
+			for (key, value) in value_set.values.iter() {
+				match value {
+					CssValue::Value(value) => {
+						push(
+							format!("{name}-{key}"),
+							Placeholder::normalize(value, self.config),
+						);
+					}
+					CssValue::Object(properties) => {
+						for (property, value) in properties.iter() {
+							push(
+								format!("{name}-{key}-{property}"),
+								Placeholder::normalize(value, self.config),
+							);
+						}
+					}
+				}
+			}
+		}
+
+		for media_query in self.config.get_media_queries() {
+			let query = Placeholder::normalize(&media_query.query, self.config);
+			push(format!("media-{}", media_query.name), quote(&query));
+		}
+
+		lines.push(String::new());
+		lines.join("\n")
+	}
+
+	fn add_figma_collection(
+		&self,
+		name: &str,
+		tokens: Vec<(String, Option<String>, IndexMap<String, String>)>,
+		collections: &mut Vec<Value>,
+		variables: &mut Vec<Value>,
+	) {
+		if tokens.is_empty() {
+			return;
+		}
+
+		let mut modes = vec![DEFAULT_MODE.to_string()];
+
+		for (_, _, values) in tokens.iter() {
+			for mode in values.keys() {
+				if !modes.contains(mode) {
+					modes.push(mode.clone());
+				}
+			}
+		}
+
+		for (token_name, description, values) in tokens {
+			let default_value = values.get(DEFAULT_MODE).cloned().unwrap_or_default();
+			let (resolved_type, _) = self.to_figma_value(&default_value);
+			let values_by_mode = modes
+				.iter()
+				.map(|mode| {
+					let value = values.get(mode).unwrap_or(&default_value);
+					(mode.clone(), self.to_figma_value(value).1)
+				})
+				.collect::<Map<_, _>>();
+
+			variables.push(json!({
+				"id": format!("{name}/{token_name}"),
+				"name": token_name,
+				"variableCollectionId": name,
+				"resolvedType": resolved_type,
+				"description": description.unwrap_or_default(),
+				"valuesByMode": values_by_mode,
+			}));
+		}
+
+		let modes = modes
+			.into_iter()
+			.map(|mode| json!({ "modeId": mode, "name": mode }))
+			.collect::<Vec<_>>();
+
+		collections.push(json!({
+			"id": name,
+			"name": name,
+			"modes": modes,
+			"defaultModeId": DEFAULT_MODE,
+		}));
+	}
+
+	/// Get the Figma type and value for the css value.
+	fn to_figma_value(&self, value: &str) -> (&'static str, Value) {
+		match get_reference(value) {
+			Some(Reference::Palette(name)) => {
+				return (
+					"COLOR",
+					json!({ "type": "VARIABLE_ALIAS", "id": format!("palette/{name}") }),
+				);
+			}
+			Some(Reference::Variable(name)) => {
+				let resolved_type =
+					self.config
+						.css_variables
+						.get(name)
+						.map_or("STRING", |variable| {
+							if variable.is_color() {
+								"COLOR"
+							} else {
+								self.to_figma_value(&variable.value).0
+							}
+						});
+
+				return (
+					resolved_type,
+					json!({ "type": "VARIABLE_ALIAS", "id": format!("variables/{name}") }),
+				);
+			}
+			None => {}
+		}
+
+		let value = Placeholder::normalize(value, self.config);
+
+		if let Some(number) = get_pixels(&value) {
+			return ("FLOAT", json!(number));
+		}
+
+		match self.config.parse_color(&value).map(Color::into_rgb) {
+			Ok(color) => {
+				let Some(rgba) = color.get_rgb() else {
+					return ("STRING", json!(value));
+				};
+
+				(
+					"COLOR",
+					json!({
+						"r": round_channel(rgba.red),
+						"g": round_channel(rgba.green),
+						"b": round_channel(rgba.blue),
+						"a": round_channel(rgba.alpha),
+					}),
+				)
+			}
+			Err(_) => ("STRING", json!(value)),
+		}
+	}
+
+	/// Convert the css value into a token value where references to the
+	/// palette and css variables become aliases.
+	fn to_dtcg_value(&self, value: &str) -> String {
+		let value = replace_placeholder(value, "var(__:CSS_VARIABLE::", ":__)", |name| {
+			let variable = self.config.css_variables.get(name)?;
+			let group = if variable.is_color() {
+				"color"
+			} else {
+				"variables"
+			};
+
+			Some(format!("{{{group}.{name}}}"))
+		});
+		let value = replace_placeholder(&value, "__:PALETTE::", ":__", |name| {
+			self.config
+				.palette
+				.contains_key(name)
+				.then(|| format!("{{color.{name}}}"))
+		});
+
+		Placeholder::normalize(value, self.config)
+	}
+
+	/// Resolve the color to a static value.
+	fn to_static_color(&self, value: &str) -> String {
+		self.config.parse_color(value).map_or_else(
+			|_| Placeholder::normalize(value, self.config),
+			|color| color.to_string(),
+		)
+	}
+}
+
+enum Reference<'a> {
+	Palette(&'a str),
+	Variable(&'a str),
+}
+
+/// Get the referenced palette color or css variable when the value is only a
+/// reference.
+fn get_reference(value: &str) -> Option<Reference<'_>> {
+	let value = value.trim();
+
+	if let Some(name) = strip_placeholder(value, Placeholder::PALETTE) {
+		return Some(Reference::Palette(name));
+	}
+
+	let inner = value.strip_prefix("var(")?.strip_suffix(')')?;
+	strip_placeholder(inner, Placeholder::CSS_VARIABLE).map(Reference::Variable)
+}
+
+fn strip_placeholder<'a>(value: &'a str, namespace: &str) -> Option<&'a str> {
+	value
+		.strip_prefix("__:")?
+		.strip_prefix(namespace)?
+		.strip_prefix("::")?
+		.strip_suffix(":__")
+		.filter(|name| is_name(name))
+}
+
+/// Replace the content between `start` and `end` when `replace` returns a
+/// value.
+fn replace_placeholder(
+	value: &str,
+	start: &str,
+	end: &str,
+	replace: impl Fn(&str) -> Option<String>,
+) -> String {
+	let mut result = String::new();
+	let mut rest = value;
+
+	while let Some(index) = rest.find(start) {
+		let (before, after) = rest.split_at(index);
+		result.push_str(before);
+
+		let content = after.get(start.len()..).unwrap_or_default();
+		let replacement = content.find(end).and_then(|end_index| {
+			let name = content.get(..end_index).filter(|name| is_name(name))?;
+			let remaining = content.get(end_index + end.len()..)?;
+			replace(name).map(|replacement| (replacement, remaining))
+		});
+
+		match replacement {
+			Some((replacement, remaining)) => {
+				result.push_str(&replacement);
+				rest = remaining;
+			}
+			None => {
+				result.push_str(start);
+				rest = content;
+			}
+		}
+	}
+
+	result.push_str(rest);
+	result
+}
+
+fn is_name(name: &str) -> bool {
+	!name.is_empty()
+		&& name
+			.chars()
+			.all(|ch| ch.is_alphanumeric() || matches!(ch, '-' | '_'))
+}
+
+/// Get the mode name for a media query which is either a placeholder or the
+/// query itself.
+fn get_mode_name(query: &str) -> String {
+	strip_placeholder(query, Placeholder::MEDIA_QUERY)
+		.unwrap_or(query)
+		.to_string()
+}
+
+/// Get the width from a `(min-width: 640px)` media query.
+fn get_min_width(query: &str) -> Option<&str> {
+	query
+		.trim()
+		.strip_prefix("(min-width:")?
+		.strip_suffix(')')
+		.map(str::trim)
+		.filter(|width| !width.contains(['(', ')']))
+}
+
+/// Convert `px` and `rem` dimensions and plain numbers into pixels.
+fn get_pixels(value: &str) -> Option<f64> {
+	let value = value.trim();
+
+	if let Some(number) = value.strip_suffix("px") {
+		return number.parse().ok();
+	}
+
+	if let Some(number) = value.strip_suffix("rem") {
+		return number.parse::<f64>().ok().map(|rem| rem * ROOT_FONT_SIZE);
+	}
+
+	value.parse().ok()
+}
+
+/// Round the color channel to remove floating point noise.
+fn round_channel(channel: f32) -> f64 {
+	(f64::from(channel) * 1e6).round() / 1e6
+}
+
+fn insert_inferred_type(token: &mut Map<String, Value>, value: &str) {
+	let value = value.trim();
+	let number = value.trim_end_matches(|ch: char| ch.is_ascii_alphabetic());
+	let unit = value.get(number.len()..).unwrap_or_default();
+
+	if number.is_empty() || number.parse::<f64>().is_err() {
+		return;
+	}
+
+	let kind = match unit {
+		"" => "number",
+		"px" | "rem" => "dimension",
+		"ms" | "s" => "duration",
+		_ => return,
+	};
+
+	token.insert("$type".into(), kind.into());
+}
+
+fn group_with_type(kind: &str) -> Map<String, Value> {
+	let mut group = Map::new();
+	group.insert("$type".into(), kind.into());
+	group
+}
+
+/// Make the name safe to use as a SCSS or Less variable.
+fn get_variable_name(name: String) -> String {
+	name.chars()
+		.map(|ch| {
+			if ch.is_alphanumeric() || matches!(ch, '-' | '_') {
+				ch
+			} else {
+				'_'
+			}
+		})
+		.collect()
+}
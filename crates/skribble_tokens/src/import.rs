@@ -0,0 +1,477 @@
+use std::cell::RefCell;
+
+use heck::ToKebabCase;
+use indexmap::IndexMap;
+use indexmap::IndexSet;
+use serde_json::Map;
+use serde_json::Value;
+use skribble_core::*;
+
+use crate::replace_references;
+use crate::Token;
+use crate::Tokens;
+use crate::TokensError;
+use crate::TokensResult;
+
+const COLOR: &str = "color";
+const TYPOGRAPHY: &str = "typography";
+
+/// Maps the design tokens onto the plugin configuration.
+pub(crate) struct TokenImporter<'a> {
+	tokens: &'a Tokens,
+	breakpoint_groups: &'a StringList,
+	/// The ids of the palette and value tokens which are referenced by other
+	/// tokens. These are exposed as css variables.
+	referenced: RefCell<IndexSet<String>>,
+}
+
+/// How a token is added to the configuration.
+enum TokenTarget {
+	Palette,
+	ColorVariable,
+	Breakpoint(String),
+	Keyframes,
+	ValueSet(String),
+}
+
+impl<'a> TokenImporter<'a> {
+	pub(crate) fn new(tokens: &'a Tokens, breakpoint_groups: &'a StringList) -> Self {
+		Self {
+			tokens,
+			breakpoint_groups,
+			referenced: RefCell::default(),
+		}
+	}
+
+	pub(crate) fn import(&self, config: &mut PluginConfig) -> TokensResult<()> {
+		let mut value_sets = IndexMap::<String, CssValues>::new();
+		let mut breakpoints = IndexMap::<String, Vec<MediaQuery>>::new();
+
+		for token in self.tokens.iter() {
+			match self.get_target(token)? {
+				TokenTarget::Palette => {
+					let value = self.get_css(token, &mut vec![])?;
+					config.palette.insert(token.name(), value);
+				}
+				TokenTarget::ColorVariable => {
+					let value = self.get_css(token, &mut vec![])?;
+					let name = token.name();
+					let variable = CssVariable::builder()
+						.name(&name)
+						.variable(format!("--{name}"))
+						.syntax(PropertySyntaxValue::Color)
+						.value(value)
+						.media_queries(self.get_media_queries(token)?);
+
+					config.variables.push(match token.description {
+						Some(ref description) => variable.description(description).build(),
+						None => variable.build(),
+					});
+				}
+				TokenTarget::Breakpoint(group) => {
+					let value = self.get_css(token, &mut vec![])?;
+					let query = if value.starts_with('(') {
+						value
+					} else {
+						format!("(min-width: {value})")
+					};
+					let media_query = MediaQuery::builder().name(token.name()).query(query);
+
+					breakpoints
+						.entry(group)
+						.or_default()
+						.push(match token.description {
+							Some(ref description) => media_query.description(description).build(),
+							None => media_query.build(),
+						});
+				}
+				TokenTarget::Keyframes => {
+					let keyframe = Keyframe::builder()
+						.name(token.name())
+						.rules(self.get_keyframe_rules(token)?);
+
+					config.keyframes.push(match token.description {
+						Some(ref description) => keyframe.description(description).build(),
+						None => keyframe.build(),
+					});
+				}
+				TokenTarget::ValueSet(name) => {
+					let value = self.get_css_value(token)?;
+					value_sets
+						.entry(name)
+						.or_default()
+						.insert(token.key().to_string(), value);
+				}
+			}
+		}
+
+		for (name, items) in breakpoints {
+			config
+				.media_queries
+				.extend_group(Group::builder().name(name).items(items).build());
+		}
+
+		for (name, values) in value_sets {
+			config
+				.value_sets
+				.push(ValueSet::builder().name(name).values(values).build());
+		}
+
+		for id in self.referenced.take() {
+			let Some(token) = self.tokens.get(&id) else {
+				continue;
+			};
+
+			// The variables of palette colors keep the configured color format.
+			let (value, syntax) = match self.get_target(token)? {
+				TokenTarget::Palette => {
+					(
+						Placeholder::palette(token.name()),
+						PropertySyntaxValue::Color,
+					)
+				}
+				_ => (self.get_css(token, &mut vec![])?, PropertySyntaxValue::Any),
+			};
+
+			let name = token.full_name();
+			let variable = CssVariable::builder()
+				.name(&name)
+				.variable(format!("--{name}"))
+				.value(value)
+				.syntax(syntax);
+
+			config.variables.push(match token.description {
+				Some(ref description) => variable.description(description).build(),
+				None => variable.build(),
+			});
+		}
+
+		Ok(())
+	}
+
+	fn get_target(&self, token: &Token) -> TokensResult<TokenTarget> {
+		let kind = self.get_kind(token)?;
+		let category = token.category();
+
+		if let Some(category) = category {
+			if self.breakpoint_groups.iter().any(|group| group == category) {
+				return Ok(TokenTarget::Breakpoint(category.to_kebab_case()));
+			}
+		}
+
+		if category == Some("mediaQueries") {
+			return Ok(TokenTarget::Breakpoint("media-queries".into()));
+		}
+
+		let is_keyframes = matches!(kind.as_deref(), Some("keyframes" | "animation"))
+			|| matches!(category, Some("keyframes" | "animation" | "animations"));
+
+		if is_keyframes && token.value.is_object() {
+			return Ok(TokenTarget::Keyframes);
+		}
+
+		if kind.as_deref() == Some(COLOR) {
+			return Ok(
+				if token.has_references() || token.media_queries().is_some() {
+					TokenTarget::ColorVariable
+				} else {
+					TokenTarget::Palette
+				},
+			);
+		}
+
+		let name = token
+			.parent_name()
+			.or_else(|| kind.map(|kind| kind.to_kebab_case()))
+			.unwrap_or_else(|| "tokens".into());
+
+		Ok(TokenTarget::ValueSet(name))
+	}
+
+	/// Get the type of the token. Aliases without a type inherit the type of
+	/// the referenced token and tokens within a `color` group are colors.
+	fn get_kind(&self, token: &Token) -> TokensResult<Option<String>> {
+		let mut visited = Vec::<String>::new();
+		let mut current = token;
+
+		loop {
+			if let Some(ref kind) = current.kind {
+				return Ok(Some(kind.clone()));
+			}
+
+			if matches!(current.category(), Some("color" | "colors")) {
+				return Ok(Some(COLOR.into()));
+			}
+
+			let Some(alias) = current.alias() else {
+				return Ok(None);
+			};
+
+			if visited.iter().any(|id| id == alias) {
+				return Err(TokensError::CircularAlias(token.id()));
+			}
+
+			visited.push(alias.to_string());
+			current = self.get_token(current, alias)?;
+		}
+	}
+
+	fn get_token(&self, token: &Token, id: &str) -> TokensResult<&'a Token> {
+		self.tokens
+			.get(id)
+			.ok_or_else(|| TokensError::UnresolvedAlias(token.id(), id.to_string()))
+	}
+
+	fn get_css_value(&self, token: &Token) -> TokensResult<CssValue> {
+		let kind = self.get_kind(token)?;
+
+		let (Some(TYPOGRAPHY) | None, Value::Object(map)) = (kind.as_deref(), &token.value) else {
+			return Ok(CssValue::Value(self.get_css(token, &mut vec![])?));
+		};
+
+		let mut properties = StringMap::default();
+
+		for (key, value) in map.iter() {
+			let kind = match key.as_str() {
+				"fontFamily" => "fontFamily",
+				"fontWeight" => "fontWeight",
+				"fontSize" | "letterSpacing" => "dimension",
+				_ => "",
+			};
+			let css = self.value_to_css(token, value, kind, &mut vec![token.id()])?;
+			properties.insert(key.to_kebab_case(), css);
+		}
+
+		Ok(CssValue::Object(properties))
+	}
+
+	/// Read the media query values stored in the skribble extension by the
+	/// [`crate::TokenExporter`].
+	fn get_media_queries(&self, token: &Token) -> TokensResult<NestedCssVariableSelectors> {
+		let mut media_queries = NestedCssVariableSelectors::default();
+
+		for (query, selectors) in token.media_queries().into_iter().flatten() {
+			let Value::Object(selectors) = selectors else {
+				return Err(TokensError::InvalidValue(token.id(), COLOR.into()));
+			};
+
+			let query = if query.starts_with('(') || query.starts_with('@') {
+				query.clone()
+			} else {
+				Placeholder::media_query(query)
+			};
+			let mut map = StringMap::default();
+
+			for (selector, value) in selectors.iter() {
+				let css = self.value_to_css(token, value, COLOR, &mut vec![token.id()])?;
+				map.insert(selector.clone(), css);
+			}
+
+			media_queries.insert(query, map);
+		}
+
+		Ok(media_queries)
+	}
+
+	fn get_keyframe_rules(&self, token: &Token) -> TokensResult<NestedStringMap> {
+		let Value::Object(ref offsets) = token.value else {
+			return Err(TokensError::InvalidValue(token.id(), "keyframes".into()));
+		};
+
+		let mut rules = NestedStringMap::default();
+
+		for (offset, properties) in offsets.iter() {
+			let Value::Object(properties) = properties else {
+				return Err(TokensError::InvalidValue(token.id(), "keyframes".into()));
+			};
+
+			let mut map = StringMap::default();
+
+			for (property, value) in properties.iter() {
+				let property = if property.starts_with("--") {
+					property.clone()
+				} else {
+					property.to_kebab_case()
+				};
+				let css = self.value_to_css(token, value, "", &mut vec![token.id()])?;
+				map.insert(property, css);
+			}
+
+			rules.insert(offset.clone(), map);
+		}
+
+		Ok(rules)
+	}
+
+	/// Get the css value of the token with all references resolved.
+	fn get_css(&self, token: &Token, visiting: &mut Vec<String>) -> TokensResult<String> {
+		let id = token.id();
+
+		if visiting.contains(&id) {
+			return Err(TokensError::CircularAlias(id));
+		}
+
+		let kind = self.get_kind(token)?.unwrap_or_default();
+		visiting.push(id);
+		let css = self.value_to_css(token, &token.value, &kind, visiting)?;
+		visiting.pop();
+
+		Ok(css)
+	}
+
+	/// Resolve a reference to another token. Palette colors and value tokens
+	/// are exposed as css variables which are referenced with `var()`. Media
+	/// queries can't use `var()` so the values are inlined for breakpoints.
+	fn get_reference(
+		&self,
+		token: &Token,
+		id: &str,
+		visiting: &mut Vec<String>,
+	) -> TokensResult<String> {
+		let target = self.get_token(token, id)?;
+		let css = self.get_css(target, visiting)?;
+
+		if let TokenTarget::Breakpoint(_) = self.get_target(token)? {
+			return Ok(css);
+		}
+
+		match self.get_target(target)? {
+			TokenTarget::ColorVariable => Ok(Placeholder::wrapped_variable(target.name(), None)),
+			TokenTarget::Palette | TokenTarget::ValueSet(_) => {
+				self.referenced.borrow_mut().insert(target.id());
+				Ok(Placeholder::wrapped_variable(target.full_name(), None))
+			}
+			TokenTarget::Breakpoint(_) | TokenTarget::Keyframes => Ok(css),
+		}
+	}
+
+	fn value_to_css(
+		&self,
+		token: &Token,
+		value: &Value,
+		kind: &str,
+		visiting: &mut Vec<String>,
+	) -> TokensResult<String> {
+		let invalid = || TokensError::InvalidValue(token.id(), kind.to_string());
+
+		match value {
+			Value::String(content) => {
+				replace_references(content, |id| self.get_reference(token, id, visiting))
+			}
+			Value::Number(number) => Ok(number.to_string()),
+			Value::Bool(value) => Ok(value.to_string()),
+			Value::Array(values) => {
+				let mut items = vec![];
+
+				for value in values.iter() {
+					let item = self.value_to_css(token, value, kind, visiting)?;
+
+					items.push(match (kind, value) {
+						("fontFamily", Value::String(_)) if item.contains(' ') => {
+							format!("\"{item}\"")
+						}
+						_ => item,
+					});
+				}
+
+				match kind {
+					"cubicBezier" => Ok(format!("cubic-bezier({})", items.join(", "))),
+					"fontFamily" | "shadow" | "gradient" => Ok(items.join(", ")),
+					_ => Ok(items.join(" ")),
+				}
+			}
+			Value::Object(map) => {
+				let mut get = |key: &str, kind: &str| -> TokensResult<Option<String>> {
+					map.get(key)
+						.map(|value| self.value_to_css(token, value, kind, visiting))
+						.transpose()
+				};
+
+				let parts = match kind {
+					COLOR => return self.color_to_css(token, map, visiting),
+					"dimension" | "duration" => {
+						let value = get("value", "")?.ok_or_else(invalid)?;
+						let unit = get("unit", "")?.unwrap_or_default();
+						return Ok(format!("{value}{unit}"));
+					}
+					"shadow" => {
+						let inset = map
+							.get("inset")
+							.and_then(Value::as_bool)
+							.unwrap_or_default()
+							.then(|| "inset".to_string());
+						vec![
+							inset,
+							get("offsetX", "dimension")?,
+							get("offsetY", "dimension")?,
+							get("blur", "dimension")?,
+							get("spread", "dimension")?,
+							get("color", COLOR)?,
+						]
+					}
+					"border" => {
+						vec![
+							get("width", "dimension")?,
+							get("style", "strokeStyle")?,
+							get("color", COLOR)?,
+						]
+					}
+					"transition" => {
+						vec![
+							get("duration", "duration")?,
+							get("timingFunction", "cubicBezier")?,
+							get("delay", "duration")?,
+						]
+					}
+					"gradient" => vec![get("color", COLOR)?, get("position", "")?],
+					_ => return Err(invalid()),
+				};
+
+				Ok(parts.into_iter().flatten().collect::<Vec<_>>().join(" "))
+			}
+			Value::Null => Err(invalid()),
+		}
+	}
+
+	/// Convert the object format for colors, e.g. `{ "colorSpace": "srgb",
+	/// "components": [1, 0, 0] }`.
+	fn color_to_css(
+		&self,
+		token: &Token,
+		map: &Map<String, Value>,
+		visiting: &mut Vec<String>,
+	) -> TokensResult<String> {
+		let invalid = || TokensError::InvalidValue(token.id(), COLOR.into());
+		let space = map.get("colorSpace").and_then(Value::as_str);
+		let components = map.get("components").and_then(Value::as_array);
+
+		let (Some(space), Some(components)) = (space, components) else {
+			return match map.get("hex") {
+				Some(hex) => self.value_to_css(token, hex, COLOR, visiting),
+				None => Err(invalid()),
+			};
+		};
+
+		let components = components
+			.iter()
+			.map(|value| self.value_to_css(token, value, "", visiting))
+			.collect::<TokensResult<Vec<_>>>()?
+			.join(" ");
+
+		let alpha = match map.get("alpha") {
+			Some(value) if value.as_f64() != Some(1.0) => {
+				format!(" / {}", self.value_to_css(token, value, "", visiting)?)
+			}
+			_ => String::new(),
+		};
+
+		match space {
+			"hsl" | "hwb" | "lab" | "lch" | "oklab" | "oklch" => {
+				Ok(format!("{space}({components}{alpha})"))
+			}
+			"srgb" | "srgb-linear" | "display-p3" | "a98-rgb" | "prophoto-rgb" | "rec2020"
+			| "xyz" | "xyz-d50" | "xyz-d65" => Ok(format!("color({space} {components}{alpha})")),
+			_ => Err(invalid()),
+		}
+	}
+}
@@ -0,0 +1,156 @@
+#![deny(clippy::all)]
+#![forbid(clippy::indexing_slicing)]
+
+doc_comment::doctest!("../readme.md");
+
+use std::path::PathBuf;
+
+pub use error::*;
+pub use export::*;
+use import::*;
+use serde::Deserialize;
+use serde::Serialize;
+use skribble_core::*;
+pub use tokens::*;
+use typed_builder::TypedBuilder;
+
+mod error;
+mod export;
+mod import;
+mod tokens;
+
+/// This plugin imports design tokens written in the [W3C Design Tokens
+/// Community Group](https://tr.designtokens.org/format/) format (or the older
+/// Style Dictionary format) into the configuration.
+///
+/// - `color` tokens become palette entries. Color tokens which reference other
+///   tokens become color css variables.
+/// - `dimension`, `fontFamily`, `fontWeight` and other value tokens become
+///   value sets named after their parent group.
+/// - Tokens within the [`TokensPlugin::breakpoint_groups`] become media
+///   queries.
+/// - `keyframes` tokens become keyframes.
+#[derive(Debug, Clone, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokensPlugin {
+	/// Where the design tokens are loaded from.
+	#[builder(setter(into))]
+	pub source: TokenSource,
+	/// The names of the top level groups which contain breakpoint tokens.
+	#[serde(default = "default_breakpoint_groups")]
+	#[builder(default = default_breakpoint_groups(), setter(into))]
+	pub breakpoint_groups: StringList,
+}
+
+impl Plugin for TokensPlugin {
+	fn get_data(&self) -> PluginData {
+		PluginData::builder()
+			.id("skribble_tokens")
+			.name("Tokens Plugin")
+			.description("This plugin imports W3C design tokens into the `skribble` configuration.")
+			.version(crate_version!())
+			.build()
+	}
+
+	fn mutate_config(&mut self, config: &mut PluginConfig, options: &Options) -> AnyEmptyResult {
+		let content = self.source.read(options)?;
+		let tokens = Tokens::from_json(content)?;
+
+		TokenImporter::new(&tokens, &self.breakpoint_groups).import(config)?;
+
+		Ok(())
+	}
+}
+
+/// The source of the design tokens.
+#[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenSource {
+	/// A path to the JSON token file. Relative paths are resolved from the
+	/// `root` option.
+	File(PathBuf),
+	/// The JSON content of the token file.
+	Json(String),
+}
+
+impl TokenSource {
+	pub fn file(path: impl Into<PathBuf>) -> Self {
+		Self::File(path.into())
+	}
+
+	pub fn json(content: impl Into<String>) -> Self {
+		Self::Json(content.into())
+	}
+
+	/// Read the JSON content of the token file.
+	pub fn read(&self, options: &Options) -> TokensResult<String> {
+		match self {
+			Self::File(path) => {
+				let path = options.root.join(path);
+				std::fs::read_to_string(&path)
+					.map_err(|source| TokensError::ReadFile { path, source })
+			}
+			Self::Json(content) => Ok(content.clone()),
+		}
+	}
+}
+
+/// This plugin exports the resolved configuration as design tokens so that
+/// designers and other tools can consume the same source of truth.
+///
+/// The palette, css variables, value sets, media queries and keyframes are
+/// written to a file for each of the [`TokenExportPlugin::formats`].
+#[derive(Debug, Clone, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenExportPlugin {
+	/// The formats to export.
+	#[serde(default = "default_formats")]
+	#[builder(default = default_formats(), setter(into))]
+	pub formats: Vec<TokenFormat>,
+	/// The directory where the token files are written.
+	#[serde(default = "default_directory")]
+	#[builder(default = default_directory(), setter(into))]
+	pub directory: PathBuf,
+}
+
+impl Plugin for TokenExportPlugin {
+	fn get_data(&self) -> PluginData {
+		PluginData::builder()
+			.id("skribble_tokens_export")
+			.name("Token Export Plugin")
+			.description("This plugin exports the `skribble` configuration as design tokens.")
+			.version(crate_version!())
+			.build()
+	}
+
+	fn generate_code(&mut self, config: &RunnerConfig) -> AnyResult<GeneratedFiles> {
+		let exporter = TokenExporter::new(config);
+		let mut files = GeneratedFiles::default();
+
+		for format in self.formats.iter() {
+			files.insert(
+				GeneratedFile::builder()
+					.path(self.directory.join(format.file_name()))
+					.content(exporter.export(*format)?)
+					.build(),
+			);
+		}
+
+		Ok(files)
+	}
+}
+
+fn default_formats() -> Vec<TokenFormat> {
+	vec![TokenFormat::Dtcg]
+}
+
+fn default_directory() -> PathBuf {
+	PathBuf::from("./tokens")
+}
+
+fn default_breakpoint_groups() -> StringList {
+	vec!["breakpoint", "breakpoints", "screens"].into()
+}
+
+#[cfg(test)]
+mod __tests;
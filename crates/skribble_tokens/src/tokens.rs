@@ -0,0 +1,279 @@
+use heck::ToKebabCase;
+use indexmap::IndexMap;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::TokensError;
+use crate::TokensResult;
+
+/// The key used for skribble specific data within `$extensions`.
+pub const SKRIBBLE_EXTENSION: &str = "dev.skribble";
+
+/// The design tokens read from a token file keyed by their dot separated
+/// path, e.g. `color.brand.500`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Tokens(IndexMap<String, Token>);
+
+impl Tokens {
+	/// Read the tokens from the JSON content of a token file.
+	pub fn from_json(content: impl AsRef<str>) -> TokensResult<Self> {
+		let value: Value =
+			serde_json::from_str(content.as_ref()).map_err(TokensError::InvalidJson)?;
+		Self::from_value(&value)
+	}
+
+	/// Read the tokens from a parsed JSON value.
+	pub fn from_value(value: &Value) -> TokensResult<Self> {
+		let Value::Object(map) = value else {
+			return Err(TokensError::InvalidRoot);
+		};
+
+		let mut tokens = Self::default();
+		tokens.collect(map, &[], None);
+
+		Ok(tokens)
+	}
+
+	pub fn get(&self, id: impl AsRef<str>) -> Option<&Token> {
+		self.0.get(id.as_ref())
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &Token> {
+		self.0.values()
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	fn collect(&mut self, group: &Map<String, Value>, path: &[String], kind: Option<&str>) {
+		let kind = group.get("$type").and_then(Value::as_str).or(kind);
+
+		for (key, value) in group.iter() {
+			if key.starts_with('$') {
+				continue;
+			}
+
+			let Value::Object(map) = value else {
+				continue;
+			};
+
+			let mut path = path.to_vec();
+			path.push(key.clone());
+
+			match Token::from_map(path.clone(), map, kind) {
+				Some(token) => {
+					self.0.insert(token.id(), token);
+				}
+				None => self.collect(map, &path, kind),
+			}
+		}
+	}
+}
+
+/// A single design token.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+	/// The names of the groups leading to the token followed by the name of the
+	/// token.
+	pub path: Vec<String>,
+	/// The type of the token. This is inherited from the parent groups when
+	/// not set on the token.
+	pub kind: Option<String>,
+	/// The raw value of the token.
+	pub value: Value,
+	/// The description of the token.
+	pub description: Option<String>,
+	/// The vendor specific `$extensions` of the token.
+	pub extensions: Map<String, Value>,
+}
+
+impl Token {
+	/// Create the token from a JSON object. Both the `$value` format and the
+	/// older Style Dictionary `value` format are supported. Returns `None` when
+	/// the object is a group.
+	fn from_map(path: Vec<String>, map: &Map<String, Value>, kind: Option<&str>) -> Option<Self> {
+		let get_string = |key: &str| map.get(key).and_then(Value::as_str).map(String::from);
+
+		if let Some(value) = map.get("$value") {
+			return Some(Self {
+				path,
+				kind: get_string("$type").or_else(|| kind.map(String::from)),
+				value: value.clone(),
+				description: get_string("$description"),
+				extensions: map
+					.get("$extensions")
+					.and_then(Value::as_object)
+					.cloned()
+					.unwrap_or_default(),
+			});
+		}
+
+		let value = map.get("value")?;
+
+		if value.is_object() && !map.contains_key("type") {
+			return None;
+		}
+
+		Some(Self {
+			path,
+			kind: get_string("type").or_else(|| kind.map(String::from)),
+			value: value.clone(),
+			description: get_string("description").or_else(|| get_string("comment")),
+			extensions: Map::new(),
+		})
+	}
+
+	/// The dot separated path of the token which is used to reference it from
+	/// other tokens.
+	pub fn id(&self) -> String {
+		self.path.join(".")
+	}
+
+	/// The top level group of the token, e.g. `color` for `color.brand.500`.
+	pub fn category(&self) -> Option<&str> {
+		match self.path.as_slice() {
+			[category, _, ..] => Some(category),
+			_ => None,
+		}
+	}
+
+	/// The name of the token without the top level group, e.g. `brand-500` for
+	/// `color.brand.500`.
+	pub fn name(&self) -> String {
+		match self.path.as_slice() {
+			[_, rest @ ..] if !rest.is_empty() => join_path(rest),
+			path => join_path(path),
+		}
+	}
+
+	/// The name of the token including every group, e.g. `spacing-md` for
+	/// `spacing.md`.
+	pub fn full_name(&self) -> String {
+		join_path(&self.path)
+	}
+
+	/// The name of the group containing the token, e.g. `font-size` for
+	/// `fontSize.lg`.
+	pub fn parent_name(&self) -> Option<String> {
+		match self.path.as_slice() {
+			[parent @ .., _] if !parent.is_empty() => {
+				Some(
+					parent
+						.iter()
+						.map(|segment| segment.to_kebab_case())
+						.collect::<Vec<_>>()
+						.join("-"),
+				)
+			}
+			_ => None,
+		}
+	}
+
+	/// The last segment of the path.
+	pub fn key(&self) -> &str {
+		self.path.last().map_or("", String::as_str)
+	}
+
+	/// The id of the referenced token when the whole value is an alias, e.g.
+	/// `{color.brand.500}`.
+	pub fn alias(&self) -> Option<&str> {
+		self.value.as_str().and_then(get_alias)
+	}
+
+	/// The values of the token for each media query which are stored in the
+	/// skribble extension.
+	pub fn media_queries(&self) -> Option<&Map<String, Value>> {
+		self.extensions
+			.get(SKRIBBLE_EXTENSION)?
+			.get("mediaQueries")?
+			.as_object()
+	}
+
+	/// Whether the value of the token references any other tokens.
+	pub fn has_references(&self) -> bool {
+		has_references(&self.value)
+	}
+}
+
+/// Get the id of the referenced token when the whole value is an alias.
+pub fn get_alias(value: &str) -> Option<&str> {
+	let id = value.trim().strip_prefix('{')?.strip_suffix('}')?;
+	is_reference_id(id).then_some(id)
+}
+
+/// Replace every `{token.id}` reference within the value.
+pub fn replace_references(
+	value: &str,
+	mut replace: impl FnMut(&str) -> TokensResult<String>,
+) -> TokensResult<String> {
+	let mut result = String::new();
+	let mut rest = value;
+
+	while let Some(start) = rest.find('{') {
+		let (before, after) = rest.split_at(start);
+		result.push_str(before);
+
+		let reference = after
+			.get(1..)
+			.and_then(|content| content.find('}').map(|end| (content, end)))
+			.and_then(|(content, end)| content.get(..end).zip(content.get(end + 1..)))
+			.filter(|(id, _)| is_reference_id(id));
+
+		match reference {
+			Some((id, remaining)) => {
+				result.push_str(&replace(id)?);
+				rest = remaining;
+			}
+			None => {
+				result.push('{');
+				rest = after.get(1..).unwrap_or_default();
+			}
+		}
+	}
+
+	result.push_str(rest);
+
+	Ok(result)
+}
+
+fn has_references(value: &Value) -> bool {
+	match value {
+		Value::String(content) => {
+			let mut found = false;
+			let _ = replace_references(content, |_| {
+				found = true;
+				Ok(String::new())
+			});
+			found
+		}
+		Value::Array(values) => values.iter().any(has_references),
+		Value::Object(map) => map.values().any(has_references),
+		_ => false,
+	}
+}
+
+fn is_reference_id(id: &str) -> bool {
+	!id.is_empty()
+		&& id
+			.chars()
+			.all(|ch| ch.is_alphanumeric() || matches!(ch, '.' | '-' | '_' | '@'))
+}
+
+fn join_path(segments: &[String]) -> String {
+	match segments {
+		[groups @ .., key] => {
+			let mut names = groups
+				.iter()
+				.map(|segment| segment.to_kebab_case())
+				.collect::<Vec<_>>();
+			names.push(key.clone());
+			names.join("-")
+		}
+		[] => String::new(),
+	}
+}